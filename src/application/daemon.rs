@@ -1,18 +1,25 @@
 //! Daemon transcription use case
 
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
 use thiserror::Error;
 use tokio::sync::Mutex;
 
-use crate::domain::daemon::{DaemonSession, DaemonState, InvalidStateTransition};
+use crate::domain::daemon::{
+    BreakerState, CircuitBreaker, DaemonSession, DaemonState, InvalidStateTransition,
+};
 use crate::domain::recording::Duration;
+use crate::domain::transcription::{char_count, word_count, TextTemplate, Timings};
 
 use super::output_dispatcher::{dispatch as dispatch_output, OutputOptions};
 use super::ports::{
-    Clipboard, Keystroke, NotificationIcon, Notifier, RecordingError, SmartPaste, Transcriber,
-    TranscriptionError, UnboundedRecorder,
+    AudioCue, AudioCueType, Clipboard, Keystroke, NotificationIcon, Notifier, PostProcessor,
+    RecordingError, SmartPaste, Transcriber, TranscriptionError, UnboundedRecorder,
+};
+use super::{
+    completion_title, copy_action, play_cue, post_process, warn, warn_if_clipping, HistoryLog,
+    RunClock, UseCaseDeps, WarningSink,
 };
-use super::{warn, UseCaseDeps, WarningSink};
 
 /// Errors from the daemon use case
 #[derive(Debug, Error)]
@@ -25,6 +32,15 @@ pub enum DaemonError {
 
     #[error("Invalid state transition: {0}")]
     InvalidState(#[from] InvalidStateTransition),
+
+    #[error("Recording too short ({elapsed}, minimum {min}); discarded without transcribing")]
+    TooShort { elapsed: Duration, min: Duration },
+
+    #[error("No audio detected (peak {peak_dbfs:.0} dBFS); check your microphone")]
+    NoAudio { peak_dbfs: f32 },
+
+    #[error("Transcription service unavailable, cooling down ({remaining} left)")]
+    CoolingDown { remaining: Duration },
 }
 
 /// Configuration for daemon mode
@@ -32,8 +48,15 @@ pub enum DaemonError {
 pub struct DaemonConfig {
     /// Maximum recording duration (safety limit)
     pub max_duration: Duration,
+    /// Recordings stopped before this are discarded (no API call)
+    pub min_duration: Option<Duration>,
+    /// Recordings whose peak stays at or below this level (dBFS) are
+    /// discarded as silent (no API call). `None` disables the check.
+    pub silence_threshold: Option<i32>,
     /// Whether to copy result to clipboard
     pub enable_clipboard: bool,
+    /// Append to the clipboard with this separator instead of replacing it
+    pub clipboard_append: Option<String>,
     /// Whether to type result into focused window
     pub enable_keystroke: bool,
     /// Whether to use smart paste (Linux KDE Wayland only)
@@ -43,17 +66,26 @@ pub struct DaemonConfig {
     /// Optional callback for non-fatal warnings. CLI plugs the presenter in;
     /// tests leave `None` to discard.
     pub warning_sink: Option<WarningSink>,
+    /// Where successful transcriptions are recorded; `None` disables history.
+    pub history: Option<HistoryLog>,
+    /// Text wrapped around each transcript before it is output.
+    pub template: TextTemplate,
 }
 
 impl Default for DaemonConfig {
     fn default() -> Self {
         Self {
             max_duration: Duration::default_max_duration(),
+            min_duration: None,
+            silence_threshold: None,
             enable_clipboard: false,
+            clipboard_append: None,
             enable_keystroke: false,
             enable_paste: false,
             enable_notify: false,
             warning_sink: None,
+            history: None,
+            template: TextTemplate::default(),
         }
     }
 }
@@ -62,15 +94,105 @@ impl std::fmt::Debug for DaemonConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("DaemonConfig")
             .field("max_duration", &self.max_duration)
+            .field("min_duration", &self.min_duration)
+            .field("silence_threshold", &self.silence_threshold)
             .field("enable_clipboard", &self.enable_clipboard)
+            .field("clipboard_append", &self.clipboard_append)
             .field("enable_keystroke", &self.enable_keystroke)
             .field("enable_paste", &self.enable_paste)
             .field("enable_notify", &self.enable_notify)
             .field("warning_sink", &self.warning_sink.is_some())
+            .field("history", &self.history.is_some())
+            .field("template", &self.template)
             .finish()
     }
 }
 
+/// The subset of [`DaemonConfig`] that can change while the daemon runs
+/// (`daemon reload` / SIGHUP). Adapters such as the recorder, transcriber and
+/// smart paste are fixed for the daemon's lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReloadableSettings {
+    pub max_duration: Duration,
+    pub min_duration: Option<Duration>,
+    pub silence_threshold: Option<i32>,
+    pub enable_clipboard: bool,
+    pub enable_keystroke: bool,
+    pub enable_notify: bool,
+}
+
+impl ReloadableSettings {
+    /// `key: old -> new` descriptions of every field that differs from `old`.
+    pub fn changes_from(&self, old: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+        let mut diff = |key: &str, before: String, after: String| {
+            if before != after {
+                changes.push(format!("{key}: {before} -> {after}"));
+            }
+        };
+        diff(
+            "max_duration",
+            old.max_duration.to_string(),
+            self.max_duration.to_string(),
+        );
+        let min = |d: Option<Duration>| d.map_or_else(|| "off".to_string(), |d| d.to_string());
+        diff(
+            "min_duration",
+            min(old.min_duration),
+            min(self.min_duration),
+        );
+        let threshold =
+            |db: Option<i32>| db.map_or_else(|| "off".to_string(), |db| format!("{db} dBFS"));
+        diff(
+            "silence_threshold",
+            threshold(old.silence_threshold),
+            threshold(self.silence_threshold),
+        );
+        diff(
+            "clipboard",
+            old.enable_clipboard.to_string(),
+            self.enable_clipboard.to_string(),
+        );
+        diff(
+            "keystroke",
+            old.enable_keystroke.to_string(),
+            self.enable_keystroke.to_string(),
+        );
+        diff(
+            "notify",
+            old.enable_notify.to_string(),
+            self.enable_notify.to_string(),
+        );
+        changes
+    }
+}
+
+impl DaemonConfig {
+    /// Current values of the reloadable fields.
+    pub fn reloadable(&self) -> ReloadableSettings {
+        ReloadableSettings {
+            max_duration: self.max_duration,
+            min_duration: self.min_duration,
+            silence_threshold: self.silence_threshold,
+            enable_clipboard: self.enable_clipboard,
+            enable_keystroke: self.enable_keystroke,
+            enable_notify: self.enable_notify,
+        }
+    }
+
+    /// Overwrite the reloadable fields; returns what changed.
+    fn apply(&mut self, settings: ReloadableSettings) -> Vec<String> {
+        let changes = settings.changes_from(&self.reloadable());
+        self.max_duration = settings.max_duration;
+        self.min_duration = settings.min_duration;
+        self.silence_threshold = settings.silence_threshold;
+        self.enable_clipboard = settings.enable_clipboard;
+        self.enable_keystroke = settings.enable_keystroke;
+        self.enable_notify = settings.enable_notify;
+        changes
+    }
+}
+
 /// Output from daemon transcription
 #[derive(Debug, Clone)]
 pub struct DaemonOutput {
@@ -78,12 +200,24 @@ pub struct DaemonOutput {
     pub text: String,
     /// Whether clipboard copy succeeded
     pub clipboard_copied: bool,
+    /// Clipboard backend the copy went through (`wl-copy`, `arboard`, ...)
+    pub clipboard_backend: Option<String>,
     /// Whether keystroke injection succeeded
     pub keystroke_sent: bool,
+    /// Tool that typed the text (`ydotool`, `enigo`, ...)
+    pub keystroke_backend: Option<String>,
     /// Whether smart paste succeeded
     pub paste_sent: bool,
     /// Audio file size in bytes. Presentation layer formats it.
     pub audio_size_bytes: u64,
+    /// Length of the captured audio, when the recorder measured it.
+    pub audio_duration_ms: Option<u64>,
+    /// Words in `text`
+    pub word_count: usize,
+    /// Characters in `text`
+    pub char_count: usize,
+    /// Where the time went (`--timing`).
+    pub timings: Timings,
 }
 
 /// Daemon transcription use case
@@ -91,26 +225,35 @@ pub struct DaemonTranscriptionUseCase<R, T, C, K, N, P>
 where
     R: UnboundedRecorder,
     T: Transcriber,
-    C: Clipboard,
+    C: Clipboard + 'static,
     K: Keystroke,
     N: Notifier,
     P: SmartPaste,
 {
     recorder: R,
     transcriber: T,
-    clipboard: C,
+    clipboard: Arc<C>,
     keystroke: K,
     notifier: N,
     smart_paste: P,
+    audio_cue: Arc<dyn AudioCue>,
+    post_processor: Option<Arc<dyn PostProcessor>>,
     session: Arc<Mutex<DaemonSession>>,
-    config: DaemonConfig,
+    clock: std::sync::Mutex<RunClock>,
+    /// Refuses recordings while transcription keeps failing; timed from
+    /// `started`.
+    breaker: std::sync::Mutex<CircuitBreaker>,
+    started: Instant,
+    /// Swappable at runtime via [`reload`](Self::reload). Methods take a
+    /// snapshot up front so a reload never changes behaviour mid-operation.
+    config: RwLock<DaemonConfig>,
 }
 
 impl<R, T, C, K, N, P> DaemonTranscriptionUseCase<R, T, C, K, N, P>
 where
     R: UnboundedRecorder,
     T: Transcriber,
-    C: Clipboard,
+    C: Clipboard + 'static,
     K: Keystroke,
     N: Notifier,
     P: SmartPaste,
@@ -121,15 +264,98 @@ where
         Self {
             recorder: deps.recorder,
             transcriber: deps.transcriber,
-            clipboard: deps.clipboard,
+            clipboard: Arc::new(deps.clipboard),
             keystroke: deps.keystroke,
             notifier: deps.notifier,
             smart_paste: deps.smart_paste,
+            audio_cue: deps.audio_cue,
+            post_processor: None,
             session: Arc::new(Mutex::new(DaemonSession::new())),
-            config,
+            clock: std::sync::Mutex::new(RunClock::default()),
+            breaker: std::sync::Mutex::new(CircuitBreaker::default()),
+            started: Instant::now(),
+            config: RwLock::new(config),
         }
     }
 
+    /// Builder: rewrite every transcript with `processor` (`--post-command`)
+    /// before it is output.
+    pub fn with_post_processor(mut self, processor: Option<Arc<dyn PostProcessor>>) -> Self {
+        self.post_processor = processor;
+        self
+    }
+
+    /// Snapshot of the current configuration.
+    pub fn config(&self) -> DaemonConfig {
+        self.config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn clock(&self) -> std::sync::MutexGuard<'_, RunClock> {
+        self.clock.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Builder: replace the default circuit breaker.
+    pub fn with_breaker(self, breaker: CircuitBreaker) -> Self {
+        *self.breaker.lock().unwrap_or_else(|e| e.into_inner()) = breaker;
+        self
+    }
+
+    /// Run `f` on the breaker with the current time, logging any state
+    /// change it makes.
+    fn update_breaker<O>(
+        &self,
+        sink: Option<&WarningSink>,
+        f: impl FnOnce(&mut CircuitBreaker, u64) -> O,
+    ) -> O {
+        let now_ms = u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        let mut breaker = self.breaker.lock().unwrap_or_else(|e| e.into_inner());
+        let before = breaker.state();
+        let result = f(&mut breaker, now_ms);
+        let after = breaker.state();
+        if after != before {
+            tracing::info!(from = %before, to = %after, "circuit breaker state changed");
+            if after == BreakerState::Open {
+                warn(
+                    sink,
+                    "transcription keeps failing; recordings are paused for a while",
+                );
+            }
+        }
+        result
+    }
+
+    /// Apply new reloadable settings; returns descriptions of what changed
+    /// (empty if nothing did).
+    pub fn reload(&self, settings: ReloadableSettings) -> Vec<String> {
+        self.config
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .apply(settings)
+    }
+
+    /// Derive new reloadable settings from the current ones and apply them
+    /// under a single lock, so a concurrent reload can't slip in between.
+    /// Nothing changes if `update` fails.
+    pub fn update_settings<E>(
+        &self,
+        update: impl FnOnce(ReloadableSettings) -> Result<ReloadableSettings, E>,
+    ) -> Result<Vec<String>, E> {
+        let mut config = self.config.write().unwrap_or_else(|e| e.into_inner());
+        let settings = update(config.reloadable())?;
+        Ok(config.apply(settings))
+    }
+
+    /// Current recording safety limit.
+    pub fn max_duration(&self) -> Duration {
+        self.config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .max_duration
+    }
+
     /// Get current daemon state
     pub async fn state(&self) -> DaemonState {
         self.session.lock().await.state()
@@ -154,12 +380,34 @@ where
             }
         }
 
+        let config = self.config();
+
+        // 0. While the service keeps failing, don't record something that
+        //    can't be transcribed.
+        if let Err(remaining) =
+            self.update_breaker(config.warning_sink.as_ref(), |b, now| b.check(now))
+        {
+            if config.enable_notify {
+                let _ = self
+                    .notifier
+                    .notify(
+                        "SmartScribe",
+                        "Transcription service unavailable, cooling down",
+                        NotificationIcon::Warning,
+                    )
+                    .await;
+            }
+            return Err(DaemonError::CoolingDown {
+                remaining: Duration::from_secs(remaining.as_millis().div_ceil(1000)),
+            });
+        }
+
         // 1. Capture active window for smart paste. Warning-only — if this
         //    fails we still want to record; the user just loses paste.
-        if self.config.enable_paste {
+        if config.enable_paste {
             if let Err(e) = self.smart_paste.capture_active_window().await {
                 warn(
-                    self.config.warning_sink.as_ref(),
+                    config.warning_sink.as_ref(),
                     &format!("failed to capture active window: {}", e),
                 );
             }
@@ -168,7 +416,8 @@ where
         // 2. Start the recorder. Fatal failure mode — if this errors the
         //    session never transitions to Recording, so callers see Idle
         //    plus a returned error.
-        self.recorder.start().await?;
+        self.clock().start();
+        self.recorder.start(Some(config.max_duration)).await?;
 
         // 3. Transition the session. If we lose the race against another
         //    caller we have to roll back the recorder we just started.
@@ -180,9 +429,10 @@ where
                 return Err(e.into());
             }
         }
+        play_cue(&self.audio_cue, AudioCueType::RecordingStart);
 
         // 4. Notify (best-effort, never fatal).
-        if self.config.enable_notify {
+        if config.enable_notify {
             let _ = self
                 .notifier
                 .notify(
@@ -203,6 +453,12 @@ where
     /// transition: if the recorder fails the session stays Recording (so
     /// the user can retry / cancel) rather than getting stuck in
     /// Processing with no audio buffer.
+    ///
+    /// A recording shorter than `min_duration` is cancelled instead: the
+    /// session returns to Idle and [`DaemonError::TooShort`] is returned.
+    /// Likewise a recording whose peak never exceeds `silence_threshold`
+    /// is dropped with [`DaemonError::NoAudio`], and one whose input
+    /// device failed returns to Idle with [`RecordingError::DeviceLost`].
     pub async fn stop_recording(
         &self,
     ) -> Result<crate::domain::transcription::AudioData, DaemonError> {
@@ -218,12 +474,83 @@ where
             }
         }
 
-        let audio = self.recorder.stop().await?;
+        let config = self.config();
+        let elapsed = Duration::from_millis(self.recorder.elapsed_ms());
+        if let Some(min) = config.min_duration.filter(|min| elapsed < *min) {
+            self.cancel_quietly().await?;
+            play_cue(&self.audio_cue, AudioCueType::RecordingCancel);
+            if config.enable_notify {
+                let _ = self
+                    .notifier
+                    .notify(
+                        "SmartScribe",
+                        "Recording too short, discarded",
+                        NotificationIcon::Warning,
+                    )
+                    .await;
+            }
+            return Err(DaemonError::TooShort { elapsed, min });
+        }
+
+        let audio = match self.recorder.stop().await {
+            Ok(audio) => audio,
+            // The stream is gone; there's nothing to retry, so don't leave
+            // the session stuck in Recording.
+            Err(e @ RecordingError::DeviceLost(_)) => {
+                self.session.lock().await.cancel_recording()?;
+                play_cue(&self.audio_cue, AudioCueType::RecordingCancel);
+                if config.enable_notify {
+                    let _ = self
+                        .notifier
+                        .notify(
+                            "SmartScribe",
+                            "Recording failed: audio device lost",
+                            NotificationIcon::Error,
+                        )
+                        .await;
+                }
+                return Err(e.into());
+            }
+            Err(e) => return Err(e.into()),
+        };
+        self.clock().stop();
+
+        if let Some(threshold) = config.silence_threshold {
+            if audio.is_silent(threshold) {
+                self.session.lock().await.cancel_recording()?;
+                play_cue(&self.audio_cue, AudioCueType::RecordingCancel);
+                if config.enable_notify {
+                    let _ = self
+                        .notifier
+                        .notify(
+                            "SmartScribe",
+                            "No audio detected, check your microphone",
+                            NotificationIcon::Error,
+                        )
+                        .await;
+                }
+                return Err(DaemonError::NoAudio {
+                    peak_dbfs: audio.peak_dbfs().unwrap_or(f32::NEG_INFINITY),
+                });
+            }
+        }
+
+        if audio.is_truncated() {
+            warn(
+                config.warning_sink.as_ref(),
+                &format!(
+                    "recording ran past max_duration ({}); audio after that was dropped",
+                    config.max_duration
+                ),
+            );
+        }
+        warn_if_clipping(config.warning_sink.as_ref(), &audio);
 
         {
             let mut session = self.session.lock().await;
             session.stop_recording()?;
         }
+        play_cue(&self.audio_cue, AudioCueType::RecordingStop);
 
         Ok(audio)
     }
@@ -234,9 +561,12 @@ where
         audio: crate::domain::transcription::AudioData,
     ) -> Result<DaemonOutput, DaemonError> {
         let audio_size_bytes = audio.size_bytes() as u64;
+        let audio_duration_ms = audio.duration_ms();
+        let config = self.config();
+        let timer = self.clock().take();
 
         // Notify transcription start
-        if self.config.enable_notify {
+        if config.enable_notify {
             let _ = self
                 .notifier
                 .notify(
@@ -249,30 +579,56 @@ where
 
         // Transcribe. If this fails we roll back the session to Idle so
         // the daemon doesn't get stuck in Processing forever.
-        let text = match self.transcriber.transcribe(&audio).await {
-            Ok(t) => t,
+        let request_started = Instant::now();
+        let transcript = match self.transcriber.transcribe(&audio).await {
+            Ok(t) => {
+                self.update_breaker(config.warning_sink.as_ref(), |b, _| b.record_success());
+                t
+            }
             Err(e) => {
+                if e.is_outage() {
+                    self.update_breaker(config.warning_sink.as_ref(), |b, now| {
+                        b.record_failure(now)
+                    });
+                }
                 let mut session = self.session.lock().await;
                 let _ = session.fail_processing();
                 return Err(e.into());
             }
         };
+        let transcription = request_started.elapsed();
+        let transcript = post_process(
+            self.post_processor.as_ref(),
+            transcript,
+            config.warning_sink.as_ref(),
+        )
+        .await;
+        // `transcript` is now the post-processed text. Everything output
+        // also gets the template; history and the counts take it without.
+        let text = config.template.apply(&transcript);
 
         let opts = OutputOptions {
-            clipboard: self.config.enable_clipboard,
-            keystroke: self.config.enable_keystroke,
-            paste: self.config.enable_paste,
+            clipboard: config.enable_clipboard,
+            clipboard_append: config.clipboard_append.as_deref(),
+            keystroke: config.enable_keystroke,
+            paste: config.enable_paste,
         };
         let result = dispatch_output(
-            &self.clipboard,
+            self.clipboard.as_ref(),
             &self.keystroke,
             &self.smart_paste,
             &text,
             opts,
-            self.config.warning_sink.as_ref(),
+            config.warning_sink.as_ref(),
         )
         .await;
 
+        if let Some(ref history) = config.history {
+            history
+                .record(&transcript, &audio, config.warning_sink.as_ref())
+                .await;
+        }
+
         // Complete processing
         {
             let mut session = self.session.lock().await;
@@ -280,23 +636,29 @@ where
         }
 
         // Notify completion
-        if self.config.enable_notify {
+        if config.enable_notify {
             let _ = self
                 .notifier
-                .notify(
-                    "SmartScribe",
-                    "Transcription complete!",
-                    NotificationIcon::Success,
+                .notify_result(
+                    &completion_title(audio_duration_ms),
+                    &text,
+                    Some(copy_action(&self.clipboard, &text)),
                 )
                 .await;
         }
 
         Ok(DaemonOutput {
+            word_count: word_count(&transcript),
+            char_count: char_count(&transcript),
             text,
             clipboard_copied: result.clipboard_copied,
+            clipboard_backend: result.clipboard_backend,
             keystroke_sent: result.keystroke_sent,
+            keystroke_backend: result.keystroke_backend,
             paste_sent: result.paste_sent,
             audio_size_bytes,
+            audio_duration_ms,
+            timings: timer.finish(&audio, Some(transcription)),
         })
     }
 
@@ -308,16 +670,11 @@ where
 
     /// Cancel recording without transcription
     pub async fn cancel(&self) -> Result<(), DaemonError> {
-        {
-            let mut session = self.session.lock().await;
-            session.cancel_recording()?;
-        }
-
-        // Cancel the recording
-        self.recorder.cancel().await?;
+        self.cancel_quietly().await?;
+        play_cue(&self.audio_cue, AudioCueType::RecordingCancel);
 
         // Notify cancellation
-        if self.config.enable_notify {
+        if self.config().enable_notify {
             let _ = self
                 .notifier
                 .notify(
@@ -331,10 +688,27 @@ where
         Ok(())
     }
 
+    /// Return to Idle and drop the recorder's buffer, without a cue or
+    /// notification.
+    async fn cancel_quietly(&self) -> Result<(), DaemonError> {
+        {
+            let mut session = self.session.lock().await;
+            session.cancel_recording()?;
+        }
+        self.recorder.cancel().await?;
+        Ok(())
+    }
+
     /// Check if recording has exceeded max duration
     pub fn check_max_duration(&self) -> bool {
         let elapsed = self.recorder.elapsed_ms();
-        elapsed >= self.config.max_duration.as_millis()
+        elapsed >= self.max_duration().as_millis()
+    }
+
+    /// Check if the input device failed mid-recording; stopping will then
+    /// return the error and put the session back to Idle.
+    pub fn check_device_failed(&self) -> bool {
+        self.recorder.has_failed()
     }
 
     /// Get elapsed recording time in milliseconds
@@ -342,6 +716,11 @@ where
         self.recorder.elapsed_ms()
     }
 
+    /// Get the recorder's current input level, if it meters one
+    pub fn audio_level(&self) -> Option<f32> {
+        self.recorder.level()
+    }
+
     /// Check if currently recording
     pub fn is_recording(&self) -> bool {
         self.recorder.is_recording()
@@ -352,7 +731,8 @@ where
 mod tests {
     use super::*;
     use crate::application::ports::{
-        ClipboardError, KeystrokeError, NotificationError, SmartPasteError,
+        AudioCueError, ClipboardError, KeystrokeError, NotificationAction, NotificationError,
+        SmartPasteError,
     };
     use crate::domain::transcription::AudioData;
     use async_trait::async_trait;
@@ -361,6 +741,10 @@ mod tests {
     struct MockUnboundedRecorder {
         recording: AtomicBool,
         elapsed: AtomicU64,
+        silent: AtomicBool,
+        truncated: AtomicBool,
+        failed: AtomicBool,
+        limit: std::sync::Mutex<Option<Duration>>,
     }
 
     impl MockUnboundedRecorder {
@@ -368,20 +752,33 @@ mod tests {
             Self {
                 recording: AtomicBool::new(false),
                 elapsed: AtomicU64::new(0),
+                silent: AtomicBool::new(false),
+                truncated: AtomicBool::new(false),
+                failed: AtomicBool::new(false),
+                limit: std::sync::Mutex::new(None),
             }
         }
     }
 
     #[async_trait]
     impl UnboundedRecorder for MockUnboundedRecorder {
-        async fn start(&self) -> Result<(), RecordingError> {
+        async fn start(&self, limit: Option<Duration>) -> Result<(), RecordingError> {
             self.recording.store(true, Ordering::SeqCst);
+            *self.limit.lock().unwrap() = limit;
             Ok(())
         }
 
         async fn stop(&self) -> Result<AudioData, RecordingError> {
             self.recording.store(false, Ordering::SeqCst);
-            Ok(AudioData::new(vec![0u8; 100], Default::default()))
+            if self.failed.load(Ordering::SeqCst) {
+                return Err(RecordingError::DeviceLost("unplugged".into()));
+            }
+            let audio = AudioData::new(vec![0u8; 100], Default::default())
+                .with_truncated(self.truncated.load(Ordering::SeqCst));
+            if self.silent.load(Ordering::SeqCst) {
+                return Ok(audio.with_peak_dbfs(f32::NEG_INFINITY));
+            }
+            Ok(audio.with_peak_dbfs(-20.0))
         }
 
         async fn cancel(&self) -> Result<(), RecordingError> {
@@ -396,6 +793,10 @@ mod tests {
         fn elapsed_ms(&self) -> u64 {
             self.elapsed.load(Ordering::SeqCst)
         }
+
+        fn has_failed(&self) -> bool {
+            self.failed.load(Ordering::SeqCst)
+        }
     }
 
     struct MockTranscriber;
@@ -416,6 +817,18 @@ mod tests {
         }
     }
 
+    /// Clipboard that records what was copied, shared with the test.
+    #[derive(Clone, Default)]
+    struct RecordingClipboard(Arc<std::sync::Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl Clipboard for RecordingClipboard {
+        async fn copy(&self, text: &str) -> Result<(), ClipboardError> {
+            self.0.lock().unwrap().push(text.to_string());
+            Ok(())
+        }
+    }
+
     struct MockKeystroke;
 
     #[async_trait]
@@ -439,6 +852,34 @@ mod tests {
         }
     }
 
+    /// Notifier that keeps the completion text and its copy action.
+    #[derive(Default)]
+    struct ResultNotifier {
+        result: std::sync::Mutex<Option<(String, Option<NotificationAction>)>>,
+    }
+
+    #[async_trait]
+    impl Notifier for ResultNotifier {
+        async fn notify(
+            &self,
+            _title: &str,
+            _message: &str,
+            _icon: NotificationIcon,
+        ) -> Result<(), NotificationError> {
+            Ok(())
+        }
+
+        async fn notify_result(
+            &self,
+            _title: &str,
+            text: &str,
+            on_copy: Option<NotificationAction>,
+        ) -> Result<(), NotificationError> {
+            *self.result.lock().unwrap() = Some((text.to_string(), on_copy));
+            Ok(())
+        }
+    }
+
     struct MockSmartPaste;
 
     #[async_trait]
@@ -452,6 +893,28 @@ mod tests {
         }
     }
 
+    /// Records every cue it is asked to play.
+    #[derive(Default)]
+    struct MockAudioCue {
+        played: std::sync::Mutex<Vec<AudioCueType>>,
+    }
+
+    impl MockAudioCue {
+        /// Cues played so far, after letting the spawned playback tasks run.
+        async fn played(&self) -> Vec<AudioCueType> {
+            tokio::task::yield_now().await;
+            self.played.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl AudioCue for MockAudioCue {
+        async fn play(&self, cue_type: AudioCueType) -> Result<(), AudioCueError> {
+            self.played.lock().unwrap().push(cue_type);
+            Ok(())
+        }
+    }
+
     #[tokio::test]
     async fn start_recording_from_idle() {
         let use_case = DaemonTranscriptionUseCase::new(
@@ -462,6 +925,7 @@ mod tests {
                 keystroke: MockKeystroke,
                 notifier: MockNotifier,
                 smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
             },
             DaemonConfig::default(),
         );
@@ -481,6 +945,7 @@ mod tests {
                 keystroke: MockKeystroke,
                 notifier: MockNotifier,
                 smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
             },
             DaemonConfig::default(),
         );
@@ -495,8 +960,239 @@ mod tests {
         assert_eq!(use_case.state().await, DaemonState::Idle);
     }
 
+    #[tokio::test]
+    async fn output_carries_timings_of_the_recording() {
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: MockTranscriber,
+                clipboard: MockClipboard,
+                keystroke: MockKeystroke,
+                notifier: MockNotifier,
+                smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
+            },
+            DaemonConfig::default(),
+        );
+
+        use_case.start_recording().await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        let timings = use_case.stop_and_transcribe().await.unwrap().timings;
+        assert!(timings.recording_ms.unwrap() >= 20, "{timings:?}");
+        assert!(timings.transcription_ms.is_some());
+        assert!(timings.total_ms >= timings.stages_ms(), "{timings:?}");
+    }
+
+    #[tokio::test]
+    async fn reload_swaps_settings_and_reports_changes() {
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: MockTranscriber,
+                clipboard: MockClipboard,
+                keystroke: MockKeystroke,
+                notifier: MockNotifier,
+                smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
+            },
+            DaemonConfig::default(),
+        );
+
+        let unchanged = use_case.config().reloadable();
+        assert!(use_case.reload(unchanged).is_empty());
+
+        let changes = use_case.reload(ReloadableSettings {
+            max_duration: Duration::from_secs(120),
+            enable_clipboard: true,
+            ..unchanged
+        });
+        assert_eq!(changes.len(), 2);
+        assert!(changes[0].starts_with("max_duration:"));
+        assert_eq!(changes[1], "clipboard: false -> true");
+        assert!(use_case.config().enable_clipboard);
+        assert_eq!(use_case.max_duration(), Duration::from_secs(120));
+
+        let rejected = use_case.update_settings(|_| Err("bad update"));
+        assert_eq!(rejected, Err("bad update"));
+        assert!(use_case.config().enable_clipboard);
+        let changes = use_case
+            .update_settings(|s| {
+                Ok::<_, ()>(ReloadableSettings {
+                    enable_notify: true,
+                    ..s
+                })
+            })
+            .unwrap();
+        assert_eq!(changes, ["notify: false -> true"]);
+        assert_eq!(use_case.max_duration(), Duration::from_secs(120));
+    }
+
+    #[tokio::test]
+    async fn stop_below_min_duration_discards_recording() {
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: FailingTranscriber,
+                clipboard: MockClipboard,
+                keystroke: MockKeystroke,
+                notifier: MockNotifier,
+                smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
+            },
+            DaemonConfig {
+                min_duration: Some(Duration::from_millis(500)),
+                ..DaemonConfig::default()
+            },
+        );
+
+        use_case.start_recording().await.unwrap();
+        use_case.recorder.elapsed.store(120, Ordering::SeqCst);
+
+        // The failing transcriber proves no API call is attempted.
+        let err = use_case.stop_and_transcribe().await.unwrap_err();
+        assert!(matches!(err, DaemonError::TooShort { .. }), "{err}");
+        assert_eq!(use_case.state().await, DaemonState::Idle);
+        assert!(!use_case.is_recording());
+    }
+
+    #[tokio::test]
+    async fn stop_at_min_duration_transcribes() {
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: MockTranscriber,
+                clipboard: MockClipboard,
+                keystroke: MockKeystroke,
+                notifier: MockNotifier,
+                smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
+            },
+            DaemonConfig {
+                min_duration: Some(Duration::from_millis(500)),
+                ..DaemonConfig::default()
+            },
+        );
+
+        use_case.start_recording().await.unwrap();
+        use_case.recorder.elapsed.store(500, Ordering::SeqCst);
+        let output = use_case.stop_and_transcribe().await.unwrap();
+        assert_eq!(output.text, "Test transcription");
+    }
+
+    #[tokio::test]
+    async fn stop_with_silent_audio_discards_recording() {
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: FailingTranscriber,
+                clipboard: MockClipboard,
+                keystroke: MockKeystroke,
+                notifier: MockNotifier,
+                smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
+            },
+            DaemonConfig {
+                silence_threshold: Some(-60),
+                ..DaemonConfig::default()
+            },
+        );
+
+        use_case.start_recording().await.unwrap();
+        use_case.recorder.silent.store(true, Ordering::SeqCst);
+
+        let err = use_case.stop_and_transcribe().await.unwrap_err();
+        assert!(matches!(err, DaemonError::NoAudio { .. }), "{err}");
+        assert_eq!(use_case.state().await, DaemonState::Idle);
+    }
+
+    #[tokio::test]
+    async fn silence_check_disabled_transcribes_silent_audio() {
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: MockTranscriber,
+                clipboard: MockClipboard,
+                keystroke: MockKeystroke,
+                notifier: MockNotifier,
+                smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
+            },
+            DaemonConfig::default(),
+        );
+
+        use_case.start_recording().await.unwrap();
+        use_case.recorder.silent.store(true, Ordering::SeqCst);
+        let output = use_case.stop_and_transcribe().await.unwrap();
+        assert_eq!(output.text, "Test transcription");
+    }
+
+    #[tokio::test]
+    async fn truncated_recording_warns_and_still_transcribes() {
+        let warnings = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+        let sink_warnings = Arc::clone(&warnings);
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: MockTranscriber,
+                clipboard: MockClipboard,
+                keystroke: MockKeystroke,
+                notifier: MockNotifier,
+                smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
+            },
+            DaemonConfig {
+                max_duration: Duration::from_secs(90),
+                warning_sink: Some(Arc::new(move |msg: &str| {
+                    sink_warnings.lock().unwrap().push(msg.to_string())
+                })),
+                ..DaemonConfig::default()
+            },
+        );
+
+        use_case.start_recording().await.unwrap();
+        assert_eq!(
+            *use_case.recorder.limit.lock().unwrap(),
+            Some(Duration::from_secs(90))
+        );
+        use_case.recorder.truncated.store(true, Ordering::SeqCst);
+
+        let output = use_case.stop_and_transcribe().await.unwrap();
+        assert_eq!(output.text, "Test transcription");
+        let warnings = warnings.lock().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("max_duration"), "{}", warnings[0]);
+    }
+
+    #[tokio::test]
+    async fn device_lost_returns_session_to_idle() {
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: MockTranscriber,
+                clipboard: MockClipboard,
+                keystroke: MockKeystroke,
+                notifier: MockNotifier,
+                smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
+            },
+            DaemonConfig::default(),
+        );
+
+        use_case.start_recording().await.unwrap();
+        use_case.recorder.failed.store(true, Ordering::SeqCst);
+        assert!(use_case.check_device_failed());
+
+        let err = use_case.stop_recording().await.unwrap_err();
+        assert!(
+            matches!(err, DaemonError::Recording(RecordingError::DeviceLost(_))),
+            "{err}"
+        );
+        assert_eq!(use_case.state().await, DaemonState::Idle);
+    }
+
     #[tokio::test]
     async fn cancel_recording() {
+        let cue = Arc::new(MockAudioCue::default());
         let use_case = DaemonTranscriptionUseCase::new(
             UseCaseDeps {
                 recorder: MockUnboundedRecorder::new(),
@@ -505,6 +1201,7 @@ mod tests {
                 keystroke: MockKeystroke,
                 notifier: MockNotifier,
                 smart_paste: MockSmartPaste,
+                audio_cue: cue.clone(),
             },
             DaemonConfig::default(),
         );
@@ -512,6 +1209,64 @@ mod tests {
         use_case.start_recording().await.unwrap();
         use_case.cancel().await.unwrap();
         assert_eq!(use_case.state().await, DaemonState::Idle);
+        assert_eq!(
+            cue.played().await,
+            [AudioCueType::RecordingStart, AudioCueType::RecordingCancel]
+        );
+    }
+
+    #[tokio::test]
+    async fn completion_notification_copies_full_text() {
+        let clipboard = RecordingClipboard::default();
+        let copied = Arc::clone(&clipboard.0);
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: MockTranscriber,
+                clipboard,
+                keystroke: MockKeystroke,
+                notifier: ResultNotifier::default(),
+                smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
+            },
+            DaemonConfig {
+                enable_notify: true,
+                ..Default::default()
+            },
+        );
+
+        use_case.start_recording().await.unwrap();
+        use_case.stop_and_transcribe().await.unwrap();
+
+        let (text, on_copy) = use_case.notifier.result.lock().unwrap().take().unwrap();
+        assert_eq!(text, "Test transcription");
+        assert!(copied.lock().unwrap().is_empty());
+        on_copy.expect("copy action")().await;
+        assert_eq!(*copied.lock().unwrap(), ["Test transcription"]);
+    }
+
+    #[tokio::test]
+    async fn start_and_stop_play_cues() {
+        let cue = Arc::new(MockAudioCue::default());
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: MockTranscriber,
+                clipboard: MockClipboard,
+                keystroke: MockKeystroke,
+                notifier: MockNotifier,
+                smart_paste: MockSmartPaste,
+                audio_cue: cue.clone(),
+            },
+            DaemonConfig::default(),
+        );
+
+        use_case.start_recording().await.unwrap();
+        use_case.stop_and_transcribe().await.unwrap();
+        assert_eq!(
+            cue.played().await,
+            [AudioCueType::RecordingStart, AudioCueType::RecordingStop]
+        );
     }
 
     #[tokio::test]
@@ -524,6 +1279,7 @@ mod tests {
                 keystroke: MockKeystroke,
                 notifier: MockNotifier,
                 smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
             },
             DaemonConfig::default(),
         );
@@ -539,7 +1295,7 @@ mod tests {
 
     #[async_trait]
     impl UnboundedRecorder for FailingRecorder {
-        async fn start(&self) -> Result<(), RecordingError> {
+        async fn start(&self, _limit: Option<Duration>) -> Result<(), RecordingError> {
             Err(RecordingError::StartFailed("simulated failure".to_string()))
         }
         async fn stop(&self) -> Result<AudioData, RecordingError> {
@@ -563,7 +1319,10 @@ mod tests {
     #[async_trait]
     impl Transcriber for FailingTranscriber {
         async fn transcribe(&self, _audio: &AudioData) -> Result<String, TranscriptionError> {
-            Err(TranscriptionError::ApiError("simulated".to_string()))
+            Err(TranscriptionError::ApiError {
+                status: Some(503),
+                message: "simulated".to_string(),
+            })
         }
     }
 
@@ -577,6 +1336,7 @@ mod tests {
                 keystroke: MockKeystroke,
                 notifier: MockNotifier,
                 smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
             },
             DaemonConfig::default(),
         );
@@ -601,6 +1361,7 @@ mod tests {
                 keystroke: MockKeystroke,
                 notifier: MockNotifier,
                 smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
             },
             DaemonConfig::default(),
         );
@@ -614,4 +1375,42 @@ mod tests {
             "session must roll back to Idle on transcription failure"
         );
     }
+
+    #[tokio::test]
+    async fn repeated_outages_pause_recording_until_cooldown() {
+        let use_case = DaemonTranscriptionUseCase::new(
+            UseCaseDeps {
+                recorder: MockUnboundedRecorder::new(),
+                transcriber: FailingTranscriber,
+                clipboard: MockClipboard,
+                keystroke: MockKeystroke,
+                notifier: MockNotifier,
+                smart_paste: MockSmartPaste,
+                audio_cue: Arc::new(MockAudioCue::default()),
+            },
+            DaemonConfig::default(),
+        )
+        .with_breaker(CircuitBreaker::new(
+            2,
+            Duration::from_secs(60),
+            Duration::from_millis(50),
+        ));
+
+        for _ in 0..2 {
+            use_case.start_recording().await.unwrap();
+            assert!(use_case.stop_and_transcribe().await.is_err());
+        }
+        let err = use_case.start_recording().await.unwrap_err();
+        assert!(matches!(err, DaemonError::CoolingDown { .. }), "{err}");
+        assert_eq!(use_case.state().await, DaemonState::Idle);
+
+        // After the cooldown one trial goes through; failing it reopens.
+        tokio::time::sleep(std::time::Duration::from_millis(60)).await;
+        use_case.start_recording().await.unwrap();
+        assert!(use_case.stop_and_transcribe().await.is_err());
+        assert!(matches!(
+            use_case.start_recording().await,
+            Err(DaemonError::CoolingDown { .. })
+        ));
+    }
 }
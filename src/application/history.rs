@@ -0,0 +1,55 @@
+//! Transcription history recording shared by both use cases.
+
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::domain::history::HistoryEntry;
+use crate::domain::transcription::AudioData;
+
+use super::ports::HistoryStore;
+use super::{warn, WarningSink};
+
+/// A history store plus the request context stamped on each entry.
+#[derive(Clone)]
+pub struct HistoryLog {
+    store: Arc<dyn HistoryStore>,
+    model: String,
+}
+
+impl HistoryLog {
+    pub fn new(store: Arc<dyn HistoryStore>, model: impl Into<String>) -> Self {
+        Self {
+            store,
+            model: model.into(),
+        }
+    }
+
+    /// Append a finished transcription and keep its audio for `replay`.
+    /// Failures become warnings; history must never fail an otherwise
+    /// successful transcription.
+    pub(crate) async fn record(
+        &self,
+        text: &str,
+        audio: &AudioData,
+        warning_sink: Option<&WarningSink>,
+    ) {
+        let entry = HistoryEntry::new(unix_now(), &self.model, text, audio.size_bytes() as u64);
+        if let Err(e) = self.store.append(&entry).await {
+            warn(warning_sink, &format!("could not save history: {}", e));
+        }
+        if let Err(e) = self.store.save_last_audio(audio).await {
+            warn(
+                warning_sink,
+                &format!("could not save audio for replay: {}", e),
+            );
+        }
+    }
+}
+
+/// Seconds since the Unix epoch (0 if the clock is before it).
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -4,13 +4,20 @@
 //! for external system interactions.
 
 pub mod daemon;
+pub mod history;
 pub mod output_dispatcher;
 pub mod ports;
 pub mod transcribe;
 
-pub use output_dispatcher::{dispatch as dispatch_output, OutputOptions, OutputResult};
+pub use output_dispatcher::{
+    dispatch as dispatch_output, OutputOptions, OutputResult, DEFAULT_CLIPBOARD_SEPARATOR,
+};
 
 use std::sync::Arc;
+use std::time::Instant;
+
+use crate::domain::transcription::{AudioData, Timings};
+use ports::{AudioCue, AudioCueType, Clipboard, NotificationAction, PostProcessor};
 
 /// Callback used by use cases to surface non-fatal warnings to the
 /// presentation layer. The application never formats or prints itself.
@@ -19,6 +26,38 @@ use std::sync::Arc;
 /// typically routes messages into [`crate::cli::Presenter::warn`].
 pub type WarningSink = Arc<dyn Fn(&str) + Send + Sync>;
 
+/// Helper: play an audio cue without waiting for it. Playback errors are
+/// dropped so a missing output device never affects recording.
+pub(crate) fn play_cue(audio_cue: &Arc<dyn AudioCue>, cue: AudioCueType) {
+    let audio_cue = Arc::clone(audio_cue);
+    tokio::spawn(async move {
+        let _ = audio_cue.play(cue).await;
+    });
+}
+
+/// Helper: completion-notification title, with the captured length when
+/// the recorder measured it (e.g. `SmartScribe (4.2s)`).
+pub(crate) fn completion_title(audio_duration_ms: Option<u64>) -> String {
+    match audio_duration_ms {
+        Some(ms) => format!("SmartScribe ({:.1}s)", ms as f64 / 1000.0),
+        None => "SmartScribe".to_string(),
+    }
+}
+
+/// Helper: a notification action that copies `text` to the clipboard.
+pub(crate) fn copy_action<C>(clipboard: &Arc<C>, text: &str) -> NotificationAction
+where
+    C: Clipboard + 'static,
+{
+    let clipboard = Arc::clone(clipboard);
+    let text = text.to_owned();
+    Box::new(move || {
+        Box::pin(async move {
+            let _ = clipboard.copy(&text).await;
+        })
+    })
+}
+
 /// Helper: dispatch a warning to the sink if one is configured.
 pub(crate) fn warn(sink: Option<&WarningSink>, message: &str) {
     if let Some(sink) = sink {
@@ -26,8 +65,108 @@ pub(crate) fn warn(sink: Option<&WarningSink>, message: &str) {
     }
 }
 
+/// Helper: warn when the capture saturated. Clipping garbles speech far
+/// more than a quiet signal does, and only the user can fix the gain.
+pub(crate) fn warn_if_clipping(sink: Option<&WarningSink>, audio: &AudioData) {
+    if let Some(ratio) = audio.clipped_ratio().filter(|_| audio.is_clipping()) {
+        warn(
+            sink,
+            &format!(
+                "input is clipping ({:.1}% of samples at full scale); lower the microphone gain",
+                ratio * 100.0
+            ),
+        );
+    }
+}
+
+/// Monotonic clock behind a use case's [`Timings`]: started when a
+/// recording begins and read when the recorder hands over the audio.
+#[derive(Debug, Default)]
+pub(crate) struct RunClock {
+    started: Option<Instant>,
+    recorded: Option<std::time::Duration>,
+}
+
+impl RunClock {
+    /// Start timing a new run.
+    pub(crate) fn start(&mut self) {
+        *self = Self {
+            started: Some(Instant::now()),
+            recorded: None,
+        };
+    }
+
+    /// Mark the recorder as done (audio captured and encoded).
+    pub(crate) fn stop(&mut self) {
+        self.recorded = self.started.map(|started| started.elapsed());
+    }
+
+    /// Hand the run over to the transcription step and reset the clock. A
+    /// run that recorded nothing (stdin input) starts here.
+    pub(crate) fn take(&mut self) -> RunTimer {
+        let clock = std::mem::take(self);
+        RunTimer {
+            started: clock.started.unwrap_or_else(Instant::now),
+            recorded: clock.recorded,
+        }
+    }
+}
+
+/// A run between the recorder and its outputs; see [`RunClock::take`].
+pub(crate) struct RunTimer {
+    started: Instant,
+    recorded: Option<std::time::Duration>,
+}
+
+impl RunTimer {
+    /// Timings of the run now that its output is done. The recorder's
+    /// encoding time is carried by `audio` and split out of the recording;
+    /// audio this run didn't record has neither.
+    pub(crate) fn finish(
+        &self,
+        audio: &AudioData,
+        transcription: Option<std::time::Duration>,
+    ) -> Timings {
+        let encoding_ms = self.recorded.and(audio.encode_ms());
+        Timings {
+            recording_ms: self
+                .recorded
+                .map(|r| as_ms(r).saturating_sub(encoding_ms.unwrap_or(0))),
+            encoding_ms,
+            transcription_ms: transcription.map(as_ms),
+            total_ms: as_ms(self.started.elapsed()),
+        }
+    }
+}
+
+fn as_ms(duration: std::time::Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Helper: run `transcript` through the post-command, if one is set. A
+/// failure is a warning and keeps the transcript as the API returned it.
+pub(crate) async fn post_process(
+    processor: Option<&Arc<dyn PostProcessor>>,
+    transcript: String,
+    sink: Option<&WarningSink>,
+) -> String {
+    let Some(processor) = processor else {
+        return transcript;
+    };
+    match processor.process(&transcript).await {
+        Ok(text) => text,
+        Err(e) => {
+            warn(sink, &format!("{e}; using the raw transcription"));
+            transcript
+        }
+    }
+}
+
 // Re-export use cases
-pub use daemon::{DaemonConfig, DaemonError, DaemonOutput, DaemonTranscriptionUseCase};
+pub use daemon::{
+    DaemonConfig, DaemonError, DaemonOutput, DaemonTranscriptionUseCase, ReloadableSettings,
+};
+pub use history::HistoryLog;
 pub use transcribe::{
     TranscribeCallbacks, TranscribeError, TranscribeInput, TranscribeOutput,
     TranscribeRecordingUseCase,
@@ -39,6 +178,9 @@ pub use transcribe::{
 /// used to take six positional arguments in identical order; threading them
 /// through a struct removes that boilerplate and keeps the call sites
 /// self-documenting.
+///
+/// The audio cue is a trait object rather than another type parameter: it
+/// is shared with fire-and-forget playback tasks and never on a hot path.
 pub struct UseCaseDeps<R, T, C, K, N, P> {
     pub recorder: R,
     pub transcriber: T,
@@ -46,4 +188,5 @@ pub struct UseCaseDeps<R, T, C, K, N, P> {
     pub keystroke: K,
     pub notifier: N,
     pub smart_paste: P,
+    pub audio_cue: Arc<dyn AudioCue>,
 }
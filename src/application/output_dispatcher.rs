@@ -2,7 +2,8 @@
 //!
 //! Once a transcript exists, both the one-shot and daemon flows want to:
 //!
-//! 1. Copy the text to the clipboard (optional).
+//! 1. Copy the text to the clipboard (optional), or append it to what is
+//!    already there.
 //! 2. Type the text into the focused window via the keystroke adapter
 //!    (optional).
 //! 3. Paste the text into the previously captured window via smart paste
@@ -21,23 +22,36 @@
 //! owning the adapters so the use cases keep ownership and we don't burden
 //! callers with a second wrapping `Arc`.
 
+use std::borrow::Cow;
+
 use super::ports::{Clipboard, Keystroke, SmartPaste};
 use super::{warn, WarningSink};
 
+/// Default separator between appended clipboard entries.
+pub const DEFAULT_CLIPBOARD_SEPARATOR: &str = "\n";
+
 /// Per-call options selecting which output channels to dispatch.
 #[derive(Debug, Clone, Copy, Default)]
-pub struct OutputOptions {
+pub struct OutputOptions<'a> {
     pub clipboard: bool,
+    /// Append to the current clipboard text with this separator instead of
+    /// replacing it. Only consulted when `clipboard` is set.
+    pub clipboard_append: Option<&'a str>,
     pub keystroke: bool,
     pub paste: bool,
 }
 
 /// Outcome of [`OutputDispatcher::dispatch`]: which channels actually
-/// succeeded. Channels that were disabled report `false`.
-#[derive(Debug, Clone, Copy, Default)]
+/// succeeded, and through which backend. Channels that were disabled report
+/// `false`.
+#[derive(Debug, Clone, Default)]
 pub struct OutputResult {
     pub clipboard_copied: bool,
+    /// Backend the clipboard copy went through, when it succeeded.
+    pub clipboard_backend: Option<String>,
     pub keystroke_sent: bool,
+    /// Tool that typed the text, when typing succeeded.
+    pub keystroke_backend: Option<String>,
     pub paste_sent: bool,
 }
 
@@ -53,7 +67,7 @@ pub async fn dispatch<C, K, P>(
     keystroke: &K,
     smart_paste: &P,
     text: &str,
-    opts: OutputOptions,
+    opts: OutputOptions<'_>,
     warning_sink: Option<&WarningSink>,
 ) -> OutputResult
 where
@@ -62,7 +76,23 @@ where
     P: SmartPaste + ?Sized,
 {
     let clipboard_copied = if opts.clipboard {
-        match clipboard.copy(text).await {
+        let contents = match opts.clipboard_append {
+            Some(separator) => {
+                let existing = match clipboard.read().await {
+                    Ok(existing) => existing,
+                    Err(e) => {
+                        warn(
+                            warning_sink,
+                            &format!("clipboard read failed, replacing instead: {}", e),
+                        );
+                        None
+                    }
+                };
+                Cow::Owned(append_with_separator(existing.as_deref(), text, separator))
+            }
+            None => Cow::Borrowed(text),
+        };
+        match clipboard.copy(&contents).await {
             Ok(()) => true,
             Err(e) => {
                 warn(warning_sink, &format!("clipboard copy failed: {}", e));
@@ -99,7 +129,108 @@ where
 
     OutputResult {
         clipboard_copied,
+        clipboard_backend: backend_if(clipboard_copied, clipboard.backend()),
         keystroke_sent,
+        keystroke_backend: backend_if(keystroke_sent, keystroke.backend()),
         paste_sent,
     }
 }
+
+/// `backend` as an owned label, if the channel it names did run.
+fn backend_if(ran: bool, backend: Option<&str>) -> Option<String> {
+    backend.filter(|_| ran).map(str::to_string)
+}
+
+/// `existing + separator + text`, or just `text` when there is nothing
+/// (or no text) on the clipboard yet.
+fn append_with_separator(existing: Option<&str>, text: &str, separator: &str) -> String {
+    match existing {
+        Some(existing) if !existing.is_empty() => format!("{existing}{separator}{text}"),
+        _ => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::application::ports::{ClipboardError, KeystrokeError, SmartPasteError};
+
+    /// Clipboard holding its contents in memory, like the real one.
+    #[derive(Default)]
+    struct StatefulClipboard(Mutex<Option<String>>);
+
+    #[async_trait]
+    impl Clipboard for StatefulClipboard {
+        async fn copy(&self, text: &str) -> Result<(), ClipboardError> {
+            *self.0.lock().unwrap() = Some(text.to_string());
+            Ok(())
+        }
+
+        async fn read(&self) -> Result<Option<String>, ClipboardError> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+    }
+
+    struct NoKeystroke;
+
+    #[async_trait]
+    impl Keystroke for NoKeystroke {
+        async fn type_text(&self, _text: &str) -> Result<(), KeystrokeError> {
+            Ok(())
+        }
+    }
+
+    struct NoPaste;
+
+    #[async_trait]
+    impl SmartPaste for NoPaste {
+        async fn capture_active_window(&self) -> Result<(), SmartPasteError> {
+            Ok(())
+        }
+
+        async fn paste(&self, _text: &str) -> Result<(), SmartPasteError> {
+            Ok(())
+        }
+    }
+
+    async fn copy(clipboard: &StatefulClipboard, text: &str, append: Option<&str>) {
+        let opts = OutputOptions {
+            clipboard: true,
+            clipboard_append: append,
+            ..Default::default()
+        };
+        let result = dispatch(clipboard, &NoKeystroke, &NoPaste, text, opts, None).await;
+        assert!(result.clipboard_copied);
+    }
+
+    #[tokio::test]
+    async fn append_accumulates_with_separator() {
+        let clipboard = StatefulClipboard::default();
+        copy(&clipboard, "first", Some("\n")).await;
+        copy(&clipboard, "second", Some("\n")).await;
+        copy(&clipboard, "third", Some(" | ")).await;
+        assert_eq!(
+            clipboard.read().await.unwrap().as_deref(),
+            Some("first\nsecond | third")
+        );
+    }
+
+    #[tokio::test]
+    async fn without_append_each_copy_replaces() {
+        let clipboard = StatefulClipboard::default();
+        copy(&clipboard, "first", None).await;
+        copy(&clipboard, "second", None).await;
+        assert_eq!(clipboard.read().await.unwrap().as_deref(), Some("second"));
+    }
+
+    #[test]
+    fn empty_or_non_text_clipboard_gets_just_the_text() {
+        assert_eq!(append_with_separator(None, "new", "\n"), "new");
+        assert_eq!(append_with_separator(Some(""), "new", "\n"), "new");
+        assert_eq!(append_with_separator(Some("old"), "new", "\n"), "old\nnew");
+    }
+}
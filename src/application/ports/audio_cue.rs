@@ -14,6 +14,8 @@ pub enum AudioCueType {
     RecordingStop,
     /// Double-beep when recording is cancelled (330Hz, 2×75ms + 50ms gap)
     RecordingCancel,
+    /// Short tick for each second of a `--countdown` (440Hz, 50ms)
+    CountdownTick,
 }
 
 /// Errors that can occur during audio cue playback
@@ -19,6 +19,10 @@ pub enum ClipboardError {
     /// The backend was reachable but the copy itself failed.
     #[error("Clipboard copy failed: {0}")]
     CopyFailed(String),
+
+    /// The backend was reachable but reading the current contents failed.
+    #[error("Clipboard read failed: {0}")]
+    ReadFailed(String),
 }
 
 /// Port for clipboard operations
@@ -32,6 +36,20 @@ pub trait Clipboard: Send + Sync {
     /// # Returns
     /// Ok(()) on success, error otherwise
     async fn copy(&self, text: &str) -> Result<(), ClipboardError>;
+
+    /// Read the current clipboard text.
+    ///
+    /// Returns `Ok(None)` when the clipboard is empty or holds something
+    /// other than text. Backends that cannot read keep this default.
+    async fn read(&self) -> Result<Option<String>, ClipboardError> {
+        Ok(None)
+    }
+
+    /// Name of the backend copies go through (`wl-copy`, `arboard`, ...),
+    /// for reporting.
+    fn backend(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// Blanket implementation for boxed clipboard types
@@ -40,4 +58,12 @@ impl Clipboard for Box<dyn Clipboard> {
     async fn copy(&self, text: &str) -> Result<(), ClipboardError> {
         self.as_ref().copy(text).await
     }
+
+    async fn read(&self) -> Result<Option<String>, ClipboardError> {
+        self.as_ref().read().await
+    }
+
+    fn backend(&self) -> Option<&str> {
+        self.as_ref().backend()
+    }
 }
@@ -11,6 +11,17 @@ use std::path::PathBuf;
 use crate::domain::config::RawAppConfig;
 use crate::domain::error::ConfigError;
 
+/// Outcome of [`ConfigStore::migrate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigMigration {
+    /// Layout version the file was at before migrating.
+    pub from_version: u32,
+    /// One line per key moved or removed; empty when already current.
+    pub changes: Vec<String>,
+    /// Copy of the original file, written only when something changed.
+    pub backup: Option<PathBuf>,
+}
+
 /// Port for configuration storage.
 #[async_trait]
 pub trait ConfigStore: Send + Sync {
@@ -31,4 +42,9 @@ pub trait ConfigStore: Send + Sync {
     /// Initialise the configuration file with sensible defaults.
     /// Fails if the file already exists.
     async fn init(&self) -> Result<(), ConfigError>;
+
+    /// Upgrade an existing file to the current layout
+    /// ([`CONFIG_VERSION`](crate::domain::config::CONFIG_VERSION)), keeping
+    /// the original as a backup.
+    async fn migrate(&self) -> Result<ConfigMigration, ConfigError>;
 }
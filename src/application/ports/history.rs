@@ -0,0 +1,35 @@
+//! Transcription history port interface
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::domain::history::HistoryEntry;
+use crate::domain::transcription::AudioData;
+
+/// History store errors.
+#[derive(Debug, Clone, Error)]
+pub enum HistoryError {
+    /// Reading or writing the backing store failed.
+    #[error("History I/O failed: {0}")]
+    Io(String),
+
+    /// A stored entry could not be decoded.
+    #[error("Corrupt history entry: {0}")]
+    Corrupt(String),
+}
+
+/// Port for persisting past transcriptions.
+#[async_trait]
+pub trait HistoryStore: Send + Sync {
+    /// Append an entry, evicting the oldest ones beyond the store's cap.
+    async fn append(&self, entry: &HistoryEntry) -> Result<(), HistoryError>;
+
+    /// Up to `limit` entries, newest first.
+    async fn recent(&self, limit: usize) -> Result<Vec<HistoryEntry>, HistoryError>;
+
+    /// Keep `audio` as the most recent capture, replacing the previous one.
+    async fn save_last_audio(&self, audio: &AudioData) -> Result<(), HistoryError>;
+
+    /// The capture kept by [`save_last_audio`](Self::save_last_audio), if any.
+    async fn last_audio(&self) -> Result<Option<AudioData>, HistoryError>;
+}
@@ -6,7 +6,8 @@ use thiserror::Error;
 /// Keystroke errors.
 ///
 /// Tool names appear only in the `tool` field of [`KeystrokeError::
-/// BackendUnavailable`] / [`KeystrokeError::TypeFailed`]; the application
+/// BackendUnavailable`] / [`KeystrokeError::DaemonNotRunning`] /
+/// [`KeystrokeError::TypeFailed`]; the application
 /// layer matches on the variant, not on tool name strings.
 #[derive(Debug, Clone, Error)]
 pub enum KeystrokeError {
@@ -20,6 +21,13 @@ pub enum KeystrokeError {
     #[error("Keystroke backend `{tool}` is not available: {reason}")]
     BackendUnavailable { tool: String, reason: String },
 
+    /// The backend is installed but the daemon it talks to is not running.
+    #[error(
+        "Keystroke backend `{tool}` is installed but `{daemon}` is not running. \
+         Start it (e.g. `systemctl --user start {daemon}` or `{daemon} &`) and try again."
+    )]
+    DaemonNotRunning { tool: String, daemon: String },
+
     /// The backend was reachable but typing the text failed.
     #[error("Failed to type text via `{tool}`: {reason}")]
     TypeFailed { tool: String, reason: String },
@@ -36,6 +44,12 @@ pub trait Keystroke: Send + Sync {
     /// # Returns
     /// Ok(()) on success, error otherwise
     async fn type_text(&self, text: &str) -> Result<(), KeystrokeError>;
+
+    /// Name of the tool doing the typing (`ydotool`, `enigo`, ...), for
+    /// reporting. `None` when nothing is actually typed.
+    fn backend(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// Blanket implementation for boxed keystroke types
@@ -44,4 +58,8 @@ impl Keystroke for Box<dyn Keystroke> {
     async fn type_text(&self, text: &str) -> Result<(), KeystrokeError> {
         self.as_ref().type_text(text).await
     }
+
+    fn backend(&self) -> Option<&str> {
+        self.as_ref().backend()
+    }
 }
@@ -6,8 +6,10 @@
 pub mod audio_cue;
 pub mod clipboard;
 pub mod config;
+pub mod history;
 pub mod keystroke;
 pub mod notifier;
+pub mod post_process;
 pub mod recorder;
 pub mod smart_paste;
 pub mod transcriber;
@@ -15,9 +17,11 @@ pub mod transcriber;
 // Re-export common types
 pub use audio_cue::{AudioCue, AudioCueError, AudioCueType};
 pub use clipboard::{Clipboard, ClipboardError};
-pub use config::ConfigStore;
+pub use config::{ConfigMigration, ConfigStore};
+pub use history::{HistoryError, HistoryStore};
 pub use keystroke::{Keystroke, KeystrokeError};
-pub use notifier::{NotificationError, NotificationIcon, Notifier};
+pub use notifier::{NotificationAction, NotificationError, NotificationIcon, Notifier};
+pub use post_process::{PostProcessError, PostProcessor};
 pub use recorder::{AudioRecorder, ProgressCallback, RecordingError, UnboundedRecorder};
 pub use smart_paste::{SmartPaste, SmartPasteError};
-pub use transcriber::{Transcriber, TranscriptionError};
+pub use transcriber::{Transcriber, TranscriptDeltaCallback, TranscriptionError};
@@ -1,8 +1,17 @@
 //! Notification port interface
 
+use std::future::Future;
+use std::pin::Pin;
+
 use async_trait::async_trait;
 use thiserror::Error;
 
+/// Characters of transcript shown in the completion notification.
+pub const PREVIEW_CHARS: usize = 100;
+
+/// Callback run when the user clicks a notification's action button.
+pub type NotificationAction = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send>;
+
 /// Notification errors
 #[derive(Debug, Clone, Error)]
 pub enum NotificationError {
@@ -56,6 +65,32 @@ pub trait Notifier: Send + Sync {
         message: &str,
         icon: NotificationIcon,
     ) -> Result<(), NotificationError>;
+
+    /// Show the completion notification for a transcript.
+    ///
+    /// The body is a [`preview`] of `text`. Backends that support actions
+    /// add a "Copy" button that runs `on_copy`; the default implementation
+    /// drops it and shows a plain notification.
+    async fn notify_result(
+        &self,
+        title: &str,
+        text: &str,
+        on_copy: Option<NotificationAction>,
+    ) -> Result<(), NotificationError> {
+        let _ = on_copy;
+        self.notify(title, &preview(text), NotificationIcon::Success)
+            .await
+    }
+}
+
+/// First [`PREVIEW_CHARS`] characters of `text` on one line.
+pub fn preview(text: &str) -> String {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flat.chars().count() <= PREVIEW_CHARS {
+        return flat;
+    }
+    let head: String = flat.chars().take(PREVIEW_CHARS).collect();
+    format!("{}…", head.trim_end())
 }
 
 /// Blanket implementation for boxed notifier types
@@ -69,4 +104,31 @@ impl Notifier for Box<dyn Notifier> {
     ) -> Result<(), NotificationError> {
         self.as_ref().notify(title, message, icon).await
     }
+
+    async fn notify_result(
+        &self,
+        title: &str,
+        text: &str,
+        on_copy: Option<NotificationAction>,
+    ) -> Result<(), NotificationError> {
+        self.as_ref().notify_result(title, text, on_copy).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preview_keeps_short_text() {
+        assert_eq!(preview("  hello\n world "), "hello world");
+    }
+
+    #[test]
+    fn preview_truncates_long_text() {
+        let long = "word ".repeat(40);
+        let p = preview(&long);
+        assert!(p.ends_with('…'));
+        assert!(p.chars().count() <= PREVIEW_CHARS + 1);
+    }
 }
@@ -0,0 +1,28 @@
+//! Post-processing port interface
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+/// Post-processing errors
+#[derive(Debug, Clone, Error)]
+pub enum PostProcessError {
+    #[error("could not run the post-command: {0}")]
+    SpawnFailed(String),
+
+    #[error("the post-command timed out after {0}s")]
+    TimedOut(u64),
+
+    #[error("the post-command failed ({0})")]
+    Failed(String),
+
+    #[error("the post-command printed no text")]
+    EmptyOutput,
+}
+
+/// Port for rewriting a transcript before it is output (spell fixers,
+/// formatters, LLM cleanup).
+#[async_trait]
+pub trait PostProcessor: Send + Sync {
+    /// Return the rewritten `text`.
+    async fn process(&self, text: &str) -> Result<String, PostProcessError>;
+}
@@ -1,6 +1,7 @@
 //! Recording port interfaces
 
 use async_trait::async_trait;
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -24,6 +25,15 @@ pub enum RecordingError {
 
     #[error("No audio device available")]
     NoAudioDevice,
+
+    #[error("Audio device error during recording: {0}")]
+    DeviceLost(String),
+
+    #[error("Input device '{name}' not found (available: {available})")]
+    DeviceNotFound { name: String, available: String },
+
+    #[error("Microphone busy: {0}")]
+    MicrophoneBusy(String),
 }
 
 /// Progress callback type for reporting recording progress.
@@ -38,6 +48,8 @@ pub trait AudioRecorder: Send + Sync {
     /// # Arguments
     /// * `duration` - How long to record
     /// * `on_progress` - Optional callback for progress updates
+    /// * `stop` - Raised to end the recording early; the audio captured so
+    ///   far is returned as if the duration had elapsed
     ///
     /// # Returns
     /// The recorded audio data or an error
@@ -45,6 +57,7 @@ pub trait AudioRecorder: Send + Sync {
         &self,
         duration: Duration,
         on_progress: Option<ProgressCallback>,
+        stop: Arc<AtomicBool>,
     ) -> Result<AudioData, RecordingError>;
 }
 
@@ -53,9 +66,13 @@ pub trait AudioRecorder: Send + Sync {
 pub trait UnboundedRecorder: Send + Sync {
     /// Start an unbounded recording session.
     ///
+    /// With a `limit`, audio captured beyond it is dropped instead of
+    /// buffered, and the [`AudioData`] returned by [`Self::stop`] is marked
+    /// truncated. This bounds memory if nothing stops the session in time.
+    ///
     /// # Returns
     /// A recording handle that can be used to stop/cancel
-    async fn start(&self) -> Result<(), RecordingError>;
+    async fn start(&self, limit: Option<Duration>) -> Result<(), RecordingError>;
 
     /// Stop the recording and return the audio data.
     ///
@@ -71,4 +88,18 @@ pub trait UnboundedRecorder: Send + Sync {
 
     /// Get elapsed recording time in milliseconds
     fn elapsed_ms(&self) -> u64;
+
+    /// Whether the input stream failed mid-session (e.g. the microphone was
+    /// unplugged). [`Self::stop`] then returns [`RecordingError::DeviceLost`].
+    fn has_failed(&self) -> bool {
+        false
+    }
+
+    /// Current input level in `0.0..=1.0` while recording.
+    ///
+    /// `None` when the backend cannot meter its input; the indicator then
+    /// shows only the state dot.
+    fn level(&self) -> Option<f32> {
+        None
+    }
 }
@@ -1,9 +1,11 @@
 //! Transcription port interface
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use thiserror::Error;
 
-use crate::domain::transcription::AudioData;
+use crate::domain::transcription::{AudioData, TimedTranscript};
 
 /// Transcription errors
 #[derive(Debug, Clone, Error)]
@@ -20,16 +22,48 @@ pub enum TranscriptionError {
     #[error("Empty audio response")]
     EmptyResponse,
 
+    /// The provider refused the request under its content policy. Distinct
+    /// from [`Self::EmptyResponse`] so users know retrying won't help.
+    #[error("Blocked by the provider's content policy: {reason}")]
+    SafetyBlocked { reason: String },
+
     #[error("API request failed: {0}")]
     RequestFailed(String),
 
     #[error("Failed to parse API response: {0}")]
     ParseError(String),
 
-    #[error("API error: {0}")]
-    ApiError(String),
+    /// Non-success response from the provider. `status` is the HTTP status
+    /// when the error came from a response, `None` for in-band errors.
+    #[error("API error: {message}")]
+    ApiError {
+        status: Option<u16>,
+        message: String,
+    },
+}
+
+impl TranscriptionError {
+    /// Whether the error says the service is down or overloaded, as opposed
+    /// to a problem with the credentials or this particular recording.
+    ///
+    /// Only transport failures, rate limits and 5xx responses count; a 4xx
+    /// such as 400 or 413 is about the request and would fail again anyway.
+    pub fn is_outage(&self) -> bool {
+        match self {
+            Self::RateLimited | Self::RequestFailed(_) => true,
+            Self::ApiError {
+                status: Some(status),
+                ..
+            } => (500..600).contains(status),
+            _ => false,
+        }
+    }
 }
 
+/// Callback receiving incremental transcript text as it streams in.
+/// Each call carries only the new fragment, not the accumulated text.
+pub type TranscriptDeltaCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
 /// Port for audio transcription
 #[async_trait]
 pub trait Transcriber: Send + Sync {
@@ -41,4 +75,57 @@ pub trait Transcriber: Send + Sync {
     /// # Returns
     /// The transcribed text or an error
     async fn transcribe(&self, audio: &AudioData) -> Result<String, TranscriptionError>;
+
+    /// Transcribe audio, reporting partial text through `on_delta` as it
+    /// arrives.
+    ///
+    /// Returns the complete transcript, same as [`Self::transcribe`].
+    /// Backends without a streaming endpoint keep this default, which calls
+    /// `transcribe` and reports the whole text as a single delta.
+    async fn transcribe_stream(
+        &self,
+        audio: &AudioData,
+        on_delta: TranscriptDeltaCallback,
+    ) -> Result<String, TranscriptionError> {
+        let text = self.transcribe(audio).await?;
+        on_delta(&text);
+        Ok(text)
+    }
+
+    /// Transcribe audio with per-segment timing.
+    ///
+    /// Backends or models without segment timing keep this default, which
+    /// calls `transcribe` and returns the text with `segments: None`.
+    async fn transcribe_timed(
+        &self,
+        audio: &AudioData,
+    ) -> Result<TimedTranscript, TranscriptionError> {
+        Ok(TimedTranscript::untimed(self.transcribe(audio).await?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(status: Option<u16>) -> TranscriptionError {
+        TranscriptionError::ApiError {
+            status,
+            message: "x".to_string(),
+        }
+    }
+
+    #[test]
+    fn only_service_side_failures_are_outages() {
+        assert!(TranscriptionError::RateLimited.is_outage());
+        assert!(TranscriptionError::RequestFailed("dns".into()).is_outage());
+        assert!(api_error(Some(503)).is_outage());
+
+        assert!(!api_error(Some(400)).is_outage());
+        assert!(!api_error(Some(413)).is_outage());
+        assert!(!api_error(Some(401)).is_outage());
+        assert!(!api_error(None).is_outage());
+        assert!(!TranscriptionError::InvalidApiKey.is_outage());
+        assert!(!TranscriptionError::ParseError("bad json".into()).is_outage());
+    }
 }
@@ -1,18 +1,25 @@
 //! Transcribe recording use case
 
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use thiserror::Error;
 
 use crate::domain::recording::Duration;
-use crate::domain::transcription::AudioData;
+use crate::domain::transcription::{
+    char_count, format_timestamped, word_count, AudioData, TextTemplate, Timings,
+};
 
 use super::output_dispatcher::{dispatch as dispatch_output, OutputOptions};
 use super::ports::{
-    AudioRecorder, Clipboard, Keystroke, NotificationIcon, Notifier, ProgressCallback,
-    RecordingError, SmartPaste, Transcriber, TranscriptionError, UnboundedRecorder,
+    AudioCue, AudioCueType, AudioRecorder, Clipboard, Keystroke, NotificationIcon, Notifier,
+    PostProcessor, ProgressCallback, RecordingError, SmartPaste, Transcriber,
+    TranscriptDeltaCallback, TranscriptionError, UnboundedRecorder,
+};
+use super::{
+    completion_title, copy_action, play_cue, post_process, warn, warn_if_clipping, HistoryLog,
+    RunClock, UseCaseDeps, WarningSink,
 };
-use super::{warn, UseCaseDeps, WarningSink};
 
 /// Errors from the transcribe use case
 #[derive(Debug, Error)]
@@ -22,6 +29,12 @@ pub enum TranscribeError {
 
     #[error("Transcription failed: {0}")]
     Transcription(#[from] TranscriptionError),
+
+    #[error("Recording too short ({elapsed}, minimum {min}); discarded without transcribing")]
+    TooShort { elapsed: Duration, min: Duration },
+
+    #[error("No audio detected (peak {peak_dbfs:.0} dBFS); check your microphone")]
+    NoAudio { peak_dbfs: f32 },
 }
 
 /// Input parameters for the transcribe use case
@@ -29,8 +42,15 @@ pub enum TranscribeError {
 pub struct TranscribeInput {
     /// Recording duration
     pub duration: Duration,
+    /// Recordings shorter than this are discarded (no API call)
+    pub min_duration: Option<Duration>,
+    /// Recordings whose peak stays at or below this level (dBFS) are
+    /// rejected as silent (no API call). `None` disables the check.
+    pub silence_threshold: Option<i32>,
     /// Whether to copy result to clipboard
     pub enable_clipboard: bool,
+    /// Append to the clipboard with this separator instead of replacing it
+    pub clipboard_append: Option<String>,
     /// Whether to type result into focused window
     pub enable_keystroke: bool,
     /// Whether to use smart paste (Linux KDE Wayland only)
@@ -40,17 +60,34 @@ pub struct TranscribeInput {
     /// Optional callback for non-fatal warnings. The CLI plugs the presenter
     /// in here; tests leave it `None` to silently discard warnings.
     pub warning_sink: Option<WarningSink>,
+    /// Where successful transcriptions are recorded; `None` disables history.
+    pub history: Option<HistoryLog>,
+    /// Record and encode, then stop before the transcriber: the output
+    /// carries empty text and no clipboard/keystroke/paste/history runs.
+    pub dry_run: bool,
+    /// Prefix each transcript segment with its `[mm:ss]` start time when
+    /// the backend reports timing; otherwise warn and emit plain text.
+    pub timestamps: bool,
+    /// Text wrapped around the transcript before it is output.
+    pub template: TextTemplate,
 }
 
 impl std::fmt::Debug for TranscribeInput {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TranscribeInput")
             .field("duration", &self.duration)
+            .field("min_duration", &self.min_duration)
+            .field("silence_threshold", &self.silence_threshold)
             .field("enable_clipboard", &self.enable_clipboard)
+            .field("clipboard_append", &self.clipboard_append)
             .field("enable_keystroke", &self.enable_keystroke)
             .field("enable_paste", &self.enable_paste)
             .field("enable_notify", &self.enable_notify)
             .field("warning_sink", &self.warning_sink.is_some())
+            .field("history", &self.history.is_some())
+            .field("dry_run", &self.dry_run)
+            .field("timestamps", &self.timestamps)
+            .field("template", &self.template)
             .finish()
     }
 }
@@ -62,12 +99,24 @@ pub struct TranscribeOutput {
     pub text: String,
     /// Whether clipboard copy succeeded (if enabled)
     pub clipboard_copied: bool,
+    /// Clipboard backend the copy went through (`wl-copy`, `arboard`, ...)
+    pub clipboard_backend: Option<String>,
     /// Whether keystroke injection succeeded (if enabled)
     pub keystroke_sent: bool,
+    /// Tool that typed the text (`ydotool`, `enigo`, ...)
+    pub keystroke_backend: Option<String>,
     /// Whether smart paste succeeded (if enabled)
     pub paste_sent: bool,
     /// Audio file size in bytes. The presentation layer formats it.
     pub audio_size_bytes: u64,
+    /// Length of the captured audio, when the recorder measured it.
+    pub audio_duration_ms: Option<u64>,
+    /// Words in `text`
+    pub word_count: usize,
+    /// Characters in `text`
+    pub char_count: usize,
+    /// Where the time went (`--timing`).
+    pub timings: Timings,
 }
 
 /// Callbacks for progress and status updates
@@ -84,6 +133,9 @@ pub struct TranscribeCallbacks {
     pub on_transcribing_start: Option<Box<dyn Fn() + Send + Sync>>,
     /// Called when transcription ends
     pub on_transcribing_end: Option<Box<dyn Fn() + Send + Sync>>,
+    /// Called with partial transcript text while transcription is in
+    /// flight. When set, the streaming transcriber path is used.
+    pub on_transcript_delta: Option<TranscriptDeltaCallback>,
 }
 
 /// One-shot transcription use case
@@ -91,25 +143,28 @@ pub struct TranscribeRecordingUseCase<R, T, C, K, N, P>
 where
     R: AudioRecorder,
     T: Transcriber,
-    C: Clipboard,
+    C: Clipboard + 'static,
     K: Keystroke,
     N: Notifier,
     P: SmartPaste,
 {
     recorder: R,
     transcriber: T,
-    clipboard: C,
+    clipboard: Arc<C>,
     keystroke: K,
     notifier: N,
     smart_paste: P,
+    audio_cue: Arc<dyn AudioCue>,
+    post_processor: Option<Arc<dyn PostProcessor>>,
     stop_flag: Arc<AtomicBool>,
+    clock: Mutex<RunClock>,
 }
 
 impl<R, T, C, K, N, P> TranscribeRecordingUseCase<R, T, C, K, N, P>
 where
     R: AudioRecorder,
     T: Transcriber,
-    C: Clipboard,
+    C: Clipboard + 'static,
     K: Keystroke,
     N: Notifier,
     P: SmartPaste,
@@ -119,14 +174,24 @@ where
         Self {
             recorder: deps.recorder,
             transcriber: deps.transcriber,
-            clipboard: deps.clipboard,
+            clipboard: Arc::new(deps.clipboard),
             keystroke: deps.keystroke,
             notifier: deps.notifier,
             smart_paste: deps.smart_paste,
+            audio_cue: deps.audio_cue,
+            post_processor: None,
             stop_flag: Arc::new(AtomicBool::new(false)),
+            clock: Mutex::new(RunClock::default()),
         }
     }
 
+    /// Builder: rewrite every transcript with `processor` (`--post-command`)
+    /// before it is output.
+    pub fn with_post_processor(mut self, processor: Option<Arc<dyn PostProcessor>>) -> Self {
+        self.post_processor = processor;
+        self
+    }
+
     /// Get the stop flag for external signal handling
     pub fn stop_flag(&self) -> Arc<AtomicBool> {
         Arc::clone(&self.stop_flag)
@@ -137,6 +202,11 @@ where
         self.stop_flag.store(true, Ordering::SeqCst);
     }
 
+    /// Play the `--countdown` tick cue (a no-op unless audio cues are on)
+    pub fn countdown_tick(&self) {
+        play_cue(&self.audio_cue, AudioCueType::CountdownTick);
+    }
+
     /// Execute the transcription workflow
     pub async fn execute(
         &self,
@@ -146,17 +216,52 @@ where
         // Reset stop flag
         self.stop_flag.store(false, Ordering::SeqCst);
 
+        // A duration below the minimum can never yield a long enough clip,
+        // so reject it before touching the microphone. Stopping early can
+        // still cut a longer one short; that is checked after recording.
+        if let Some(min) = input.min_duration.filter(|min| input.duration < *min) {
+            return Err(TranscribeError::TooShort {
+                elapsed: input.duration,
+                min,
+            });
+        }
+
         self.prepare_recording(&input, &callbacks, true).await;
+        play_cue(&self.audio_cue, AudioCueType::RecordingStart);
 
         // Record audio
+        self.clock().start();
         let audio = self
             .recorder
-            .record(input.duration, callbacks.on_progress.clone())
+            .record(
+                input.duration,
+                callbacks.on_progress.clone(),
+                Arc::clone(&self.stop_flag),
+            )
             .await?;
+        self.clock().stop();
+        let elapsed = audio.duration_ms().map(Duration::from_millis);
+        if let Some((elapsed, min)) = elapsed
+            .zip(input.min_duration)
+            .filter(|(elapsed, min)| elapsed < min)
+        {
+            play_cue(&self.audio_cue, AudioCueType::RecordingCancel);
+            return Err(TranscribeError::TooShort { elapsed, min });
+        }
+        if let Err(e) = check_silence(&audio, input.silence_threshold) {
+            play_cue(&self.audio_cue, AudioCueType::RecordingCancel);
+            return Err(e);
+        }
+        play_cue(&self.audio_cue, AudioCueType::RecordingStop);
+        warn_if_clipping(input.warning_sink.as_ref(), &audio);
 
         self.finalize_recording(&input, &callbacks, audio).await
     }
 
+    fn clock(&self) -> std::sync::MutexGuard<'_, RunClock> {
+        self.clock.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
     async fn prepare_recording(
         &self,
         input: &TranscribeInput,
@@ -191,6 +296,26 @@ where
         }
     }
 
+    /// Transcribe with `[mm:ss]` segment prefixes, falling back to the
+    /// plain transcript (with a warning) when there is no timing.
+    async fn transcribe_timestamped(
+        &self,
+        input: &TranscribeInput,
+        audio: &AudioData,
+    ) -> Result<String, TranscribeError> {
+        let timed = self.transcriber.transcribe_timed(audio).await?;
+        match timed.segments {
+            Some(segments) => Ok(format_timestamped(&segments)),
+            None => {
+                warn(
+                    input.warning_sink.as_ref(),
+                    "segment timestamps are not available for this model; output is not timestamped",
+                );
+                Ok(timed.text)
+            }
+        }
+    }
+
     async fn finalize_recording(
         &self,
         input: &TranscribeInput,
@@ -213,6 +338,24 @@ where
         audio: AudioData,
     ) -> Result<TranscribeOutput, TranscribeError> {
         let audio_size_bytes = audio.size_bytes() as u64;
+        let audio_duration_ms = audio.duration_ms();
+        let timer = self.clock().take();
+
+        if input.dry_run {
+            return Ok(TranscribeOutput {
+                text: String::new(),
+                clipboard_copied: false,
+                clipboard_backend: None,
+                keystroke_sent: false,
+                keystroke_backend: None,
+                paste_sent: false,
+                audio_size_bytes,
+                audio_duration_ms,
+                word_count: 0,
+                char_count: 0,
+                timings: timer.finish(&audio, None),
+            });
+        }
 
         // Notify transcription start
         if input.enable_notify {
@@ -230,22 +373,44 @@ where
             cb();
         }
 
-        // Transcribe
-        let text = self.transcriber.transcribe(&audio).await?;
+        // Transcribe. Timed output needs the whole response, so it skips
+        // streaming.
+        let request_started = Instant::now();
+        let transcript = match callbacks.on_transcript_delta {
+            _ if input.timestamps => self.transcribe_timestamped(input, &audio).await?,
+            Some(ref on_delta) => {
+                self.transcriber
+                    .transcribe_stream(&audio, Arc::clone(on_delta))
+                    .await?
+            }
+            None => self.transcriber.transcribe(&audio).await?,
+        };
+        let transcription = request_started.elapsed();
 
         if let Some(ref cb) = callbacks.on_transcribing_end {
             cb();
         }
 
+        let transcript = post_process(
+            self.post_processor.as_ref(),
+            transcript,
+            input.warning_sink.as_ref(),
+        )
+        .await;
+        // `transcript` is now the post-processed text. Everything output
+        // also gets the template; history and the counts take it without.
+        let text = input.template.apply(&transcript);
+
         // Output actions are best-effort and delegated to the shared
         // dispatcher so the daemon flow can reuse the same logic.
         let opts = OutputOptions {
             clipboard: input.enable_clipboard,
+            clipboard_append: input.clipboard_append.as_deref(),
             keystroke: input.enable_keystroke,
             paste: input.enable_paste,
         };
         let result = dispatch_output(
-            &self.clipboard,
+            self.clipboard.as_ref(),
             &self.keystroke,
             &self.smart_paste,
             &text,
@@ -254,24 +419,36 @@ where
         )
         .await;
 
+        if let Some(ref history) = input.history {
+            history
+                .record(&transcript, &audio, input.warning_sink.as_ref())
+                .await;
+        }
+
         // Notify completion
         if input.enable_notify {
             let _ = self
                 .notifier
-                .notify(
-                    "SmartScribe",
-                    "Transcription complete!",
-                    NotificationIcon::Success,
+                .notify_result(
+                    &completion_title(audio_duration_ms),
+                    &text,
+                    Some(copy_action(&self.clipboard, &text)),
                 )
                 .await;
         }
 
         Ok(TranscribeOutput {
+            word_count: word_count(&transcript),
+            char_count: char_count(&transcript),
             text,
             clipboard_copied: result.clipboard_copied,
+            clipboard_backend: result.clipboard_backend,
             keystroke_sent: result.keystroke_sent,
+            keystroke_backend: result.keystroke_backend,
             paste_sent: result.paste_sent,
             audio_size_bytes,
+            audio_duration_ms,
+            timings: timer.finish(&audio, Some(transcription)),
         })
     }
 }
@@ -280,7 +457,7 @@ impl<R, T, C, K, N, P> TranscribeRecordingUseCase<R, T, C, K, N, P>
 where
     R: AudioRecorder + UnboundedRecorder,
     T: Transcriber,
-    C: Clipboard,
+    C: Clipboard + 'static,
     K: Keystroke,
     N: Notifier,
     P: SmartPaste,
@@ -293,18 +470,44 @@ where
     ) -> Result<(), TranscribeError> {
         self.stop_flag.store(false, Ordering::SeqCst);
         self.prepare_recording(input, callbacks, false).await;
-        self.recorder.start().await?;
+        // No limit: foreground sessions end on Ctrl+C or `--max-duration`
+        // and exit with the process.
+        self.clock().start();
+        self.recorder.start(None).await?;
+        play_cue(&self.audio_cue, AudioCueType::RecordingStart);
         Ok(())
     }
 
     /// Stop an unbounded recording session and return the captured audio.
-    pub async fn stop_recording(&self) -> Result<AudioData, TranscribeError> {
-        Ok(self.recorder.stop().await?)
+    ///
+    /// Recordings shorter than `input.min_duration` are cancelled and
+    /// reported as [`TranscribeError::TooShort`]; silent ones are reported
+    /// as [`TranscribeError::NoAudio`].
+    pub async fn stop_recording(
+        &self,
+        input: &TranscribeInput,
+    ) -> Result<AudioData, TranscribeError> {
+        let elapsed = Duration::from_millis(self.recorder.elapsed_ms());
+        if let Some(min) = input.min_duration.filter(|min| elapsed < *min) {
+            self.recorder.cancel().await?;
+            play_cue(&self.audio_cue, AudioCueType::RecordingCancel);
+            return Err(TranscribeError::TooShort { elapsed, min });
+        }
+        let audio = self.recorder.stop().await?;
+        self.clock().stop();
+        if let Err(e) = check_silence(&audio, input.silence_threshold) {
+            play_cue(&self.audio_cue, AudioCueType::RecordingCancel);
+            return Err(e);
+        }
+        play_cue(&self.audio_cue, AudioCueType::RecordingStop);
+        warn_if_clipping(input.warning_sink.as_ref(), &audio);
+        Ok(audio)
     }
 
     /// Cancel an in-progress unbounded recording session.
     pub async fn cancel_recording(&self) -> Result<(), TranscribeError> {
         self.recorder.cancel().await?;
+        play_cue(&self.audio_cue, AudioCueType::RecordingCancel);
         Ok(())
     }
 
@@ -324,11 +527,24 @@ where
     }
 }
 
+/// Reject audio whose peak never rose above `threshold` dBFS, so a muted
+/// or unplugged microphone doesn't cost an API call.
+fn check_silence(audio: &AudioData, threshold: Option<i32>) -> Result<(), TranscribeError> {
+    match threshold {
+        Some(threshold) if audio.is_silent(threshold) => Err(TranscribeError::NoAudio {
+            peak_dbfs: audio.peak_dbfs().unwrap_or(f32::NEG_INFINITY),
+        }),
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::ports::{ClipboardError, KeystrokeError, SmartPasteError};
-    use crate::domain::transcription::AudioData;
+    use crate::application::ports::{
+        AudioCueError, ClipboardError, KeystrokeError, SmartPasteError,
+    };
+    use crate::domain::transcription::{AudioData, TimedTranscript, TranscriptSegment};
     use async_trait::async_trait;
 
     // Mock implementations for testing
@@ -340,11 +556,115 @@ mod tests {
             &self,
             _duration: Duration,
             _on_progress: Option<ProgressCallback>,
+            _stop: Arc<AtomicBool>,
         ) -> Result<AudioData, RecordingError> {
             Ok(AudioData::new(vec![0u8; 100], Default::default()))
         }
     }
 
+    /// Recorder whose capture never left digital silence.
+    struct SilentRecorder;
+
+    #[async_trait]
+    impl AudioRecorder for SilentRecorder {
+        async fn record(
+            &self,
+            _duration: Duration,
+            _on_progress: Option<ProgressCallback>,
+            _stop: Arc<AtomicBool>,
+        ) -> Result<AudioData, RecordingError> {
+            Ok(
+                AudioData::new(vec![0u8; 100], Default::default())
+                    .with_peak_dbfs(f32::NEG_INFINITY),
+            )
+        }
+    }
+
+    /// Recorder whose capture saturated for 5% of its samples.
+    struct ClippingRecorder;
+
+    #[async_trait]
+    impl AudioRecorder for ClippingRecorder {
+        async fn record(
+            &self,
+            _duration: Duration,
+            _on_progress: Option<ProgressCallback>,
+            _stop: Arc<AtomicBool>,
+        ) -> Result<AudioData, RecordingError> {
+            Ok(AudioData::new(vec![0u8; 100], Default::default())
+                .with_peak_dbfs(0.0)
+                .with_clipped_ratio(0.05))
+        }
+    }
+
+    /// Recorder stopped after 200ms, whatever duration it was asked for.
+    struct CutShortRecorder;
+
+    #[async_trait]
+    impl AudioRecorder for CutShortRecorder {
+        async fn record(
+            &self,
+            _duration: Duration,
+            _on_progress: Option<ProgressCallback>,
+            _stop: Arc<AtomicBool>,
+        ) -> Result<AudioData, RecordingError> {
+            Ok(AudioData::new(vec![0u8; 100], Default::default()).with_duration_ms(200))
+        }
+    }
+
+    /// Recorder that runs for the full duration unless the stop flag is
+    /// raised, returning one byte per elapsed millisecond.
+    struct StoppableRecorder;
+
+    #[async_trait]
+    impl AudioRecorder for StoppableRecorder {
+        async fn record(
+            &self,
+            duration: Duration,
+            _on_progress: Option<ProgressCallback>,
+            stop: Arc<AtomicBool>,
+        ) -> Result<AudioData, RecordingError> {
+            let start = std::time::Instant::now();
+            while !stop.load(Ordering::SeqCst)
+                && (start.elapsed().as_millis() as u64) < duration.as_millis()
+            {
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            }
+            let captured = start.elapsed().as_millis().max(1) as usize;
+            Ok(AudioData::new(vec![0u8; captured], Default::default()))
+        }
+    }
+
+    /// Recorder that captures for 30ms, then spends 10ms "encoding".
+    struct TimedRecorder;
+
+    #[async_trait]
+    impl AudioRecorder for TimedRecorder {
+        async fn record(
+            &self,
+            _duration: Duration,
+            _on_progress: Option<ProgressCallback>,
+            _stop: Arc<AtomicBool>,
+        ) -> Result<AudioData, RecordingError> {
+            tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+            let encode_started = std::time::Instant::now();
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            Ok(AudioData::new(vec![0u8; 100], Default::default())
+                .with_encode_ms(encode_started.elapsed().as_millis() as u64))
+        }
+    }
+
+    /// Transcriber whose request takes 20ms.
+    struct SlowTranscriber;
+
+    #[async_trait]
+    impl Transcriber for SlowTranscriber {
+        async fn transcribe(&self, _audio: &AudioData) -> Result<String, TranscriptionError> {
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            Ok("Test transcription".to_string())
+        }
+    }
+
     struct MockTranscriber;
 
     #[async_trait]
@@ -361,6 +681,10 @@ mod tests {
         async fn copy(&self, _text: &str) -> Result<(), ClipboardError> {
             Ok(())
         }
+
+        fn backend(&self) -> Option<&str> {
+            Some("wl-copy")
+        }
     }
 
     struct MockKeystroke;
@@ -370,6 +694,10 @@ mod tests {
         async fn type_text(&self, _text: &str) -> Result<(), KeystrokeError> {
             Ok(())
         }
+
+        fn backend(&self) -> Option<&str> {
+            Some("ydotool")
+        }
     }
 
     struct MockNotifier;
@@ -399,6 +727,28 @@ mod tests {
         }
     }
 
+    /// Records every cue it is asked to play.
+    #[derive(Default)]
+    struct MockAudioCue {
+        played: std::sync::Mutex<Vec<AudioCueType>>,
+    }
+
+    impl MockAudioCue {
+        /// Cues played so far, after letting the spawned playback tasks run.
+        async fn played(&self) -> Vec<AudioCueType> {
+            tokio::task::yield_now().await;
+            self.played.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl AudioCue for MockAudioCue {
+        async fn play(&self, cue_type: AudioCueType) -> Result<(), AudioCueError> {
+            self.played.lock().unwrap().push(cue_type);
+            Ok(())
+        }
+    }
+
     #[tokio::test]
     async fn execute_returns_transcription() {
         let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
@@ -408,6 +758,7 @@ mod tests {
             keystroke: MockKeystroke,
             notifier: MockNotifier,
             smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
         });
 
         let input = TranscribeInput::default();
@@ -417,6 +768,31 @@ mod tests {
         assert_eq!(output.text, "Test transcription");
         assert!(!output.clipboard_copied); // Not enabled
         assert!(!output.keystroke_sent); // Not enabled
+        assert_eq!(output.clipboard_backend, None);
+        assert_eq!(output.keystroke_backend, None);
+    }
+
+    #[tokio::test]
+    async fn execute_plays_start_and_stop_cues() {
+        let cue = Arc::new(MockAudioCue::default());
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: MockRecorder,
+            transcriber: MockTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: cue.clone(),
+        });
+
+        use_case
+            .execute(TranscribeInput::default(), TranscribeCallbacks::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            cue.played().await,
+            [AudioCueType::RecordingStart, AudioCueType::RecordingStop]
+        );
     }
 
     #[tokio::test]
@@ -428,6 +804,7 @@ mod tests {
             keystroke: MockKeystroke,
             notifier: MockNotifier,
             smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
         });
 
         let input = TranscribeInput {
@@ -438,6 +815,31 @@ mod tests {
 
         let output = use_case.execute(input, callbacks).await.unwrap();
         assert!(output.clipboard_copied);
+        assert_eq!(output.clipboard_backend.as_deref(), Some("wl-copy"));
+    }
+
+    #[tokio::test]
+    async fn template_wraps_output_but_not_counts() {
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: MockRecorder,
+            transcriber: MockTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
+        });
+
+        let input = TranscribeInput {
+            template: TextTemplate::new(Some("Note: "), Some("\\n")),
+            ..Default::default()
+        };
+        let output = use_case
+            .execute(input, TranscribeCallbacks::default())
+            .await
+            .unwrap();
+        assert_eq!(output.text, "Note: Test transcription\n");
+        assert_eq!(output.word_count, 2);
     }
 
     #[tokio::test]
@@ -449,6 +851,7 @@ mod tests {
             keystroke: MockKeystroke,
             notifier: MockNotifier,
             smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
         });
 
         let input = TranscribeInput {
@@ -459,5 +862,371 @@ mod tests {
 
         let output = use_case.execute(input, callbacks).await.unwrap();
         assert!(output.keystroke_sent);
+        assert_eq!(output.keystroke_backend.as_deref(), Some("ydotool"));
+        assert_eq!(output.clipboard_backend, None);
+    }
+
+    /// Transcriber reporting two timed segments.
+    struct TimedTranscriber;
+
+    #[async_trait]
+    impl Transcriber for TimedTranscriber {
+        async fn transcribe(&self, _audio: &AudioData) -> Result<String, TranscriptionError> {
+            Ok("Hello there. Next item.".to_string())
+        }
+
+        async fn transcribe_timed(
+            &self,
+            _audio: &AudioData,
+        ) -> Result<TimedTranscript, TranscriptionError> {
+            Ok(TimedTranscript {
+                text: "Hello there. Next item.".to_string(),
+                segments: Some(vec![
+                    TranscriptSegment {
+                        start_ms: 0,
+                        text: "Hello there.".to_string(),
+                    },
+                    TranscriptSegment {
+                        start_ms: 65_000,
+                        text: "Next item.".to_string(),
+                    },
+                ]),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn timestamps_prefix_segments() {
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: MockRecorder,
+            transcriber: TimedTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
+        });
+
+        let input = TranscribeInput {
+            timestamps: true,
+            ..Default::default()
+        };
+        let output = use_case
+            .execute(input, TranscribeCallbacks::default())
+            .await
+            .unwrap();
+        assert_eq!(output.text, "[00:00] Hello there.\n[01:05] Next item.");
+    }
+
+    #[tokio::test]
+    async fn timestamps_fall_back_to_plain_text_with_warning() {
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: MockRecorder,
+            transcriber: MockTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
+        });
+
+        let warnings = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+        let sink = Arc::clone(&warnings);
+        let input = TranscribeInput {
+            timestamps: true,
+            warning_sink: Some(Arc::new(move |m: &str| {
+                sink.lock().unwrap().push(m.to_string())
+            })),
+            ..Default::default()
+        };
+        let output = use_case
+            .execute(input, TranscribeCallbacks::default())
+            .await
+            .unwrap();
+        assert_eq!(output.text, "Test transcription");
+        assert!(warnings.lock().unwrap()[0].contains("not available"));
+    }
+
+    #[tokio::test]
+    async fn clipped_recording_warns_and_still_transcribes() {
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: ClippingRecorder,
+            transcriber: MockTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
+        });
+
+        let warnings = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+        let sink = Arc::clone(&warnings);
+        let input = TranscribeInput {
+            warning_sink: Some(Arc::new(move |m: &str| {
+                sink.lock().unwrap().push(m.to_string())
+            })),
+            ..Default::default()
+        };
+        let output = use_case
+            .execute(input, TranscribeCallbacks::default())
+            .await
+            .unwrap();
+        assert_eq!(output.text, "Test transcription");
+        let warnings = warnings.lock().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0].contains("clipping (5.0% of samples"),
+            "{}",
+            warnings[0]
+        );
+    }
+
+    /// Transcriber that must never be reached.
+    struct UnreachableTranscriber;
+
+    #[async_trait]
+    impl Transcriber for UnreachableTranscriber {
+        async fn transcribe(&self, _audio: &AudioData) -> Result<String, TranscriptionError> {
+            panic!("dry run must not call the transcriber");
+        }
+    }
+
+    /// Clipboard that counts copies.
+    #[derive(Default)]
+    struct CountingClipboard {
+        copies: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Clipboard for CountingClipboard {
+        async fn copy(&self, _text: &str) -> Result<(), ClipboardError> {
+            self.copies.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_reports_stage_timings() {
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: TimedRecorder,
+            transcriber: SlowTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
+        });
+
+        let timings = use_case
+            .execute(TranscribeInput::default(), TranscribeCallbacks::default())
+            .await
+            .unwrap()
+            .timings;
+        assert!(timings.recording_ms.unwrap() >= 30, "{timings:?}");
+        assert!(timings.encoding_ms.unwrap() >= 10, "{timings:?}");
+        assert!(timings.transcription_ms.unwrap() >= 20, "{timings:?}");
+        assert!(timings.total_ms >= timings.stages_ms(), "{timings:?}");
+
+        // Audio that wasn't recorded by this run has no recording stages.
+        let audio = AudioData::new(vec![0u8; 100], Default::default()).with_encode_ms(5);
+        let timings = use_case
+            .transcribe_audio(
+                &TranscribeInput::default(),
+                &TranscribeCallbacks::default(),
+                audio,
+            )
+            .await
+            .unwrap()
+            .timings;
+        assert_eq!(timings.recording_ms, None);
+        assert_eq!(timings.encoding_ms, None);
+        assert!(timings.transcription_ms.unwrap() >= 20);
+        assert!(timings.total_ms >= timings.stages_ms());
+    }
+
+    #[tokio::test]
+    async fn dry_run_skips_transcriber_and_outputs() {
+        let clipboard = CountingClipboard::default();
+        let copies = Arc::clone(&clipboard.copies);
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: MockRecorder,
+            transcriber: UnreachableTranscriber,
+            clipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
+        });
+
+        let input = TranscribeInput {
+            enable_clipboard: true,
+            enable_keystroke: true,
+            dry_run: true,
+            ..Default::default()
+        };
+        let output = use_case
+            .execute(input, TranscribeCallbacks::default())
+            .await
+            .unwrap();
+        assert!(output.text.is_empty());
+        assert_eq!(output.audio_size_bytes, 100);
+        assert!(!output.clipboard_copied);
+        assert!(!output.keystroke_sent);
+        assert_eq!(copies.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn stop_early_ends_recording_and_still_transcribes() {
+        let use_case = Arc::new(TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: StoppableRecorder,
+            transcriber: MockTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
+        }));
+
+        let stopper = Arc::clone(&use_case);
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+            stopper.stop_early();
+        });
+
+        let input = TranscribeInput {
+            duration: Duration::from_secs(60),
+            ..Default::default()
+        };
+        let output = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            use_case.execute(input, TranscribeCallbacks::default()),
+        )
+        .await
+        .expect("recording should stop early")
+        .unwrap();
+        assert_eq!(output.text, "Test transcription");
+        assert!(output.audio_size_bytes < 5_000);
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_duration_below_minimum() {
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: MockRecorder,
+            transcriber: MockTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
+        });
+
+        let input = TranscribeInput {
+            duration: Duration::from_millis(300),
+            min_duration: Some(Duration::from_secs(1)),
+            ..Default::default()
+        };
+        let err = use_case
+            .execute(input, TranscribeCallbacks::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TranscribeError::TooShort { .. }));
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_recording_cut_below_minimum() {
+        let cue = Arc::new(MockAudioCue::default());
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: CutShortRecorder,
+            transcriber: MockTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: cue.clone(),
+        });
+
+        let input = TranscribeInput {
+            duration: Duration::from_secs(10),
+            min_duration: Some(Duration::from_secs(1)),
+            ..Default::default()
+        };
+        let err = use_case
+            .execute(input, TranscribeCallbacks::default())
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                TranscribeError::TooShort { elapsed, .. } if elapsed == Duration::from_millis(200)
+            ),
+            "{err}"
+        );
+        assert_eq!(
+            cue.played().await,
+            [AudioCueType::RecordingStart, AudioCueType::RecordingCancel]
+        );
+    }
+
+    #[tokio::test]
+    async fn execute_rejects_silent_recording() {
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: SilentRecorder,
+            transcriber: MockTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
+        });
+
+        let input = TranscribeInput {
+            silence_threshold: Some(-60),
+            ..Default::default()
+        };
+        let err = use_case
+            .execute(input, TranscribeCallbacks::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TranscribeError::NoAudio { .. }), "{err}");
+
+        // With the check off the same audio goes to the transcriber.
+        let output = use_case
+            .execute(TranscribeInput::default(), TranscribeCallbacks::default())
+            .await
+            .unwrap();
+        assert_eq!(output.text, "Test transcription");
+    }
+
+    #[tokio::test]
+    async fn execute_streams_deltas_when_callback_set() {
+        let use_case = TranscribeRecordingUseCase::new(UseCaseDeps {
+            recorder: MockRecorder,
+            transcriber: MockTranscriber,
+            clipboard: MockClipboard,
+            keystroke: MockKeystroke,
+            notifier: MockNotifier,
+            smart_paste: MockSmartPaste,
+            audio_cue: Arc::new(MockAudioCue::default()),
+        });
+
+        let seen = Arc::new(std::sync::Mutex::new(Vec::<String>::new()));
+        let sink = Arc::clone(&seen);
+        let callbacks = TranscribeCallbacks {
+            on_transcript_delta: Some(Arc::new(move |delta: &str| {
+                sink.lock().unwrap().push(delta.to_string());
+            })),
+            ..Default::default()
+        };
+
+        let output = use_case
+            .execute(TranscribeInput::default(), callbacks)
+            .await
+            .unwrap();
+        assert_eq!(output.text, "Test transcription");
+        // Default `transcribe_stream` reports the whole text as one delta.
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec!["Test transcription".to_string()]
+        );
     }
 }
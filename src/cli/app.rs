@@ -1,18 +1,30 @@
 //! Main app runner for one-shot mode
 
 use std::env;
+use std::future::Future;
+use std::io::IsTerminal;
+use std::path::Path;
 use std::process::ExitCode;
 use std::sync::Arc;
 use std::time::Duration as StdDuration;
 
-use tokio::time::timeout;
-
-use crate::application::ports::{AudioCue, AudioCueType, ConfigStore};
-use crate::application::{TranscribeCallbacks, TranscribeInput, TranscribeRecordingUseCase};
-use crate::domain::config::{AppConfig, RawAppConfig};
+use indicatif::ProgressBar;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::sync::Notify;
+use tokio::time::{timeout, timeout_at, Instant};
+
+use crate::application::ports::{
+    Clipboard, ConfigStore, Keystroke, NotificationIcon, Notifier, SmartPaste,
+};
+use crate::application::{
+    HistoryLog, TranscribeCallbacks, TranscribeError, TranscribeInput, TranscribeOutput,
+    TranscribeRecordingUseCase,
+};
+use crate::domain::config::{normalize_api_key, AppConfig, RawAppConfig};
 use crate::domain::error::ConfigError;
 use crate::domain::recording::Duration;
-use crate::infrastructure::XdgConfigStore;
+use crate::domain::transcription::{AudioData, AudioMimeType};
+use crate::infrastructure::{create_notifier, CpalRecorder, Transcriber, XdgConfigStore};
 
 // Re-export the transcriber factory at this path for backwards compatibility
 // with `super::app::create_transcriber` callers (still used by daemon_app).
@@ -21,28 +33,69 @@ pub use crate::infrastructure::create_transcriber;
 use super::args::TranscribeOptions;
 use super::auth_cmd::describe_auth;
 use super::exit_codes;
-use super::output::OneshotResponse;
-use super::presenter::Presenter;
-use super::runtime::{build_adapters, BuildError, RuntimeOptions};
-use super::signals::DaemonSignalHandler;
+use super::output::{
+    format_audio_summary, format_text_summary, format_timings, write_transcript_file,
+    DryRunResponse, OneshotResponse,
+};
+use super::pid_file::{acquire_oneshot_lock, running_daemon_pid};
+use super::presenter::{show_pending_spinner, transcript_preview_on, Presenter};
+use super::runtime::{build_adapters, RuntimeOptions};
+use super::signals::{DaemonSignalHandler, ShutdownSignal};
 
 /// Poll interval for foreground recording updates.
 const FOREGROUND_POLL_MS: u64 = 200;
 
 /// Run the one-shot transcription
+///
+/// With `--timeout`, everything from here to the last output must finish
+/// before the deadline; otherwise the recording is cancelled, any in-flight
+/// request is dropped, and the exit code is [`exit_codes::TIMEOUT`]. With
+/// `--json-errors`, a failure ends with one JSON error object on stderr.
 pub async fn run_oneshot(options: TranscribeOptions, config: &AppConfig) -> ExitCode {
-    let mut presenter = Presenter::new(options.output);
+    let mut presenter = Presenter::new(options.format)
+        .with_quiet(options.quiet)
+        .with_json_errors(options.json_errors);
+    let code = oneshot(&options, config, &mut presenter).await;
+    notify_failure(&presenter, &options, code).await;
+    presenter.report_failure(code);
+    code
+}
 
-    let runtime_opts = RuntimeOptions::from(&options);
-    let bundle = match build_adapters(config, &runtime_opts, &presenter).await {
-        Ok(b) => b,
-        Err(BuildError::Transcriber(msg)) => {
-            presenter.error(&msg);
+/// Body of [`run_oneshot`]; errors go through `presenter` so the wrapper
+/// can report the one that ended the run.
+async fn oneshot(
+    options: &TranscribeOptions,
+    config: &AppConfig,
+    presenter: &mut Presenter,
+) -> ExitCode {
+    let started = Instant::now();
+
+    // Two recorders on one microphone garble both captures, so check before
+    // anything opens the device. Piped audio never touches it.
+    if let Some(pid) = running_daemon_pid().filter(|_| !options.stdin) {
+        let message = format!(
+            "A daemon is already running (PID: {pid}) and would share the microphone; \
+             use `smart-scribe daemon toggle` instead"
+        );
+        if !options.force {
+            presenter.error(&format!("{message}, or pass --force to record anyway"));
             return ExitCode::from(exit_codes::ERROR);
         }
-        Err(BuildError::SmartPaste(msg)) => {
-            presenter.error(&format!("Paste mode unavailable: {}", msg));
-            return ExitCode::from(exit_codes::ERROR);
+        presenter.warn(&message);
+    }
+    // Held until return so a daemon started meanwhile can warn.
+    let _oneshot_lock = if options.stdin {
+        None
+    } else {
+        acquire_oneshot_lock()
+    };
+
+    let runtime_opts = RuntimeOptions::from(options);
+    let bundle = match build_adapters(config, &runtime_opts, presenter).await {
+        Ok(b) => b,
+        Err(e) => {
+            presenter.error_with_kind(e.kind(), &e.to_string());
+            return ExitCode::from(e.exit_code());
         }
     };
     presenter.info(&describe_auth(config));
@@ -54,219 +107,547 @@ pub async fn run_oneshot(options: TranscribeOptions, config: &AppConfig) -> Exit
         keystroke: bundle.keystroke,
         notifier: bundle.notifier,
         smart_paste: bundle.smart_paste,
-    });
-    let audio_cue: Arc<dyn AudioCue> = bundle.audio_cue;
-
-    let enable_paste = options.paste;
-
-    match options.duration {
-        Some(duration) => {
-            let input = TranscribeInput {
-                duration,
-                enable_clipboard: options.clipboard,
-                enable_keystroke: options.keystroke,
-                enable_paste,
-                enable_notify: options.notify,
-                warning_sink: Some(presenter.warning_sink()),
-            };
-            let callbacks = fixed_callbacks(Arc::clone(&audio_cue));
+        audio_cue: bundle.audio_cue,
+    })
+    .with_post_processor(bundle.post_processor);
+    let history = bundle.history;
+
+    // Ctrl+C ends a fixed-length recording early (what was captured is
+    // still transcribed) and, with `--repeat`, ends the session after the
+    // clip in flight.
+    let shutdown = ShutdownSignal::new().with_linked_flag(use_case.stop_flag());
+    if let Err(e) = shutdown.setup().await {
+        presenter.error(&format!("Failed to setup signal handler: {}", e));
+        return ExitCode::from(exit_codes::ERROR);
+    }
+    if options.repeat && options.duration.is_none() && options.max_duration.is_none() {
+        presenter.warn(
+            "--repeat without --duration or --max-duration: Ctrl+C ends the first clip and the session",
+        );
+    }
 
-            match use_case.execute(input, callbacks).await {
-                Ok(output) => present_output(&presenter, output),
-                Err(e) => {
-                    presenter.error(&e.to_string());
-                    ExitCode::from(exit_codes::ERROR)
+    // With `--repeat`, Ctrl+C only raises the shutdown flag; the clip in
+    // flight (cut short if fixed-length) is still transcribed and output
+    // before the loop exits.
+    let session = async {
+        let mut iteration = 0usize;
+        loop {
+            if options.countdown > 0 && !options.stdin {
+                presenter.start_spinner("Get ready...");
+                let finished = run_countdown(
+                    options.countdown,
+                    |remaining| {
+                        presenter.update_spinner(&format!(
+                            "Recording starts in {remaining}... (Ctrl+C to abort)"
+                        ));
+                        use_case.countdown_tick();
+                    },
+                    tokio::time::sleep,
+                    || shutdown.is_shutdown(),
+                )
+                .await;
+                presenter.stop_spinner();
+                if !finished {
+                    // Only the first clip counts as aborted; later ones end
+                    // a `--repeat` session the same way Ctrl+C always does.
+                    if iteration > 0 {
+                        return ExitCode::from(exit_codes::SUCCESS);
+                    }
+                    presenter.error("Aborted before recording started");
+                    return ExitCode::from(exit_codes::ERROR);
                 }
             }
-        }
-        None => {
-            let input = TranscribeInput {
-                duration: options
-                    .max_duration
-                    .unwrap_or_else(Duration::default_duration),
-                enable_clipboard: options.clipboard,
-                enable_keystroke: options.keystroke,
-                enable_paste,
-                enable_notify: options.notify,
-                warning_sink: Some(presenter.warning_sink()),
-            };
-            let callbacks = TranscribeCallbacks {
-                on_progress: None,
-                on_recording_start: None,
-                on_recording_end: None,
-                on_transcribing_start: None,
-                on_transcribing_end: None,
-            };
-
-            let (mut signals, _signal_tx) = match DaemonSignalHandler::new().await {
-                Ok(s) => s,
-                Err(e) => {
-                    presenter.error(&format!("Failed to setup signal handler: {}", e));
-                    return ExitCode::from(exit_codes::ERROR);
+            let result = match options.duration {
+                _ if options.stdin => {
+                    transcribe_stdin(&use_case, presenter, options, &history).await
+                }
+                Some(duration) => {
+                    record_fixed(&use_case, presenter, options, duration, &history).await
                 }
+                None => record_dynamic(&use_case, presenter, options, &history).await,
+            };
+            let output = match result {
+                Ok(output) => output,
+                Err(code) => return code,
             };
 
-            if let Err(e) = use_case.start_recording(&input, &callbacks).await {
-                presenter.error(&e.to_string());
-                return ExitCode::from(exit_codes::ERROR);
+            let separator = (iteration > 0).then_some(options.repeat_separator.as_str());
+            let code = present_output(presenter, output, options, config, separator);
+            if !options.repeat
+                || shutdown.is_shutdown()
+                || code != ExitCode::from(exit_codes::SUCCESS)
+            {
+                return code;
             }
+            iteration += 1;
+        }
+    };
 
-            let cue = Arc::clone(&audio_cue);
-            tokio::spawn(async move {
-                let _ = cue.play(AudioCueType::RecordingStart).await;
-            });
+    let Some(limit) = options.timeout else {
+        return session.await;
+    };
+    let result = timeout_at(started + limit.as_std(), session).await;
+    match result {
+        Ok(code) => code,
+        Err(_) => {
+            presenter.stop_spinner();
+            let _ = use_case.cancel_recording().await;
+            presenter.error(&format!("Timed out after {}", limit));
+            ExitCode::from(exit_codes::TIMEOUT)
+        }
+    }
+}
 
-            presenter.start_spinner(&foreground_recording_message(0, options.max_duration));
+/// With `--notify-on-error`, repeat a recording, transcription, auth or
+/// timeout failure as a desktop notification: a run bound to a hotkey has no terminal to
+/// show the error in. Aborts and discarded clips exit with other codes and
+/// stay quiet, as does everything under `--quiet`.
+async fn notify_failure(presenter: &Presenter, options: &TranscribeOptions, code: ExitCode) {
+    if !options.notify_on_error || options.quiet || !is_failure_worth_notifying(code) {
+        return;
+    }
+    let message = presenter
+        .last_error()
+        .unwrap_or_else(|| "Transcription failed".to_string());
+    let _ = create_notifier()
+        .notify("SmartScribe", &message, NotificationIcon::Warning)
+        .await;
+}
 
-            loop {
-                let elapsed_ms = use_case.elapsed_ms();
+/// Exit codes of a failed recording, transcription, credential check or
+/// `--timeout` run.
+fn is_failure_worth_notifying(code: ExitCode) -> bool {
+    [
+        exit_codes::RECORDING,
+        exit_codes::TRANSCRIPTION,
+        exit_codes::AUTH,
+        exit_codes::TIMEOUT,
+    ]
+    .into_iter()
+    .any(|c| code == ExitCode::from(c))
+}
 
-                if let Some(max_duration) = options.max_duration {
-                    if elapsed_ms >= max_duration.as_millis() {
-                        presenter.warn("Max duration reached, stopping recording");
-                        break;
-                    }
-                }
+/// Type of the one-shot use case as wired by [`build_adapters`].
+type OneshotUseCase = TranscribeRecordingUseCase<
+    CpalRecorder,
+    Transcriber,
+    Box<dyn Clipboard>,
+    Box<dyn Keystroke>,
+    Box<dyn Notifier>,
+    Box<dyn SmartPaste>,
+>;
+
+/// Record one fixed-length clip (`--duration`) and transcribe it.
+///
+/// Ctrl+C raises the use case's stop flag (see [`ShutdownSignal`]), which
+/// ends the recording early; the progress bar is closed and the captured
+/// audio is transcribed as usual. A Ctrl+C once transcription has started
+/// aborts the run instead, except under `--repeat`, where the clip is
+/// finished and the session then ends.
+async fn record_fixed(
+    use_case: &OneshotUseCase,
+    presenter: &mut Presenter,
+    options: &TranscribeOptions,
+    duration: Duration,
+    history: &Option<HistoryLog>,
+) -> Result<TranscribeOutput, ExitCode> {
+    let input = TranscribeInput {
+        duration,
+        min_duration: options.min_duration,
+        silence_threshold: options.silence_threshold,
+        enable_clipboard: options.clipboard,
+        clipboard_append: options.clipboard_append.clone(),
+        enable_keystroke: options.keystroke,
+        enable_paste: options.paste,
+        enable_notify: options.notify,
+        warning_sink: Some(presenter.warning_sink()),
+        history: history.clone(),
+        dry_run: options.dry_run,
+        timestamps: options.timestamps,
+        template: options.template.clone(),
+    };
+    presenter.show_recording_progress(duration.as_millis());
+    let transcribing = Arc::new(Notify::new());
+    let spinner = presenter.pending_spinner();
+    let callbacks = fixed_callbacks(presenter, Arc::clone(&transcribing), spinner.clone());
+    // Clear a transcription spinner the callbacks didn't get to finish.
+    let clear_spinner = || {
+        if let Some(spinner) = spinner.as_ref().filter(|s| !s.is_finished()) {
+            spinner.finish_and_clear();
+        }
+    };
 
-                presenter.update_spinner(&foreground_recording_message(
-                    elapsed_ms,
-                    options.max_duration,
-                ));
-
-                let wait_ms = options
-                    .max_duration
-                    .map(|max| {
-                        max.as_millis()
-                            .saturating_sub(elapsed_ms)
-                            .min(FOREGROUND_POLL_MS)
-                    })
-                    .unwrap_or(FOREGROUND_POLL_MS)
-                    .max(1);
-
-                match timeout(StdDuration::from_millis(wait_ms), signals.recv()).await {
-                    Ok(Some(_)) => break,
-                    Ok(None) => break,
-                    Err(_) => continue,
-                }
+    let result = tokio::select! {
+        result = use_case.execute(input, callbacks) => result,
+        () = interrupt_after(&transcribing, options.repeat) => {
+            clear_spinner();
+            presenter.stop_spinner();
+            presenter.error("Transcription aborted");
+            return Err(ExitCode::from(exit_codes::ERROR));
+        }
+    };
+    clear_spinner();
+    presenter.stop_spinner();
+    result.map_err(|e| transcribe_failed(presenter, &e))
+}
+
+/// Resolve on the first Ctrl+C after `started` is notified. Before that,
+/// Ctrl+C belongs to [`ShutdownSignal`] (stop the recording early); after
+/// it, there is nothing left to stop but the request, so the run aborts.
+///
+/// Never resolves under `--repeat`: there Ctrl+C only ends the session,
+/// which the loop notices once the clip in flight has been output.
+async fn interrupt_after(started: &Notify, repeat: bool) {
+    started.notified().await;
+    if repeat {
+        std::future::pending::<()>().await;
+    }
+    if tokio::signal::ctrl_c().await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Transcribe audio piped on stdin (`--stdin`); nothing is recorded.
+async fn transcribe_stdin(
+    use_case: &OneshotUseCase,
+    presenter: &mut Presenter,
+    options: &TranscribeOptions,
+    history: &Option<HistoryLog>,
+) -> Result<TranscribeOutput, ExitCode> {
+    if std::io::stdin().is_terminal() {
+        presenter.error(
+            "--stdin expects audio piped in, e.g. `arecord -f S16_LE -r 16000 -d 5 | smart-scribe --stdin`",
+        );
+        return Err(ExitCode::from(exit_codes::USAGE_ERROR));
+    }
+    let audio = read_piped_audio(tokio::io::stdin(), options.stdin_format)
+        .await
+        .map_err(|e| {
+            presenter.error(&e);
+            ExitCode::from(exit_codes::RECORDING)
+        })?;
+    presenter.info(&format!(
+        "Read {} ({}) from stdin",
+        format_audio_summary(audio.size_bytes() as u64, None),
+        audio.mime_type()
+    ));
+
+    // Duration limits and the silence check describe a recording; piped
+    // audio is taken as-is.
+    let input = TranscribeInput {
+        enable_clipboard: options.clipboard,
+        clipboard_append: options.clipboard_append.clone(),
+        enable_keystroke: options.keystroke,
+        enable_paste: options.paste,
+        enable_notify: options.notify,
+        warning_sink: Some(presenter.warning_sink()),
+        history: history.clone(),
+        dry_run: options.dry_run,
+        timestamps: options.timestamps,
+        template: options.template.clone(),
+        ..Default::default()
+    };
+    if options.dry_run {
+        return use_case
+            .transcribe_audio(&input, &TranscribeCallbacks::default(), audio)
+            .await
+            .map_err(|e| transcribe_failed(presenter, &e));
+    }
+
+    presenter.start_spinner("Transcribing... Press Ctrl+C to abort");
+    let callbacks = TranscribeCallbacks {
+        on_transcript_delta: presenter.transcript_preview_sink(),
+        ..Default::default()
+    };
+    let started = Notify::new();
+    started.notify_one();
+    let result = tokio::select! {
+        result = use_case.transcribe_audio(&input, &callbacks, audio) => result,
+        () = interrupt_after(&started, false) => {
+            presenter.spinner_fail("Transcription aborted");
+            return Err(ExitCode::from(exit_codes::ERROR));
+        }
+    };
+    match result {
+        Ok(output) => {
+            presenter.spinner_success("Transcription complete");
+            Ok(output)
+        }
+        Err(e) => {
+            presenter.spinner_fail("Transcription failed");
+            Err(transcribe_failed(presenter, &e))
+        }
+    }
+}
+
+/// Read piped audio to the end and work out its format: an explicit
+/// `--stdin-format` wins, then the data's magic bytes, then Ogg.
+async fn read_piped_audio<R: AsyncRead + Unpin>(
+    mut reader: R,
+    format: Option<AudioMimeType>,
+) -> Result<AudioData, String> {
+    let mut data = Vec::new();
+    reader
+        .read_to_end(&mut data)
+        .await
+        .map_err(|e| format!("Failed to read audio from stdin: {e}"))?;
+    if data.is_empty() {
+        return Err("No audio on stdin (the input was empty)".to_string());
+    }
+    let mime_type = format.unwrap_or_else(|| {
+        AudioData::detect_mime_from_magic_bytes(&data, None).unwrap_or(AudioMimeType::Ogg)
+    });
+    Ok(AudioData::new(data, mime_type))
+}
+
+/// Print a failed recording or transcription and pick its exit code.
+fn transcribe_failed(presenter: &Presenter, error: &TranscribeError) -> ExitCode {
+    presenter.error_with_kind(exit_codes::transcribe_error_kind(error), &error.to_string());
+    ExitCode::from(exit_codes::for_transcribe_error(error))
+}
+
+/// Report a failed stop; a recording under `--min-duration` is a warning,
+/// not an error, since nothing went wrong but nothing was transcribed.
+fn stop_failed(presenter: &mut Presenter, error: TranscribeError) -> ExitCode {
+    if matches!(error, TranscribeError::TooShort { .. }) {
+        presenter.spinner_fail("Recording too short");
+        presenter.warn(&error.to_string());
+        presenter.record_failure(
+            exit_codes::transcribe_error_kind(&error),
+            &error.to_string(),
+        );
+        return ExitCode::from(exit_codes::for_transcribe_error(&error));
+    }
+    if matches!(error, TranscribeError::NoAudio { .. }) {
+        presenter.spinner_fail("No audio detected");
+    } else {
+        presenter.spinner_fail("Recording failed");
+    }
+    transcribe_failed(presenter, &error)
+}
+
+/// Record one clip until Ctrl+C (or `--max-duration`) and transcribe it.
+async fn record_dynamic(
+    use_case: &OneshotUseCase,
+    presenter: &mut Presenter,
+    options: &TranscribeOptions,
+    history: &Option<HistoryLog>,
+) -> Result<TranscribeOutput, ExitCode> {
+    let input = TranscribeInput {
+        duration: options
+            .max_duration
+            .unwrap_or_else(Duration::default_duration),
+        min_duration: options.min_duration,
+        silence_threshold: options.silence_threshold,
+        enable_clipboard: options.clipboard,
+        clipboard_append: options.clipboard_append.clone(),
+        enable_keystroke: options.keystroke,
+        enable_paste: options.paste,
+        enable_notify: options.notify,
+        warning_sink: Some(presenter.warning_sink()),
+        history: history.clone(),
+        dry_run: options.dry_run,
+        timestamps: options.timestamps,
+        template: options.template.clone(),
+    };
+    let callbacks = TranscribeCallbacks {
+        on_progress: None,
+        on_recording_start: None,
+        on_recording_end: None,
+        on_transcribing_start: None,
+        on_transcribing_end: None,
+        on_transcript_delta: None,
+    };
+
+    let (mut signals, _signal_tx) = match DaemonSignalHandler::new().await {
+        Ok(s) => s,
+        Err(e) => {
+            presenter.error(&format!("Failed to setup signal handler: {}", e));
+            return Err(ExitCode::from(exit_codes::ERROR));
+        }
+    };
+
+    if let Err(e) = use_case.start_recording(&input, &callbacks).await {
+        return Err(transcribe_failed(presenter, &e));
+    }
+
+    presenter.start_spinner(&foreground_recording_message(0, options.max_duration));
+
+    loop {
+        let elapsed_ms = use_case.elapsed_ms();
+
+        if let Some(max_duration) = options.max_duration {
+            if elapsed_ms >= max_duration.as_millis() {
+                presenter.warn("Max duration reached, stopping recording");
+                break;
             }
+        }
 
-            let stop_future = use_case.stop_recording();
-            tokio::pin!(stop_future);
-
-            let audio = tokio::select! {
-                result = &mut stop_future => {
-                    match result {
-                        Ok(audio) => audio,
-                        Err(e) => {
-                            presenter.spinner_fail("Recording failed");
-                            presenter.error(&e.to_string());
-                            return ExitCode::from(exit_codes::ERROR);
-                        }
-                    }
-                }
-                signal = signals.recv() => {
-                    if signal.is_some() {
-                        presenter.spinner_fail("Recording aborted");
-                        return ExitCode::from(exit_codes::ERROR);
-                    }
+        presenter.update_spinner(&foreground_recording_message(
+            elapsed_ms,
+            options.max_duration,
+        ));
+
+        let wait_ms = options
+            .max_duration
+            .map(|max| {
+                max.as_millis()
+                    .saturating_sub(elapsed_ms)
+                    .min(FOREGROUND_POLL_MS)
+            })
+            .unwrap_or(FOREGROUND_POLL_MS)
+            .max(1);
+
+        match timeout(StdDuration::from_millis(wait_ms), signals.recv()).await {
+            Ok(Some(_)) => break,
+            Ok(None) => break,
+            Err(_) => continue,
+        }
+    }
 
-                    match stop_future.await {
-                        Ok(audio) => audio,
-                        Err(e) => {
-                            presenter.spinner_fail("Recording failed");
-                            presenter.error(&e.to_string());
-                            return ExitCode::from(exit_codes::ERROR);
-                        }
-                    }
-                }
-            };
+    let stop_future = use_case.stop_recording(&input);
+    tokio::pin!(stop_future);
 
-            presenter.spinner_success(&format!(
-                "Recording complete ({})",
-                super::output::format_audio_size(audio.size_bytes() as u64)
-            ));
+    let audio = tokio::select! {
+        result = &mut stop_future => {
+            match result {
+                Ok(audio) => audio,
+                Err(e) => return Err(stop_failed(presenter, e)),
+            }
+        }
+        signal = signals.recv() => {
+            if signal.is_some() {
+                presenter.spinner_fail("Recording aborted");
+                return Err(ExitCode::from(exit_codes::ERROR));
+            }
 
-            let cue = Arc::clone(&audio_cue);
-            tokio::spawn(async move {
-                let _ = cue.play(AudioCueType::RecordingStop).await;
-            });
-
-            presenter.start_spinner("Transcribing... Press Ctrl+C to abort");
-
-            let transcribe_future = use_case.finalize_dynamic_recording(&input, &callbacks, audio);
-            tokio::pin!(transcribe_future);
-
-            let output = tokio::select! {
-                result = &mut transcribe_future => {
-                    match result {
-                        Ok(output) => output,
-                        Err(e) => {
-                            presenter.spinner_fail("Transcription failed");
-                            presenter.error(&e.to_string());
-                            return ExitCode::from(exit_codes::ERROR);
-                        }
-                    }
-                }
-                signal = signals.recv() => {
-                    if signal.is_some() {
-                        presenter.spinner_fail("Transcription aborted");
-                        return ExitCode::from(exit_codes::ERROR);
-                    }
+            match stop_future.await {
+                Ok(audio) => audio,
+                Err(e) => return Err(stop_failed(presenter, e)),
+            }
+        }
+    };
 
-                    match transcribe_future.await {
-                        Ok(output) => output,
-                        Err(e) => {
-                            presenter.spinner_fail("Transcription failed");
-                            presenter.error(&e.to_string());
-                            return ExitCode::from(exit_codes::ERROR);
-                        }
-                    }
+    presenter.spinner_success(&format!(
+        "Recording complete ({})",
+        format_audio_summary(audio.size_bytes() as u64, audio.duration_ms())
+    ));
+
+    if options.dry_run {
+        return use_case
+            .finalize_dynamic_recording(&input, &TranscribeCallbacks::default(), audio)
+            .await
+            .map_err(|e| transcribe_failed(presenter, &e));
+    }
+
+    presenter.start_spinner("Transcribing... Press Ctrl+C to abort");
+
+    // Partial text streams into the spinner line so long recordings
+    // show progress; stdout still only gets the final transcript.
+    let transcribe_callbacks = TranscribeCallbacks {
+        on_transcript_delta: presenter.transcript_preview_sink(),
+        ..Default::default()
+    };
+    let transcribe_future =
+        use_case.finalize_dynamic_recording(&input, &transcribe_callbacks, audio);
+    tokio::pin!(transcribe_future);
+
+    let output = tokio::select! {
+        result = &mut transcribe_future => {
+            match result {
+                Ok(output) => output,
+                Err(e) => {
+                    presenter.spinner_fail("Transcription failed");
+                    return Err(transcribe_failed(presenter, &e));
                 }
-            };
+            }
+        }
+        signal = signals.recv() => {
+            if signal.is_some() {
+                presenter.spinner_fail("Transcription aborted");
+                return Err(ExitCode::from(exit_codes::ERROR));
+            }
 
-            presenter.spinner_success("Transcription complete");
-            present_output(&presenter, output)
+            match transcribe_future.await {
+                Ok(output) => output,
+                Err(e) => {
+                    presenter.spinner_fail("Transcription failed");
+                    return Err(transcribe_failed(presenter, &e));
+                }
+            }
         }
-    }
+    };
+
+    presenter.spinner_success("Transcription complete");
+    Ok(output)
 }
 
-fn fixed_callbacks(audio_cue: Arc<dyn AudioCue>) -> TranscribeCallbacks {
+/// Callbacks for a fixed-length recording: the recorder's progress drives
+/// the bar from [`Presenter::show_recording_progress`], which is replaced
+/// by the completion line; transcription shows `spinner` (previewing the
+/// streamed text, as in [`record_dynamic`]) or, without one, plain status
+/// lines, and notifies `transcribing` as it starts.
+fn fixed_callbacks(
+    presenter: &Presenter,
+    transcribing: Arc<Notify>,
+    spinner: Option<ProgressBar>,
+) -> TranscribeCallbacks {
+    let status = presenter.status_sink();
+    let recording_done = presenter.recording_done_sink();
     TranscribeCallbacks {
-        on_progress: Some(Arc::new(move |_elapsed, _total| {
-            // Progress handled by spinner
+        on_progress: presenter.recording_progress_sink(),
+        on_recording_start: None,
+        on_recording_end: Some(Box::new(move |size_bytes: u64| {
+            recording_done(&format!(
+                "✓ Recording complete ({})",
+                super::output::format_audio_size(size_bytes)
+            ));
         })),
-        on_recording_start: Some(Box::new({
-            let cue = Arc::clone(&audio_cue);
+        on_transcribing_start: Some(Box::new({
+            let status = Arc::clone(&status);
+            let spinner = spinner.clone();
             move || {
-                eprintln!("⠋ Recording...");
-                let cue = Arc::clone(&cue);
-                tokio::spawn(async move {
-                    let _ = cue.play(AudioCueType::RecordingStart).await;
-                });
-            }
-        })),
-        on_recording_end: Some(Box::new({
-            let cue = Arc::clone(&audio_cue);
-            move |size_bytes: u64| {
-                eprintln!(
-                    "✓ Recording complete ({})",
-                    super::output::format_audio_size(size_bytes)
-                );
-                let cue = Arc::clone(&cue);
-                tokio::spawn(async move {
-                    let _ = cue.play(AudioCueType::RecordingStop).await;
-                });
+                transcribing.notify_one();
+                match &spinner {
+                    Some(spinner) => {
+                        show_pending_spinner(spinner, "Transcribing... Press Ctrl+C to abort")
+                    }
+                    None => status("⠋ Transcribing... Press Ctrl+C to abort"),
+                }
             }
         })),
-        on_transcribing_start: Some(Box::new(|| {
-            eprintln!("⠋ Transcribing...");
-        })),
-        on_transcribing_end: Some(Box::new(|| {
-            eprintln!("✓ Transcription complete");
+        on_transcript_delta: spinner.clone().map(transcript_preview_on),
+        on_transcribing_end: Some(Box::new(move || match &spinner {
+            Some(spinner) => spinner.finish_with_message("✓ Transcription complete"),
+            None => status("✓ Transcription complete"),
         })),
     }
 }
 
+/// Count `seconds` down to 1, calling `tick` with the seconds left and then
+/// `sleep` for a second each step. Returns `false` as soon as `cancelled`
+/// reports true (checked before every tick and after the last sleep), so
+/// an interrupted countdown never gets to open the microphone.
+async fn run_countdown<S, F>(
+    seconds: u32,
+    mut tick: impl FnMut(u32),
+    mut sleep: S,
+    cancelled: impl Fn() -> bool,
+) -> bool
+where
+    S: FnMut(StdDuration) -> F,
+    F: Future<Output = ()>,
+{
+    for remaining in (1..=seconds).rev() {
+        if cancelled() {
+            return false;
+        }
+        tick(remaining);
+        sleep(StdDuration::from_secs(1)).await;
+    }
+    !cancelled()
+}
+
 fn foreground_recording_message(elapsed_ms: u64, max_duration: Option<Duration>) -> String {
     let elapsed = Duration::from_millis(elapsed_ms);
 
@@ -276,19 +657,85 @@ fn foreground_recording_message(elapsed_ms: u64, max_duration: Option<Duration>)
     }
 }
 
-fn present_output(presenter: &Presenter, output: crate::application::TranscribeOutput) -> ExitCode {
+/// Emit one transcription. `separator` is set for every `--repeat`
+/// iteration after the first and goes before the text (stdout or file).
+fn present_output(
+    presenter: &Presenter,
+    output: TranscribeOutput,
+    options: &TranscribeOptions,
+    config: &AppConfig,
+    separator: Option<&str>,
+) -> ExitCode {
+    if options.timing {
+        // Straight to stderr: asked for explicitly, so --quiet and
+        // --format json don't hide it.
+        eprintln!("{}", format_timings(&output.timings));
+    }
+    if options.dry_run {
+        return present_dry_run(presenter, &output, options, config);
+    }
+
+    // `-o -` is an explicit request for stdout, same as no `-o` at all.
+    let output_file = options
+        .output_file
+        .as_deref()
+        .filter(|p| p.as_os_str() != "-");
+
+    if let Some(path) = output_file {
+        // Later repeat iterations always append so earlier clips survive.
+        let (text, append) = match separator {
+            Some(sep) => (format!("{sep}\n{}", output.text), true),
+            None => (output.text.clone(), options.append),
+        };
+        if let Err(e) = write_transcript_file(path, &text, append) {
+            presenter.error(&format!(
+                "Failed to write transcription to {}: {}",
+                path.display(),
+                e
+            ));
+            return ExitCode::from(exit_codes::ERROR);
+        }
+    }
+
     if presenter.is_json() {
-        presenter.output_json(&OneshotResponse::from(output));
+        presenter.output_json(&OneshotResponse::new(
+            output,
+            &config.openai_transcribe_model,
+            options.duration,
+        ));
         return ExitCode::from(exit_codes::SUCCESS);
     }
 
-    presenter.output(&output.text);
+    match output_file {
+        Some(path) => presenter.info(&format!(
+            "{} {}",
+            if options.append {
+                "Appended to"
+            } else {
+                "Wrote"
+            },
+            path.display()
+        )),
+        None => {
+            if let Some(sep) = separator {
+                presenter.output(sep);
+            }
+            presenter.output(&output.text);
+        }
+    }
+    presenter.info(&format_text_summary(output.word_count, output.char_count));
 
     if output.clipboard_copied {
-        presenter.info("Copied to clipboard");
+        presenter.info(&match output.clipboard_backend {
+            Some(ref backend) => format!("Copied via {backend}"),
+            None => "Copied to clipboard".to_string(),
+        });
     }
     if output.keystroke_sent {
-        presenter.info("Typed into window");
+        presenter.info(&match output.keystroke_backend {
+            Some(ref backend) => format!("Typed via {backend}"),
+            None => "Typed into window".to_string(),
+        });
     }
     if output.paste_sent {
         presenter.info("Pasted into window");
@@ -297,45 +744,215 @@ fn present_output(presenter: &Presenter, output: crate::application::TranscribeO
     ExitCode::from(exit_codes::SUCCESS)
 }
 
+/// Report a `--dry-run` clip: what was captured and the settings the
+/// transcription request would have carried. Nothing is written to `-o`.
+fn present_dry_run(
+    presenter: &Presenter,
+    output: &TranscribeOutput,
+    options: &TranscribeOptions,
+    config: &AppConfig,
+) -> ExitCode {
+    let prompt = config.transcribe_prompt.as_deref();
+    let language = config.transcribe_language.as_deref();
+
+    if presenter.is_json() {
+        presenter.output_json(&DryRunResponse::new(
+            output,
+            &config.openai_transcribe_model,
+            prompt,
+            language,
+            options.duration,
+        ));
+        return ExitCode::from(exit_codes::SUCCESS);
+    }
+
+    presenter.output(&format!(
+        "audio: {}",
+        format_audio_summary(output.audio_size_bytes, output.audio_duration_ms)
+    ));
+    presenter.output(&format!("model: {}", config.openai_transcribe_model));
+    presenter.output(&format!("language: {}", language.unwrap_or("auto")));
+    presenter.output(&format!("prompt: {}", prompt.unwrap_or("(none)")));
+    presenter.info("Dry run: transcription skipped");
+
+    ExitCode::from(exit_codes::SUCCESS)
+}
+
 /// Get the OpenAI API key from environment or config file (for `auth = api_key`).
-pub async fn get_openai_api_key() -> Result<String, String> {
+pub async fn get_openai_api_key(config_path: Option<&Path>) -> Result<String, String> {
     if let Ok(key) = env::var("OPENAI_API_KEY") {
-        if !key.is_empty() {
-            return Ok(key);
+        if !key.trim().is_empty() {
+            return normalize_api_key(&key).map_err(|e| format!("OPENAI_API_KEY: {e}"));
         }
     }
 
-    let store = XdgConfigStore::new();
+    let store = XdgConfigStore::at(config_path);
     let config = store.load().await.unwrap_or_else(|_| RawAppConfig::empty());
+    let config = config
+        .expand_env(|name| env::var(name).ok())
+        .map_err(|e| e.to_string())?;
 
-    config
+    let key = config
         .openai_api_key
-        .filter(|s| !s.is_empty())
+        .filter(|s| !s.trim().is_empty())
         .ok_or_else(|| {
             "Missing OpenAI API key. Set OPENAI_API_KEY or run \
          'smart-scribe config set openai_api_key <key>'."
                 .to_string()
-        })
+        })?;
+    normalize_api_key(&key).map_err(|e| format!("openai_api_key: {e}"))
 }
 
 /// Load and merge configuration from file, env, and CLI inputs.
 ///
+/// `profile` selects a `[profiles.<name>]` table, layered between the file
+/// and env (`defaults < file < profile < env < CLI`). `config_path`
+/// replaces the default config file (`--config`).
+///
 /// Returns the validated [`AppConfig`]; surfaces validation errors
 /// (`auth=garbage`, malformed durations, ...) as [`ConfigError::
 /// ValidationError`].
-pub async fn load_merged_config(cli_config: RawAppConfig) -> Result<AppConfig, ConfigError> {
-    let store = XdgConfigStore::new();
-    let file_config = store.load().await.unwrap_or_else(|_| RawAppConfig::empty());
+pub async fn load_merged_config(
+    cli_config: RawAppConfig,
+    profile: Option<&str>,
+    config_path: Option<&Path>,
+) -> Result<AppConfig, ConfigError> {
+    let file_config = XdgConfigStore::at(config_path).load().await;
+    AppConfig::try_from(merge_config_layers(file_config, cli_config, profile)?)
+}
+
+/// The layering behind [`load_merged_config`], before validation, for
+/// callers that show the raw values (`config list --profile`).
+///
+/// An unreadable `file_config` counts as empty, except with a profile
+/// selected: the profile lives in that file, so the read error is returned
+/// instead of being reported as an unknown profile.
+pub fn merge_config_layers(
+    file_config: Result<RawAppConfig, ConfigError>,
+    cli_config: RawAppConfig,
+    profile: Option<&str>,
+) -> Result<RawAppConfig, ConfigError> {
+    let mut file_config = match (file_config, profile) {
+        (Ok(config), _) => config,
+        (Err(e), Some(_)) => return Err(e),
+        (Err(_), None) => RawAppConfig::empty(),
+    };
+    if let Some(name) = profile {
+        file_config = file_config.select_profile(name)?;
+    }
+    let file_config = file_config.expand_env(|name| env::var(name).ok())?;
 
     let env_config = RawAppConfig {
         openai_api_key: env::var("OPENAI_API_KEY").ok().filter(|s| !s.is_empty()),
+        openai_base_url: env::var("OPENAI_BASE_URL").ok().filter(|s| !s.is_empty()),
+        ipc_token: env::var("SMART_SCRIBE_IPC_TOKEN")
+            .ok()
+            .filter(|s| !s.is_empty()),
         ..Default::default()
     };
 
-    let merged = RawAppConfig::defaults()
+    Ok(RawAppConfig::defaults()
         .merge(file_config)
         .merge(env_config)
-        .merge(cli_config);
+        .merge(cli_config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_run_failures_notify() {
+        let notifies = |c: u8| is_failure_worth_notifying(ExitCode::from(c));
+        assert!(notifies(exit_codes::RECORDING));
+        assert!(notifies(exit_codes::TRANSCRIPTION));
+        assert!(notifies(exit_codes::AUTH));
+        assert!(notifies(exit_codes::TIMEOUT));
+        assert!(!notifies(exit_codes::ERROR));
+        assert!(!notifies(exit_codes::USAGE_ERROR));
+    }
+
+    #[tokio::test]
+    async fn repeat_transcriptions_are_not_aborted() {
+        let started = Notify::new();
+        started.notify_one();
+        let interrupted = tokio::time::timeout(
+            std::time::Duration::from_millis(50),
+            interrupt_after(&started, true),
+        )
+        .await;
+        assert!(interrupted.is_err());
+    }
 
-    AppConfig::try_from(merged)
+    #[tokio::test]
+    async fn countdown_ticks_each_second_then_finishes() {
+        let ticks = std::cell::RefCell::new(Vec::new());
+        let slept = std::cell::Cell::new(0u64);
+        let finished = run_countdown(
+            3,
+            |n| ticks.borrow_mut().push(n),
+            |d| {
+                slept.set(slept.get() + d.as_secs());
+                async {}
+            },
+            || false,
+        )
+        .await;
+
+        assert!(finished);
+        assert_eq!(*ticks.borrow(), vec![3, 2, 1]);
+        assert_eq!(slept.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn countdown_stops_when_cancelled() {
+        let ticks = std::cell::RefCell::new(Vec::new());
+        let cancelled = std::cell::Cell::new(false);
+        let finished = run_countdown(
+            5,
+            |n| {
+                ticks.borrow_mut().push(n);
+                // Ctrl+C arrives during the second tick's sleep.
+                if n == 4 {
+                    cancelled.set(true);
+                }
+            },
+            |_| async {},
+            || cancelled.get(),
+        )
+        .await;
+
+        assert!(!finished);
+        assert_eq!(*ticks.borrow(), vec![5, 4]);
+    }
+
+    #[tokio::test]
+    async fn zero_countdown_finishes_immediately() {
+        let finished = run_countdown(0, |_| panic!("no ticks"), |_| async {}, || false).await;
+        assert!(finished);
+    }
+
+    #[tokio::test]
+    async fn piped_audio_format_is_explicit_then_sniffed_then_ogg() {
+        let wav = b"RIFF\x24\x00\x00\x00WAVEfmt ".as_slice();
+        let audio = read_piped_audio(wav, None).await.unwrap();
+        assert_eq!(audio.mime_type(), AudioMimeType::Wav);
+        assert_eq!(audio.size_bytes(), wav.len());
+
+        let audio = read_piped_audio(wav, Some(AudioMimeType::Mp3))
+            .await
+            .unwrap();
+        assert_eq!(audio.mime_type(), AudioMimeType::Mp3);
+
+        let audio = read_piped_audio(b"\x00\x01\x02".as_slice(), None)
+            .await
+            .unwrap();
+        assert_eq!(audio.mime_type(), AudioMimeType::Ogg);
+    }
+
+    #[tokio::test]
+    async fn empty_stdin_is_an_error() {
+        let err = read_piped_audio(b"".as_slice(), None).await.unwrap_err();
+        assert!(err.contains("empty"), "{err}");
+    }
 }
@@ -1,9 +1,14 @@
 //! CLI argument definitions using Clap
 
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand, ValueEnum};
 use serde::{Deserialize, Serialize};
 
+use super::ipc::IpcEndpoint;
+use crate::domain::config::RawAppConfig;
 use crate::domain::recording::Duration;
+use crate::domain::transcription::{AudioMimeType, TextTemplate};
 
 /// SmartScribe - AI-powered voice to text transcription
 #[derive(Parser, Debug)]
@@ -12,7 +17,8 @@ use crate::domain::recording::Duration;
 #[command(about = "AI-powered voice to text transcription")]
 #[command(long_about = None)]
 pub struct Cli {
-    /// Output format (text for humans, json for machine-readable output)
+    /// Output format (text for humans, json for machine-readable output).
+    /// In json mode stdout carries only JSON; status lines go to stderr.
     #[arg(
         long,
         value_enum,
@@ -20,9 +26,81 @@ pub struct Cli {
         global = true,
         default_value = "text"
     )]
-    pub output: OutputFormatArg,
+    pub format: OutputFormatArg,
+
+    /// Suppress status output; only errors (stderr) and results (stdout) remain
+    #[arg(short = 'q', long, global = true)]
+    pub quiet: bool,
+
+    /// Disable ANSI colors (also honoured: a non-empty NO_COLOR env var;
+    /// colors are off automatically when stderr is not a terminal)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// On failure, write one JSON object (`error_kind`, `message`,
+    /// `exit_code`) to stderr instead of the human error line (not used by
+    /// the daemon, whose failures are `error` events)
+    #[arg(long, global = true)]
+    pub json_errors: bool,
+
+    /// Diagnostic log filter (error, warn, info, debug, trace, or RUST_LOG
+    /// directives). Overrides RUST_LOG; defaults to warn.
+    #[arg(long, value_name = "LEVEL", global = true)]
+    pub log_level: Option<String>,
+
+    /// Append diagnostic logs to PATH instead of stderr
+    #[arg(long, value_name = "PATH", global = true)]
+    pub log_file: Option<PathBuf>,
+
+    /// Daemon control endpoint: `local` (Unix socket / named pipe) or
+    /// `tcp:HOST:PORT`. Must match between the daemon and its clients.
+    #[arg(long, value_name = "ENDPOINT", global = true, env = "SMART_SCRIBE_IPC")]
+    pub ipc: Option<IpcEndpoint>,
+
+    /// Apply the `[profiles.<NAME>]` table from the config file on top of
+    /// the base settings
+    #[arg(long, value_name = "NAME", global = true, env = "SMART_SCRIBE_PROFILE")]
+    pub profile: Option<String>,
 
-    /// Fixed recording duration (e.g., 10s, 1m, 2m30s). If omitted, recording runs until Ctrl+C.
+    /// Read and write this config file instead of the default
+    /// (`smart-scribe config path`)
+    #[arg(
+        long = "config",
+        value_name = "PATH",
+        global = true,
+        env = "SMART_SCRIBE_CONFIG"
+    )]
+    pub config_path: Option<PathBuf>,
+
+    /// Write the transcription to PATH instead of stdout (`-` means stdout)
+    #[arg(
+        short = 'o',
+        long,
+        value_name = "PATH",
+        conflicts_with = "daemon",
+        value_parser = parse_output_path
+    )]
+    pub output: Option<PathBuf>,
+
+    /// Append to the --output file instead of overwriting it
+    #[arg(long, requires = "output")]
+    pub append: bool,
+
+    /// Text placed before each transcription, wherever it is output
+    /// (`{text}` stands for the transcription; `\n` and `\t` escapes)
+    #[arg(long, value_name = "STR")]
+    pub prepend: Option<String>,
+
+    /// Text placed after each transcription (see --prepend)
+    #[arg(long, value_name = "STR")]
+    pub append_text: Option<String>,
+
+    /// Shell command the transcription is piped through before output; its
+    /// stdout replaces the text (the raw text is kept if it fails)
+    #[arg(long, value_name = "CMD")]
+    pub post_command: Option<String>,
+
+    /// Fixed recording duration (e.g., 10s, 2m30s, 1h, 500ms). If omitted, recording runs until Ctrl+C.
     #[arg(short = 'd', long, value_name = "TIME", conflicts_with = "daemon")]
     pub duration: Option<String>,
 
@@ -30,11 +108,20 @@ pub struct Cli {
     #[arg(short = 'c', long)]
     pub clipboard: bool,
 
+    /// Append each transcription to the current clipboard text instead of
+    /// replacing it (implies --clipboard)
+    #[arg(long)]
+    pub clipboard_append: bool,
+
+    /// Separator placed before each appended transcription [default: newline]
+    #[arg(long, value_name = "STR", requires = "clipboard_append")]
+    pub clipboard_separator: Option<String>,
+
     /// Type transcription into focused window
     #[arg(short = 'k', long)]
     pub keystroke: bool,
 
-    /// Keystroke tool to use (Linux: enigo, auto, ydotool, xdotool, wtype)
+    /// Keystroke tool to use (Linux: enigo, auto, ydotool, xdotool, wtype, kdotool)
     #[arg(long, value_name = "TOOL")]
     pub keystroke_tool: Option<String>,
 
@@ -42,15 +129,86 @@ pub struct Cli {
     #[arg(short = 'n', long)]
     pub notify: bool,
 
+    /// Show a desktop notification when recording or transcription fails
+    /// (one-shot mode)
+    #[arg(long, conflicts_with = "daemon")]
+    pub notify_on_error: bool,
+
     /// Smart paste: capture window, transcribe, paste via clipboard (Linux/KDE Wayland)
     #[cfg(target_os = "linux")]
-    #[arg(short = 'p', long, conflicts_with_all = ["clipboard", "keystroke"])]
+    #[arg(short = 'p', long, conflicts_with_all = ["clipboard", "clipboard_append", "keystroke"])]
     pub paste: bool,
 
     /// Play audio cues on recording events
     #[arg(short = 'a', long)]
     pub audio_cue: bool,
 
+    /// Reuse cached transcripts for identical audio, model and prompt
+    #[arg(long)]
+    pub cache: bool,
+
+    /// Don't record transcriptions in the history file
+    #[arg(long)]
+    pub no_history: bool,
+
+    /// Keep recording and transcribing clips until Ctrl+C (one-shot mode)
+    #[arg(long, conflicts_with = "daemon")]
+    pub repeat: bool,
+
+    /// Record and encode, print the audio size/duration and the request
+    /// settings, then stop without transcribing or touching the
+    /// clipboard/keyboard (one-shot mode)
+    #[arg(long, conflicts_with = "daemon")]
+    pub dry_run: bool,
+
+    /// Prefix each transcript segment with its `[mm:ss]` start time (needs a
+    /// model with segment timing, e.g. `whisper-1`; otherwise plain text
+    /// and a warning)
+    #[arg(long, conflicts_with = "daemon")]
+    pub timestamps: bool,
+
+    /// Record even if a daemon is running (both would share the microphone)
+    #[arg(long, conflicts_with = "daemon")]
+    pub force: bool,
+
+    /// Print the raw JSON body of each transcription response to stderr,
+    /// credentials redacted (one-shot mode; disables streaming and the
+    /// transcript cache)
+    #[arg(long, conflicts_with = "daemon")]
+    pub raw: bool,
+
+    /// Print how long recording, encoding and the transcription request
+    /// took to stderr after each transcription (one-shot and daemon)
+    #[arg(long)]
+    pub timing: bool,
+
+    /// Line printed between --repeat outputs
+    #[arg(long, value_name = "STR", requires = "repeat")]
+    pub repeat_separator: Option<String>,
+
+    /// Transcribe audio piped on stdin instead of recording
+    /// (e.g. `arecord -f S16_LE -r 16000 -d 5 | smart-scribe --stdin`)
+    #[arg(
+        long,
+        conflicts_with_all = ["daemon", "duration", "max_duration", "repeat", "force"]
+    )]
+    pub stdin: bool,
+
+    /// Count down N seconds before the microphone opens, so the first word
+    /// isn't clipped (one-shot mode; Ctrl+C during it aborts)
+    #[arg(
+        long,
+        value_name = "N",
+        default_value_t = 0,
+        conflicts_with_all = ["daemon", "stdin"]
+    )]
+    pub countdown: u32,
+
+    /// Format of the --stdin audio: ogg, wav, mp3, flac, webm, m4a, ...
+    /// [default: detected from the data, else ogg]
+    #[arg(long, value_name = "FORMAT", requires = "stdin", value_parser = parse_audio_format)]
+    pub stdin_format: Option<AudioMimeType>,
+
     /// Run as daemon (control via: smart-scribe daemon toggle/cancel/status)
     #[arg(long)]
     pub daemon: bool,
@@ -59,16 +217,51 @@ pub struct Cli {
     #[arg(long, value_name = "TIME", conflicts_with = "duration")]
     pub max_duration: Option<String>,
 
-    /// Show recording indicator (daemon mode only; Wayland overlay on Linux, system tray on Windows)
+    /// Discard recordings shorter than TIME instead of transcribing them
+    #[arg(long, value_name = "TIME")]
+    pub min_duration: Option<String>,
+
+    /// Transcribe even if the recording looks silent (skips the
+    /// "no audio detected" check)
+    #[arg(long)]
+    pub no_silence_check: bool,
+
+    /// Record from the input device NAME (exact or partial match; e.g.
+    /// `pipewire`, `pulse`, `hw:1`) instead of the system default
+    #[arg(long, value_name = "NAME")]
+    pub device: Option<String>,
+
+    /// Capture in FORMAT when the device supports it: `i16` skips the float
+    /// round-trip, `f32` keeps the device's own conversion, `auto` (default)
+    /// lets the sample rate decide
+    #[arg(long, value_name = "FORMAT", value_parser = ["auto", "i16", "f32"])]
+    pub sample_format: Option<String>,
+
+    /// Give up on the whole one-shot run (recording + transcription) after
+    /// TIME, exiting with code 124
+    #[arg(long, value_name = "TIME", conflicts_with = "daemon")]
+    pub timeout: Option<Duration>,
+
+    /// Stop recording by itself once speech is followed by silence
+    /// (daemon mode only; window set by `daemon_vad_silence`)
+    #[arg(long, requires = "daemon")]
+    pub vad: bool,
+
+    /// Show recording indicator (daemon mode only; Wayland or X11 overlay on Linux, tray icon and overlay on Windows; not available on macOS)
     #[cfg(any(target_os = "linux", target_os = "windows"))]
     #[arg(long, requires = "daemon")]
     pub indicator: bool,
 
-    /// Indicator position on screen (daemon mode only, Linux/Wayland)
-    #[cfg(target_os = "linux")]
+    /// Indicator position on screen (daemon mode only, Linux and Windows)
+    #[cfg(any(target_os = "linux", target_os = "windows"))]
     #[arg(long, value_name = "POSITION", requires = "indicator")]
     pub indicator_position: Option<IndicatorPosition>,
 
+    /// Click the indicator to stop recording (daemon mode only, Linux; default is click-through)
+    #[cfg(target_os = "linux")]
+    #[arg(long, requires = "indicator")]
+    pub indicator_clickable: bool,
+
     /// Config subcommand
     #[command(subcommand)]
     pub command: Option<Commands>,
@@ -100,6 +293,75 @@ pub enum Commands {
         #[command(subcommand)]
         action: AuthAction,
     },
+    /// Manage the transcript cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Show or re-copy past transcriptions
+    History {
+        #[command(subcommand)]
+        action: HistoryAction,
+    },
+    /// Transcribe existing audio files (paths, directories or globs such
+    /// as `memos/*.ogg`); each transcript goes next to its file as `.txt`
+    Batch {
+        /// Audio files, directories (their audio files, non-recursive) or
+        /// quoted glob patterns (`*` and `?` in the file name)
+        #[arg(required = true, value_name = "PATH|GLOB")]
+        paths: Vec<String>,
+
+        /// Transcribe up to N files at once
+        #[arg(
+            short = 'j',
+            long,
+            value_name = "N",
+            default_value_t = 1,
+            value_parser = clap::value_parser!(u32).range(1..=32)
+        )]
+        concurrency: u32,
+
+        /// Write every transcript into PATH (in input order, one
+        /// `# <file>` heading each) instead of per-file `.txt` files
+        #[arg(short = 'o', long, value_name = "PATH")]
+        output: Option<PathBuf>,
+
+        /// Give up on a file whose loading (including audio extraction)
+        /// and transcription take longer than TIME
+        #[arg(long, value_name = "TIME")]
+        timeout: Option<Duration>,
+    },
+    /// Re-transcribe the audio of the last transcription, e.g. with
+    /// another model or prompt, without recording again
+    Replay {
+        /// Transcription model to use instead of `openai_transcribe_model`
+        #[arg(long, value_name = "ID")]
+        model: Option<String>,
+
+        /// Prompt to use instead of `transcribe_prompt` (vocabulary, topic)
+        #[arg(long, value_name = "TEXT")]
+        prompt: Option<String>,
+
+        /// Language hint to use instead of `transcribe_language` (e.g. `en`)
+        #[arg(long, value_name = "CODE")]
+        language: Option<String>,
+    },
+    /// Check the microphone without calling any API
+    Devices {
+        #[command(subcommand)]
+        action: DevicesAction,
+    },
+    /// Discover valid `--model` / `openai_transcribe_model` values
+    Models {
+        #[command(subcommand)]
+        action: ModelsAction,
+    },
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Target shell
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
 }
 
 /// Daemon control actions
@@ -107,12 +369,36 @@ pub enum Commands {
 pub enum DaemonAction {
     /// Toggle recording (start if idle, stop if recording)
     Toggle,
+    /// Start recording (does nothing unless the daemon is idle)
+    Start,
+    /// Stop recording and transcribe (does nothing unless recording)
+    Stop,
     /// Cancel current recording without transcribing
     Cancel,
     /// Show daemon status
     Status,
     /// Subscribe to daemon events (JSON output only)
     Subscribe,
+    /// Stop the running daemon (if any) and start a fresh one with the same flags
+    Restart,
+    /// Re-read the config file (clipboard, keystroke, notify, max_duration, min_duration)
+    Reload,
+    /// Print the daemon's most recent transcription
+    Last,
+    /// Print the running daemon's version
+    Version,
+    /// Print the end of the daemon log (`--log-file`, else the running
+    /// daemon's, else the default under the XDG state dir); works whether
+    /// or not the daemon is running
+    Logs {
+        /// Number of lines to print
+        #[arg(short = 'n', long, default_value_t = 50)]
+        lines: usize,
+
+        /// Keep printing lines as the daemon writes them (Ctrl+C to stop)
+        #[arg(short = 'f', long)]
+        follow: bool,
+    },
 }
 
 /// Auth subcommands
@@ -122,8 +408,56 @@ pub enum AuthAction {
     Status,
 }
 
+/// Transcript cache subcommands
+#[derive(Subcommand, Debug, Clone, Copy)]
+pub enum CacheAction {
+    /// Delete all cached transcripts
+    Clear,
+}
+
+/// Model discovery subcommands
+#[derive(Subcommand, Debug, Clone, Copy)]
+pub enum ModelsAction {
+    /// List the transcription models your API key can use (`auth = api_key`)
+    List {
+        /// Show every model, not just speech-to-text ones
+        #[arg(long)]
+        all: bool,
+    },
+}
+
+/// Audio device subcommands
+#[derive(Subcommand, Debug, Clone, Copy)]
+pub enum DevicesAction {
+    /// Record a short clip from the input device (`--device` /
+    /// `audio_device`), report its peak level and play it back
+    Test {
+        /// How long to record
+        #[arg(long, value_name = "TIME", default_value = "3s")]
+        duration: Duration,
+    },
+}
+
+/// History subcommands
+#[derive(Subcommand, Debug, Clone, Copy)]
+pub enum HistoryAction {
+    /// List recent transcriptions, newest first
+    List {
+        /// Number of entries to show
+        #[arg(short = 'n', long, default_value_t = 10)]
+        limit: usize,
+    },
+    /// Print the most recent transcription
+    Last,
+    /// Copy entry N (1 = most recent) to the clipboard
+    Copy {
+        /// Entry number as shown by `history list`
+        index: usize,
+    },
+}
+
 /// Config action subcommands
-#[derive(Subcommand, Debug)]
+#[derive(Subcommand, Debug, Clone)]
 pub enum ConfigAction {
     /// Create config file with defaults
     Init,
@@ -139,10 +473,29 @@ pub enum ConfigAction {
         /// Config key
         key: String,
     },
-    /// List all config values
+    /// Remove a config value so its default applies
+    Unset {
+        /// Config key
+        key: String,
+    },
+    /// Open the config file in an editor, then validate it
+    Edit {
+        /// Editor command (default: $VISUAL, then $EDITOR)
+        #[arg(long)]
+        editor: Option<String>,
+    },
+    /// Overwrite the config file with defaults
+    Reset {
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// List all config values (with `--profile`, as merged for that profile)
     List,
     /// Show config file path
     Path,
+    /// Upgrade an older config file to the current layout (keeps a .bak)
+    Migrate,
 }
 
 /// Output format argument for clap ValueEnum
@@ -159,8 +512,8 @@ impl OutputFormatArg {
     }
 }
 
-/// Indicator position on screen (Linux only)
-#[cfg(target_os = "linux")]
+/// Indicator position on screen (Linux and Windows overlays)
+#[cfg(any(target_os = "linux", target_os = "windows"))]
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum)]
 pub enum IndicatorPosition {
     #[value(name = "top-right")]
@@ -178,7 +531,7 @@ pub enum IndicatorPosition {
     BottomLeft,
 }
 
-#[cfg(target_os = "linux")]
+#[cfg(any(target_os = "linux", target_os = "windows"))]
 impl std::str::FromStr for IndicatorPosition {
     type Err = String;
 
@@ -203,33 +556,131 @@ impl std::str::FromStr for IndicatorPosition {
 /// Linux-gated; non-Linux builds always set `paste = false`.
 #[derive(Debug, Clone)]
 pub struct TranscribeOptions {
-    pub output: OutputFormatArg,
+    pub format: OutputFormatArg,
+    pub quiet: bool,
+    /// Transcript destination; `None` (or `-`) means stdout.
+    pub output_file: Option<PathBuf>,
+    /// Append to `output_file` rather than truncating it.
+    pub append: bool,
+    /// Loop record → transcribe until Ctrl+C.
+    pub repeat: bool,
+    /// Delimiter emitted between `repeat` outputs (text mode).
+    pub repeat_separator: String,
     pub duration: Option<Duration>,
     pub max_duration: Option<Duration>,
+    pub min_duration: Option<Duration>,
+    /// Peak level (dBFS) at or below which a recording counts as silent;
+    /// `None` skips the check.
+    pub silence_threshold: Option<i32>,
+    /// Wall-clock limit for the whole run, independent of the recording
+    /// length and the transcriber's request timeout.
+    pub timeout: Option<Duration>,
+    /// Stop after encoding; nothing is sent to the transcriber.
+    pub dry_run: bool,
+    /// Prefix transcript segments with `[mm:ss]` start times.
+    pub timestamps: bool,
+    /// Dump raw response bodies to stderr.
+    pub raw: bool,
+    /// Print the stage timings to stderr.
+    pub timing: bool,
+    /// Record even if a daemon is running.
+    pub force: bool,
+    /// Transcribe audio read from stdin instead of recording.
+    pub stdin: bool,
+    /// Explicit `--stdin-format`; `None` sniffs the data.
+    pub stdin_format: Option<AudioMimeType>,
+    /// Seconds counted down before each recording starts (0 = none).
+    pub countdown: u32,
     pub clipboard: bool,
+    /// Separator for `--clipboard-append`; `None` replaces the clipboard.
+    pub clipboard_append: Option<String>,
     pub keystroke: bool,
     pub keystroke_tool: Option<String>,
     pub paste: bool,
     pub notify: bool,
+    /// Repeat recording/transcription failures as a desktop notification.
+    pub notify_on_error: bool,
+    /// Report a failure as one JSON object on stderr.
+    pub json_errors: bool,
     pub audio_cue: bool,
+    /// Text wrapped around each transcription before it is output.
+    pub template: TextTemplate,
+}
+
+/// Parsed `batch` options.
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    pub paths: Vec<String>,
+    pub concurrency: usize,
+    /// Combined transcript file; `None` writes `<file>.txt` sidecars.
+    pub output: Option<PathBuf>,
+    /// Per-file limit on loading plus the transcription request.
+    pub timeout: Option<Duration>,
 }
 
 /// Parsed daemon options. Same portability rationale as
 /// [`TranscribeOptions`].
 #[derive(Debug, Clone)]
 pub struct DaemonOptions {
-    pub output: OutputFormatArg,
+    pub format: OutputFormatArg,
+    pub quiet: bool,
+    /// Report a startup failure as one JSON object on stderr.
+    pub json_errors: bool,
+    pub ipc: IpcEndpoint,
+    /// CLI layer re-applied on top of the file/env config on reload.
+    pub cli_overlay: RawAppConfig,
+    /// Config profile re-selected on reload.
+    pub profile: Option<String>,
+    /// Config file re-read on reload (`--config`); `None` is the default.
+    pub config_path: Option<PathBuf>,
+    /// Where diagnostics are logged; reported at startup.
+    pub log_file: Option<PathBuf>,
     pub max_duration: Duration,
+    pub min_duration: Option<Duration>,
+    pub silence_threshold: Option<i32>,
     pub clipboard: bool,
+    /// Separator for `--clipboard-append`; `None` replaces the clipboard.
+    pub clipboard_append: Option<String>,
     pub keystroke: bool,
     pub keystroke_tool: Option<String>,
     pub paste: bool,
     pub notify: bool,
     pub audio_cue: bool,
+    /// Text wrapped around each transcription before it is output.
+    pub template: TextTemplate,
+    /// Silence after speech that auto-stops a recording; `None` without
+    /// `--vad` / `daemon_vad`.
+    pub vad_silence: Option<Duration>,
+    /// Print the stage timings of each transcription to stderr.
+    pub timing: bool,
     #[cfg(any(target_os = "linux", target_os = "windows"))]
     pub indicator: bool,
-    #[cfg(target_os = "linux")]
+    #[cfg(any(target_os = "linux", target_os = "windows"))]
     pub indicator_position: IndicatorPosition,
+    #[cfg(target_os = "linux")]
+    pub indicator_clickable: bool,
+    /// How each transcription is written to stdout in text mode.
+    pub output_style: super::output::DaemonOutputStyle,
+}
+
+/// Parse `--stdin-format` from a file extension (`ogg`, `m4a`, ...).
+fn parse_audio_format(value: &str) -> Result<AudioMimeType, String> {
+    AudioMimeType::from_extension(value.trim_start_matches('.')).ok_or_else(|| {
+        format!("unsupported audio format '{value}' (try ogg, wav, mp3, flac, webm or m4a)")
+    })
+}
+
+/// Parse `--output`, rejecting the bare format names `-o` took before it
+/// became a file path.
+fn parse_output_path(value: &str) -> Result<PathBuf, String> {
+    match value {
+        "json" | "text" | "raw" => Err(format!(
+            "'{value}' looks like an output format, but -o/--output takes a file path; \
+             use --format (text or json) to choose the format, or -o ./{value} for a file \
+             named '{value}'"
+        )),
+        _ => Ok(PathBuf::from(value)),
+    }
 }
 
 // Configuration-key validation lives in [`super::config_schema`]; the CLI
@@ -244,7 +695,8 @@ mod tests {
     #[test]
     fn cli_parses_defaults() {
         let cli = Cli::parse_from(["smart-scribe"]);
-        assert_eq!(cli.output, OutputFormatArg::Text);
+        assert_eq!(cli.format, OutputFormatArg::Text);
+        assert!(cli.output.is_none());
         assert!(cli.duration.is_none());
         assert!(!cli.clipboard);
         assert!(!cli.keystroke);
@@ -276,9 +728,87 @@ mod tests {
     }
 
     #[test]
-    fn cli_parses_output_json() {
-        let cli = Cli::parse_from(["smart-scribe", "--output", "json"]);
-        assert_eq!(cli.output, OutputFormatArg::Json);
+    fn cli_parses_format_json() {
+        let cli = Cli::parse_from(["smart-scribe", "--format", "json"]);
+        assert_eq!(cli.format, OutputFormatArg::Json);
+    }
+
+    #[test]
+    fn cli_parses_output_file_and_append() {
+        let cli = Cli::parse_from(["smart-scribe", "-o", "notes.txt", "--append"]);
+        assert_eq!(cli.output, Some(PathBuf::from("notes.txt")));
+        assert!(cli.append);
+
+        // --append is meaningless without a file.
+        assert!(Cli::try_parse_from(["smart-scribe", "--append"]).is_err());
+        // One-shot only.
+        assert!(Cli::try_parse_from(["smart-scribe", "--daemon", "-o", "x"]).is_err());
+    }
+
+    #[test]
+    fn cli_rejects_format_names_as_output_path() {
+        for format in ["json", "text", "raw"] {
+            let err = Cli::try_parse_from(["smart-scribe", "-o", format]).unwrap_err();
+            assert!(err.to_string().contains("--format"), "{err}");
+        }
+        let cli = Cli::parse_from(["smart-scribe", "-o", "./json"]);
+        assert_eq!(cli.output, Some(PathBuf::from("./json")));
+    }
+
+    #[test]
+    fn cli_parses_repeat() {
+        let cli = Cli::parse_from(["smart-scribe", "-d", "5s", "--repeat"]);
+        assert!(cli.repeat);
+        assert!(cli.repeat_separator.is_none());
+
+        let cli = Cli::parse_from(["smart-scribe", "--repeat", "--repeat-separator", "==="]);
+        assert_eq!(cli.repeat_separator.as_deref(), Some("==="));
+
+        assert!(Cli::try_parse_from(["smart-scribe", "--repeat-separator", "x"]).is_err());
+        assert!(Cli::try_parse_from(["smart-scribe", "--daemon", "--repeat"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_timestamps() {
+        assert!(!Cli::parse_from(["smart-scribe"]).timestamps);
+        assert!(Cli::parse_from(["smart-scribe", "--timestamps"]).timestamps);
+        assert!(Cli::try_parse_from(["smart-scribe", "--daemon", "--timestamps"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_clipboard_append() {
+        let cli = Cli::parse_from(["smart-scribe", "--clipboard-append"]);
+        assert!(cli.clipboard_append);
+        assert!(cli.clipboard_separator.is_none());
+        let cli = Cli::parse_from([
+            "smart-scribe",
+            "--clipboard-append",
+            "--clipboard-separator",
+            " | ",
+        ]);
+        assert_eq!(cli.clipboard_separator.as_deref(), Some(" | "));
+        assert!(Cli::try_parse_from(["smart-scribe", "--clipboard-separator", ","]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_force() {
+        assert!(!Cli::parse_from(["smart-scribe"]).force);
+        assert!(Cli::parse_from(["smart-scribe", "--force"]).force);
+        assert!(Cli::try_parse_from(["smart-scribe", "--daemon", "--force"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_raw() {
+        assert!(!Cli::parse_from(["smart-scribe"]).raw);
+        assert!(Cli::parse_from(["smart-scribe", "--raw"]).raw);
+        assert!(Cli::try_parse_from(["smart-scribe", "--daemon", "--raw"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_dry_run() {
+        assert!(!Cli::parse_from(["smart-scribe"]).dry_run);
+        assert!(Cli::parse_from(["smart-scribe", "-d", "5s", "--dry-run"]).dry_run);
+        assert!(Cli::try_parse_from(["smart-scribe", "--daemon", "--dry-run"]).is_err());
     }
 
     #[test]
@@ -288,6 +818,55 @@ mod tests {
         assert_eq!(cli.max_duration, Some("5m".to_string()));
     }
 
+    #[test]
+    fn cli_parses_prepend_and_append_text() {
+        let cli = Cli::parse_from([
+            "smart-scribe",
+            "--prepend",
+            "Note: ",
+            "--append-text",
+            "\\n",
+        ]);
+        assert_eq!(cli.prepend.as_deref(), Some("Note: "));
+        assert_eq!(cli.append_text.as_deref(), Some("\\n"));
+        assert!(!cli.append);
+    }
+
+    #[test]
+    fn cli_parses_timing_in_both_modes() {
+        assert!(Cli::parse_from(["smart-scribe", "--timing"]).timing);
+        assert!(Cli::parse_from(["smart-scribe", "--daemon", "--timing"]).timing);
+        assert!(!Cli::parse_from(["smart-scribe"]).timing);
+    }
+
+    #[test]
+    fn cli_parses_post_command() {
+        let cli = Cli::parse_from(["smart-scribe", "--post-command", "tr a-z A-Z"]);
+        assert_eq!(cli.post_command.as_deref(), Some("tr a-z A-Z"));
+        assert_eq!(Cli::parse_from(["smart-scribe"]).post_command, None);
+    }
+
+    #[test]
+    fn cli_vad_requires_daemon() {
+        assert!(Cli::parse_from(["smart-scribe", "--daemon", "--vad"]).vad);
+        assert!(Cli::try_parse_from(["smart-scribe", "--vad"]).is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn cli_indicator_clickable_requires_indicator() {
+        let cli = Cli::parse_from([
+            "smart-scribe",
+            "--daemon",
+            "--indicator",
+            "--indicator-clickable",
+        ]);
+        assert!(cli.indicator_clickable);
+        assert!(
+            Cli::try_parse_from(["smart-scribe", "--daemon", "--indicator-clickable"]).is_err()
+        );
+    }
+
     #[test]
     fn cli_parses_standalone_max_duration() {
         let cli = Cli::parse_from(["smart-scribe", "--max-duration", "5m"]);
@@ -295,6 +874,14 @@ mod tests {
         assert_eq!(cli.max_duration, Some("5m".to_string()));
     }
 
+    #[test]
+    fn cli_parses_timeout_as_duration() {
+        let cli = Cli::parse_from(["smart-scribe", "--timeout", "1m30s"]);
+        assert_eq!(cli.timeout, Some(Duration::from_secs(90)));
+        assert!(Cli::try_parse_from(["smart-scribe", "--timeout", "soon"]).is_err());
+        assert!(Cli::try_parse_from(["smart-scribe", "--daemon", "--timeout", "1m"]).is_err());
+    }
+
     #[test]
     fn cli_rejects_duration_and_max_duration_together() {
         assert!(
@@ -360,6 +947,129 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn cli_parses_notify_on_error_for_oneshot_only() {
+        let cli = Cli::parse_from(["smart-scribe", "--notify-on-error"]);
+        assert!(cli.notify_on_error);
+        assert!(!Cli::parse_from(["smart-scribe"]).notify_on_error);
+        assert!(Cli::try_parse_from(["smart-scribe", "--daemon", "--notify-on-error"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_json_errors_globally() {
+        assert!(Cli::parse_from(["smart-scribe", "--json-errors"]).json_errors);
+        assert!(Cli::parse_from(["smart-scribe", "history", "list", "--json-errors"]).json_errors);
+    }
+
+    #[test]
+    fn cli_parses_cache_flag_and_clear() {
+        let cli = Cli::parse_from(["smart-scribe", "--cache"]);
+        assert!(cli.cache);
+
+        let cli = Cli::parse_from(["smart-scribe", "cache", "clear"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Cache {
+                action: CacheAction::Clear
+            })
+        ));
+    }
+
+    #[test]
+    fn cli_parses_countdown() {
+        assert_eq!(Cli::parse_from(["smart-scribe"]).countdown, 0);
+        let cli = Cli::parse_from(["smart-scribe", "--countdown", "3", "-d", "10s"]);
+        assert_eq!(cli.countdown, 3);
+        assert!(Cli::try_parse_from(["smart-scribe", "--countdown", "3", "--daemon"]).is_err());
+        assert!(Cli::try_parse_from(["smart-scribe", "--countdown", "-1"]).is_err());
+    }
+
+    #[test]
+    fn cli_parses_replay_overrides() {
+        let cli = Cli::parse_from([
+            "smart-scribe",
+            "replay",
+            "--model",
+            "whisper-1",
+            "--prompt",
+            "Kubernetes, kubectl",
+        ]);
+        let Some(Commands::Replay {
+            model,
+            prompt,
+            language,
+        }) = cli.command
+        else {
+            panic!("expected replay, got {:?}", cli.command);
+        };
+        assert_eq!(model.as_deref(), Some("whisper-1"));
+        assert_eq!(prompt.as_deref(), Some("Kubernetes, kubectl"));
+        assert!(language.is_none());
+    }
+
+    #[test]
+    fn cli_parses_devices_test() {
+        let cli = Cli::parse_from(["smart-scribe", "devices", "test"]);
+        let Some(Commands::Devices {
+            action: DevicesAction::Test { duration },
+        }) = cli.command
+        else {
+            panic!("expected devices test, got {:?}", cli.command);
+        };
+        assert_eq!(duration, Duration::from_secs(3));
+
+        let cli = Cli::parse_from(["smart-scribe", "--device", "pulse", "devices", "test"]);
+        assert_eq!(cli.device.as_deref(), Some("pulse"));
+    }
+
+    #[test]
+    fn cli_parses_stdin_and_its_format() {
+        let cli = Cli::parse_from(["smart-scribe", "--stdin", "--stdin-format", "wav"]);
+        assert!(cli.stdin);
+        assert_eq!(cli.stdin_format, Some(AudioMimeType::Wav));
+
+        assert!(Cli::try_parse_from(["smart-scribe", "--stdin", "--stdin-format", "txt"]).is_err());
+        assert!(Cli::try_parse_from(["smart-scribe", "--stdin-format", "ogg"]).is_err());
+        for conflicting in [["--daemon", ""], ["--duration", "5s"], ["--repeat", ""]] {
+            let args = ["smart-scribe", "--stdin"]
+                .into_iter()
+                .chain(conflicting.into_iter().filter(|a| !a.is_empty()));
+            assert!(Cli::try_parse_from(args).is_err(), "{conflicting:?}");
+        }
+    }
+
+    #[test]
+    fn cli_parses_history_actions() {
+        let cli = Cli::parse_from(["smart-scribe", "history", "list", "-n", "3"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::History {
+                action: HistoryAction::List { limit: 3 }
+            })
+        ));
+        let cli = Cli::parse_from(["smart-scribe", "history", "copy", "2"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::History {
+                action: HistoryAction::Copy { index: 2 }
+            })
+        ));
+        let cli = Cli::parse_from(["smart-scribe", "--no-history"]);
+        assert!(cli.no_history);
+    }
+
+    #[test]
+    fn cli_parses_completions() {
+        let cli = Cli::parse_from(["smart-scribe", "completions", "zsh"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Completions {
+                shell: clap_complete::Shell::Zsh
+            })
+        ));
+        assert!(Cli::try_parse_from(["smart-scribe", "completions", "tcsh"]).is_err());
+    }
+
     #[test]
     fn valid_config_keys() {
         use crate::cli::config_schema;
@@ -392,14 +1102,47 @@ mod tests {
 
     #[test]
     fn cli_parses_daemon_subscribe() {
-        let cli = Cli::parse_from(["smart-scribe", "daemon", "subscribe", "--output", "json"]);
+        let cli = Cli::parse_from(["smart-scribe", "daemon", "subscribe", "--format", "json"]);
         assert!(matches!(
             cli.command,
             Some(Commands::Daemon {
                 action: DaemonAction::Subscribe
             })
         ));
-        assert_eq!(cli.output, OutputFormatArg::Json);
+        assert_eq!(cli.format, OutputFormatArg::Json);
+    }
+
+    #[test]
+    fn cli_parses_daemon_restart() {
+        let cli = Cli::parse_from(["smart-scribe", "daemon", "restart"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Daemon {
+                action: DaemonAction::Restart
+            })
+        ));
+    }
+
+    #[test]
+    fn cli_parses_daemon_reload() {
+        let cli = Cli::parse_from(["smart-scribe", "daemon", "reload"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Daemon {
+                action: DaemonAction::Reload
+            })
+        ));
+    }
+
+    #[test]
+    fn cli_parses_daemon_version() {
+        let cli = Cli::parse_from(["smart-scribe", "daemon", "version"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Daemon {
+                action: DaemonAction::Version
+            })
+        ));
     }
 
     #[test]
@@ -6,15 +6,14 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::domain::config::{AppConfig, AuthMode};
 use crate::infrastructure::auth::{import_from_codex, run_pkce_login, OAuthStore};
+use crate::infrastructure::create_http_client;
 
-use super::args::OutputFormatArg;
 use super::exit_codes;
 use super::presenter::Presenter;
 
-/// Run the OAuth login flow and persist the token.
-pub async fn run_login(from_codex: bool, output: OutputFormatArg) -> ExitCode {
-    let presenter = Presenter::new(output);
-
+/// Run the OAuth login flow and persist the token. Token requests go
+/// through `proxy` when one is configured.
+pub async fn run_login(from_codex: bool, proxy: Option<&str>, presenter: &Presenter) -> ExitCode {
     let store = match OAuthStore::new() {
         Ok(s) => s,
         Err(e) => {
@@ -22,11 +21,18 @@ pub async fn run_login(from_codex: bool, output: OutputFormatArg) -> ExitCode {
             return ExitCode::from(exit_codes::ERROR);
         }
     };
+    let client = match create_http_client(proxy) {
+        Ok(c) => c,
+        Err(e) => {
+            presenter.error(&e);
+            return ExitCode::from(exit_codes::USAGE_ERROR);
+        }
+    };
 
     let token_result = if from_codex {
-        import_from_codex().await
+        import_from_codex(&client).await
     } else {
-        run_pkce_login().await
+        run_pkce_login(&client).await
     };
 
     let token = match token_result {
@@ -67,9 +73,7 @@ pub async fn run_login(from_codex: bool, output: OutputFormatArg) -> ExitCode {
 }
 
 /// Delete the persisted OAuth token (idempotent).
-pub async fn run_logout(output: OutputFormatArg) -> ExitCode {
-    let presenter = Presenter::new(output);
-
+pub async fn run_logout(presenter: &Presenter) -> ExitCode {
     let store = match OAuthStore::new() {
         Ok(s) => s,
         Err(e) => {
@@ -96,9 +100,7 @@ pub async fn run_logout(output: OutputFormatArg) -> ExitCode {
 }
 
 /// Print the current authentication status.
-pub async fn run_auth_status(config: &AppConfig, output: OutputFormatArg) -> ExitCode {
-    let presenter = Presenter::new(output);
-
+pub async fn run_auth_status(config: &AppConfig, presenter: &Presenter) -> ExitCode {
     let mode = config.auth;
 
     let store = OAuthStore::new().ok();
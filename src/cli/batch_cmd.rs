@@ -0,0 +1,494 @@
+//! `batch` subcommand: transcribe audio files that already exist on disk.
+//!
+//! Audio files are uploaded as-is (the transcription endpoints accept mp3,
+//! m4a, ogg, wav and flac); video files and other codecs have their audio
+//! extracted with ffmpeg first. Each file is an independent request: a
+//! failure is reported and the batch moves on.
+
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::application::ports::Transcriber;
+use crate::domain::config::AppConfig;
+use crate::domain::recording::Duration;
+use crate::domain::transcription::AudioSource;
+use crate::infrastructure::{create_transcriber, load_audio_file};
+
+use super::args::BatchOptions;
+use super::exit_codes;
+use super::output::write_transcript_file;
+use super::presenter::Presenter;
+use super::runtime::BuildError;
+
+/// Outcome for one input file.
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchFileResult {
+    pub path: PathBuf,
+    /// Sidecar the transcript was written to (`null` on failure or when
+    /// writing a combined `--output`).
+    pub output: Option<PathBuf>,
+    pub text: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BatchResponse {
+    ok: bool,
+    action: &'static str,
+    succeeded: usize,
+    failed: usize,
+    /// Combined transcript file, when `--output` was given.
+    output: Option<PathBuf>,
+    files: Vec<BatchFileResult>,
+}
+
+/// Run `smart-scribe batch`.
+pub async fn run_batch(
+    options: BatchOptions,
+    config: &AppConfig,
+    presenter: &Presenter,
+) -> ExitCode {
+    let files = match collect_inputs(&options.paths) {
+        Ok(files) => files,
+        Err(e) => {
+            presenter.error(&e);
+            return ExitCode::from(exit_codes::USAGE_ERROR);
+        }
+    };
+    let transcriber = match create_transcriber(config) {
+        Ok(t) => Arc::new(t),
+        Err(e) => {
+            let e = BuildError::Transcriber(e);
+            presenter.error(&e.to_string());
+            return ExitCode::from(e.exit_code());
+        }
+    };
+
+    presenter.info(&format!(
+        "Transcribing {} file(s), {} at a time",
+        files.len(),
+        options.concurrency
+    ));
+
+    let combined = options.output.is_some();
+    let mut finished: Vec<Option<BatchFileResult>> = vec![None; files.len()];
+    let outcomes = transcribe_all(
+        transcriber,
+        &files,
+        options.concurrency,
+        options.timeout,
+        |index, outcome| {
+            let result = finish_file(&files[index], outcome, combined);
+            match (&result.error, &result.output) {
+                (Some(e), _) => presenter.error(&format!("{}: {}", files[index].display(), e)),
+                (None, Some(out)) => {
+                    presenter.success(&format!("{} → {}", files[index].display(), out.display()))
+                }
+                (None, None) => presenter.success(&files[index].display().to_string()),
+            }
+            finished[index] = Some(result);
+        },
+    )
+    .await;
+    // Only a panicked task leaves a slot unfilled.
+    let results: Vec<BatchFileResult> = finished
+        .into_iter()
+        .zip(&outcomes)
+        .zip(&files)
+        .map(|((result, outcome), path)| {
+            result.unwrap_or_else(|| finish_file(path, outcome, combined))
+        })
+        .collect();
+
+    if let Some(ref path) = options.output {
+        if let Err(e) = write_transcript_file(path, &combined_transcript(&results), false) {
+            presenter.error(&format!("Failed to write {}: {}", path.display(), e));
+            return ExitCode::from(exit_codes::ERROR);
+        }
+    }
+
+    let failed = results.iter().filter(|r| r.error.is_some()).count();
+    let succeeded = results.len() - failed;
+    if presenter.is_json() {
+        presenter.output_json(&BatchResponse {
+            ok: failed == 0,
+            action: "batch",
+            succeeded,
+            failed,
+            output: options.output.clone(),
+            files: results,
+        });
+    } else if failed > 0 {
+        presenter.warn(&format!(
+            "Transcribed {succeeded} of {} file(s); {failed} failed",
+            files.len()
+        ));
+    } else {
+        presenter.info(&format!("Transcribed {succeeded} file(s)"));
+    }
+
+    if failed == 0 {
+        ExitCode::from(exit_codes::SUCCESS)
+    } else {
+        ExitCode::from(exit_codes::ERROR)
+    }
+}
+
+/// Transcribe `files` with at most `concurrency` requests in flight.
+///
+/// `on_done` runs as each file finishes (completion order); the returned
+/// outcomes are in input order.
+async fn transcribe_all<T>(
+    transcriber: Arc<T>,
+    files: &[PathBuf],
+    concurrency: usize,
+    timeout: Option<Duration>,
+    mut on_done: impl FnMut(usize, &Result<String, String>),
+) -> Vec<Result<String, String>>
+where
+    T: Transcriber + 'static,
+{
+    let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+    let mut tasks = JoinSet::new();
+    for (index, path) in files.iter().cloned().enumerate() {
+        let transcriber = Arc::clone(&transcriber);
+        let semaphore = Arc::clone(&semaphore);
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            (
+                index,
+                transcribe_file(transcriber.as_ref(), &path, timeout).await,
+            )
+        });
+    }
+
+    let mut outcomes: Vec<Option<Result<String, String>>> = vec![None; files.len()];
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((index, outcome)) = joined {
+            on_done(index, &outcome);
+            outcomes[index] = Some(outcome);
+        }
+    }
+    outcomes
+        .into_iter()
+        .map(|o| o.unwrap_or_else(|| Err("transcription task panicked".to_string())))
+        .collect()
+}
+
+/// Upload one file (its audio track, for a video) and return its
+/// transcript.
+async fn transcribe_file<T: Transcriber + ?Sized>(
+    transcriber: &T,
+    path: &Path,
+    timeout: Option<Duration>,
+) -> Result<String, String> {
+    within(timeout, async {
+        let audio = load_audio_file(path).await.map_err(|e| e.to_string())?;
+        transcriber
+            .transcribe(&audio)
+            .await
+            .map_err(|e| e.to_string())
+    })
+    .await
+}
+
+/// Run `work` under the per-file `--timeout`. It covers loading too, so a
+/// stuck ffmpeg is dropped (and killed) along with the request.
+async fn within(
+    timeout: Option<Duration>,
+    work: impl Future<Output = Result<String, String>>,
+) -> Result<String, String> {
+    match timeout {
+        Some(limit) => tokio::time::timeout(limit.as_std(), work)
+            .await
+            .map_err(|_| format!("timed out after {limit}"))?,
+        None => work.await,
+    }
+}
+
+/// Turn a file's outcome into its result, writing the `.txt` sidecar
+/// unless transcripts go to a combined file.
+fn finish_file(path: &Path, outcome: &Result<String, String>, combined: bool) -> BatchFileResult {
+    let mut result = BatchFileResult {
+        path: path.to_path_buf(),
+        output: None,
+        text: None,
+        error: None,
+    };
+    match outcome {
+        Ok(text) => {
+            if !combined {
+                let sidecar = sidecar_path(path);
+                if let Err(e) = write_transcript_file(&sidecar, text, false) {
+                    result.error = Some(format!("cannot write {}: {e}", sidecar.display()));
+                    return result;
+                }
+                result.output = Some(sidecar);
+            }
+            result.text = Some(text.clone());
+        }
+        Err(e) => result.error = Some(e.clone()),
+    }
+    result
+}
+
+/// `memo.ogg` → `memo.txt`
+fn sidecar_path(path: &Path) -> PathBuf {
+    path.with_extension("txt")
+}
+
+/// Successful transcripts under a `# <file>` heading each, blank-line
+/// separated, in input order.
+fn combined_transcript(results: &[BatchFileResult]) -> String {
+    results
+        .iter()
+        .filter_map(|r| {
+            r.text
+                .as_ref()
+                .map(|text| format!("# {}\n\n{}\n", r.path.display(), text))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Expand the `batch` arguments into audio files, in argument order with
+/// duplicates removed. Each argument must match at least one file.
+fn collect_inputs(patterns: &[String]) -> Result<Vec<PathBuf>, String> {
+    let mut files: Vec<PathBuf> = Vec::new();
+    for pattern in patterns {
+        let path = Path::new(pattern);
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let matched = if name.contains(['*', '?']) {
+            let dir = path
+                .parent()
+                .filter(|p| !p.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            audio_files_in(dir)?
+                .into_iter()
+                .filter(|f| {
+                    f.file_name()
+                        .and_then(|n| n.to_str())
+                        .is_some_and(|n| wildcard_match(name, n))
+                })
+                .collect()
+        } else if path.is_dir() {
+            audio_files_in(path)?
+        } else if path.is_file() {
+            if !is_audio_file(path) {
+                return Err(format!("Not a supported audio file: {pattern}"));
+            }
+            vec![path.to_path_buf()]
+        } else {
+            return Err(format!("No such file or directory: {pattern}"));
+        };
+
+        if matched.is_empty() {
+            return Err(format!("No audio files match {pattern}"));
+        }
+        for file in matched {
+            if !files.contains(&file) {
+                files.push(file);
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Audio files directly inside `dir`, sorted by name.
+fn audio_files_in(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries =
+        std::fs::read_dir(dir).map_err(|e| format!("Cannot read {}: {e}", dir.display()))?;
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && is_audio_file(p))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+fn is_audio_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(AudioSource::accepts_extension)
+}
+
+/// Shell-style match of `name` against `pattern` (`*` any run, `?` one char).
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::TranscriptionError;
+    use crate::domain::transcription::AudioData;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Echoes the upload size, fails empty files, and tracks how many
+    /// requests are in flight at once.
+    #[derive(Default)]
+    struct SizeTranscriber {
+        in_flight: AtomicUsize,
+        peak: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Transcriber for SizeTranscriber {
+        async fn transcribe(&self, audio: &AudioData) -> Result<String, TranscriptionError> {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            match audio.size_bytes() {
+                0 => Err(TranscriptionError::EmptyResponse),
+                n => Ok(format!("{n} bytes")),
+            }
+        }
+    }
+
+    #[test]
+    fn wildcard_match_handles_star_and_question_mark() {
+        assert!(wildcard_match("*.ogg", "memo.ogg"));
+        assert!(wildcard_match("memo-?.ogg", "memo-1.ogg"));
+        assert!(wildcard_match("*-*.m4a", "a-b-c.m4a"));
+        assert!(!wildcard_match("*.ogg", "memo.mp3"));
+        assert!(!wildcard_match("memo-?.ogg", "memo-10.ogg"));
+    }
+
+    #[test]
+    fn sidecar_replaces_extension() {
+        assert_eq!(
+            sidecar_path(Path::new("memos/memo.ogg")),
+            PathBuf::from("memos/memo.txt")
+        );
+    }
+
+    #[test]
+    fn collect_inputs_expands_dirs_and_globs() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["b.ogg", "a.m4a", "notes.txt", "c.mp3"] {
+            std::fs::write(dir.path().join(name), b"x").unwrap();
+        }
+        let root = dir.path().display().to_string();
+
+        let all = collect_inputs(std::slice::from_ref(&root)).unwrap();
+        let names: Vec<_> = all
+            .iter()
+            .map(|p| p.file_name().unwrap().to_str().unwrap())
+            .collect();
+        assert_eq!(names, ["a.m4a", "b.ogg", "c.mp3"]);
+
+        let glob = format!("{root}/*.ogg");
+        let file = format!("{root}/b.ogg");
+        assert_eq!(collect_inputs(&[glob.clone(), file]).unwrap().len(), 1);
+
+        assert!(collect_inputs(&[format!("{root}/*.wav")]).is_err());
+        assert!(collect_inputs(&[format!("{root}/notes.txt")]).is_err());
+        assert!(collect_inputs(&[format!("{root}/missing.ogg")]).is_err());
+    }
+
+    #[tokio::test]
+    async fn transcribe_all_bounds_concurrency_and_continues_past_failures() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut files = Vec::new();
+        for (i, len) in [3usize, 0, 5, 7].iter().enumerate() {
+            let path = dir.path().join(format!("{i}.ogg"));
+            std::fs::write(&path, vec![0u8; *len]).unwrap();
+            files.push(path);
+        }
+
+        let transcriber = Arc::new(SizeTranscriber::default());
+        let mut done = 0;
+        let outcomes = transcribe_all(Arc::clone(&transcriber), &files, 2, None, |_, _| {
+            done += 1;
+        })
+        .await;
+
+        assert_eq!(done, 4);
+        assert_eq!(outcomes[0].as_deref(), Ok("3 bytes"));
+        assert!(outcomes[1].is_err());
+        assert_eq!(outcomes[3].as_deref(), Ok("7 bytes"));
+        assert_eq!(transcriber.peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn transcribe_file_honours_timeout() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("slow.wav");
+        std::fs::write(&path, b"abc").unwrap();
+
+        let err = transcribe_file(
+            &SizeTranscriber::default(),
+            &path,
+            Some(Duration::from_millis(1)),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.contains("timed out"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn timeout_covers_a_slow_load() {
+        let slow_load = async {
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            Ok("never".to_string())
+        };
+        let err = within(Some(Duration::from_millis(10)), slow_load)
+            .await
+            .unwrap_err();
+        assert!(err.contains("timed out"), "{err}");
+    }
+
+    #[test]
+    fn finish_file_writes_sidecar_unless_combined() {
+        let dir = tempfile::tempdir().unwrap();
+        let audio = dir.path().join("memo.ogg");
+
+        let result = finish_file(&audio, &Ok("hello".to_string()), false);
+        assert_eq!(result.output, Some(dir.path().join("memo.txt")));
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("memo.txt")).unwrap(),
+            "hello\n"
+        );
+
+        let other = dir.path().join("other.ogg");
+        let result = finish_file(&other, &Ok("hi".to_string()), true);
+        assert!(result.output.is_none());
+        assert!(!dir.path().join("other.txt").exists());
+
+        let combined =
+            combined_transcript(&[result, finish_file(&other, &Err("boom".to_string()), true)]);
+        assert_eq!(combined, format!("# {}\n\nhi\n", other.display()));
+    }
+}
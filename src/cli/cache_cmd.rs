@@ -0,0 +1,34 @@
+//! `cache` subcommand handlers.
+
+use crate::infrastructure::TranscriptCache;
+
+use super::args::CacheAction;
+use super::presenter::Presenter;
+
+/// Handle a `cache <action>` invocation.
+pub fn handle_cache_command(action: CacheAction, presenter: &Presenter) -> Result<(), String> {
+    let cache =
+        TranscriptCache::new().map_err(|e| format!("Could not locate transcript cache: {e}"))?;
+
+    match action {
+        CacheAction::Clear => {
+            let removed = cache
+                .clear()
+                .map_err(|e| format!("Failed to clear {}: {e}", cache.dir().display()))?;
+            if presenter.is_json() {
+                presenter.output_json(&serde_json::json!({
+                    "ok": true,
+                    "action": "cache_clear",
+                    "removed": removed,
+                    "path": cache.dir().to_string_lossy(),
+                }));
+            } else {
+                presenter.success(&format!(
+                    "Removed {removed} cached transcript(s) from {}",
+                    cache.dir().display()
+                ));
+            }
+        }
+    }
+    Ok(())
+}
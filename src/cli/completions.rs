@@ -0,0 +1,45 @@
+//! `completions` subcommand: shell completion scripts generated from the
+//! clap definition, so new flags and value enums are picked up for free.
+
+use std::io::Write;
+
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+use super::args::Cli;
+
+/// Binary name the completion scripts register against.
+const BIN_NAME: &str = "smart-scribe";
+
+/// Write the completion script for `shell` to `out`.
+pub fn write_completions(shell: Shell, out: &mut dyn Write) {
+    clap_complete::generate(shell, &mut Cli::command(), BIN_NAME, out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use clap::ValueEnum;
+
+    #[test]
+    fn generates_for_every_shell() {
+        for shell in Shell::value_variants() {
+            let mut buf = Vec::new();
+            write_completions(*shell, &mut buf);
+            let script = String::from_utf8(buf).unwrap();
+            assert!(script.contains(BIN_NAME), "{shell} script missing bin name");
+        }
+    }
+
+    #[test]
+    fn bash_script_lists_subcommands_and_value_enums() {
+        let mut buf = Vec::new();
+        write_completions(Shell::Bash, &mut buf);
+        let script = String::from_utf8(buf).unwrap();
+        assert!(script.contains("config"));
+        assert!(script.contains("daemon"));
+        // `--format` values come from the `OutputFormatArg` ValueEnum.
+        assert!(script.contains("text json"));
+    }
+}
@@ -4,26 +4,45 @@
 //! orchestrates the load → validate → mutate → save lifecycle.
 
 use std::collections::BTreeMap;
+use std::io::{BufRead, IsTerminal, Write};
 
 use crate::application::ports::ConfigStore;
+use crate::domain::config::{api_key_warning, AppConfig, RawAppConfig, CONFIG_VERSION};
 use crate::domain::error::ConfigError;
 
+use super::app::merge_config_layers;
 use super::args::ConfigAction;
 use super::config_schema;
 use super::presenter::Presenter;
 
 /// Handle a `config <action>` invocation.
+///
+/// `profile` only applies to `list`, which then shows the effective values
+/// for that profile: defaults, file, profile, env and `cli_config` merged as
+/// a run would see them.
 pub async fn handle_config_command<S: ConfigStore>(
     action: ConfigAction,
+    profile: Option<&str>,
+    cli_config: RawAppConfig,
     store: &S,
     presenter: &Presenter,
 ) -> Result<(), ConfigError> {
+    if profile.is_some() && !matches!(action, ConfigAction::List) {
+        return Err(ConfigError::ValidationError {
+            key: "profile".into(),
+            message: "--profile is only supported by `config list`".into(),
+        });
+    }
     match action {
         ConfigAction::Init => handle_init(store, presenter).await,
         ConfigAction::Set { key, value } => handle_set(store, presenter, &key, &value).await,
         ConfigAction::Get { key } => handle_get(store, presenter, &key).await,
-        ConfigAction::List => handle_list(store, presenter).await,
+        ConfigAction::Unset { key } => handle_unset(store, presenter, &key).await,
+        ConfigAction::Edit { editor } => handle_edit(store, presenter, editor).await,
+        ConfigAction::Reset { yes } => handle_reset(store, presenter, yes).await,
+        ConfigAction::List => handle_list(store, presenter, profile, cli_config).await,
         ConfigAction::Path => handle_path(store, presenter),
+        ConfigAction::Migrate => handle_migrate(store, presenter).await,
     }
 }
 
@@ -52,6 +71,11 @@ async fn handle_set<S: ConfigStore>(
 ) -> Result<(), ConfigError> {
     let entry = lookup(key)?;
     (entry.validate)(value)?;
+    if entry.name == "openai_api_key" {
+        if let Some(warning) = api_key_warning(value.trim()) {
+            presenter.warn(&warning);
+        }
+    }
 
     let mut config = store.load().await?;
     (entry.set)(&mut config, value)?;
@@ -99,20 +123,186 @@ async fn handle_get<S: ConfigStore>(
     Ok(())
 }
 
-async fn handle_list<S: ConfigStore>(store: &S, presenter: &Presenter) -> Result<(), ConfigError> {
-    let config = store.load().await?;
-    let mut values: BTreeMap<String, Option<String>> = BTreeMap::new();
-    for entry in config_schema::KEYS {
-        values.insert(
-            entry.name.to_string(),
-            (entry.get)(&config).map(|v| (entry.display)(&v)),
-        );
+async fn handle_unset<S: ConfigStore>(
+    store: &S,
+    presenter: &Presenter,
+    key: &str,
+) -> Result<(), ConfigError> {
+    let entry = lookup(key)?;
+
+    let mut config = store.load().await?;
+    (entry.unset)(&mut config);
+    store.save(&config).await?;
+
+    if presenter.is_json() {
+        presenter.output_json(&serde_json::json!({
+            "ok": true,
+            "action": "unset",
+            "key": key,
+        }));
+    } else {
+        presenter.success(&format!("{} unset", key));
     }
 
+    Ok(())
+}
+
+async fn handle_reset<S: ConfigStore>(
+    store: &S,
+    presenter: &Presenter,
+    yes: bool,
+) -> Result<(), ConfigError> {
+    if !yes {
+        let question = format!("Overwrite {} with defaults?", store.path().display());
+        match confirm(&question) {
+            Some(true) => {}
+            Some(false) => {
+                presenter.info("Reset cancelled");
+                return Ok(());
+            }
+            None => {
+                return Err(ConfigError::ValidationError {
+                    key: "reset".into(),
+                    message: "Refusing to reset without confirmation; pass --yes".into(),
+                })
+            }
+        }
+    }
+
+    store.save(&RawAppConfig::defaults()).await?;
+
+    if presenter.is_json() {
+        presenter.output_json(&serde_json::json!({
+            "ok": true,
+            "action": "reset",
+            "path": store.path().to_string_lossy(),
+        }));
+    } else {
+        presenter.success(&format!(
+            "Config file reset to defaults: {}",
+            store.path().display()
+        ));
+    }
+    Ok(())
+}
+
+async fn handle_edit<S: ConfigStore>(
+    store: &S,
+    presenter: &Presenter,
+    editor: Option<String>,
+) -> Result<(), ConfigError> {
+    if !store.exists() {
+        store.init().await?;
+    }
+    let path = store.path();
+    let original = tokio::fs::read_to_string(&path)
+        .await
+        .map_err(|e| ConfigError::ReadError(e.to_string()))?;
+
+    let visual = std::env::var("VISUAL").ok();
+    let env_editor = std::env::var("EDITOR").ok();
+    let command = editor_command(editor.as_deref(), visual.as_deref(), env_editor.as_deref());
+
+    loop {
+        run_editor(&command, &path).await?;
+
+        // `load` goes through the same TOML parser the daemon uses; the
+        // `AppConfig` conversion then applies every per-key validation.
+        let error = match store.load().await {
+            Ok(raw) => match AppConfig::try_from(raw) {
+                Ok(_) => break,
+                Err(e) => e,
+            },
+            Err(e) => e,
+        };
+
+        presenter.error(&error.to_string());
+        if confirm("Re-open the editor?") != Some(true) {
+            // Never leave an invalid file behind: keep the attempt next to
+            // it and restore what was there before.
+            let rejected = path.with_extension("toml.rejected");
+            let _ = tokio::fs::copy(&path, &rejected).await;
+            tokio::fs::write(&path, &original)
+                .await
+                .map_err(|e| ConfigError::WriteError(e.to_string()))?;
+            presenter.warn(&format!(
+                "Config left unchanged; your edits were saved to {}",
+                rejected.display()
+            ));
+            return Err(error);
+        }
+    }
+
+    if presenter.is_json() {
+        presenter.output_json(&serde_json::json!({
+            "ok": true,
+            "action": "edit",
+            "path": path.to_string_lossy(),
+        }));
+    } else {
+        presenter.success(&format!("Config file is valid: {}", path.display()));
+    }
+    Ok(())
+}
+
+/// Editor to launch, split into program + arguments: the `--editor` flag,
+/// then `$VISUAL`, then `$EDITOR`, then the platform default.
+fn editor_command(flag: Option<&str>, visual: Option<&str>, editor: Option<&str>) -> Vec<String> {
+    let chosen = [flag, visual, editor]
+        .into_iter()
+        .flatten()
+        .find(|v| !v.trim().is_empty())
+        .unwrap_or(if cfg!(windows) { "notepad" } else { "vi" });
+    chosen.split_whitespace().map(str::to_string).collect()
+}
+
+async fn run_editor(command: &[String], path: &std::path::Path) -> Result<(), ConfigError> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| ConfigError::EditorError("no editor configured".into()))?;
+    let status = tokio::process::Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()
+        .await
+        .map_err(|e| ConfigError::EditorError(format!("{}: {}", program, e)))?;
+    if !status.success() {
+        return Err(ConfigError::EditorError(format!(
+            "{} exited with {}",
+            program, status
+        )));
+    }
+    Ok(())
+}
+
+/// Ask a yes/no question on the terminal. Returns `None` when stdin is not
+/// interactive so callers can decide how to fail instead of guessing.
+fn confirm(question: &str) -> Option<bool> {
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() {
+        return None;
+    }
+
+    eprint!("{} [y/N] ", question);
+    let _ = std::io::stderr().flush();
+    let mut answer = String::new();
+    stdin.lock().read_line(&mut answer).ok()?;
+    Some(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+async fn handle_list<S: ConfigStore>(
+    store: &S,
+    presenter: &Presenter,
+    profile: Option<&str>,
+    cli_config: RawAppConfig,
+) -> Result<(), ConfigError> {
+    let values = list_values(store, profile, cli_config).await?;
+
     if presenter.is_json() {
         presenter.output_json(&serde_json::json!({
             "ok": true,
             "action": "list",
+            "profile": profile,
             "values": values,
         }));
     } else {
@@ -124,6 +314,80 @@ async fn handle_list<S: ConfigStore>(store: &S, presenter: &Presenter) -> Result
     Ok(())
 }
 
+/// Display value of every key: as written in the file, or with `profile`
+/// as a run with that profile would see it.
+async fn list_values<S: ConfigStore>(
+    store: &S,
+    profile: Option<&str>,
+    cli_config: RawAppConfig,
+) -> Result<BTreeMap<String, Option<String>>, ConfigError> {
+    let mut config = store.load().await?;
+    if let Some(name) = profile {
+        config = merge_config_layers(Ok(config), cli_config, Some(name))?;
+    }
+    Ok(config_schema::KEYS
+        .iter()
+        .map(|entry| {
+            let value = (entry.get)(&config).map(|v| (entry.display)(&v));
+            (entry.name.to_string(), value)
+        })
+        .collect())
+}
+
+async fn handle_migrate<S: ConfigStore>(
+    store: &S,
+    presenter: &Presenter,
+) -> Result<(), ConfigError> {
+    let path = store.path();
+    if !store.exists() {
+        if presenter.is_json() {
+            presenter.output_json(&serde_json::json!({
+                "ok": true,
+                "action": "migrate",
+                "path": path.to_string_lossy(),
+                "changes": [],
+            }));
+        } else {
+            presenter.info(&format!(
+                "No config file at {}; nothing to migrate",
+                path.display()
+            ));
+        }
+        return Ok(());
+    }
+
+    let migration = store.migrate().await?;
+    if presenter.is_json() {
+        presenter.output_json(&serde_json::json!({
+            "ok": true,
+            "action": "migrate",
+            "path": path.to_string_lossy(),
+            "from_version": migration.from_version,
+            "to_version": CONFIG_VERSION.max(migration.from_version),
+            "changes": migration.changes,
+            "backup": migration.backup.as_ref().map(|b| b.to_string_lossy()),
+        }));
+    } else if migration.changes.is_empty() {
+        presenter.success(&format!(
+            "Config is already up to date (version {})",
+            migration.from_version
+        ));
+    } else {
+        for change in &migration.changes {
+            presenter.output(&format!("  {change}"));
+        }
+        presenter.success(&format!(
+            "Migrated {} from version {} to {CONFIG_VERSION}",
+            path.display(),
+            migration.from_version
+        ));
+        if let Some(backup) = &migration.backup {
+            presenter.info(&format!("Original saved to {}", backup.display()));
+        }
+    }
+    Ok(())
+}
+
 fn handle_path<S: ConfigStore>(store: &S, presenter: &Presenter) -> Result<(), ConfigError> {
     if presenter.is_json() {
         presenter.output_json(&serde_json::json!({
@@ -150,7 +414,18 @@ fn lookup(key: &str) -> Result<&'static config_schema::ConfigKey, ConfigError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::config::RawAppConfig;
+    use crate::cli::args::OutputFormatArg;
+    use crate::infrastructure::XdgConfigStore;
+
+    fn temp_store() -> (tempfile::TempDir, XdgConfigStore) {
+        let dir = tempfile::tempdir().unwrap();
+        let store = XdgConfigStore::with_path(dir.path().join("config.toml"));
+        (dir, store)
+    }
+
+    fn quiet_presenter() -> Presenter {
+        Presenter::new(OutputFormatArg::Text).with_quiet(true)
+    }
 
     fn validate(key: &str, value: &str) -> Result<(), ConfigError> {
         let entry = lookup(key)?;
@@ -207,6 +482,234 @@ mod tests {
         assert!(matches!(err, ConfigError::ValidationError { .. }));
     }
 
+    #[tokio::test]
+    async fn unset_clears_previously_set_key() {
+        let (_dir, store) = temp_store();
+        let presenter = quiet_presenter();
+        handle_set(&store, &presenter, "max_duration", "2m")
+            .await
+            .unwrap();
+        handle_set(&store, &presenter, "linux.paste", "true")
+            .await
+            .unwrap();
+
+        handle_unset(&store, &presenter, "max_duration")
+            .await
+            .unwrap();
+        handle_unset(&store, &presenter, "linux.paste")
+            .await
+            .unwrap();
+
+        let config = store.load().await.unwrap();
+        assert_eq!(config.max_duration, None);
+        assert_eq!(config.linux.and_then(|l| l.paste), None);
+    }
+
+    #[tokio::test]
+    async fn unset_rejects_unknown_key() {
+        let (_dir, store) = temp_store();
+        let err = handle_unset(&store, &quiet_presenter(), "nope")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::ValidationError { .. }));
+    }
+
+    #[tokio::test]
+    async fn reset_writes_default_contents() {
+        let (_dir, store) = temp_store();
+        let presenter = quiet_presenter();
+        handle_set(&store, &presenter, "clipboard", "true")
+            .await
+            .unwrap();
+        handle_set(&store, &presenter, "auth", "api_key")
+            .await
+            .unwrap();
+
+        handle_reset(&store, &presenter, true).await.unwrap();
+
+        let reset = std::fs::read_to_string(store.path()).unwrap();
+        let (_dir2, fresh) = temp_store();
+        fresh.init().await.unwrap();
+        assert_eq!(reset, std::fs::read_to_string(fresh.path()).unwrap());
+    }
+
+    #[tokio::test]
+    async fn migrate_upgrades_old_file_and_keeps_backup() {
+        let (_dir, store) = temp_store();
+        let old = "domain = \"dev\"\nkeystroke_tool = \"wtype\"\nindicator_width = 140\n";
+        std::fs::write(store.path(), old).unwrap();
+
+        handle_migrate(&store, &quiet_presenter()).await.unwrap();
+
+        let backup = store.path().parent().unwrap().join("config.toml.bak");
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), old);
+        let raw = store.load().await.unwrap();
+        assert_eq!(raw.version, Some(CONFIG_VERSION));
+        assert_eq!(raw.linux.unwrap().keystroke_tool.as_deref(), Some("wtype"));
+        assert_eq!(raw.indicator.unwrap().width, Some(140));
+
+        // A second run finds nothing to do and leaves the backup alone.
+        std::fs::remove_file(&backup).unwrap();
+        let migration = store.migrate().await.unwrap();
+        assert!(migration.changes.is_empty());
+        assert!(!backup.exists());
+    }
+
+    #[tokio::test]
+    async fn init_writes_the_current_version() {
+        let (_dir, store) = temp_store();
+        store.init().await.unwrap();
+        assert_eq!(store.load().await.unwrap().version, Some(CONFIG_VERSION));
+        assert!(store.migrate().await.unwrap().changes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_with_unknown_profile_errors() {
+        let (_dir, store) = temp_store();
+        std::fs::write(store.path(), "[profiles.coding]\nkeystroke = true\n").unwrap();
+        let err = handle_list(
+            &store,
+            &quiet_presenter(),
+            Some("meetings"),
+            RawAppConfig::empty(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownProfile { .. }));
+        handle_list(
+            &store,
+            &quiet_presenter(),
+            Some("coding"),
+            RawAppConfig::empty(),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn list_with_profile_shows_every_layer() {
+        let (_dir, store) = temp_store();
+        std::fs::write(
+            store.path(),
+            "notify = true\n[profiles.coding]\nkeystroke = true\n",
+        )
+        .unwrap();
+        let cli = RawAppConfig {
+            clipboard: Some(true),
+            ..Default::default()
+        };
+        let values = list_values(&store, Some("coding"), cli).await.unwrap();
+        let value = |key: &str| values[key].as_deref();
+        assert_eq!(value("notify"), Some("true"));
+        assert_eq!(value("keystroke"), Some("true"));
+        assert_eq!(value("clipboard"), Some("true"));
+        // Defaults fill in what no layer sets.
+        assert_eq!(value("audio_cue"), Some("false"));
+    }
+
+    #[tokio::test]
+    async fn list_with_profile_reports_unreadable_file() {
+        let dir = tempfile::tempdir().unwrap();
+        // A directory where the file should be: it exists but can't be read.
+        let store = XdgConfigStore::with_path(dir.path());
+        let err = list_values(&store, Some("coding"), RawAppConfig::empty())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::ReadError(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn profile_is_rejected_outside_list() {
+        let (_dir, store) = temp_store();
+        let action = ConfigAction::Get {
+            key: "clipboard".into(),
+        };
+        let err = handle_config_command(
+            action,
+            Some("coding"),
+            RawAppConfig::empty(),
+            &store,
+            &quiet_presenter(),
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ConfigError::ValidationError { .. }));
+    }
+
+    #[test]
+    fn editor_command_prefers_flag_then_visual_then_editor() {
+        assert_eq!(
+            editor_command(Some("code --wait"), Some("vim"), Some("nano")),
+            vec!["code", "--wait"]
+        );
+        assert_eq!(editor_command(None, Some("vim"), Some("nano")), vec!["vim"]);
+        assert_eq!(editor_command(None, Some(" "), Some("nano")), vec!["nano"]);
+        assert!(!editor_command(None, None, None).is_empty());
+    }
+
+    /// Stand-in editor: a script that appends `line` to the file it's given.
+    #[cfg(unix)]
+    fn appending_editor(store: &XdgConfigStore, line: &str) -> String {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script = store.path().with_file_name("editor.sh");
+        std::fs::write(&script, format!("#!/bin/sh\necho '{}' >> \"$1\"\n", line)).unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        script.to_string_lossy().into_owned()
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn edit_accepts_valid_changes() {
+        let (_dir, store) = temp_store();
+        std::fs::write(store.path(), "auth = \"oauth\"\n").unwrap();
+        let editor = appending_editor(&store, "clipboard = true");
+
+        handle_edit(&store, &quiet_presenter(), Some(editor))
+            .await
+            .unwrap();
+        assert_eq!(store.load().await.unwrap().clipboard, Some(true));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn edit_creates_missing_file_first() {
+        let (_dir, store) = temp_store();
+        handle_edit(&store, &quiet_presenter(), Some("true".into()))
+            .await
+            .unwrap();
+        assert!(store.exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn edit_restores_file_when_result_is_invalid() {
+        let (_dir, store) = temp_store();
+        let before = "auth = \"oauth\"\n";
+        std::fs::write(store.path(), before).unwrap();
+        let editor = appending_editor(&store, "duration = \"soon\"");
+
+        let err = handle_edit(&store, &quiet_presenter(), Some(editor))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ConfigError::ValidationError { .. }));
+        assert_eq!(std::fs::read_to_string(store.path()).unwrap(), before);
+        assert!(store.path().with_extension("toml.rejected").exists());
+    }
+
+    #[test]
+    fn every_key_unsets_what_it_sets() {
+        for entry in config_schema::KEYS {
+            let mut cfg = RawAppConfig::empty();
+            let sample = (entry.get)(&RawAppConfig::defaults()).unwrap_or_else(|| "1".into());
+            if (entry.set)(&mut cfg, &sample).is_err() {
+                continue;
+            }
+            (entry.unset)(&mut cfg);
+            assert_eq!((entry.get)(&cfg), None, "{}", entry.name);
+        }
+    }
+
     #[test]
     fn set_roundtrip_through_schema() {
         let entry = lookup("clipboard").unwrap();
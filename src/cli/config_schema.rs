@@ -10,9 +10,14 @@
 //! Adding a new key is a single entry in [`KEYS`]; the `config_cmd` handlers
 //! iterate the registry rather than maintaining four parallel match blocks.
 
-use crate::domain::config::{AuthMode, RawAppConfig, RawLinuxConfig, RawWindowsConfig};
+use crate::domain::config::{
+    normalize_api_key, normalize_base_url, parse_hex_color, validate_cue_volume,
+    validate_dimension, validate_proxy_url, validate_sample_rate, validate_silence_threshold,
+    AuthMode, RawAppConfig, RawCuesConfig, RawIndicatorConfig, RawLinuxConfig, RawWindowsConfig,
+    MIN_INDICATOR_HEIGHT, MIN_INDICATOR_WIDTH,
+};
 use crate::domain::error::ConfigError;
-use crate::domain::recording::Duration;
+use crate::domain::recording::{Duration, SampleFormatPreference};
 
 /// Default keystroke tool name.
 pub const KEYSTROKE_TOOL_ENIGO: &str = "enigo";
@@ -23,9 +28,10 @@ pub const VALID_AUTH_MODES: &[&str] = &["oauth", "api_key"];
 /// Accepted keystroke-tool strings. `enigo` is the portable default; the
 /// other backends are Linux-only at runtime but stay valid in the schema so a
 /// portable config can target Linux from any host.
-pub const VALID_KEYSTROKE_TOOLS: &[&str] = &["enigo", "auto", "ydotool", "xdotool", "wtype"];
+pub const VALID_KEYSTROKE_TOOLS: &[&str] =
+    &["enigo", "auto", "ydotool", "xdotool", "wtype", "kdotool"];
 
-/// Accepted indicator positions (Linux overlay).
+/// Accepted indicator positions (Linux and Windows overlays).
 const VALID_INDICATOR_POSITIONS: &[&str] = &[
     "top-right",
     "top-left",
@@ -42,6 +48,8 @@ pub struct ConfigKey {
     pub validate: fn(&str) -> Result<(), ConfigError>,
     pub set: fn(&mut RawAppConfig, &str) -> Result<(), ConfigError>,
     pub get: fn(&RawAppConfig) -> Option<String>,
+    /// Clear the stored value so the default (or a lower layer) applies.
+    pub unset: fn(&mut RawAppConfig),
     /// Render a stored value for display. Identity for most keys; masks
     /// `openai_api_key`.
     pub display: fn(&str) -> String,
@@ -74,16 +82,18 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.auth.clone(),
+        unset: |c| c.auth = None,
         display: identity,
     },
     ConfigKey {
         name: "openai_api_key",
-        validate: |_| Ok(()),
+        validate: |v| parse_api_key(v).map(|_| ()),
         set: |c, v| {
-            c.openai_api_key = Some(v.to_string());
+            c.openai_api_key = Some(parse_api_key(v)?);
             Ok(())
         },
         get: |c| c.openai_api_key.clone(),
+        unset: |c| c.openai_api_key = None,
         display: mask_api_key,
     },
     ConfigKey {
@@ -103,6 +113,37 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.openai_transcribe_model.clone(),
+        unset: |c| c.openai_transcribe_model = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "openai_base_url",
+        validate: |v| parse_base_url(v).map(|_| ()),
+        set: |c, v| {
+            // Persist the normalised form (no trailing slash).
+            c.openai_base_url = Some(parse_base_url(v)?);
+            Ok(())
+        },
+        get: |c| c.openai_base_url.clone(),
+        unset: |c| c.openai_base_url = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "proxy",
+        validate: |v| {
+            validate_proxy_url(v)
+                .map(|_| ())
+                .map_err(|message| ConfigError::ValidationError {
+                    key: "proxy".into(),
+                    message,
+                })
+        },
+        set: |c, v| {
+            c.proxy = Some(v.trim().to_string());
+            Ok(())
+        },
+        get: |c| c.proxy.clone(),
+        unset: |c| c.proxy = None,
         display: identity,
     },
     ConfigKey {
@@ -122,6 +163,7 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.transcribe_prompt.clone(),
+        unset: |c| c.transcribe_prompt = None,
         display: identity,
     },
     ConfigKey {
@@ -142,6 +184,40 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.transcribe_language.clone(),
+        unset: |c| c.transcribe_language = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "text_prepend",
+        validate: |_| Ok(()),
+        set: |c, v| {
+            c.text_prepend = Some(v.to_string());
+            Ok(())
+        },
+        get: |c| c.text_prepend.clone(),
+        unset: |c| c.text_prepend = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "text_append",
+        validate: |_| Ok(()),
+        set: |c, v| {
+            c.text_append = Some(v.to_string());
+            Ok(())
+        },
+        get: |c| c.text_append.clone(),
+        unset: |c| c.text_append = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "post_command",
+        validate: |_| Ok(()),
+        set: |c, v| {
+            c.post_command = Some(v.to_string());
+            Ok(())
+        },
+        get: |c| c.post_command.clone(),
+        unset: |c| c.post_command = None,
         display: identity,
     },
     ConfigKey {
@@ -152,6 +228,7 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.duration.clone(),
+        unset: |c| c.duration = None,
         display: identity,
     },
     ConfigKey {
@@ -162,6 +239,73 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.max_duration.clone(),
+        unset: |c| c.max_duration = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "min_duration",
+        validate: validate_duration,
+        set: |c, v| {
+            c.min_duration = Some(v.to_string());
+            Ok(())
+        },
+        get: |c| c.min_duration.clone(),
+        unset: |c| c.min_duration = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "silence_check",
+        validate: validate_bool,
+        set: |c, v| {
+            c.silence_check = Some(parse_bool(v)?);
+            Ok(())
+        },
+        get: |c| c.silence_check.map(|b| b.to_string()),
+        unset: |c| c.silence_check = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "silence_threshold",
+        validate: |v| parse_silence_threshold(v).map(|_| ()),
+        set: |c, v| {
+            c.silence_threshold = Some(parse_silence_threshold(v)?);
+            Ok(())
+        },
+        get: |c| c.silence_threshold.map(|n| n.to_string()),
+        unset: |c| c.silence_threshold = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "sample_rate",
+        validate: |v| parse_sample_rate(v).map(|_| ()),
+        set: |c, v| {
+            c.sample_rate = Some(parse_sample_rate(v)?);
+            Ok(())
+        },
+        get: |c| c.sample_rate.map(|n| n.to_string()),
+        unset: |c| c.sample_rate = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "sample_format",
+        validate: |v| parse_sample_format(v).map(|_| ()),
+        set: |c, v| {
+            c.sample_format = Some(parse_sample_format(v)?.to_string());
+            Ok(())
+        },
+        get: |c| c.sample_format.clone(),
+        unset: |c| c.sample_format = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "audio_device",
+        validate: |_| Ok(()),
+        set: |c, v| {
+            c.audio_device = Some(v.trim().to_string());
+            Ok(())
+        },
+        get: |c| c.audio_device.clone(),
+        unset: |c| c.audio_device = None,
         display: identity,
     },
     ConfigKey {
@@ -172,6 +316,7 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.clipboard.map(|b| b.to_string()),
+        unset: |c| c.clipboard = None,
         display: identity,
     },
     ConfigKey {
@@ -182,6 +327,7 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.keystroke.map(|b| b.to_string()),
+        unset: |c| c.keystroke = None,
         display: identity,
     },
     ConfigKey {
@@ -192,6 +338,18 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.notify.map(|b| b.to_string()),
+        unset: |c| c.notify = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "notify_on_error",
+        validate: validate_bool,
+        set: |c, v| {
+            c.notify_on_error = Some(parse_bool(v)?);
+            Ok(())
+        },
+        get: |c| c.notify_on_error.map(|b| b.to_string()),
+        unset: |c| c.notify_on_error = None,
         display: identity,
     },
     ConfigKey {
@@ -202,6 +360,126 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.audio_cue.map(|b| b.to_string()),
+        unset: |c| c.audio_cue = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "cue_volume",
+        validate: |v| parse_cue_volume(v).map(|_| ()),
+        set: |c, v| {
+            c.cue_volume = Some(parse_cue_volume(v)?);
+            Ok(())
+        },
+        get: |c| c.cue_volume.map(|n| n.to_string()),
+        unset: |c| c.cue_volume = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "cache",
+        validate: validate_bool,
+        set: |c, v| {
+            c.cache = Some(parse_bool(v)?);
+            Ok(())
+        },
+        get: |c| c.cache.map(|b| b.to_string()),
+        unset: |c| c.cache = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "history",
+        validate: validate_bool,
+        set: |c, v| {
+            c.history = Some(parse_bool(v)?);
+            Ok(())
+        },
+        get: |c| c.history.map(|b| b.to_string()),
+        unset: |c| c.history = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "history_max_entries",
+        validate: |v| parse_history_max_entries(v).map(|_| ()),
+        set: |c, v| {
+            c.history_max_entries = Some(parse_history_max_entries(v)?);
+            Ok(())
+        },
+        get: |c| c.history_max_entries.map(|n| n.to_string()),
+        unset: |c| c.history_max_entries = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "ipc_token",
+        validate: |v| {
+            if v.trim().chars().any(char::is_whitespace) {
+                Err(ConfigError::ValidationError {
+                    key: "ipc_token".into(),
+                    message: "Token cannot contain whitespace".into(),
+                })
+            } else {
+                Ok(())
+            }
+        },
+        set: |c, v| {
+            c.ipc_token = Some(v.trim().to_string());
+            Ok(())
+        },
+        get: |c| c.ipc_token.clone(),
+        unset: |c| c.ipc_token = None,
+        display: mask_api_key,
+    },
+    ConfigKey {
+        name: "daemon_output_timestamp",
+        validate: validate_bool,
+        set: |c, v| {
+            c.daemon_output_timestamp = Some(parse_bool(v)?);
+            Ok(())
+        },
+        get: |c| c.daemon_output_timestamp.map(|b| b.to_string()),
+        unset: |c| c.daemon_output_timestamp = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "daemon_output_separator",
+        validate: |_| Ok(()),
+        set: |c, v| {
+            c.daemon_output_separator = Some(v.to_string());
+            Ok(())
+        },
+        get: |c| c.daemon_output_separator.clone(),
+        unset: |c| c.daemon_output_separator = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "daemon_output_profile",
+        validate: validate_bool,
+        set: |c, v| {
+            c.daemon_output_profile = Some(parse_bool(v)?);
+            Ok(())
+        },
+        get: |c| c.daemon_output_profile.map(|b| b.to_string()),
+        unset: |c| c.daemon_output_profile = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "daemon_vad",
+        validate: validate_bool,
+        set: |c, v| {
+            c.daemon_vad = Some(parse_bool(v)?);
+            Ok(())
+        },
+        get: |c| c.daemon_vad.map(|b| b.to_string()),
+        unset: |c| c.daemon_vad = None,
+        display: identity,
+    },
+    ConfigKey {
+        name: "daemon_vad_silence",
+        validate: validate_duration,
+        set: |c, v| {
+            c.daemon_vad_silence = Some(v.to_string());
+            Ok(())
+        },
+        get: |c| c.daemon_vad_silence.clone(),
+        unset: |c| c.daemon_vad_silence = None,
         display: identity,
     },
     ConfigKey {
@@ -226,6 +504,11 @@ pub const KEYS: &[ConfigKey] = &[
             Ok(())
         },
         get: |c| c.linux.as_ref().and_then(|l| l.keystroke_tool.clone()),
+        unset: |c| {
+            if let Some(s) = c.linux.as_mut() {
+                s.keystroke_tool = None;
+            }
+        },
         display: identity,
     },
     ConfigKey {
@@ -241,29 +524,46 @@ pub const KEYS: &[ConfigKey] = &[
                 .and_then(|l| l.indicator)
                 .map(|b| b.to_string())
         },
+        unset: |c| {
+            if let Some(s) = c.linux.as_mut() {
+                s.indicator = None;
+            }
+        },
         display: identity,
     },
     ConfigKey {
         name: "linux.indicator_position",
-        validate: |v| {
-            if VALID_INDICATOR_POSITIONS.contains(&v) {
-                Ok(())
-            } else {
-                Err(ConfigError::ValidationError {
-                    key: "linux.indicator_position".into(),
-                    message: format!(
-                        "Invalid value '{}'. Valid: {}",
-                        v,
-                        VALID_INDICATOR_POSITIONS.join(", ")
-                    ),
-                })
-            }
-        },
+        validate: |v| validate_indicator_position("linux.indicator_position", v),
         set: |c, v| {
             linux_section(c).indicator_position = Some(v.to_string());
             Ok(())
         },
         get: |c| c.linux.as_ref().and_then(|l| l.indicator_position.clone()),
+        unset: |c| {
+            if let Some(s) = c.linux.as_mut() {
+                s.indicator_position = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "linux.indicator_clickable",
+        validate: validate_bool,
+        set: |c, v| {
+            linux_section(c).indicator_clickable = Some(parse_bool(v)?);
+            Ok(())
+        },
+        get: |c| {
+            c.linux
+                .as_ref()
+                .and_then(|l| l.indicator_clickable)
+                .map(|b| b.to_string())
+        },
+        unset: |c| {
+            if let Some(s) = c.linux.as_mut() {
+                s.indicator_clickable = None;
+            }
+        },
         display: identity,
     },
     ConfigKey {
@@ -279,6 +579,11 @@ pub const KEYS: &[ConfigKey] = &[
                 .and_then(|l| l.paste)
                 .map(|b| b.to_string())
         },
+        unset: |c| {
+            if let Some(s) = c.linux.as_mut() {
+                s.paste = None;
+            }
+        },
         display: identity,
     },
     ConfigKey {
@@ -294,6 +599,30 @@ pub const KEYS: &[ConfigKey] = &[
                 .and_then(|w| w.indicator)
                 .map(|b| b.to_string())
         },
+        unset: |c| {
+            if let Some(s) = c.windows.as_mut() {
+                s.indicator = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "windows.indicator_position",
+        validate: |v| validate_indicator_position("windows.indicator_position", v),
+        set: |c, v| {
+            windows_section(c).indicator_position = Some(v.to_string());
+            Ok(())
+        },
+        get: |c| {
+            c.windows
+                .as_ref()
+                .and_then(|w| w.indicator_position.clone())
+        },
+        unset: |c| {
+            if let Some(s) = c.windows.as_mut() {
+                s.indicator_position = None;
+            }
+        },
         display: identity,
     },
     ConfigKey {
@@ -309,6 +638,184 @@ pub const KEYS: &[ConfigKey] = &[
                 .and_then(|w| w.show_balloon)
                 .map(|b| b.to_string())
         },
+        unset: |c| {
+            if let Some(s) = c.windows.as_mut() {
+                s.show_balloon = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "indicator.width",
+        validate: |v| {
+            parse_indicator_dimension("indicator.width", v, MIN_INDICATOR_WIDTH).map(|_| ())
+        },
+        set: |c, v| {
+            indicator_section(c).width = Some(parse_indicator_dimension(
+                "indicator.width",
+                v,
+                MIN_INDICATOR_WIDTH,
+            )?);
+            Ok(())
+        },
+        get: |c| {
+            c.indicator
+                .as_ref()
+                .and_then(|i| i.width)
+                .map(|n| n.to_string())
+        },
+        unset: |c| {
+            if let Some(s) = c.indicator.as_mut() {
+                s.width = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "indicator.height",
+        validate: |v| {
+            parse_indicator_dimension("indicator.height", v, MIN_INDICATOR_HEIGHT).map(|_| ())
+        },
+        set: |c, v| {
+            indicator_section(c).height = Some(parse_indicator_dimension(
+                "indicator.height",
+                v,
+                MIN_INDICATOR_HEIGHT,
+            )?);
+            Ok(())
+        },
+        get: |c| {
+            c.indicator
+                .as_ref()
+                .and_then(|i| i.height)
+                .map(|n| n.to_string())
+        },
+        unset: |c| {
+            if let Some(s) = c.indicator.as_mut() {
+                s.height = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "indicator.margin",
+        validate: |v| parse_indicator_dimension("indicator.margin", v, 0).map(|_| ()),
+        set: |c, v| {
+            indicator_section(c).margin =
+                Some(parse_indicator_dimension("indicator.margin", v, 0)?);
+            Ok(())
+        },
+        get: |c| {
+            c.indicator
+                .as_ref()
+                .and_then(|i| i.margin)
+                .map(|n| n.to_string())
+        },
+        unset: |c| {
+            if let Some(s) = c.indicator.as_mut() {
+                s.margin = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "indicator.background",
+        validate: |v| validate_indicator_color("indicator.background", v),
+        set: |c, v| {
+            validate_indicator_color("indicator.background", v)?;
+            indicator_section(c).background = Some(v.trim().to_string());
+            Ok(())
+        },
+        get: |c| c.indicator.as_ref().and_then(|i| i.background.clone()),
+        unset: |c| {
+            if let Some(s) = c.indicator.as_mut() {
+                s.background = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "indicator.recording_color",
+        validate: |v| validate_indicator_color("indicator.recording_color", v),
+        set: |c, v| {
+            validate_indicator_color("indicator.recording_color", v)?;
+            indicator_section(c).recording_color = Some(v.trim().to_string());
+            Ok(())
+        },
+        get: |c| c.indicator.as_ref().and_then(|i| i.recording_color.clone()),
+        unset: |c| {
+            if let Some(s) = c.indicator.as_mut() {
+                s.recording_color = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "indicator.processing_color",
+        validate: |v| validate_indicator_color("indicator.processing_color", v),
+        set: |c, v| {
+            validate_indicator_color("indicator.processing_color", v)?;
+            indicator_section(c).processing_color = Some(v.trim().to_string());
+            Ok(())
+        },
+        get: |c| {
+            c.indicator
+                .as_ref()
+                .and_then(|i| i.processing_color.clone())
+        },
+        unset: |c| {
+            if let Some(s) = c.indicator.as_mut() {
+                s.processing_color = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "cues.start_sound",
+        validate: |v| validate_sound_path("cues.start_sound", v),
+        set: |c, v| {
+            validate_sound_path("cues.start_sound", v)?;
+            cues_section(c).start_sound = Some(v.trim().to_string());
+            Ok(())
+        },
+        get: |c| c.cues.as_ref().and_then(|s| s.start_sound.clone()),
+        unset: |c| {
+            if let Some(s) = c.cues.as_mut() {
+                s.start_sound = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "cues.stop_sound",
+        validate: |v| validate_sound_path("cues.stop_sound", v),
+        set: |c, v| {
+            validate_sound_path("cues.stop_sound", v)?;
+            cues_section(c).stop_sound = Some(v.trim().to_string());
+            Ok(())
+        },
+        get: |c| c.cues.as_ref().and_then(|s| s.stop_sound.clone()),
+        unset: |c| {
+            if let Some(s) = c.cues.as_mut() {
+                s.stop_sound = None;
+            }
+        },
+        display: identity,
+    },
+    ConfigKey {
+        name: "cues.cancel_sound",
+        validate: |v| validate_sound_path("cues.cancel_sound", v),
+        set: |c, v| {
+            validate_sound_path("cues.cancel_sound", v)?;
+            cues_section(c).cancel_sound = Some(v.trim().to_string());
+            Ok(())
+        },
+        get: |c| c.cues.as_ref().and_then(|s| s.cancel_sound.clone()),
+        unset: |c| {
+            if let Some(s) = c.cues.as_mut() {
+                s.cancel_sound = None;
+            }
+        },
         display: identity,
     },
 ];
@@ -333,6 +840,14 @@ fn windows_section(c: &mut RawAppConfig) -> &mut RawWindowsConfig {
     c.windows.get_or_insert_with(RawWindowsConfig::default)
 }
 
+fn indicator_section(c: &mut RawAppConfig) -> &mut RawIndicatorConfig {
+    c.indicator.get_or_insert_with(RawIndicatorConfig::default)
+}
+
+fn cues_section(c: &mut RawAppConfig) -> &mut RawCuesConfig {
+    c.cues.get_or_insert_with(RawCuesConfig::default)
+}
+
 fn validate_auth(value: &str) -> Result<(), ConfigError> {
     value
         .parse::<AuthMode>()
@@ -343,6 +858,13 @@ fn validate_auth(value: &str) -> Result<(), ConfigError> {
         })
 }
 
+fn parse_base_url(value: &str) -> Result<String, ConfigError> {
+    normalize_base_url(value).map_err(|message| ConfigError::ValidationError {
+        key: "openai_base_url".into(),
+        message,
+    })
+}
+
 fn validate_duration(value: &str) -> Result<(), ConfigError> {
     value
         .parse::<Duration>()
@@ -353,6 +875,21 @@ fn validate_duration(value: &str) -> Result<(), ConfigError> {
         })
 }
 
+fn validate_indicator_position(key: &str, value: &str) -> Result<(), ConfigError> {
+    if VALID_INDICATOR_POSITIONS.contains(&value) {
+        Ok(())
+    } else {
+        Err(ConfigError::ValidationError {
+            key: key.into(),
+            message: format!(
+                "Invalid value '{}'. Valid: {}",
+                value,
+                VALID_INDICATOR_POSITIONS.join(", ")
+            ),
+        })
+    }
+}
+
 fn validate_bool(value: &str) -> Result<(), ConfigError> {
     parse_bool(value).map(|_| ())
 }
@@ -368,12 +905,117 @@ fn parse_bool(value: &str) -> Result<bool, ConfigError> {
     }
 }
 
+fn parse_history_max_entries(value: &str) -> Result<u32, ConfigError> {
+    match value.trim().parse::<u32>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(ConfigError::ValidationError {
+            key: "history_max_entries".into(),
+            message: "Value must be a positive integer".to_string(),
+        }),
+    }
+}
+
+fn parse_silence_threshold(value: &str) -> Result<i32, ConfigError> {
+    let message = match value.trim().parse::<i32>() {
+        Ok(n) => match validate_silence_threshold(n) {
+            Ok(n) => return Ok(n),
+            Err(m) => m,
+        },
+        Err(_) => "Value must be an integer level in dBFS (e.g. -60)".to_string(),
+    };
+    Err(ConfigError::ValidationError {
+        key: "silence_threshold".into(),
+        message,
+    })
+}
+
+fn parse_sample_rate(value: &str) -> Result<u32, ConfigError> {
+    let message = match value.trim().parse::<u32>() {
+        Ok(n) => match validate_sample_rate(n) {
+            Ok(n) => return Ok(n),
+            Err(m) => m,
+        },
+        Err(_) => "Value must be a sample rate in Hz (e.g. 16000)".to_string(),
+    };
+    Err(ConfigError::ValidationError {
+        key: "sample_rate".into(),
+        message,
+    })
+}
+
+fn parse_sample_format(value: &str) -> Result<SampleFormatPreference, ConfigError> {
+    value
+        .parse()
+        .map_err(|message| ConfigError::ValidationError {
+            key: "sample_format".into(),
+            message,
+        })
+}
+
+fn parse_cue_volume(value: &str) -> Result<f32, ConfigError> {
+    let message = match value.trim().parse::<f32>() {
+        Ok(v) => match validate_cue_volume(v) {
+            Ok(v) => return Ok(v),
+            Err(m) => m,
+        },
+        Err(_) => "Value must be a number (e.g. 0.5)".to_string(),
+    };
+    Err(ConfigError::ValidationError {
+        key: "cue_volume".into(),
+        message,
+    })
+}
+
+/// Sound files are only checked when the cue adapter loads them (a missing
+/// file warns and falls back), so `set` just rejects an empty path.
+fn validate_sound_path(key: &str, value: &str) -> Result<(), ConfigError> {
+    if value.trim().is_empty() {
+        return Err(ConfigError::ValidationError {
+            key: key.into(),
+            message: "Path must not be empty; use `config unset` to restore the built-in sound"
+                .to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn parse_indicator_dimension(key: &str, value: &str, min: u32) -> Result<u32, ConfigError> {
+    let message = match value.trim().parse::<u32>() {
+        Ok(n) => match validate_dimension(n, min) {
+            Ok(n) => return Ok(n),
+            Err(m) => m,
+        },
+        Err(_) => "Value must be a non-negative integer".to_string(),
+    };
+    Err(ConfigError::ValidationError {
+        key: key.into(),
+        message,
+    })
+}
+
+fn validate_indicator_color(key: &str, value: &str) -> Result<(), ConfigError> {
+    parse_hex_color(value)
+        .map(|_| ())
+        .map_err(|message| ConfigError::ValidationError {
+            key: key.into(),
+            message,
+        })
+}
+
 fn identity(s: &str) -> String {
     s.to_string()
 }
 
-/// Mask an API key for display (first 4 + last 4 chars).
+fn parse_api_key(value: &str) -> Result<String, ConfigError> {
+    normalize_api_key(value).map_err(|message| ConfigError::ValidationError {
+        key: "openai_api_key".into(),
+        message,
+    })
+}
+
+/// Mask an API key for display (first 4 + last 4 chars of the trimmed key).
 pub fn mask_api_key(key: &str) -> String {
+    let key = key.trim();
     if key.len() <= 8 {
         "*".repeat(key.len())
     } else {
@@ -408,6 +1050,24 @@ mod tests {
         assert!((entry.validate)("garbage").is_err());
     }
 
+    #[test]
+    fn validate_sample_rate_accepts_supported_rates() {
+        let entry = find("sample_rate").unwrap();
+        assert!((entry.validate)("16000").is_ok());
+        assert!((entry.validate)("48000").is_ok());
+        assert!((entry.validate)("44100").is_err());
+        assert!((entry.validate)("16k").is_err());
+    }
+
+    #[test]
+    fn set_sample_format_normalises_case() {
+        let entry = find("sample_format").unwrap();
+        let mut raw = RawAppConfig::empty();
+        (entry.set)(&mut raw, "I16").unwrap();
+        assert_eq!((entry.get)(&raw).as_deref(), Some("i16"));
+        assert!((entry.validate)("s24").is_err());
+    }
+
     #[test]
     fn validate_auth_rejects_invalid() {
         let entry = find("auth").unwrap();
@@ -447,6 +1107,33 @@ mod tests {
         assert!((entry.validate)("nowhere").is_err());
     }
 
+    #[test]
+    fn set_base_url_strips_trailing_slash() {
+        let entry = find("openai_base_url").unwrap();
+        let mut cfg = RawAppConfig::empty();
+        (entry.set)(&mut cfg, "http://localhost:8080/v1/").unwrap();
+        assert_eq!(
+            (entry.get)(&cfg).as_deref(),
+            Some("http://localhost:8080/v1")
+        );
+        assert!((entry.validate)("localhost:8080").is_err());
+    }
+
+    #[test]
+    fn indicator_keys_validate_at_set_time() {
+        let mut cfg = RawAppConfig::empty();
+        let width = find("indicator.width").unwrap();
+        (width.set)(&mut cfg, "140").unwrap();
+        assert_eq!((width.get)(&cfg).as_deref(), Some("140"));
+        assert!((width.validate)("10").is_err());
+        assert!((width.validate)("wide").is_err());
+
+        let color = find("indicator.recording_color").unwrap();
+        (color.set)(&mut cfg, "#00ff0080").unwrap();
+        assert_eq!((color.get)(&cfg).as_deref(), Some("#00ff0080"));
+        assert!((color.set)(&mut cfg, "green").is_err());
+    }
+
     #[test]
     fn mask_api_key_long() {
         assert_eq!(mask_api_key("abcdefghijklmnop"), "abcd...mnop");
@@ -456,4 +1143,21 @@ mod tests {
     fn mask_api_key_short() {
         assert_eq!(mask_api_key("short"), "*****");
     }
+
+    #[test]
+    fn mask_api_key_ignores_surrounding_whitespace() {
+        assert_eq!(mask_api_key("  abcdefghijklmnop\n"), "abcd...mnop");
+    }
+
+    #[test]
+    fn api_key_is_trimmed_and_short_values_rejected() {
+        let entry = find("openai_api_key").unwrap();
+        let mut cfg = RawAppConfig::empty();
+        (entry.set)(&mut cfg, " sk-proj-abcdef123456\r\n").unwrap();
+        assert_eq!((entry.get)(&cfg).as_deref(), Some("sk-proj-abcdef123456"));
+
+        assert!((entry.validate)("   ").is_err());
+        let err = (entry.validate)("sk-abc").unwrap_err();
+        assert!(err.to_string().contains("too short"), "{err}");
+    }
 }
@@ -1,44 +1,83 @@
 //! Daemon app runner
 
+use std::path::Path;
 use std::process::ExitCode;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Duration as StdDuration;
+use std::time::{Duration as StdDuration, Instant, SystemTime, UNIX_EPOCH};
 
 use tokio::sync::broadcast;
 use tokio::time::timeout;
 
-use crate::application::ports::{AudioCue, AudioCueType};
-use crate::application::{DaemonConfig, DaemonTranscriptionUseCase};
-use crate::domain::config::AppConfig;
+use crate::application::ports::ConfigStore;
+use crate::application::{
+    DaemonConfig, DaemonError, DaemonTranscriptionUseCase, ReloadableSettings,
+};
+use crate::domain::config::{AppConfig, RawAppConfig};
 use crate::domain::daemon::{DaemonState, StateUpdate};
+use crate::domain::recording::{Duration, EndOfSpeech};
+use crate::infrastructure::XdgConfigStore;
 
+use super::app::load_merged_config;
 use super::args::DaemonOptions;
 use super::auth_cmd::describe_auth;
 use super::exit_codes;
-use super::ipc::create_ipc_server;
-use super::output::DaemonEvent;
-use super::pid_file::{PidFile, PidFileError};
+use super::ipc::{create_ipc_server, IpcEndpoint, IpcHandlers, IpcServer};
+use super::output::{DaemonConfigPayload, DaemonConfigUpdate, DaemonEvent, DaemonOutputStyle};
+use super::pid_file::{running_oneshot_pid, PidFile, PidFileError};
 use super::presenter::Presenter;
-use super::runtime::{build_adapters, BuildError, RuntimeOptions};
+use super::runtime::{build_adapters, RuntimeOptions};
 use super::signals::{DaemonSignal, DaemonSignalHandler};
 
 /// Buffer size for state update broadcast channel
 const STATE_BROADCAST_CAPACITY: usize = 16;
 
+/// Interval between state events for IPC subscribers while recording
+const STATE_TICK: StdDuration = StdDuration::from_millis(500);
+
+/// Faster indicator refresh while the recorder reports an input level
+const LEVEL_TICK: StdDuration = StdDuration::from_millis(100);
+
 /// Context for the daemon loop to reduce argument count
 struct DaemonLoopContext<'a> {
     presenter: &'a Presenter,
-    max_duration_ms: u64,
+    cli_overlay: &'a RawAppConfig,
+    profile: Option<&'a str>,
+    config_path: Option<&'a Path>,
     shared_state: &'a Arc<Mutex<DaemonState>>,
     shared_elapsed: &'a Arc<Mutex<u64>>,
+    last_text: &'a Arc<Mutex<Option<String>>>,
     state_tx: &'a broadcast::Sender<StateUpdate>,
     event_tx: &'a broadcast::Sender<DaemonEvent>,
-    audio_cue: &'a Arc<dyn AudioCue>,
+    output_style: &'a DaemonOutputStyle,
+    /// `--vad`: silence after speech that stops a recording.
+    vad_silence: Option<Duration>,
+    /// `--timing`: print each transcription's stage timings to stderr.
+    timing: bool,
+    /// Cleared once the first transcription is printed (no separator
+    /// before it).
+    first_output: AtomicBool,
 }
 
 /// Run daemon mode
 pub async fn run_daemon(options: DaemonOptions, config: &AppConfig) -> ExitCode {
-    let presenter = Presenter::new(options.output);
+    let presenter = Presenter::new(options.format)
+        .with_quiet(options.quiet)
+        .with_json_errors(options.json_errors);
+    let code = daemon(&options, config, &presenter).await;
+    presenter.report_failure(code);
+    code
+}
+
+/// Body of [`run_daemon`]; startup errors go through `presenter` so the
+/// wrapper can report the one that ended the run.
+async fn daemon(options: &DaemonOptions, config: &AppConfig, presenter: &Presenter) -> ExitCode {
+    if let Some(pid) = running_oneshot_pid() {
+        presenter.warn(&format!(
+            "A one-shot recording (PID: {pid}) is in progress; \
+             recordings may fail or be garbled until it finishes"
+        ));
+    }
 
     // Acquire PID file
     let pid_file = PidFile::new();
@@ -54,16 +93,12 @@ pub async fn run_daemon(options: DaemonOptions, config: &AppConfig) -> ExitCode
         return ExitCode::from(exit_codes::ERROR);
     }
 
-    let runtime_opts = RuntimeOptions::from(&options);
-    let bundle = match build_adapters(config, &runtime_opts, &presenter).await {
+    let runtime_opts = RuntimeOptions::from(options);
+    let bundle = match build_adapters(config, &runtime_opts, presenter).await {
         Ok(b) => b,
-        Err(BuildError::Transcriber(msg)) => {
-            presenter.error(&msg);
-            return ExitCode::from(exit_codes::ERROR);
-        }
-        Err(BuildError::SmartPaste(msg)) => {
-            presenter.error(&format!("Paste mode unavailable: {}", msg));
-            return ExitCode::from(exit_codes::ERROR);
+        Err(e) => {
+            presenter.error_with_kind(e.kind(), &e.to_string());
+            return ExitCode::from(e.exit_code());
         }
     };
     presenter.info(&describe_auth(config));
@@ -72,25 +107,33 @@ pub async fn run_daemon(options: DaemonOptions, config: &AppConfig) -> ExitCode
 
     let daemon_config = DaemonConfig {
         max_duration: options.max_duration,
+        min_duration: options.min_duration,
+        silence_threshold: options.silence_threshold,
         enable_clipboard: options.clipboard,
+        clipboard_append: options.clipboard_append.clone(),
         enable_keystroke: options.keystroke,
         enable_paste,
         enable_notify: options.notify,
         warning_sink: Some(presenter.warning_sink()),
+        history: bundle.history.clone(),
+        template: options.template.clone(),
     };
 
-    let audio_cue: Arc<dyn AudioCue> = bundle.audio_cue;
-
-    let use_case = DaemonTranscriptionUseCase::new(
-        crate::application::UseCaseDeps {
-            recorder: bundle.recorder,
-            transcriber: bundle.transcriber,
-            clipboard: bundle.clipboard,
-            keystroke: bundle.keystroke,
-            notifier: bundle.notifier,
-            smart_paste: bundle.smart_paste,
-        },
-        daemon_config,
+    // Shared with the IPC server, which serves `get-config` / `set-config`.
+    let use_case = Arc::new(
+        DaemonTranscriptionUseCase::new(
+            crate::application::UseCaseDeps {
+                recorder: bundle.recorder,
+                transcriber: bundle.transcriber,
+                clipboard: bundle.clipboard,
+                keystroke: bundle.keystroke,
+                notifier: bundle.notifier,
+                smart_paste: bundle.smart_paste,
+                audio_cue: bundle.audio_cue,
+            },
+            daemon_config,
+        )
+        .with_post_processor(bundle.post_processor),
     );
 
     // Setup signal handler (returns handler + sender for socket server)
@@ -103,19 +146,24 @@ pub async fn run_daemon(options: DaemonOptions, config: &AppConfig) -> ExitCode
     };
 
     // Setup IPC server (Unix socket on Linux/macOS, named pipe on Windows)
-    let mut ipc_server = create_ipc_server();
-    let ipc_path = ipc_server.path();
-
+    let mut ipc_server = create_ipc_server(&options.ipc, config.ipc_token.clone());
     if let Err(e) = ipc_server.bind() {
         presenter.error(&format!("Failed to bind IPC: {}", e));
         return ExitCode::from(exit_codes::ERROR);
     }
+    let ipc_server: Arc<dyn IpcServer> = Arc::from(ipc_server);
+    let ipc_path = ipc_server.path();
+    if matches!(options.ipc, IpcEndpoint::Tcp(_)) && config.ipc_token.is_none() {
+        presenter.warn("TCP control is unauthenticated; set ipc_token or bind to loopback");
+    }
 
     // Wrap state and elapsed time in Arc<Mutex> for sharing with IPC server
     let state = Arc::new(Mutex::new(DaemonState::Idle));
     let elapsed = Arc::new(Mutex::new(0u64));
     let state_for_ipc = Arc::clone(&state);
     let elapsed_for_ipc = Arc::clone(&elapsed);
+    let last_text = Arc::new(Mutex::new(None::<String>));
+    let last_text_for_ipc = Arc::clone(&last_text);
 
     // Create broadcast channels for indicator and external subscribers
     let (state_tx, _state_rx) = broadcast::channel::<StateUpdate>(STATE_BROADCAST_CAPACITY);
@@ -126,10 +174,15 @@ pub async fn run_daemon(options: DaemonOptions, config: &AppConfig) -> ExitCode
     if options.indicator {
         let indicator_rx = state_tx.subscribe();
         let position = options.indicator_position;
+        let style = config.indicator;
+        let click_tx = options.indicator_clickable.then(|| signal_tx.clone());
+        for warning in &config.indicator_warnings {
+            presenter.warn(warning);
+        }
         std::thread::spawn(move || {
-            if let Err(e) = crate::gui::run_indicator(position, indicator_rx) {
-                eprintln!(
-                    "Indicator error: {} (requires Wayland with wlr-layer-shell)",
+            if let Err(e) = crate::gui::run_indicator(position, style, indicator_rx, click_tx) {
+                tracing::error!(
+                    "Indicator error: {} (requires Wayland with wlr-layer-shell or an X11 display)",
                     e
                 );
             }
@@ -140,50 +193,106 @@ pub async fn run_daemon(options: DaemonOptions, config: &AppConfig) -> ExitCode
     #[cfg(target_os = "windows")]
     if options.indicator {
         let indicator_rx = state_tx.subscribe();
+        let position = options.indicator_position;
+        let style = config.indicator;
         let signal_tx_for_tray = signal_tx.clone();
+        for warning in &config.indicator_warnings {
+            presenter.warn(warning);
+        }
         std::thread::spawn(move || {
-            if let Err(e) = crate::gui::run_indicator(indicator_rx, signal_tx_for_tray) {
-                eprintln!("Indicator error: {} (tray icon unavailable)", e);
+            if let Err(e) =
+                crate::gui::run_indicator(position, style, indicator_rx, signal_tx_for_tray)
+            {
+                tracing::error!("Indicator error: {} (tray icon unavailable)", e);
             }
         });
-        presenter.info("Tray indicator enabled");
+        presenter.info("Tray and overlay indicator enabled");
+    }
+
+    if let Some(silence) = options.vad_silence {
+        presenter.info(&format!(
+            "Auto-stop: recordings end after {silence} of silence following speech"
+        ));
     }
 
     // Spawn IPC server task
-    tokio::spawn(async move {
-        let _ = ipc_server
-            .run(
-                signal_tx,
-                Box::new(move || {
-                    // Use std::sync::Mutex - safe because lock is very brief
-                    *state_for_ipc.lock().unwrap_or_else(|e| e.into_inner())
-                }),
-                Box::new(move || *elapsed_for_ipc.lock().unwrap_or_else(|e| e.into_inner())),
-                event_rx,
-            )
-            .await;
+    let config_for_ipc = Arc::clone(&use_case);
+    let update_for_ipc = Arc::clone(&use_case);
+    let handlers = IpcHandlers {
+        state: Box::new(move || {
+            // Use std::sync::Mutex - safe because lock is very brief
+            *state_for_ipc.lock().unwrap_or_else(|e| e.into_inner())
+        }),
+        elapsed: Box::new(move || *elapsed_for_ipc.lock().unwrap_or_else(|e| e.into_inner())),
+        last_text: Box::new(move || {
+            last_text_for_ipc
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone()
+        }),
+        get_config: Box::new(move || DaemonConfigPayload::from(&config_for_ipc.config())),
+        set_config: Box::new(move |json| {
+            let update: DaemonConfigUpdate =
+                serde_json::from_str(json).map_err(|e| e.to_string())?;
+            let changes = update_for_ipc.update_settings(|current| update.apply(current))?;
+            // Like a reload, this lasts until the next one; the config
+            // file is not touched.
+            tracing::info!(?changes, "settings updated over IPC");
+            Ok(changes)
+        }),
+    };
+    let ipc_task = tokio::spawn({
+        let ipc_server = Arc::clone(&ipc_server);
+        async move {
+            let _ = ipc_server.run(signal_tx, handlers, event_rx).await;
+        }
     });
 
     presenter.daemon_status("Started, waiting for commands...");
     presenter.info(&format!(
-        "PID: {} | IPC: {} | SIGINT: exit",
+        "PID: {} | IPC: {} | SIGINT/SIGTERM: exit",
         std::process::id(),
         ipc_path
     ));
+    let log_path = options
+        .log_file
+        .as_deref()
+        .map_or_else(|| "stderr".to_string(), |p| p.display().to_string());
+    presenter.info(&format!(
+        "PID file: {} | Log: {}",
+        pid_file.path().display(),
+        log_path
+    ));
+    tracing::info!(
+        pid = std::process::id(),
+        ipc = %ipc_path,
+        pid_file = %pid_file.path().display(),
+        "daemon started"
+    );
 
     // Main signal loop
     let ctx = DaemonLoopContext {
-        presenter: &presenter,
-        max_duration_ms: options.max_duration.as_millis(),
+        presenter,
+        cli_overlay: &options.cli_overlay,
+        profile: options.profile.as_deref(),
+        config_path: options.config_path.as_deref(),
         shared_state: &state,
         shared_elapsed: &elapsed,
+        last_text: &last_text,
         state_tx: &state_tx,
         event_tx: &event_tx,
-        audio_cue: &audio_cue,
+        output_style: &options.output_style,
+        vad_silence: options.vad_silence,
+        timing: options.timing,
+        first_output: AtomicBool::new(true),
     };
-    let result = daemon_loop(&use_case, &mut signals, &ctx).await;
+    let result = daemon_loop(&*use_case, &mut signals, &ctx).await;
 
-    // Cleanup (IPC server Drop will clean up resources)
+    // Stop serving and remove the socket explicitly rather than relying on
+    // the task being dropped at runtime teardown, then release the PID file.
+    ipc_task.abort();
+    let _ = ipc_task.await;
+    ipc_server.cleanup();
     let _ = pid_file.release();
 
     if result {
@@ -206,8 +315,12 @@ where
     N: crate::application::ports::Notifier,
     P: crate::application::ports::SmartPaste,
 {
+    // State and time of the last event sent to IPC subscribers. Level ticks
+    // only reach the indicator; subscribers keep the `STATE_TICK` cadence.
+    let last_event = std::cell::Cell::new(None::<(DaemonState, Instant)>);
+
     // Helper to broadcast state updates
-    let broadcast_state = |state: DaemonState, elapsed_ms: u64| {
+    let broadcast_state = |state: DaemonState, elapsed_ms: u64, level: Option<f32>| {
         // Update shared state for status queries
         if let Ok(mut guard) = ctx.shared_state.lock() {
             *guard = state;
@@ -216,34 +329,71 @@ where
             *guard = elapsed_ms;
         }
 
-        let update = StateUpdate::new(state, elapsed_ms);
+        let remaining_ms = (state == DaemonState::Recording).then(|| {
+            use_case
+                .max_duration()
+                .as_millis()
+                .saturating_sub(elapsed_ms)
+        });
+        let update = StateUpdate::new(state, elapsed_ms)
+            .with_level(level)
+            .with_remaining(remaining_ms);
 
         // Broadcast to subscribers (ignore if no receivers)
         let _ = ctx.state_tx.send(update.clone());
-        let _ = ctx.event_tx.send(DaemonEvent::from(update));
+        let due = match last_event.get() {
+            Some((last_state, at)) => last_state != state || at.elapsed() >= STATE_TICK,
+            None => true,
+        };
+        if due {
+            last_event.set(Some((state, Instant::now())));
+            let _ = ctx.event_tx.send(DaemonEvent::from(update));
+        }
     };
 
     let emit_event = |event: DaemonEvent| {
+        log_event(&event);
         let _ = ctx.event_tx.send(event);
     };
 
+    // Fed every level tick; re-armed whenever no recording is running.
+    let mut vad = ctx.vad_silence.map(EndOfSpeech::new);
+
     loop {
         let state = use_case.state().await;
         let elapsed_ms = use_case.elapsed_ms();
+        let level = match state {
+            DaemonState::Recording => use_case.audio_level(),
+            _ => None,
+        };
+        if state != DaemonState::Recording {
+            if let Some(vad) = vad.as_mut() {
+                vad.reset();
+            }
+        }
 
         // Update shared state and broadcast
-        broadcast_state(state, elapsed_ms);
+        broadcast_state(state, elapsed_ms, level);
 
         // If recording, use timeout for max duration check and periodic broadcasts
         let signal = if state == DaemonState::Recording {
-            let remaining_ms = ctx.max_duration_ms.saturating_sub(elapsed_ms);
+            let remaining_ms = use_case
+                .max_duration()
+                .as_millis()
+                .saturating_sub(elapsed_ms);
             if remaining_ms == 0 {
                 // Max duration reached
                 Some(DaemonSignal::Toggle)
             } else {
-                // Use 500ms timeout for periodic state broadcasts during recording
+                // Periodic state broadcasts during recording; tick faster
+                // when there is a level meter to animate.
+                let tick = if level.is_some() {
+                    LEVEL_TICK
+                } else {
+                    STATE_TICK
+                };
                 match timeout(
-                    StdDuration::from_millis(remaining_ms.min(500)),
+                    StdDuration::from_millis(remaining_ms).min(tick),
                     signals.recv(),
                 )
                 .await
@@ -254,6 +404,17 @@ where
                         if use_case.check_max_duration() {
                             ctx.presenter.warn("Max duration reached, auto-stopping");
                             Some(DaemonSignal::Toggle)
+                        } else if use_case.check_device_failed() {
+                            // Stopping surfaces the error and returns to Idle.
+                            Some(DaemonSignal::Toggle)
+                        } else if vad
+                            .as_mut()
+                            .zip(level)
+                            .is_some_and(|(vad, level)| vad.update(level, elapsed_ms))
+                        {
+                            tracing::info!(elapsed_ms, "silence after speech, auto-stopping");
+                            ctx.presenter.info("Silence detected, auto-stopping");
+                            Some(DaemonSignal::Toggle)
                         } else {
                             // Periodic broadcast during recording - continue loop
                             continue;
@@ -265,65 +426,135 @@ where
             signals.recv().await
         };
 
+        // `start`/`stop` are a toggle limited to the state they apply in.
+        // The IPC layer already answered "noop" otherwise; this re-check
+        // covers the state changing before the signal arrived.
+        let signal = match signal {
+            Some(DaemonSignal::Start | DaemonSignal::Stop) => {
+                let wanted = if signal == Some(DaemonSignal::Start) {
+                    DaemonState::Idle
+                } else {
+                    DaemonState::Recording
+                };
+                if use_case.state().await != wanted {
+                    continue;
+                }
+                Some(DaemonSignal::Toggle)
+            }
+            other => other,
+        };
+
         match signal {
             Some(DaemonSignal::Toggle) => {
                 let current_state = use_case.state().await;
-                ctx.presenter
-                    .info(&format!("Processing toggle, state={:?}", current_state));
+                tracing::debug!(state = ?current_state, "processing toggle");
                 match current_state {
                     DaemonState::Idle => {
                         // Start recording
-                        if let Err(e) = use_case.start_recording().await {
-                            ctx.presenter
-                                .error(&format!("Failed to start recording: {}", e));
-                            emit_event(DaemonEvent::error("start", e.to_string()));
-                            continue;
+                        match use_case.start_recording().await {
+                            Ok(()) => {}
+                            Err(e @ DaemonError::CoolingDown { .. }) => {
+                                ctx.presenter.warn(&e.to_string());
+                                emit_event(DaemonEvent::error("start", e.to_string()));
+                                continue;
+                            }
+                            Err(e) => {
+                                ctx.presenter.error_with_kind(
+                                    exit_codes::daemon_error_kind(&e),
+                                    &format!("Failed to start recording: {}", e),
+                                );
+                                emit_event(DaemonEvent::error("start", e.to_string()));
+                                continue;
+                            }
                         }
-                        let _ = ctx.audio_cue.play(AudioCueType::RecordingStart).await;
+                        tracing::info!("recording started");
                         ctx.presenter.daemon_status("Recording...");
-                        broadcast_state(DaemonState::Recording, 0);
+                        broadcast_state(DaemonState::Recording, 0, None);
                     }
                     DaemonState::Recording => {
                         // Stop recording first to get audio size
                         let final_elapsed = use_case.elapsed_ms();
                         match use_case.stop_recording().await {
                             Ok(audio) => {
-                                let audio_size =
-                                    super::output::format_audio_size(audio.size_bytes() as u64);
+                                let summary = super::output::format_audio_summary(
+                                    audio.size_bytes() as u64,
+                                    audio.duration_ms(),
+                                );
                                 ctx.presenter
-                                    .daemon_status(&format!("Processing ({})...", audio_size));
-                                broadcast_state(DaemonState::Processing, final_elapsed);
-
-                                let _ = ctx.audio_cue.play(AudioCueType::RecordingStop).await;
+                                    .daemon_status(&format!("Processing ({})...", summary));
+                                broadcast_state(DaemonState::Processing, final_elapsed, None);
 
                                 // Now transcribe
                                 match use_case.transcribe_audio(audio).await {
                                     Ok(output) => {
+                                        *ctx.last_text.lock().unwrap_or_else(|e| e.into_inner()) =
+                                            Some(output.text.clone());
+                                        if ctx.timing {
+                                            eprintln!(
+                                                "{}",
+                                                super::output::format_timings(&output.timings)
+                                            );
+                                        }
                                         if ctx.presenter.is_json() {
                                             let event = DaemonEvent::from(output.clone());
                                             ctx.presenter.output_json(&event);
                                         } else {
-                                            ctx.presenter.output(&output.text);
+                                            let now = SystemTime::now()
+                                                .duration_since(UNIX_EPOCH)
+                                                .map(|d| d.as_secs())
+                                                .unwrap_or(0);
+                                            let first =
+                                                ctx.first_output.swap(false, Ordering::Relaxed);
+                                            ctx.presenter.output(&ctx.output_style.render(
+                                                &output.text,
+                                                now,
+                                                first,
+                                            ));
+                                            ctx.presenter.info(
+                                                &super::output::format_text_summary(
+                                                    output.word_count,
+                                                    output.char_count,
+                                                ),
+                                            );
                                         }
                                         emit_event(DaemonEvent::from(output));
                                         ctx.presenter.daemon_status("Idle");
-                                        broadcast_state(DaemonState::Idle, 0);
+                                        broadcast_state(DaemonState::Idle, 0, None);
                                     }
                                     Err(e) => {
-                                        ctx.presenter
-                                            .error(&format!("Transcription failed: {}", e));
+                                        ctx.presenter.error_with_kind(
+                                            exit_codes::daemon_error_kind(&e),
+                                            &format!("Transcription failed: {}", e),
+                                        );
                                         emit_event(DaemonEvent::error("transcribe", e.to_string()));
                                         ctx.presenter.daemon_status("Idle (error)");
-                                        broadcast_state(DaemonState::Idle, 0);
+                                        broadcast_state(DaemonState::Idle, 0, None);
                                     }
                                 }
                             }
+                            Err(e @ DaemonError::TooShort { .. }) => {
+                                ctx.presenter.warn(&e.to_string());
+                                emit_event(DaemonEvent::Cancelled);
+                                ctx.presenter.daemon_status("Idle");
+                                broadcast_state(DaemonState::Idle, 0, None);
+                            }
+                            Err(e @ DaemonError::NoAudio { .. }) => {
+                                ctx.presenter.error_with_kind(
+                                    exit_codes::daemon_error_kind(&e),
+                                    &e.to_string(),
+                                );
+                                emit_event(DaemonEvent::error("stop", e.to_string()));
+                                ctx.presenter.daemon_status("Idle (error)");
+                                broadcast_state(DaemonState::Idle, 0, None);
+                            }
                             Err(e) => {
-                                ctx.presenter
-                                    .error(&format!("Failed to stop recording: {}", e));
+                                ctx.presenter.error_with_kind(
+                                    exit_codes::daemon_error_kind(&e),
+                                    &format!("Failed to stop recording: {}", e),
+                                );
                                 emit_event(DaemonEvent::error("stop", e.to_string()));
                                 ctx.presenter.daemon_status("Idle (error)");
-                                broadcast_state(DaemonState::Idle, 0);
+                                broadcast_state(DaemonState::Idle, 0, None);
                             }
                         }
                     }
@@ -333,34 +564,57 @@ where
                     }
                 }
             }
+            Some(DaemonSignal::Start | DaemonSignal::Stop) => unreachable!("mapped above"),
             Some(DaemonSignal::Cancel) => {
                 let current_state = use_case.state().await;
-                ctx.presenter
-                    .info(&format!("Processing cancel, state={:?}", current_state));
+                tracing::debug!(state = ?current_state, "processing cancel");
                 if current_state == DaemonState::Recording {
                     if let Err(e) = use_case.cancel().await {
                         ctx.presenter.error(&format!("Failed to cancel: {}", e));
                         emit_event(DaemonEvent::error("cancel", e.to_string()));
                     } else {
-                        let _ = ctx.audio_cue.play(AudioCueType::RecordingCancel).await;
                         emit_event(DaemonEvent::Cancelled);
                         ctx.presenter.daemon_status("Recording cancelled");
-                        broadcast_state(DaemonState::Idle, 0);
+                        broadcast_state(DaemonState::Idle, 0, None);
                     }
                 } else {
                     ctx.presenter.warn("Not recording, nothing to cancel");
                 }
             }
+            Some(DaemonSignal::Reload) => {
+                match reload_settings(ctx.cli_overlay, ctx.profile, ctx.config_path).await {
+                    Ok(settings) => {
+                        let changes = use_case.reload(settings);
+                        if changes.is_empty() {
+                            ctx.presenter.info("Config reloaded (no changes)");
+                        } else {
+                            ctx.presenter
+                                .success(&format!("Config reloaded: {}", changes.join(", ")));
+                        }
+                    }
+                    Err(e) => {
+                        ctx.presenter
+                            .error(&format!("Config reload failed, keeping current: {}", e));
+                        emit_event(DaemonEvent::error("reload", e));
+                    }
+                }
+            }
             Some(DaemonSignal::Shutdown) => {
-                ctx.presenter.info("Processing shutdown");
+                tracing::debug!("processing shutdown");
                 let current_state = use_case.state().await;
                 if current_state == DaemonState::Recording {
-                    // Cancel any in-progress recording
-                    let _ = use_case.cancel().await;
+                    // Cancel so the input stream and sample buffer are
+                    // released before exit; nothing is transcribed.
+                    match use_case.cancel().await {
+                        Ok(()) => emit_event(DaemonEvent::Cancelled),
+                        Err(e) => ctx
+                            .presenter
+                            .warn(&format!("Failed to cancel recording: {}", e)),
+                    }
                 }
                 emit_event(DaemonEvent::Shutdown);
                 ctx.presenter.daemon_status("Shutting down...");
-                broadcast_state(DaemonState::Idle, 0);
+                broadcast_state(DaemonState::Idle, 0, None);
                 return true;
             }
             None => {
@@ -370,3 +624,61 @@ where
         }
     }
 }
+
+/// Record a daemon event in the diagnostic log. Transcripts themselves are
+/// left out; their size is enough to follow what happened.
+fn log_event(event: &DaemonEvent) {
+    match event {
+        DaemonEvent::State { state, elapsed_ms } => {
+            tracing::debug!(?state, elapsed_ms, "state changed");
+        }
+        DaemonEvent::Result {
+            audio_size,
+            audio_duration_ms,
+            word_count,
+            char_count,
+            clipboard_backend,
+            keystroke_backend,
+            ..
+        } => tracing::info!(
+            %audio_size,
+            ?audio_duration_ms,
+            word_count,
+            char_count,
+            ?clipboard_backend,
+            ?keystroke_backend,
+            "transcription complete"
+        ),
+        DaemonEvent::Cancelled => tracing::info!("recording cancelled"),
+        DaemonEvent::Error { stage, message } => tracing::warn!(%stage, "{message}"),
+        DaemonEvent::Shutdown => tracing::info!("daemon shutting down"),
+    }
+}
+
+/// Re-read file + env config under the daemon's original CLI flags and
+/// extract the settings that may change at runtime.
+async fn reload_settings(
+    cli_overlay: &RawAppConfig,
+    profile: Option<&str>,
+    config_path: Option<&Path>,
+) -> Result<ReloadableSettings, String> {
+    // `load_merged_config` tolerates an unreadable file (falls back to
+    // defaults); on reload that would silently drop settings, so check first.
+    XdgConfigStore::at(config_path)
+        .load()
+        .await
+        .map_err(|e| e.to_string())?;
+    let config = load_merged_config(cli_overlay.clone(), profile, config_path)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(ReloadableSettings {
+        max_duration: config
+            .max_duration
+            .unwrap_or_else(Duration::default_max_duration),
+        min_duration: config.min_duration,
+        silence_threshold: config.silence_threshold,
+        enable_clipboard: config.clipboard,
+        enable_keystroke: config.keystroke,
+        enable_notify: config.notify,
+    })
+}
@@ -1,21 +1,48 @@
 //! Daemon command handler - sends commands to running daemon via IPC
 
-use tokio::io::AsyncBufReadExt;
+use std::future::Future;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt};
 
 use super::args::DaemonAction;
-use super::ipc::create_ipc_client;
-use super::output::{DaemonCommandAck, DaemonStatusCommandResponse, DaemonStatusPayload};
+use super::ipc::{create_ipc_client, AckReply, IpcClient, IpcEndpoint};
+use super::logging::default_daemon_log_path;
+use super::output::{
+    DaemonCommandAck, DaemonLastCommandResponse, DaemonStatusCommandResponse, DaemonStatusPayload,
+    DaemonVersionCommandResponse,
+};
+use super::pid_file::running_daemon_pid;
 use super::presenter::Presenter;
 
+/// How long `daemon restart` waits for the old daemon to exit and the new
+/// one to come up.
+const RESTART_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Poll interval while waiting on the socket / PID file.
+const RESTART_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often `daemon logs --follow` checks the log for new lines.
+const LOG_FOLLOW_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Handle daemon subcommand
 pub async fn handle_daemon_command(
     action: DaemonAction,
+    endpoint: &IpcEndpoint,
+    auth_token: Option<String>,
     presenter: &Presenter,
 ) -> Result<(), String> {
-    let client = create_ipc_client();
+    let client = create_ipc_client(endpoint, auth_token);
+
+    if let DaemonAction::Restart = action {
+        return restart_daemon(client.as_ref(), presenter).await;
+    }
 
     // Check if daemon is running
-    if !client.is_daemon_running() {
+    if !client.is_daemon_running().await {
         return Err("No daemon running. Start with: smart-scribe --daemon".to_string());
     }
 
@@ -36,11 +63,37 @@ pub async fn handle_daemon_command(
                     ok: true,
                     command: "toggle",
                     accepted: true,
+                    reason: None,
                 });
             } else {
                 presenter.info("Command sent: toggle");
             }
         }
+        DaemonAction::Start | DaemonAction::Stop => {
+            let (command, reply) = if let DaemonAction::Start = action {
+                ("start", client.start_recording().await)
+            } else {
+                ("stop", client.stop_recording().await)
+            };
+            let reason = match reply.map_err(|e| format!("Failed to send {command}: {e}"))? {
+                AckReply::Accepted => None,
+                AckReply::Ignored(reason) => Some(reason),
+            };
+
+            if presenter.is_json() {
+                presenter.output_json(&DaemonCommandAck {
+                    ok: true,
+                    command,
+                    accepted: reason.is_none(),
+                    reason,
+                });
+            } else {
+                match reason {
+                    None => presenter.info(&format!("Command sent: {command}")),
+                    Some(reason) => presenter.warn(&format!("Nothing to {command}: {reason}")),
+                }
+            }
+        }
         DaemonAction::Cancel => {
             let response = client
                 .send_command("cancel")
@@ -57,20 +110,45 @@ pub async fn handle_daemon_command(
                     ok: true,
                     command: "cancel",
                     accepted: true,
+                    reason: None,
                 });
             } else {
                 presenter.info("Command sent: cancel");
             }
         }
-        DaemonAction::Status => {
+        DaemonAction::Reload => {
+            let response = client
+                .send_command("reload")
+                .await
+                .map_err(|e| format!("Failed to communicate with daemon: {}", e))?;
+            let response = response.trim();
+
+            if let Some(stripped) = response.strip_prefix("error:") {
+                return Err(stripped.trim().to_string());
+            }
+
             if presenter.is_json() {
-                let response = client
-                    .send_command("status-json")
-                    .await
-                    .map_err(|e| format!("Failed to communicate with daemon: {}", e))?;
-                let payload: DaemonStatusPayload = serde_json::from_str(response.trim())
-                    .map_err(|e| format!("Failed to parse daemon status: {}", e))?;
+                presenter.output_json(&DaemonCommandAck {
+                    ok: true,
+                    command: "reload",
+                    accepted: true,
+                    reason: None,
+                });
+            } else {
+                presenter.info("Command sent: reload (see daemon output for applied changes)");
+            }
+        }
+        DaemonAction::Status => {
+            // `status-json` rather than `status --json`: daemons from
+            // before the new spelling reject it.
+            let response = client
+                .send_command("status-json")
+                .await
+                .map_err(|e| format!("Failed to communicate with daemon: {}", e))?;
+            let payload: DaemonStatusPayload = serde_json::from_str(response.trim())
+                .map_err(|e| format!("Failed to parse daemon status: {}", e))?;
 
+            if presenter.is_json() {
                 presenter.output_json(&DaemonStatusCommandResponse {
                     ok: true,
                     command: "status",
@@ -78,16 +156,52 @@ pub async fn handle_daemon_command(
                     elapsed_ms: payload.elapsed_ms,
                 });
             } else {
-                let response = client
-                    .send_command("status")
-                    .await
-                    .map_err(|e| format!("Failed to communicate with daemon: {}", e))?;
-                presenter.info(&format!("Daemon status: {}", response.trim()));
+                presenter.info(&format!("Daemon status: {}", payload.describe()));
+            }
+        }
+        DaemonAction::Last => {
+            let text = client
+                .last_transcription()
+                .await
+                .map_err(|e| format!("Failed to communicate with daemon: {}", e))?
+                .ok_or_else(|| "The daemon has not transcribed anything yet".to_string())?;
+
+            if presenter.is_json() {
+                presenter.output_json(&DaemonLastCommandResponse {
+                    ok: true,
+                    command: "last",
+                    text,
+                });
+            } else {
+                presenter.output(&text);
+            }
+        }
+        DaemonAction::Version => {
+            let version = client
+                .daemon_version()
+                .await
+                .map_err(|e| format!("Failed to communicate with daemon: {}", e))?;
+            let client_version = env!("CARGO_PKG_VERSION");
+
+            if presenter.is_json() {
+                presenter.output_json(&DaemonVersionCommandResponse {
+                    ok: true,
+                    command: "version",
+                    version,
+                    client_version,
+                });
+            } else {
+                presenter.output(&version);
+                if version != client_version {
+                    presenter.warn(&format!(
+                        "Daemon is {version} but this binary is {client_version}; run `smart-scribe daemon restart`"
+                    ));
+                }
             }
         }
         DaemonAction::Subscribe => {
             if !presenter.is_json() {
-                return Err("daemon subscribe requires --output json".to_string());
+                return Err("daemon subscribe requires --format json".to_string());
             }
 
             let mut reader = client
@@ -109,7 +223,274 @@ pub async fn handle_daemon_command(
                 presenter.output_line(&line);
             }
         }
+        DaemonAction::Restart => unreachable!("handled above"),
+        DaemonAction::Logs { .. } => unreachable!("handled by show_daemon_logs"),
     }
 
     Ok(())
 }
+
+/// Shut down the running daemon (if any), wait for it to go away, then
+/// spawn a new one with the old daemon's arguments.
+async fn restart_daemon(client: &dyn IpcClient, presenter: &Presenter) -> Result<(), String> {
+    let old_pid = running_daemon_pid();
+    let mut args = vec!["--daemon".to_string()];
+
+    if client.is_daemon_running().await || old_pid.is_some() {
+        // Ask for the old flags first so `-c -n` etc. survive the restart.
+        if let Ok(line) = client.send_command("args").await {
+            if let Ok(old_args) = serde_json::from_str::<Vec<String>>(line.trim()) {
+                if old_args.iter().any(|a| a == "--daemon") {
+                    args = old_args;
+                }
+            }
+        }
+
+        if client.send_command("shutdown").await.is_err() {
+            // Socket gone or unresponsive: fall back to signalling the PID.
+            #[cfg(unix)]
+            if let Some(pid) = old_pid {
+                super::pid_file::terminate_process(pid);
+            }
+        }
+
+        let stopped = wait_until(RESTART_TIMEOUT, || async {
+            !client.is_daemon_running().await && running_daemon_pid().is_none()
+        })
+        .await;
+        if !stopped {
+            let who = old_pid.map_or_else(String::new, |p| format!(" (PID: {p})"));
+            return Err(format!(
+                "Daemon{who} did not exit within {}s; stop it manually and retry",
+                RESTART_TIMEOUT.as_secs()
+            ));
+        }
+        presenter.info("Stopped running daemon");
+    } else {
+        presenter.info("No daemon running; starting one");
+    }
+
+    let exe = std::env::current_exe()
+        .map_err(|e| format!("Cannot locate smart-scribe executable: {}", e))?;
+    let mut command = Command::new(exe);
+    command
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    // Detach from our process group so the daemon outlives this terminal.
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(&mut command, 0);
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to start daemon: {}", e))?;
+
+    let mut exited = None;
+    let started = wait_until(RESTART_TIMEOUT, || {
+        if let Ok(Some(status)) = child.try_wait() {
+            exited = Some(status);
+        }
+        let exited = exited.is_some();
+        async move { exited || client.is_daemon_running().await }
+    })
+    .await;
+    if let Some(status) = exited {
+        return Err(format!(
+            "New daemon exited immediately ({status}); run `smart-scribe {}` to see why",
+            args.join(" ")
+        ));
+    }
+    if !started {
+        return Err(format!(
+            "New daemon did not open its control socket within {}s",
+            RESTART_TIMEOUT.as_secs()
+        ));
+    }
+
+    if presenter.is_json() {
+        presenter.output_json(&DaemonCommandAck {
+            ok: true,
+            command: "restart",
+            accepted: true,
+            reason: None,
+        });
+    } else {
+        presenter.success(&format!("Daemon restarted (PID: {})", child.id()));
+    }
+    Ok(())
+}
+
+/// Where the daemon on `endpoint` logs: the `--log-file` it was started
+/// with (asked for over IPC, like `daemon restart` does), else the default.
+/// A stopped daemon, or one on another host, gets the default.
+pub async fn daemon_log_path(endpoint: &IpcEndpoint, auth_token: Option<String>) -> PathBuf {
+    if matches!(endpoint, IpcEndpoint::Local) {
+        let client = create_ipc_client(endpoint, auth_token);
+        if let Ok(line) = client.send_command("args").await {
+            if let Ok(args) = serde_json::from_str::<Vec<String>>(line.trim()) {
+                if let Some(path) = log_file_arg(&args) {
+                    return path;
+                }
+            }
+        }
+    }
+    default_daemon_log_path()
+}
+
+/// The `--log-file` value in a daemon's arguments, in either spelling.
+fn log_file_arg(args: &[String]) -> Option<PathBuf> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--log-file" {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg.strip_prefix("--log-file=") {
+            return Some(PathBuf::from(path));
+        }
+    }
+    None
+}
+
+/// Handle `daemon logs`: print the last `lines` lines of the log at `path`
+/// and, with `follow`, keep printing new ones. The log is read directly, so
+/// no IPC connection is needed. In JSON mode each line is one
+/// `{"path", "line"}` object.
+pub async fn show_daemon_logs(
+    path: &Path,
+    lines: usize,
+    follow: bool,
+    presenter: &Presenter,
+) -> Result<(), String> {
+    let print = |line: &str| {
+        if presenter.is_json() {
+            presenter.output_json(&serde_json::json!({ "path": path, "line": line }));
+        } else {
+            presenter.output(line);
+        }
+    };
+
+    let content = match tokio::fs::read(path).await {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Err(format!(
+                "No daemon log at {}; start one with: smart-scribe --daemon",
+                path.display()
+            ))
+        }
+        Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+    };
+    for line in last_lines(&String::from_utf8_lossy(&content), lines) {
+        print(line);
+    }
+    if !follow {
+        return Ok(());
+    }
+
+    let mut position = content.len() as u64;
+    let mut partial = String::new();
+    loop {
+        tokio::time::sleep(LOG_FOLLOW_INTERVAL).await;
+        let Ok(mut file) = tokio::fs::File::open(path).await else {
+            // Mid-rotation; the new file appears on a later poll.
+            continue;
+        };
+        let len = file.metadata().await.map(|m| m.len()).unwrap_or(0);
+        if len < position {
+            // Rotated: the rest of the old file is in `<log>.1`; carry on
+            // from the start of the new one.
+            position = 0;
+            partial.clear();
+        }
+        if len == position {
+            continue;
+        }
+        let mut chunk = Vec::new();
+        file.seek(SeekFrom::Start(position))
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        file.read_to_end(&mut chunk)
+            .await
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        position += chunk.len() as u64;
+
+        partial.push_str(&String::from_utf8_lossy(&chunk));
+        while let Some(end) = partial.find('\n') {
+            print(partial[..end].trim_end_matches('\r'));
+            partial.drain(..=end);
+        }
+    }
+}
+
+/// The last `n` lines of `content`, oldest first.
+fn last_lines(content: &str, n: usize) -> Vec<&str> {
+    let all: Vec<&str> = content.lines().collect();
+    all[all.len().saturating_sub(n)..].to_vec()
+}
+
+/// Poll `done` every [`RESTART_POLL_INTERVAL`] until it holds or `timeout`
+/// elapses. Returns whether it held.
+async fn wait_until<F, Fut>(timeout: Duration, mut done: F) -> bool
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let deadline = Instant::now() + timeout;
+    loop {
+        if done().await {
+            return true;
+        }
+        if Instant::now() >= deadline {
+            return false;
+        }
+        tokio::time::sleep(RESTART_POLL_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn wait_until_polls_until_condition_holds() {
+        let mut calls = 0;
+        assert!(
+            wait_until(Duration::from_secs(5), || {
+                calls += 1;
+                let hit = calls == 3;
+                async move { hit }
+            })
+            .await
+        );
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn log_file_arg_reads_both_spellings() {
+        let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            log_file_arg(&args(&["--daemon", "--log-file", "/tmp/d.log", "-c"])),
+            Some(PathBuf::from("/tmp/d.log"))
+        );
+        assert_eq!(
+            log_file_arg(&args(&["--log-file=/tmp/d.log", "--daemon"])),
+            Some(PathBuf::from("/tmp/d.log"))
+        );
+        assert_eq!(log_file_arg(&args(&["--daemon", "-c"])), None);
+    }
+
+    #[test]
+    fn last_lines_returns_the_tail_in_order() {
+        let log = "one\ntwo\nthree\n";
+        assert_eq!(last_lines(log, 2), vec!["two", "three"]);
+        assert_eq!(last_lines(log, 10), vec!["one", "two", "three"]);
+        assert!(last_lines(log, 0).is_empty());
+        assert!(last_lines("", 5).is_empty());
+    }
+
+    #[tokio::test]
+    async fn wait_until_times_out() {
+        let start = Instant::now();
+        assert!(!wait_until(Duration::from_millis(250), || async { false }).await);
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+}
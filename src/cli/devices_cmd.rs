@@ -0,0 +1,137 @@
+//! `devices` subcommand handlers.
+
+use std::process::ExitCode;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use crate::application::ports::{AudioCueError, AudioRecorder};
+use crate::domain::config::{AppConfig, DEFAULT_SILENCE_THRESHOLD_DB};
+use crate::infrastructure::{create_recorder, play_audio_bytes};
+
+use super::args::DevicesAction;
+use super::exit_codes;
+use super::presenter::Presenter;
+
+/// Handle a `devices <action>` invocation.
+pub async fn run_devices_command(
+    action: DevicesAction,
+    config: &AppConfig,
+    presenter: &Presenter,
+) -> ExitCode {
+    let DevicesAction::Test { duration } = action;
+    let device = config.audio_device.as_deref();
+
+    presenter.info(&format!(
+        "Recording {duration} from {}; speak now",
+        device.unwrap_or("the default input device")
+    ));
+    let recorder = create_recorder(
+        config.audio_device.clone(),
+        config.sample_rate,
+        config.sample_format,
+    );
+    let audio = match recorder
+        .record(duration, None, Arc::new(AtomicBool::new(false)))
+        .await
+    {
+        Ok(audio) => audio,
+        Err(e) => {
+            presenter.error(&e.to_string());
+            return ExitCode::from(exit_codes::RECORDING);
+        }
+    };
+
+    let peak = audio.peak_dbfs().unwrap_or(f32::NEG_INFINITY);
+    let duration_ms = audio.duration_ms().unwrap_or(duration.as_millis());
+    // Judged against the configured threshold even with `silence_check`
+    // off: that is exactly the level a real recording would be held to.
+    let threshold = config
+        .silence_threshold
+        .unwrap_or(DEFAULT_SILENCE_THRESHOLD_DB);
+    let silent = audio.is_silent(threshold);
+    let clipped_ratio = audio.clipped_ratio().unwrap_or(0.0);
+    let clipping = audio.is_clipping();
+
+    if !presenter.is_json() {
+        presenter.output(&format!(
+            "Captured {:.1}s, peak {}",
+            duration_ms as f64 / 1000.0,
+            format_peak(peak)
+        ));
+        if silent {
+            presenter.warn(&format!(
+                "No signal above {threshold} dBFS; check the input device and its volume"
+            ));
+        }
+        if clipping {
+            presenter.warn(&format!(
+                "Input is clipping ({:.1}% of samples at full scale); lower the input gain",
+                clipped_ratio * 100.0
+            ));
+        }
+        presenter.info("Playing back");
+    }
+
+    let bytes = audio.into_data();
+    let playback = tokio::task::spawn_blocking(move || play_audio_bytes(bytes))
+        .await
+        .unwrap_or_else(|e| Err(AudioCueError::PlaybackFailed(e.to_string())));
+    let playback_error = playback.as_ref().err().map(playback_message);
+
+    if presenter.is_json() {
+        presenter.output_json(&serde_json::json!({
+            "ok": true,
+            "action": "devices_test",
+            "device": device,
+            "duration_ms": duration_ms,
+            // Digital silence has no finite level; reported as null.
+            "peak_dbfs": peak.is_finite().then_some(peak),
+            "silent": silent,
+            "clipped_ratio": clipped_ratio,
+            "clipping": clipping,
+            "played": playback.is_ok(),
+            "playback_error": playback_error,
+        }));
+    } else if let Some(message) = playback_error {
+        // Capture is what this command checks; a missing speaker is only
+        // worth a warning.
+        presenter.warn(&message);
+    }
+    ExitCode::from(exit_codes::SUCCESS)
+}
+
+/// `-23.4 dBFS`, or `silence` for a clip of all-zero samples.
+fn format_peak(peak: f32) -> String {
+    if peak.is_finite() {
+        format!("{peak:.1} dBFS")
+    } else {
+        "silence (no signal at all)".to_string()
+    }
+}
+
+fn playback_message(error: &AudioCueError) -> String {
+    match error {
+        AudioCueError::DeviceNotAvailable(e) => {
+            format!("No output device available for playback ({e}); the level above is still valid")
+        }
+        AudioCueError::PlaybackFailed(e) => format!("Playback failed: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peak_is_formatted_in_dbfs_or_as_silence() {
+        assert_eq!(format_peak(-23.44), "-23.4 dBFS");
+        assert_eq!(format_peak(f32::NEG_INFINITY), "silence (no signal at all)");
+    }
+
+    #[test]
+    fn missing_output_device_is_reported_plainly() {
+        let message = playback_message(&AudioCueError::DeviceNotAvailable("no sink".into()));
+        assert!(message.starts_with("No output device available for playback"));
+        assert!(message.contains("no sink"));
+    }
+}
@@ -3,12 +3,183 @@
 //! Centralised so the CLI surface stays consistent and so we don't redeclare
 //! the same constants across `app.rs`, `auth_cmd.rs`, etc.
 
+use std::process::ExitCode;
+
+use crate::application::ports::TranscriptionError;
+use crate::application::{DaemonError, TranscribeError};
+
 /// Process completed successfully.
 pub const SUCCESS: u8 = 0;
 
-/// Recoverable runtime error (transcription failed, network down, etc.).
+/// Recoverable runtime error that fits no more specific code below.
 pub const ERROR: u8 = 1;
 
 /// Invalid arguments / configuration. Matches the conventional `2` used by
 /// `clap` for argument errors.
 pub const USAGE_ERROR: u8 = 2;
+
+/// No usable credentials: missing or rejected API key, or not logged in.
+pub const AUTH: u8 = 3;
+
+/// Audio capture failed: no input device, stream error, or silent input.
+pub const RECORDING: u8 = 4;
+
+/// The transcription request failed: network, rate limit, API error.
+pub const TRANSCRIPTION: u8 = 5;
+
+/// A one-shot run exceeded `--timeout`. Matches `timeout(1)`.
+pub const TIMEOUT: u8 = 124;
+
+/// Every code above, for mapping an [`ExitCode`] back to its number.
+const ALL: [u8; 7] = [
+    SUCCESS,
+    ERROR,
+    USAGE_ERROR,
+    AUTH,
+    RECORDING,
+    TRANSCRIPTION,
+    TIMEOUT,
+];
+
+/// The number behind `code`; codes not built from the constants above
+/// count as [`ERROR`].
+pub fn code_of(code: ExitCode) -> u8 {
+    ALL.into_iter()
+        .find(|&c| ExitCode::from(c) == code)
+        .unwrap_or(ERROR)
+}
+
+/// `error_kind` reported by `--json-errors` for an error that carries no
+/// more specific kind.
+pub const fn kind_of(code: u8) -> &'static str {
+    match code {
+        USAGE_ERROR => "usage",
+        AUTH => "auth",
+        RECORDING => "recording",
+        TRANSCRIPTION => "transcription",
+        TIMEOUT => "timeout",
+        _ => "error",
+    }
+}
+
+/// `error_kind` reported by `--json-errors` for a failed one-shot
+/// recording or transcription.
+pub const fn transcribe_error_kind(error: &TranscribeError) -> &'static str {
+    match error {
+        TranscribeError::Recording(_) => "recording",
+        TranscribeError::NoAudio { .. } => "no_audio",
+        TranscribeError::TooShort { .. } => "too_short",
+        TranscribeError::Transcription(e) => transcription_error_kind(e),
+    }
+}
+
+/// `error_kind` for a failed daemon recording or transcription; shares
+/// the one-shot kinds where the failure is the same.
+pub const fn daemon_error_kind(error: &DaemonError) -> &'static str {
+    match error {
+        DaemonError::Recording(_) => "recording",
+        DaemonError::NoAudio { .. } => "no_audio",
+        DaemonError::TooShort { .. } => "too_short",
+        DaemonError::Transcription(e) => transcription_error_kind(e),
+        DaemonError::InvalidState(_) => "invalid_state",
+        DaemonError::CoolingDown { .. } => "cooling_down",
+    }
+}
+
+const fn transcription_error_kind(error: &TranscriptionError) -> &'static str {
+    match error {
+        TranscriptionError::InvalidApiKey => "invalid_api_key",
+        TranscriptionError::NotAuthenticated => "not_authenticated",
+        TranscriptionError::RateLimited => "rate_limited",
+        TranscriptionError::EmptyResponse => "empty_response",
+        TranscriptionError::SafetyBlocked { .. } => "safety_blocked",
+        TranscriptionError::RequestFailed(_) => "request_failed",
+        TranscriptionError::ParseError(_) => "parse_error",
+        TranscriptionError::ApiError { .. } => "api_error",
+    }
+}
+
+/// Exit code for a failed one-shot recording or transcription.
+pub fn for_transcribe_error(error: &TranscribeError) -> u8 {
+    match error {
+        TranscribeError::Recording(_) | TranscribeError::NoAudio { .. } => RECORDING,
+        TranscribeError::Transcription(
+            TranscriptionError::InvalidApiKey | TranscriptionError::NotAuthenticated,
+        ) => AUTH,
+        TranscribeError::Transcription(_) => TRANSCRIPTION,
+        // Nothing failed; the clip was just discarded.
+        TranscribeError::TooShort { .. } => ERROR,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::ports::RecordingError;
+    use crate::domain::recording::Duration;
+
+    #[test]
+    fn transcribe_errors_map_to_categories() {
+        let code = |e| for_transcribe_error(&e);
+        assert_eq!(code(RecordingError::NoAudioDevice.into()), RECORDING);
+        assert_eq!(
+            code(TranscribeError::NoAudio {
+                peak_dbfs: f32::NEG_INFINITY
+            }),
+            RECORDING
+        );
+        assert_eq!(code(TranscriptionError::NotAuthenticated.into()), AUTH);
+        assert_eq!(code(TranscriptionError::InvalidApiKey.into()), AUTH);
+        assert_eq!(code(TranscriptionError::RateLimited.into()), TRANSCRIPTION);
+        assert_eq!(
+            code(TranscriptionError::RequestFailed("timeout".into()).into()),
+            TRANSCRIPTION
+        );
+        assert_eq!(
+            code(TranscribeError::TooShort {
+                elapsed: Duration::from_millis(100),
+                min: Duration::from_secs(1),
+            }),
+            ERROR
+        );
+    }
+
+    #[test]
+    fn exit_codes_round_trip_and_name_a_kind() {
+        for code in ALL {
+            assert_eq!(code_of(ExitCode::from(code)), code);
+        }
+        assert_eq!(code_of(ExitCode::from(42)), ERROR);
+        assert_eq!(kind_of(AUTH), "auth");
+        assert_eq!(kind_of(ERROR), "error");
+    }
+
+    #[test]
+    fn transcribe_errors_name_their_variant() {
+        let kind = |e| transcribe_error_kind(&e);
+        assert_eq!(kind(RecordingError::NoAudioDevice.into()), "recording");
+        assert_eq!(kind(TranscriptionError::RateLimited.into()), "rate_limited");
+        assert_eq!(
+            kind(TranscribeError::NoAudio {
+                peak_dbfs: f32::NEG_INFINITY
+            }),
+            "no_audio"
+        );
+    }
+
+    #[test]
+    fn daemon_errors_name_their_variant() {
+        let kind = |e| daemon_error_kind(&e);
+        assert_eq!(kind(RecordingError::NoAudioDevice.into()), "recording");
+        assert_eq!(
+            kind(TranscriptionError::NotAuthenticated.into()),
+            "not_authenticated"
+        );
+        assert_eq!(
+            kind(DaemonError::CoolingDown {
+                remaining: Duration::from_secs(30)
+            }),
+            "cooling_down"
+        );
+    }
+}
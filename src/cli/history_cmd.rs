@@ -0,0 +1,140 @@
+//! `history` subcommand handlers.
+
+use crate::application::history::unix_now;
+use crate::application::ports::{Clipboard, HistoryStore};
+use crate::domain::history::{format_age, HistoryEntry};
+use crate::infrastructure::{create_clipboard, JsonlHistoryStore};
+
+use super::args::HistoryAction;
+use super::presenter::Presenter;
+
+/// Characters of each entry shown by `history list`.
+const LIST_PREVIEW_CHARS: usize = 70;
+
+/// Handle a `history <action>` invocation.
+pub async fn handle_history_command(
+    action: HistoryAction,
+    presenter: &Presenter,
+) -> Result<(), String> {
+    let store = JsonlHistoryStore::default();
+
+    match action {
+        HistoryAction::List { limit } => {
+            let entries = recent(&store, limit).await?;
+            if presenter.is_json() {
+                presenter.output_json(&serde_json::json!({
+                    "ok": true,
+                    "action": "history_list",
+                    "entries": entries,
+                }));
+            } else if entries.is_empty() {
+                presenter.info("No transcriptions in history yet");
+            } else {
+                let now = unix_now();
+                for (i, entry) in entries.iter().enumerate() {
+                    presenter.output(&format!(
+                        "{:>3}  {:>9}  {}",
+                        i + 1,
+                        format_age(entry.timestamp, now),
+                        preview(&entry.text)
+                    ));
+                }
+            }
+        }
+        HistoryAction::Last => {
+            let entry = recent(&store, 1).await?.into_iter().next();
+            if presenter.is_json() {
+                presenter.output_json(&serde_json::json!({
+                    "ok": true,
+                    "action": "history_last",
+                    "entry": entry,
+                }));
+            } else {
+                match entry {
+                    Some(e) => presenter.output(&e.text),
+                    None => return Err("History is empty".to_string()),
+                }
+            }
+        }
+        HistoryAction::Copy { index } => {
+            let entry = nth(recent(&store, index).await?, index)?;
+            let (clipboard, _tool) = create_clipboard().await;
+            clipboard
+                .copy(&entry.text)
+                .await
+                .map_err(|e| e.to_string())?;
+            if presenter.is_json() {
+                presenter.output_json(&serde_json::json!({
+                    "ok": true,
+                    "action": "history_copy",
+                    "index": index,
+                }));
+            } else {
+                presenter.success(&format!("Copied entry {index}: {}", preview(&entry.text)));
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn recent(store: &JsonlHistoryStore, limit: usize) -> Result<Vec<HistoryEntry>, String> {
+    store
+        .recent(limit)
+        .await
+        .map_err(|e| format!("Failed to read {}: {e}", store.path().display()))
+}
+
+/// Pick the 1-based `index` from newest-first `entries`.
+fn nth(entries: Vec<HistoryEntry>, index: usize) -> Result<HistoryEntry, String> {
+    let len = entries.len();
+    index
+        .checked_sub(1)
+        .and_then(|i| entries.into_iter().nth(i))
+        .ok_or_else(|| match len {
+            0 => "History is empty".to_string(),
+            n => format!("No history entry {index} (have {n}; 1 is the most recent)"),
+        })
+}
+
+/// First [`LIST_PREVIEW_CHARS`] characters of `text` on one line.
+fn preview(text: &str) -> String {
+    let flat: String = text
+        .chars()
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .collect();
+    if flat.chars().count() <= LIST_PREVIEW_CHARS {
+        return flat;
+    }
+    let head: String = flat.chars().take(LIST_PREVIEW_CHARS).collect();
+    format!("{}…", head.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(n: u64) -> Vec<HistoryEntry> {
+        (1..=n)
+            .rev()
+            .map(|i| HistoryEntry::new(i, "m", format!("t{i}"), 0))
+            .collect()
+    }
+
+    #[test]
+    fn nth_is_one_based_newest_first() {
+        assert_eq!(nth(entries(3), 1).unwrap().text, "t3");
+        assert_eq!(nth(entries(3), 3).unwrap().text, "t1");
+        assert!(nth(entries(3), 0).is_err());
+        assert!(nth(entries(3), 4).unwrap_err().contains("have 3"));
+        assert_eq!(nth(Vec::new(), 1).unwrap_err(), "History is empty");
+    }
+
+    #[test]
+    fn preview_flattens_and_truncates() {
+        assert_eq!(preview("a\nb"), "a b");
+        let long = "word ".repeat(30);
+        let p = preview(&long);
+        assert!(p.ends_with('…'));
+        assert!(p.chars().count() <= LIST_PREVIEW_CHARS + 1);
+    }
+}
@@ -3,22 +3,31 @@
 //! Provides platform-specific implementations:
 //! - Unix (Linux/macOS): Unix Domain Sockets
 //! - Windows: Named Pipes
+//!
+//! plus a TCP transport (`--ipc tcp:HOST:PORT`) for controlling a daemon on
+//! another machine. All transports speak the same line protocol.
 
 #[cfg(windows)]
 mod named_pipe;
+mod protocol;
+mod tcp;
 #[cfg(unix)]
 mod unix_socket;
 
 #[cfg(windows)]
 pub use named_pipe::{NamedPipeClient, NamedPipeServer, PipePath};
+pub use protocol::AckReply;
+pub use tcp::{TcpSocketClient, TcpSocketServer};
 #[cfg(unix)]
 pub use unix_socket::{SocketPath, UnixSocketClient, UnixSocketServer};
 
+use std::fmt;
 use std::io;
+use std::str::FromStr;
 use tokio::io::AsyncBufRead;
 use tokio::sync::{broadcast, mpsc};
 
-use super::output::DaemonEvent;
+use super::output::{DaemonConfigPayload, DaemonEvent, DaemonLastPayload};
 use super::signals::DaemonSignal;
 use crate::domain::daemon::DaemonState;
 
@@ -28,6 +37,55 @@ pub type StateFn = Box<dyn Fn() -> DaemonState + Send + Sync>;
 /// Elapsed time function type for IPC servers
 pub type ElapsedFn = Box<dyn Fn() -> u64 + Send + Sync>;
 
+/// Last transcription function type for IPC servers
+///
+/// Must return an owned copy so no lock is held while the reply is written.
+pub type LastTextFn = Box<dyn Fn() -> Option<String> + Send + Sync>;
+
+/// Live daemon settings function type for IPC servers (`get-config`)
+pub type GetConfigFn = Box<dyn Fn() -> DaemonConfigPayload + Send + Sync>;
+
+/// Settings update function type for IPC servers (`set-config <json>`)
+///
+/// Applies a JSON partial update and returns the `key: old -> new` changes,
+/// or why the update was rejected (nothing is applied then).
+pub type SetConfigFn = Box<dyn Fn(&str) -> Result<Vec<String>, String> + Send + Sync>;
+
+/// What IPC servers read from (and write to) the running daemon.
+pub struct IpcHandlers {
+    pub state: StateFn,
+    pub elapsed: ElapsedFn,
+    pub last_text: LastTextFn,
+    pub get_config: GetConfigFn,
+    pub set_config: SetConfigFn,
+}
+
+#[cfg(test)]
+impl IpcHandlers {
+    /// Handlers reporting fixed values over a default [`DaemonConfig`];
+    /// `set-config` validates its update but applies nothing.
+    ///
+    /// [`DaemonConfig`]: crate::application::DaemonConfig
+    pub(crate) fn fixed(state: DaemonState, elapsed_ms: u64, last_text: Option<&str>) -> Self {
+        use super::output::DaemonConfigUpdate;
+        use crate::application::DaemonConfig;
+
+        let last_text = last_text.map(str::to_string);
+        Self {
+            state: Box::new(move || state),
+            elapsed: Box::new(move || elapsed_ms),
+            last_text: Box::new(move || last_text.clone()),
+            get_config: Box::new(|| DaemonConfigPayload::from(&DaemonConfig::default())),
+            set_config: Box::new(|json| {
+                let update: DaemonConfigUpdate =
+                    serde_json::from_str(json).map_err(|e| e.to_string())?;
+                let current = DaemonConfig::default().reloadable();
+                Ok(update.apply(current)?.changes_from(&current))
+            }),
+        }
+    }
+}
+
 /// Trait for IPC servers that listen for daemon commands
 #[async_trait::async_trait]
 pub trait IpcServer: Send + Sync {
@@ -41,12 +99,11 @@ pub trait IpcServer: Send + Sync {
     ///
     /// This runs in a loop, accepting connections and processing commands.
     /// Each command is sent to the provided channel.
-    /// The state_fn is called to get current daemon state for status queries.
+    /// Queries (`status`, `last`, `get-config`) are answered from `handlers`.
     async fn run(
         &self,
         tx: mpsc::Sender<DaemonSignal>,
-        state_fn: StateFn,
-        elapsed_fn: ElapsedFn,
+        handlers: IpcHandlers,
         event_rx: broadcast::Receiver<DaemonEvent>,
     ) -> io::Result<()>;
 
@@ -57,34 +114,176 @@ pub trait IpcServer: Send + Sync {
 /// Trait for IPC clients that send commands to the daemon
 #[async_trait::async_trait]
 pub trait IpcClient: Send + Sync {
-    /// Check if daemon appears to be running (endpoint exists)
-    fn is_daemon_running(&self) -> bool;
+    /// Check if a daemon is listening on the endpoint
+    async fn is_daemon_running(&self) -> bool;
 
     /// Send a command and receive response
     async fn send_command(&self, cmd: &str) -> io::Result<String>;
 
     /// Subscribe to streaming daemon events
     async fn subscribe(&self) -> io::Result<Box<dyn AsyncBufRead + Unpin + Send>>;
+
+    /// Fetch the daemon's most recent transcription (`None` if there is none yet)
+    async fn last_transcription(&self) -> io::Result<Option<String>> {
+        let response = self.send_command("last").await?;
+        serde_json::from_str::<DaemonLastPayload>(response.trim())
+            .map(|payload| payload.text)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Start recording if the daemon is idle
+    async fn start_recording(&self) -> io::Result<AckReply> {
+        protocol::parse_ack(&self.send_command("start").await?)
+    }
+
+    /// Stop and transcribe if the daemon is recording
+    async fn stop_recording(&self) -> io::Result<AckReply> {
+        protocol::parse_ack(&self.send_command("stop").await?)
+    }
+
+    /// Fetch the running daemon's version, which may differ from this binary's
+    async fn daemon_version(&self) -> io::Result<String> {
+        let response = self.send_command("version").await?;
+        let version = response.trim();
+        if version.starts_with("error:") {
+            // Daemons from before the `version` command reply "unknown command".
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "daemon is too old to report its version; restart it",
+            ));
+        }
+        Ok(version.to_string())
+    }
+}
+
+/// Reply to the `args` command: the daemon's command-line arguments (without
+/// the program name) as a JSON array line, so `daemon restart` can relaunch
+/// it with the same flags.
+pub(crate) fn daemon_args_line() -> String {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    format!("{}\n", serde_json::Value::from(args))
+}
+
+/// Reply to the `version` command: the daemon's crate version.
+pub(crate) fn daemon_version_line() -> String {
+    format!("{}\n", env!("CARGO_PKG_VERSION"))
+}
+
+/// Where the daemon listens for control commands (`--ipc`)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum IpcEndpoint {
+    /// Platform default: Unix socket or named pipe
+    #[default]
+    Local,
+    /// TCP `host:port`
+    Tcp(String),
+}
+
+impl FromStr for IpcEndpoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "local" {
+            return Ok(Self::Local);
+        }
+        let Some(addr) = s.strip_prefix("tcp:") else {
+            return Err(format!(
+                "Invalid IPC endpoint '{s}': expected 'local' or 'tcp:HOST:PORT'"
+            ));
+        };
+        match addr.rsplit_once(':') {
+            Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok() => {
+                Ok(Self::Tcp(addr.to_string()))
+            }
+            _ => Err(format!(
+                "Invalid IPC endpoint '{s}': expected 'tcp:HOST:PORT' (e.g. tcp:127.0.0.1:7331)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for IpcEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local => write!(f, "local"),
+            Self::Tcp(addr) => write!(f, "tcp:{addr}"),
+        }
+    }
+}
+
+/// Create the IPC server for `endpoint`, requiring `auth_token` if set
+pub fn create_ipc_server(endpoint: &IpcEndpoint, auth_token: Option<String>) -> Box<dyn IpcServer> {
+    match endpoint {
+        IpcEndpoint::Local => create_local_server(auth_token),
+        IpcEndpoint::Tcp(addr) => {
+            Box::new(TcpSocketServer::new(addr.clone()).with_auth_token(auth_token))
+        }
+    }
+}
+
+/// Create the IPC client for `endpoint`, authenticating with `auth_token` if set
+pub fn create_ipc_client(endpoint: &IpcEndpoint, auth_token: Option<String>) -> Box<dyn IpcClient> {
+    match endpoint {
+        IpcEndpoint::Local => create_local_client(auth_token),
+        IpcEndpoint::Tcp(addr) => {
+            Box::new(TcpSocketClient::new(addr.clone()).with_auth_token(auth_token))
+        }
+    }
 }
 
-/// Create the appropriate IPC server for the current platform
 #[cfg(unix)]
-pub fn create_ipc_server() -> Box<dyn IpcServer> {
-    Box::new(UnixSocketServer::new(SocketPath::new()))
+fn create_local_server(auth_token: Option<String>) -> Box<dyn IpcServer> {
+    Box::new(UnixSocketServer::new(SocketPath::new()).with_auth_token(auth_token))
 }
 
 #[cfg(windows)]
-pub fn create_ipc_server() -> Box<dyn IpcServer> {
-    Box::new(NamedPipeServer::new(PipePath::new()))
+fn create_local_server(auth_token: Option<String>) -> Box<dyn IpcServer> {
+    Box::new(NamedPipeServer::new(PipePath::new()).with_auth_token(auth_token))
 }
 
-/// Create the appropriate IPC client for the current platform
 #[cfg(unix)]
-pub fn create_ipc_client() -> Box<dyn IpcClient> {
-    Box::new(UnixSocketClient::new(SocketPath::new()))
+fn create_local_client(auth_token: Option<String>) -> Box<dyn IpcClient> {
+    Box::new(UnixSocketClient::new(SocketPath::new()).with_auth_token(auth_token))
 }
 
 #[cfg(windows)]
-pub fn create_ipc_client() -> Box<dyn IpcClient> {
-    Box::new(NamedPipeClient::new(PipePath::new()))
+fn create_local_client(auth_token: Option<String>) -> Box<dyn IpcClient> {
+    Box::new(NamedPipeClient::new(PipePath::new()).with_auth_token(auth_token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_parses_local_and_tcp() {
+        assert_eq!("local".parse::<IpcEndpoint>().unwrap(), IpcEndpoint::Local);
+        assert_eq!(
+            "tcp:127.0.0.1:7331".parse::<IpcEndpoint>().unwrap(),
+            IpcEndpoint::Tcp("127.0.0.1:7331".into())
+        );
+        assert_eq!(
+            "tcp:[::1]:7331".parse::<IpcEndpoint>().unwrap(),
+            IpcEndpoint::Tcp("[::1]:7331".into())
+        );
+        assert_eq!(
+            IpcEndpoint::Tcp("box.lan:7331".into()).to_string(),
+            "tcp:box.lan:7331"
+        );
+    }
+
+    #[test]
+    fn endpoint_rejects_malformed_values() {
+        for bad in [
+            "",
+            "unix",
+            "tcp:",
+            "tcp:7331",
+            "tcp:host",
+            "tcp::7331",
+            "tcp:host:99999",
+        ] {
+            assert!(bad.parse::<IpcEndpoint>().is_err(), "{bad}");
+        }
+    }
 }
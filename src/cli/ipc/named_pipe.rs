@@ -8,10 +8,10 @@ use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::windows::named_pipe::{ClientOptions, ServerOptions};
 use tokio::sync::{broadcast, mpsc};
 
-use super::{ElapsedFn, IpcClient, IpcServer, StateFn};
-use crate::cli::output::{DaemonEvent, DaemonStatusPayload};
+use super::protocol::{check_reply, handle_connection, request};
+use super::{IpcClient, IpcHandlers, IpcServer};
+use crate::cli::output::DaemonEvent;
 use crate::cli::signals::DaemonSignal;
-use crate::domain::daemon::DaemonState;
 
 /// Named pipe path
 const PIPE_NAME: &str = r"\\.\pipe\smart-scribe";
@@ -52,6 +52,7 @@ impl Default for PipePath {
 pub struct NamedPipeServer {
     pipe_path: PipePath,
     bound: bool,
+    auth_token: Option<Arc<str>>,
 }
 
 impl NamedPipeServer {
@@ -60,8 +61,15 @@ impl NamedPipeServer {
         Self {
             pipe_path,
             bound: false,
+            auth_token: None,
         }
     }
+
+    /// Builder: require clients to authenticate with `token`
+    pub fn with_auth_token(mut self, token: Option<String>) -> Self {
+        self.auth_token = token.map(Arc::from);
+        self
+    }
 }
 
 #[async_trait]
@@ -79,8 +87,7 @@ impl IpcServer for NamedPipeServer {
     async fn run(
         &self,
         tx: mpsc::Sender<DaemonSignal>,
-        state_fn: StateFn,
-        elapsed_fn: ElapsedFn,
+        handlers: IpcHandlers,
         event_rx: broadcast::Receiver<DaemonEvent>,
     ) -> io::Result<()> {
         if !self.bound {
@@ -90,9 +97,8 @@ impl IpcServer for NamedPipeServer {
             ));
         }
 
-        // Wrap functions in Arc for sharing across connections
-        let state_fn = Arc::new(state_fn);
-        let elapsed_fn = Arc::new(elapsed_fn);
+        // Wrap handlers in Arc for sharing across connections
+        let handlers = Arc::new(handlers);
 
         // Create the first pipe instance before entering the loop so that
         // a client can connect at any time.
@@ -114,17 +120,17 @@ impl IpcServer for NamedPipeServer {
                 .create(&self.pipe_path.path)?;
 
             let tx = tx.clone();
-            let state_fn = Arc::clone(&state_fn);
-            let elapsed_fn = Arc::clone(&elapsed_fn);
+            let handlers = Arc::clone(&handlers);
+            let auth_token = self.auth_token.clone();
             let event_rx = event_rx.resubscribe();
 
             tokio::spawn(async move {
                 if let Err(e) =
-                    handle_connection(connected, tx, state_fn, elapsed_fn, event_rx).await
+                    handle_connection(connected, tx, handlers, auth_token, event_rx).await
                 {
                     // Don't log BrokenPipe errors - they're expected when clients disconnect
                     if e.kind() != io::ErrorKind::BrokenPipe {
-                        eprintln!("Pipe connection error: {}", e);
+                        tracing::warn!("Pipe connection error: {}", e);
                     }
                 }
             });
@@ -136,120 +142,32 @@ impl IpcServer for NamedPipeServer {
     }
 }
 
-/// Handle a single client connection
-async fn handle_connection<T>(
-    pipe: T,
-    tx: mpsc::Sender<DaemonSignal>,
-    state_fn: Arc<StateFn>,
-    elapsed_fn: Arc<ElapsedFn>,
-    mut event_rx: broadcast::Receiver<DaemonEvent>,
-) -> io::Result<()>
-where
-    T: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
-{
-    let (reader, mut writer) = tokio::io::split(pipe);
-    let mut reader = BufReader::new(reader);
-    let mut line = String::new();
-
-    // Read command
-    reader.read_line(&mut line).await?;
-    let cmd = line.trim();
-
-    // Process command
-    match cmd {
-        "toggle" => {
-            let _ = tx.send(DaemonSignal::Toggle).await;
-            writer.write_all(b"ok\n").await?;
-            writer.flush().await?;
-            writer.shutdown().await?;
-        }
-        "cancel" => {
-            let _ = tx.send(DaemonSignal::Cancel).await;
-            writer.write_all(b"ok\n").await?;
-            writer.flush().await?;
-            writer.shutdown().await?;
-        }
-        "status" => {
-            let current_state = state_fn();
-            let response = match current_state {
-                DaemonState::Idle => "idle\n",
-                DaemonState::Recording => "recording\n",
-                DaemonState::Processing => "processing\n",
-            };
-            writer.write_all(response.as_bytes()).await?;
-            writer.flush().await?;
-            writer.shutdown().await?;
-        }
-        "status-json" => {
-            let payload = DaemonStatusPayload {
-                state: state_fn(),
-                elapsed_ms: elapsed_fn(),
-            };
-            writer.write_all(payload.to_json_line().as_bytes()).await?;
-            writer.flush().await?;
-            writer.shutdown().await?;
-        }
-        "subscribe" => {
-            // Send initial state
-            let initial = DaemonEvent::state(state_fn(), elapsed_fn());
-            writer.write_all(initial.to_json_line().as_bytes()).await?;
-            writer.flush().await?;
-
-            // Stream events until client disconnects
-            loop {
-                match event_rx.recv().await {
-                    Ok(event) => {
-                        if let Err(e) = writer.write_all(event.to_json_line().as_bytes()).await {
-                            if e.kind() == io::ErrorKind::BrokenPipe {
-                                break;
-                            }
-                            return Err(e);
-                        }
-                        if let Err(e) = writer.flush().await {
-                            if e.kind() == io::ErrorKind::BrokenPipe {
-                                break;
-                            }
-                            return Err(e);
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Closed) => break,
-                    Err(broadcast::error::RecvError::Lagged(_)) => {
-                        let current = DaemonEvent::state(state_fn(), elapsed_fn());
-                        if let Err(e) = writer.write_all(current.to_json_line().as_bytes()).await {
-                            if e.kind() == io::ErrorKind::BrokenPipe {
-                                break;
-                            }
-                            return Err(e);
-                        }
-                    }
-                }
-            }
-        }
-        _ => {
-            writer.write_all(b"error: unknown command\n").await?;
-            writer.flush().await?;
-            writer.shutdown().await?;
-        }
-    }
-
-    Ok(())
-}
-
 /// Named Pipe client for sending commands to daemon
 pub struct NamedPipeClient {
     pipe_path: PipePath,
+    auth_token: Option<String>,
 }
 
 impl NamedPipeClient {
     /// Create a new pipe client
     pub fn new(pipe_path: PipePath) -> Self {
-        Self { pipe_path }
+        Self {
+            pipe_path,
+            auth_token: None,
+        }
+    }
+
+    /// Builder: authenticate every request with `token`
+    pub fn with_auth_token(mut self, token: Option<String>) -> Self {
+        self.auth_token = token;
+        self
     }
 }
 
 #[async_trait]
 impl IpcClient for NamedPipeClient {
-    fn is_daemon_running(&self) -> bool {
+    async fn is_daemon_running(&self) -> bool {
+        // Pipe instances disappear with the server, so existence is liveness.
         self.pipe_path.exists()
     }
 
@@ -276,7 +194,9 @@ impl IpcClient for NamedPipeClient {
         let (reader, mut writer) = tokio::io::split(client);
 
         // Send command
-        writer.write_all(format!("{}\n", cmd).as_bytes()).await?;
+        writer
+            .write_all(request(self.auth_token.as_deref(), cmd).as_bytes())
+            .await?;
         writer.flush().await?;
 
         // Read response
@@ -284,7 +204,7 @@ impl IpcClient for NamedPipeClient {
         let mut response = String::new();
         reader.read_line(&mut response).await?;
 
-        Ok(response)
+        check_reply(response)
     }
 
     async fn subscribe(&self) -> io::Result<Box<dyn AsyncBufRead + Unpin + Send>> {
@@ -305,7 +225,9 @@ impl IpcClient for NamedPipeClient {
         };
 
         let mut client = client;
-        client.write_all(b"subscribe\n").await?;
+        client
+            .write_all(request(self.auth_token.as_deref(), "subscribe").as_bytes())
+            .await?;
         client.flush().await?;
         Ok(Box::new(BufReader::new(client)))
     }
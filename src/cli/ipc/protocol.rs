@@ -0,0 +1,504 @@
+//! Line-based control protocol shared by every IPC transport.
+//!
+//! A client writes one command line and reads one reply line (or, for
+//! `subscribe`, an NDJSON stream). The transports only accept connections
+//! and hand the stream to [`handle_connection`].
+//!
+//! When the daemon has an `ipc_token`, every connection must open with an
+//! `auth <token>` line; anything else gets `error: unauthorized`.
+
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::{broadcast, mpsc};
+
+use super::{daemon_args_line, daemon_version_line, IpcHandlers};
+use crate::cli::output::{DaemonEvent, DaemonLastPayload, DaemonStatusPayload};
+use crate::cli::signals::DaemonSignal;
+use crate::domain::daemon::DaemonState;
+
+/// First line a client sends to authenticate.
+const AUTH_PREFIX: &str = "auth ";
+
+/// Reply to a connection that failed authentication.
+const UNAUTHORIZED_REPLY: &str = "error: unauthorized\n";
+
+/// Longest request line accepted; commands and `set-config` payloads are
+/// far shorter.
+const MAX_LINE: u64 = 64 * 1024;
+
+/// How long a client has to send each request line. These lines arrive
+/// before authentication, so an idle peer must not hold the connection.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Read one request line into `line`, capped at [`MAX_LINE`] bytes and
+/// `timeout`. Returns the bytes read (`0` at EOF).
+async fn read_request_line<R>(
+    reader: &mut R,
+    line: &mut String,
+    timeout: Duration,
+) -> io::Result<usize>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let read = tokio::time::timeout(timeout, reader.take(MAX_LINE).read_line(line))
+        .await
+        .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "IPC request timed out"))??;
+    if read as u64 == MAX_LINE && !line.ends_with('\n') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "IPC request line too long",
+        ));
+    }
+    Ok(read)
+}
+
+/// Build the bytes a client writes for `cmd`, authenticating if it has a token.
+pub(super) fn request(auth_token: Option<&str>, cmd: &str) -> String {
+    match auth_token {
+        Some(token) => format!("{AUTH_PREFIX}{token}\n{cmd}\n"),
+        None => format!("{cmd}\n"),
+    }
+}
+
+/// Daemon's answer to an explicit `start` / `stop`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AckReply {
+    /// The command applies to the current state and was forwarded.
+    Accepted,
+    /// Wrong state for the command (e.g. `start` while recording); the
+    /// daemon did nothing. Carries the reason it gave.
+    Ignored(String),
+}
+
+/// Prefix of a reply to a command that did not apply in the current state.
+const NOOP_PREFIX: &str = "noop: ";
+
+/// Parse the reply to `start` / `stop`.
+pub(super) fn parse_ack(reply: &str) -> io::Result<AckReply> {
+    let reply = reply.trim();
+    if reply == "ok" {
+        return Ok(AckReply::Accepted);
+    }
+    if let Some(reason) = reply.strip_prefix(NOOP_PREFIX.trim_end()) {
+        return Ok(AckReply::Ignored(reason.trim().to_string()));
+    }
+    let message = reply.strip_prefix("error:").unwrap_or(reply).trim();
+    Err(io::Error::other(message.to_string()))
+}
+
+/// Which signal an explicit `start` / `stop` turns into in `state`, and the
+/// reply line. `None` means the command does not apply and is answered with
+/// a `noop:` reason instead.
+fn start_stop_signal(cmd: &str, state: DaemonState) -> (Option<DaemonSignal>, String) {
+    let noop = |reason: &str| (None, format!("{NOOP_PREFIX}{reason}\n"));
+    match (cmd, state) {
+        ("start", DaemonState::Idle) => (Some(DaemonSignal::Start), "ok\n".to_string()),
+        ("start", DaemonState::Recording) => noop("already recording"),
+        ("start", DaemonState::Processing) => noop("still processing the last recording"),
+        (_, DaemonState::Recording) => (Some(DaemonSignal::Stop), "ok\n".to_string()),
+        (_, _) => noop("not recording"),
+    }
+}
+
+/// Command that applies a JSON partial update of the daemon's settings.
+const SET_CONFIG: &str = "set-config";
+
+/// Reply to `set-config <update>`: the changes plus the resulting settings
+/// as one JSON line, or `error: <reason>` when nothing was applied.
+fn set_config_reply(handlers: &IpcHandlers, update: &str) -> String {
+    if update.is_empty() {
+        return "error: set-config needs a JSON object, e.g. set-config {\"notify\":true}\n"
+            .to_string();
+    }
+    match (handlers.set_config)(update) {
+        Ok(changes) => {
+            let reply = serde_json::json!({
+                "changes": changes,
+                "config": (handlers.get_config)(),
+            });
+            format!("{reply}\n")
+        }
+        Err(e) => format!("error: {e}\n"),
+    }
+}
+
+/// Turn an `unauthorized` reply into an error the CLI can explain.
+pub(super) fn check_reply(reply: String) -> io::Result<String> {
+    if reply == UNAUTHORIZED_REPLY {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "daemon rejected the IPC token (check ipc_token / SMART_SCRIBE_IPC_TOKEN)",
+        ));
+    }
+    Ok(reply)
+}
+
+/// Compare tokens without leaking how many leading bytes matched. Hashing
+/// first also hides the expected token's length.
+fn token_matches(expected: &str, presented: &str) -> bool {
+    let expected = Sha256::digest(expected.as_bytes());
+    let presented = Sha256::digest(presented.as_bytes());
+    expected
+        .iter()
+        .zip(presented.iter())
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
+
+/// Handle a single client connection
+pub(super) async fn handle_connection<T>(
+    stream: T,
+    tx: mpsc::Sender<DaemonSignal>,
+    handlers: Arc<IpcHandlers>,
+    auth_token: Option<Arc<str>>,
+    mut event_rx: broadcast::Receiver<DaemonEvent>,
+) -> io::Result<()>
+where
+    T: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+
+    // Read command; EOF before a command is a liveness probe, not an error
+    if read_request_line(&mut reader, &mut line, REQUEST_TIMEOUT).await? == 0 {
+        return Ok(());
+    }
+
+    // Authenticate. A token sent to a daemon without one is ignored, so
+    // clients with a stale config keep working.
+    let authorized = match (line.trim().strip_prefix(AUTH_PREFIX), auth_token.as_deref()) {
+        (Some(presented), Some(expected)) => token_matches(expected, presented.trim()),
+        (Some(_), None) | (None, None) => true,
+        (None, Some(_)) => false,
+    };
+    if !authorized {
+        tracing::warn!("IPC connection rejected: missing or invalid auth token");
+        writer.write_all(UNAUTHORIZED_REPLY.as_bytes()).await?;
+        writer.flush().await?;
+        writer.shutdown().await?;
+        return Ok(());
+    }
+    if line.starts_with(AUTH_PREFIX) {
+        line.clear();
+        if read_request_line(&mut reader, &mut line, REQUEST_TIMEOUT).await? == 0 {
+            return Ok(());
+        }
+    }
+
+    let cmd = line.trim();
+    tracing::debug!(command = cmd, "IPC command received");
+
+    // Process command
+    match cmd {
+        "toggle" => {
+            let _ = tx.send(DaemonSignal::Toggle).await;
+            writer.write_all(b"ok\n").await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        "start" | "stop" => {
+            let (signal, reply) = start_stop_signal(cmd, (handlers.state)());
+            if let Some(signal) = signal {
+                let _ = tx.send(signal).await;
+            }
+            writer.write_all(reply.as_bytes()).await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        "cancel" => {
+            let _ = tx.send(DaemonSignal::Cancel).await;
+            writer.write_all(b"ok\n").await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        "shutdown" => {
+            let _ = tx.send(DaemonSignal::Shutdown).await;
+            writer.write_all(b"ok\n").await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        "reload" => {
+            let _ = tx.send(DaemonSignal::Reload).await;
+            writer.write_all(b"ok\n").await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        "args" => {
+            writer.write_all(daemon_args_line().as_bytes()).await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        "version" => {
+            writer.write_all(daemon_version_line().as_bytes()).await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        "status" => {
+            let current_state = (handlers.state)();
+            let response = match current_state {
+                DaemonState::Idle => "idle\n",
+                DaemonState::Recording => "recording\n",
+                DaemonState::Processing => "processing\n",
+            };
+            writer.write_all(response.as_bytes()).await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        // `status-json` is the original spelling, kept for older scripts and
+        // used by `daemon status` so it works against older daemons too.
+        "status --json" | "status-json" => {
+            let payload = DaemonStatusPayload {
+                state: (handlers.state)(),
+                elapsed_ms: (handlers.elapsed)(),
+            };
+            writer.write_all(payload.to_json_line().as_bytes()).await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        "last" => {
+            // The closure hands back an owned copy, so the daemon's lock is
+            // already released before we await on the write.
+            let payload = DaemonLastPayload {
+                text: (handlers.last_text)(),
+            };
+            writer.write_all(payload.to_json_line().as_bytes()).await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        "subscribe" => {
+            // Send initial state
+            let initial = DaemonEvent::state((handlers.state)(), (handlers.elapsed)());
+            writer.write_all(initial.to_json_line().as_bytes()).await?;
+            writer.flush().await?;
+
+            // Stream events until client disconnects
+            loop {
+                match event_rx.recv().await {
+                    Ok(event) => {
+                        if let Err(e) = writer.write_all(event.to_json_line().as_bytes()).await {
+                            if e.kind() == io::ErrorKind::BrokenPipe {
+                                break;
+                            }
+                            return Err(e);
+                        }
+                        if let Err(e) = writer.flush().await {
+                            if e.kind() == io::ErrorKind::BrokenPipe {
+                                break;
+                            }
+                            return Err(e);
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                    Err(broadcast::error::RecvError::Lagged(_)) => {
+                        let current = DaemonEvent::state((handlers.state)(), (handlers.elapsed)());
+                        if let Err(e) = writer.write_all(current.to_json_line().as_bytes()).await {
+                            if e.kind() == io::ErrorKind::BrokenPipe {
+                                break;
+                            }
+                            return Err(e);
+                        }
+                    }
+                }
+            }
+        }
+        "get-config" => {
+            let payload = (handlers.get_config)();
+            writer.write_all(payload.to_json_line().as_bytes()).await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        _ if cmd.split_whitespace().next() == Some(SET_CONFIG) => {
+            let update = cmd[SET_CONFIG.len()..].trim();
+            let reply = set_config_reply(&handlers, update);
+            writer.write_all(reply.as_bytes()).await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+        _ => {
+            writer.write_all(b"error: unknown command\n").await?;
+            writer.flush().await?;
+            writer.shutdown().await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::io::AsyncReadExt;
+
+    /// Run one connection against a server with `token`, sending `input`,
+    /// and return everything the server wrote back.
+    async fn exchange(token: Option<&str>, input: &str) -> (String, Option<DaemonSignal>) {
+        let (mut client, server) = tokio::io::duplex(1024);
+        let (tx, mut rx) = mpsc::channel(1);
+        let (_event_tx, event_rx) = broadcast::channel(1);
+        let handle = tokio::spawn(handle_connection(
+            server,
+            tx,
+            Arc::new(IpcHandlers::fixed(DaemonState::Idle, 0, None)),
+            token.map(Arc::from),
+            event_rx,
+        ));
+
+        client.write_all(input.as_bytes()).await.unwrap();
+        let mut reply = String::new();
+        client.read_to_string(&mut reply).await.unwrap();
+        handle.await.unwrap().unwrap();
+        (reply, rx.try_recv().ok())
+    }
+
+    #[tokio::test]
+    async fn oversized_request_line_is_rejected() {
+        let (mut client, server) = tokio::io::duplex(usize::try_from(MAX_LINE).unwrap() * 2);
+        let (tx, _rx) = mpsc::channel(1);
+        let (_event_tx, event_rx) = broadcast::channel(1);
+        let handle = tokio::spawn(handle_connection(
+            server,
+            tx,
+            Arc::new(IpcHandlers::fixed(DaemonState::Idle, 0, None)),
+            Some(Arc::from("secret")),
+            event_rx,
+        ));
+
+        let junk = "x".repeat(usize::try_from(MAX_LINE).unwrap() + 10);
+        client.write_all(junk.as_bytes()).await.unwrap();
+        let err = handle.await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn silent_client_times_out() {
+        let (_client, server) = tokio::io::duplex(1024);
+        let mut reader = BufReader::new(server);
+        let mut line = String::new();
+        let err = read_request_line(&mut reader, &mut line, Duration::from_millis(50))
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+    }
+
+    #[tokio::test]
+    async fn get_config_reports_live_settings() {
+        let (reply, _) = exchange(None, &request(None, "get-config")).await;
+        let json: serde_json::Value = serde_json::from_str(&reply).unwrap();
+        assert_eq!(json["clipboard"], false);
+        assert!(json["max_duration"].is_string(), "{reply}");
+    }
+
+    #[tokio::test]
+    async fn set_config_reports_changes_and_rejects_bad_updates() {
+        let (reply, _) = exchange(None, &request(None, r#"set-config {"notify":true}"#)).await;
+        let json: serde_json::Value = serde_json::from_str(&reply).unwrap();
+        assert_eq!(json["changes"][0], "notify: false -> true");
+        assert!(json["config"].is_object(), "{reply}");
+
+        let (reply, _) = exchange(None, &request(None, r#"set-config {"paste":true}"#)).await;
+        assert!(reply.starts_with("error: unknown field `paste`"), "{reply}");
+        let (reply, _) = exchange(None, &request(None, "set-config")).await;
+        assert!(
+            reply.starts_with("error: set-config needs a JSON object"),
+            "{reply}"
+        );
+    }
+
+    #[tokio::test]
+    async fn correct_token_is_accepted() {
+        let input = request(Some("s3cret"), "toggle");
+        let (reply, signal) = exchange(Some("s3cret"), &input).await;
+        assert_eq!(reply, "ok\n");
+        assert!(matches!(signal, Some(DaemonSignal::Toggle)));
+    }
+
+    #[test]
+    fn start_and_stop_apply_only_in_their_state() {
+        assert_eq!(
+            start_stop_signal("start", DaemonState::Idle),
+            (Some(DaemonSignal::Start), "ok\n".to_string())
+        );
+        assert_eq!(
+            start_stop_signal("stop", DaemonState::Recording),
+            (Some(DaemonSignal::Stop), "ok\n".to_string())
+        );
+        assert_eq!(
+            start_stop_signal("start", DaemonState::Recording),
+            (None, "noop: already recording\n".to_string())
+        );
+        assert_eq!(
+            start_stop_signal("stop", DaemonState::Idle),
+            (None, "noop: not recording\n".to_string())
+        );
+        assert_eq!(start_stop_signal("stop", DaemonState::Processing).0, None);
+    }
+
+    #[tokio::test]
+    async fn start_is_accepted_and_stop_is_a_noop_while_idle() {
+        // The test server always reports Idle, so only `stop` can no-op here.
+        let (reply, signal) = exchange(None, &request(None, "start")).await;
+        assert_eq!(reply, "ok\n");
+        assert!(matches!(signal, Some(DaemonSignal::Start)));
+
+        let (reply, signal) = exchange(None, &request(None, "stop")).await;
+        assert_eq!(reply, "noop: not recording\n");
+        assert!(signal.is_none());
+    }
+
+    #[test]
+    fn ack_replies_parse() {
+        assert_eq!(parse_ack("ok\n").unwrap(), AckReply::Accepted);
+        assert_eq!(
+            parse_ack("noop: already recording\n").unwrap(),
+            AckReply::Ignored("already recording".to_string())
+        );
+        // Daemons from before `start`/`stop` existed.
+        let err = parse_ack("error: unknown command\n").unwrap_err();
+        assert_eq!(err.to_string(), "unknown command");
+    }
+
+    #[tokio::test]
+    async fn wrong_or_missing_token_is_rejected() {
+        for input in [request(Some("guess"), "toggle"), request(None, "toggle")] {
+            let (reply, signal) = exchange(Some("s3cret"), &input).await;
+            assert_eq!(reply, UNAUTHORIZED_REPLY);
+            assert!(signal.is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn token_is_optional_without_server_token() {
+        let (reply, _) = exchange(None, &request(None, "status")).await;
+        assert_eq!(reply, "idle\n");
+        let (reply, _) = exchange(None, &request(Some("extra"), "status")).await;
+        assert_eq!(reply, "idle\n");
+    }
+
+    #[tokio::test]
+    async fn version_reports_crate_version() {
+        let (reply, signal) = exchange(None, &request(None, "version")).await;
+        assert_eq!(reply, format!("{}\n", env!("CARGO_PKG_VERSION")));
+        assert!(signal.is_none());
+    }
+
+    #[test]
+    fn token_comparison() {
+        assert!(token_matches("abc", "abc"));
+        assert!(!token_matches("abc", "abd"));
+        assert!(!token_matches("abc", "abcd"));
+        assert!(!token_matches("abc", ""));
+    }
+
+    #[test]
+    fn unauthorized_reply_becomes_permission_denied() {
+        let err = check_reply(UNAUTHORIZED_REPLY.to_string()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(check_reply("ok\n".to_string()).unwrap(), "ok\n");
+    }
+}
@@ -0,0 +1,222 @@
+//! TCP communication for daemon control
+//!
+//! Selected with `--ipc tcp:HOST:PORT` to drive a daemon on another machine.
+//! There are no filesystem permissions to lean on, so bind to loopback or a
+//! trusted LAN address only.
+
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, mpsc};
+
+use super::protocol::{check_reply, handle_connection, request};
+use super::{IpcClient, IpcHandlers, IpcServer};
+use crate::cli::output::DaemonEvent;
+use crate::cli::signals::DaemonSignal;
+
+/// How long [`TcpSocketClient::is_daemon_running`] waits for a connect.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// TCP server for daemon commands
+pub struct TcpSocketServer {
+    addr: String,
+    listener: Option<TcpListener>,
+    auth_token: Option<Arc<str>>,
+}
+
+impl TcpSocketServer {
+    /// Create a new TCP server for `host:port`
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self {
+            addr: addr.into(),
+            listener: None,
+            auth_token: None,
+        }
+    }
+
+    /// Builder: require clients to authenticate with `token`
+    pub fn with_auth_token(mut self, token: Option<String>) -> Self {
+        self.auth_token = token.map(Arc::from);
+        self
+    }
+
+    /// Address actually bound (resolves port `0`)
+    pub fn local_addr(&self) -> Option<std::net::SocketAddr> {
+        self.listener.as_ref().and_then(|l| l.local_addr().ok())
+    }
+}
+
+#[async_trait]
+impl IpcServer for TcpSocketServer {
+    fn bind(&mut self) -> io::Result<()> {
+        let listener = std::net::TcpListener::bind(&self.addr)?;
+        listener.set_nonblocking(true)?;
+        self.listener = Some(TcpListener::from_std(listener)?);
+        Ok(())
+    }
+
+    fn path(&self) -> String {
+        let addr = self
+            .local_addr()
+            .map_or_else(|| self.addr.clone(), |a| a.to_string());
+        format!("tcp:{addr}")
+    }
+
+    async fn run(
+        &self,
+        tx: mpsc::Sender<DaemonSignal>,
+        handlers: IpcHandlers,
+        event_rx: broadcast::Receiver<DaemonEvent>,
+    ) -> io::Result<()> {
+        let listener = self
+            .listener
+            .as_ref()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "Socket not bound"))?;
+
+        // Wrap handlers in Arc for sharing across connections
+        let handlers = Arc::new(handlers);
+
+        loop {
+            match listener.accept().await {
+                Ok((stream, peer)) => {
+                    tracing::debug!(%peer, "IPC TCP connection");
+                    let tx = tx.clone();
+                    let handlers = Arc::clone(&handlers);
+                    let auth_token = self.auth_token.clone();
+                    let event_rx = event_rx.resubscribe();
+                    tokio::spawn(async move {
+                        if let Err(e) =
+                            handle_connection(stream, tx, handlers, auth_token, event_rx).await
+                        {
+                            if !matches!(
+                                e.kind(),
+                                io::ErrorKind::BrokenPipe | io::ErrorKind::ConnectionReset
+                            ) {
+                                tracing::warn!("TCP connection error: {}", e);
+                            }
+                        }
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!("TCP accept error: {}", e);
+                }
+            }
+        }
+    }
+
+    fn cleanup(&self) {
+        // The listener closes when the server drops
+    }
+}
+
+/// TCP client for sending commands to a (possibly remote) daemon
+pub struct TcpSocketClient {
+    addr: String,
+    auth_token: Option<String>,
+}
+
+impl TcpSocketClient {
+    /// Create a new TCP client for `host:port`
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self {
+            addr: addr.into(),
+            auth_token: None,
+        }
+    }
+
+    /// Builder: authenticate every request with `token`
+    pub fn with_auth_token(mut self, token: Option<String>) -> Self {
+        self.auth_token = token;
+        self
+    }
+}
+
+#[async_trait]
+impl IpcClient for TcpSocketClient {
+    async fn is_daemon_running(&self) -> bool {
+        matches!(
+            tokio::time::timeout(PROBE_TIMEOUT, TcpStream::connect(&self.addr)).await,
+            Ok(Ok(_))
+        )
+    }
+
+    async fn send_command(&self, cmd: &str) -> io::Result<String> {
+        let stream = TcpStream::connect(&self.addr).await?;
+        let (reader, mut writer) = stream.into_split();
+
+        // Send command
+        writer
+            .write_all(request(self.auth_token.as_deref(), cmd).as_bytes())
+            .await?;
+        writer.flush().await?;
+
+        // Read response
+        let mut reader = BufReader::new(reader);
+        let mut response = String::new();
+        reader.read_line(&mut response).await?;
+
+        check_reply(response)
+    }
+
+    async fn subscribe(&self) -> io::Result<Box<dyn AsyncBufRead + Unpin + Send>> {
+        let mut stream = TcpStream::connect(&self.addr).await?;
+        stream
+            .write_all(request(self.auth_token.as_deref(), "subscribe").as_bytes())
+            .await?;
+        stream.flush().await?;
+        Ok(Box::new(BufReader::new(stream)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::domain::daemon::DaemonState;
+
+    #[tokio::test]
+    async fn client_and_server_speak_the_line_protocol() {
+        let mut server = TcpSocketServer::new("127.0.0.1:0");
+        server.bind().unwrap();
+        let addr = server.local_addr().unwrap().to_string();
+        assert_eq!(server.path(), format!("tcp:{addr}"));
+
+        let (tx, mut rx) = mpsc::channel(4);
+        let (_event_tx, event_rx) = broadcast::channel(4);
+        tokio::spawn(async move {
+            server
+                .run(
+                    tx,
+                    IpcHandlers::fixed(DaemonState::Recording, 4200, Some("hello")),
+                    event_rx,
+                )
+                .await
+        });
+
+        let client = TcpSocketClient::new(addr);
+        assert!(client.is_daemon_running().await);
+        assert_eq!(client.send_command("status").await.unwrap(), "recording\n");
+        assert_eq!(
+            client.last_transcription().await.unwrap().as_deref(),
+            Some("hello")
+        );
+        assert_eq!(client.send_command("toggle").await.unwrap(), "ok\n");
+        assert!(matches!(rx.recv().await, Some(DaemonSignal::Toggle)));
+    }
+
+    #[tokio::test]
+    async fn closed_port_is_not_running() {
+        // Bind then drop to get a port nobody is listening on.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let client = TcpSocketClient::new(format!("127.0.0.1:{port}"));
+        assert!(!client.is_daemon_running().await);
+    }
+}
@@ -3,32 +3,61 @@
 //! Used on Linux and macOS.
 
 use std::io;
+use std::os::unix::fs::PermissionsExt;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::{UnixListener, UnixStream};
 use tokio::sync::{broadcast, mpsc};
 
-use super::{ElapsedFn, IpcClient, IpcServer, StateFn};
-use crate::cli::output::{DaemonEvent, DaemonStatusPayload};
+use super::protocol::{check_reply, handle_connection, request};
+use super::{IpcClient, IpcHandlers, IpcServer};
+use crate::cli::output::DaemonEvent;
 use crate::cli::signals::DaemonSignal;
-use crate::domain::daemon::DaemonState;
+
+/// File name of the daemon socket.
+const SOCKET_NAME: &str = "smart-scribe.sock";
 
 /// Socket path resolver
 #[derive(Debug, Clone)]
 pub struct SocketPath {
     path: PathBuf,
+    /// Runtime dir that was skipped for not being writable, if any.
+    fallback_from: Option<PathBuf>,
 }
 
 impl SocketPath {
     /// Create socket path, preferring XDG_RUNTIME_DIR
+    ///
+    /// Server and client both resolve through here, so they agree on the
+    /// fallback when the runtime dir can't be written to.
     pub fn new() -> Self {
-        let path = std::env::var("XDG_RUNTIME_DIR")
-            .map(|dir| PathBuf::from(dir).join("smart-scribe.sock"))
-            .unwrap_or_else(|_| std::env::temp_dir().join("smart-scribe.sock"));
-        Self { path }
+        Self::resolve(std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from))
+    }
+
+    /// Socket in `runtime_dir` if it is a writable directory, else in the
+    /// temp dir.
+    fn resolve(runtime_dir: Option<PathBuf>) -> Self {
+        let fallback = std::env::temp_dir().join(SOCKET_NAME);
+        match runtime_dir.filter(|dir| !dir.as_os_str().is_empty()) {
+            Some(dir) if is_writable_dir(&dir) => Self::with_path(dir.join(SOCKET_NAME)),
+            Some(dir) => Self {
+                path: fallback,
+                fallback_from: Some(dir),
+            },
+            None => Self::with_path(fallback),
+        }
+    }
+
+    /// Socket at an explicit path (tests, custom layouts)
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            fallback_from: None,
+        }
     }
 
     /// Get the socket path
@@ -36,6 +65,11 @@ impl SocketPath {
         &self.path
     }
 
+    /// The unwritable runtime dir the temp-dir fallback replaced, if any
+    pub fn fallback_from(&self) -> Option<&Path> {
+        self.fallback_from.as_deref()
+    }
+
     /// Check if socket file exists
     pub fn exists(&self) -> bool {
         self.path.exists()
@@ -56,10 +90,37 @@ impl Default for SocketPath {
     }
 }
 
+/// Whether a file can be created in `dir`. Probed by creating one, since
+/// permission bits don't tell about read-only mounts or sandbox policies.
+fn is_writable_dir(dir: &Path) -> bool {
+    let probe = dir.join(format!(".smart-scribe-probe-{}", std::process::id()));
+    match std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+    {
+        Ok(_) => {
+            let _ = std::fs::remove_file(&probe);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// How long [`UnixSocketClient::is_daemon_running`] waits for a connect.
+/// A live daemon accepts in microseconds; a timeout means it is busy, not gone.
+const PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Mode applied to the bound socket. Connecting needs write permission, so
+/// owner-only stops other local users on a shared host from driving the
+/// daemon (`toggle`, `last`, `shutdown`, ...).
+const SOCKET_MODE: u32 = 0o600;
+
 /// Unix Domain Socket server for daemon commands
 pub struct UnixSocketServer {
     socket_path: SocketPath,
     listener: Option<UnixListener>,
+    auth_token: Option<Arc<str>>,
 }
 
 impl UnixSocketServer {
@@ -68,8 +129,15 @@ impl UnixSocketServer {
         Self {
             socket_path,
             listener: None,
+            auth_token: None,
         }
     }
+
+    /// Builder: require clients to authenticate with `token`
+    pub fn with_auth_token(mut self, token: Option<String>) -> Self {
+        self.auth_token = token.map(Arc::from);
+        self
+    }
 }
 
 impl Drop for UnixSocketServer {
@@ -81,11 +149,23 @@ impl Drop for UnixSocketServer {
 #[async_trait]
 impl IpcServer for UnixSocketServer {
     fn bind(&mut self) -> io::Result<()> {
+        if let Some(dir) = self.socket_path.fallback_from() {
+            tracing::warn!(
+                "XDG_RUNTIME_DIR {} is not writable; using {} for the daemon socket",
+                dir.display(),
+                self.socket_path.path().display()
+            );
+        }
+
         // Remove stale socket file if it exists
         self.socket_path.cleanup()?;
 
         // Bind listener
         let listener = UnixListener::bind(self.socket_path.path())?;
+        std::fs::set_permissions(
+            self.socket_path.path(),
+            std::fs::Permissions::from_mode(SOCKET_MODE),
+        )?;
         self.listener = Some(listener);
         Ok(())
     }
@@ -97,8 +177,7 @@ impl IpcServer for UnixSocketServer {
     async fn run(
         &self,
         tx: mpsc::Sender<DaemonSignal>,
-        state_fn: StateFn,
-        elapsed_fn: ElapsedFn,
+        handlers: IpcHandlers,
         event_rx: broadcast::Receiver<DaemonEvent>,
     ) -> io::Result<()> {
         let listener = self
@@ -106,30 +185,29 @@ impl IpcServer for UnixSocketServer {
             .as_ref()
             .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "Socket not bound"))?;
 
-        // Wrap functions in Arc for sharing across connections
-        let state_fn = Arc::new(state_fn);
-        let elapsed_fn = Arc::new(elapsed_fn);
+        // Wrap handlers in Arc for sharing across connections
+        let handlers = Arc::new(handlers);
 
         loop {
             match listener.accept().await {
                 Ok((stream, _addr)) => {
                     let tx = tx.clone();
-                    let state_fn = Arc::clone(&state_fn);
-                    let elapsed_fn = Arc::clone(&elapsed_fn);
+                    let handlers = Arc::clone(&handlers);
+                    let auth_token = self.auth_token.clone();
                     let event_rx = event_rx.resubscribe();
                     tokio::spawn(async move {
                         if let Err(e) =
-                            handle_connection(stream, tx, state_fn, elapsed_fn, event_rx).await
+                            handle_connection(stream, tx, handlers, auth_token, event_rx).await
                         {
                             // Don't log BrokenPipe errors - they're expected when clients disconnect
                             if e.kind() != io::ErrorKind::BrokenPipe {
-                                eprintln!("Socket connection error: {}", e);
+                                tracing::warn!("Socket connection error: {}", e);
                             }
                         }
                     });
                 }
                 Err(e) => {
-                    eprintln!("Socket accept error: {}", e);
+                    tracing::warn!("Socket accept error: {}", e);
                 }
             }
         }
@@ -140,113 +218,47 @@ impl IpcServer for UnixSocketServer {
     }
 }
 
-/// Handle a single client connection
-async fn handle_connection(
-    stream: UnixStream,
-    tx: mpsc::Sender<DaemonSignal>,
-    state_fn: Arc<StateFn>,
-    elapsed_fn: Arc<ElapsedFn>,
-    mut event_rx: broadcast::Receiver<DaemonEvent>,
-) -> io::Result<()> {
-    let (reader, mut writer) = stream.into_split();
-    let mut reader = BufReader::new(reader);
-    let mut line = String::new();
-
-    // Read command
-    reader.read_line(&mut line).await?;
-    let cmd = line.trim();
-
-    // Process command
-    match cmd {
-        "toggle" => {
-            let _ = tx.send(DaemonSignal::Toggle).await;
-            writer.write_all(b"ok\n").await?;
-            writer.flush().await?;
-        }
-        "cancel" => {
-            let _ = tx.send(DaemonSignal::Cancel).await;
-            writer.write_all(b"ok\n").await?;
-            writer.flush().await?;
-        }
-        "status" => {
-            let current_state = state_fn();
-            let response = match current_state {
-                DaemonState::Idle => "idle\n",
-                DaemonState::Recording => "recording\n",
-                DaemonState::Processing => "processing\n",
-            };
-            writer.write_all(response.as_bytes()).await?;
-            writer.flush().await?;
-        }
-        "status-json" => {
-            let payload = DaemonStatusPayload {
-                state: state_fn(),
-                elapsed_ms: elapsed_fn(),
-            };
-            writer.write_all(payload.to_json_line().as_bytes()).await?;
-            writer.flush().await?;
-        }
-        "subscribe" => {
-            // Send initial state
-            let initial = DaemonEvent::state(state_fn(), elapsed_fn());
-            writer.write_all(initial.to_json_line().as_bytes()).await?;
-            writer.flush().await?;
-
-            // Stream events until client disconnects
-            loop {
-                match event_rx.recv().await {
-                    Ok(event) => {
-                        if let Err(e) = writer.write_all(event.to_json_line().as_bytes()).await {
-                            if e.kind() == io::ErrorKind::BrokenPipe {
-                                break;
-                            }
-                            return Err(e);
-                        }
-                        if let Err(e) = writer.flush().await {
-                            if e.kind() == io::ErrorKind::BrokenPipe {
-                                break;
-                            }
-                            return Err(e);
-                        }
-                    }
-                    Err(broadcast::error::RecvError::Closed) => break,
-                    Err(broadcast::error::RecvError::Lagged(_)) => {
-                        let current = DaemonEvent::state(state_fn(), elapsed_fn());
-                        if let Err(e) = writer.write_all(current.to_json_line().as_bytes()).await {
-                            if e.kind() == io::ErrorKind::BrokenPipe {
-                                break;
-                            }
-                            return Err(e);
-                        }
-                    }
-                }
-            }
-        }
-        _ => {
-            writer.write_all(b"error: unknown command\n").await?;
-            writer.flush().await?;
-        }
-    }
-
-    Ok(())
-}
-
 /// Unix Domain Socket client for sending commands to daemon
 pub struct UnixSocketClient {
     socket_path: SocketPath,
+    auth_token: Option<String>,
 }
 
 impl UnixSocketClient {
     /// Create a new socket client
     pub fn new(socket_path: SocketPath) -> Self {
-        Self { socket_path }
+        Self {
+            socket_path,
+            auth_token: None,
+        }
+    }
+
+    /// Builder: authenticate every request with `token`
+    pub fn with_auth_token(mut self, token: Option<String>) -> Self {
+        self.auth_token = token;
+        self
     }
 }
 
 #[async_trait]
 impl IpcClient for UnixSocketClient {
-    fn is_daemon_running(&self) -> bool {
-        self.socket_path.exists()
+    async fn is_daemon_running(&self) -> bool {
+        // Cheap pre-filter before touching the socket.
+        if !self.socket_path.exists() {
+            return false;
+        }
+        match tokio::time::timeout(PROBE_TIMEOUT, UnixStream::connect(self.socket_path.path()))
+            .await
+        {
+            Ok(Ok(_)) | Err(_) => true,
+            Ok(Err(e)) if e.kind() == io::ErrorKind::ConnectionRefused => {
+                // Left behind by a daemon that crashed; nobody is listening.
+                tracing::debug!(path = %self.socket_path.path().display(), "removing stale socket");
+                let _ = self.socket_path.cleanup();
+                false
+            }
+            Ok(Err(_)) => false,
+        }
     }
 
     async fn send_command(&self, cmd: &str) -> io::Result<String> {
@@ -254,7 +266,9 @@ impl IpcClient for UnixSocketClient {
         let (reader, mut writer) = stream.into_split();
 
         // Send command
-        writer.write_all(format!("{}\n", cmd).as_bytes()).await?;
+        writer
+            .write_all(request(self.auth_token.as_deref(), cmd).as_bytes())
+            .await?;
         writer.flush().await?;
 
         // Read response
@@ -262,12 +276,14 @@ impl IpcClient for UnixSocketClient {
         let mut response = String::new();
         reader.read_line(&mut response).await?;
 
-        Ok(response)
+        check_reply(response)
     }
 
     async fn subscribe(&self) -> io::Result<Box<dyn AsyncBufRead + Unpin + Send>> {
         let mut stream = UnixStream::connect(self.socket_path.path()).await?;
-        stream.write_all(b"subscribe\n").await?;
+        stream
+            .write_all(request(self.auth_token.as_deref(), "subscribe").as_bytes())
+            .await?;
         stream.flush().await?;
         Ok(Box::new(BufReader::new(stream)))
     }
@@ -287,6 +303,116 @@ mod tests {
         assert_eq!(socket_path.path(), path.as_path());
     }
 
+    #[test]
+    fn socket_path_falls_back_to_temp_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let preferred = SocketPath::resolve(Some(dir.path().to_path_buf()));
+        assert_eq!(preferred.path(), dir.path().join(SOCKET_NAME));
+        assert_eq!(preferred.fallback_from(), None);
+        // The probe file is cleaned up.
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+
+        let temp = std::env::temp_dir().join(SOCKET_NAME);
+        let missing = dir.path().join("missing");
+        let fallback = SocketPath::resolve(Some(missing.clone()));
+        assert_eq!(fallback.path(), temp);
+        assert_eq!(fallback.fallback_from(), Some(missing.as_path()));
+
+        let file = dir.path().join("not-a-dir");
+        std::fs::write(&file, b"").unwrap();
+        assert_eq!(SocketPath::resolve(Some(file)).path(), temp);
+
+        let unset = SocketPath::resolve(None);
+        assert_eq!(unset.path(), temp);
+        assert_eq!(unset.fallback_from(), None);
+    }
+
+    #[tokio::test]
+    async fn bound_socket_is_owner_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut server = UnixSocketServer::new(SocketPath::with_path(dir.path().join("s.sock")));
+        server.bind().unwrap();
+
+        let mode = std::fs::metadata(dir.path().join("s.sock"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[tokio::test]
+    async fn commands_and_subscribe_round_trip_over_the_socket() {
+        use crate::domain::daemon::DaemonState;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("s.sock");
+        let mut server = UnixSocketServer::new(SocketPath::with_path(&path));
+        server.bind().unwrap();
+
+        let (tx, mut rx) = mpsc::channel(4);
+        let (event_tx, event_rx) = broadcast::channel(4);
+        tokio::spawn(async move {
+            server
+                .run(
+                    tx,
+                    IpcHandlers::fixed(DaemonState::Recording, 4200, None),
+                    event_rx,
+                )
+                .await
+        });
+
+        let client = UnixSocketClient::new(SocketPath::with_path(&path));
+        assert!(client.is_daemon_running().await);
+        assert_eq!(client.send_command("status").await.unwrap(), "recording\n");
+        assert_eq!(client.send_command("toggle").await.unwrap(), "ok\n");
+        assert!(matches!(rx.recv().await, Some(DaemonSignal::Toggle)));
+        assert_eq!(client.send_command("cancel").await.unwrap(), "ok\n");
+        assert!(matches!(rx.recv().await, Some(DaemonSignal::Cancel)));
+
+        let mut events = client.subscribe().await.unwrap();
+        let mut line = String::new();
+        events.read_line(&mut line).await.unwrap();
+        assert!(line.contains("recording"), "{line}");
+
+        event_tx
+            .send(DaemonEvent::error("transcribe", "boom".to_string()))
+            .unwrap();
+        line.clear();
+        events.read_line(&mut line).await.unwrap();
+        assert!(line.contains("boom"), "{line}");
+    }
+
+    #[tokio::test]
+    async fn listening_socket_is_running_even_without_accept() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("s.sock");
+        // Bound and listening, but nothing ever calls accept().
+        let _listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+
+        let client = UnixSocketClient::new(SocketPath::with_path(&path));
+        assert!(client.is_daemon_running().await);
+        assert!(path.exists());
+    }
+
+    #[tokio::test]
+    async fn stale_socket_is_not_running_and_is_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("s.sock");
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+
+        let client = UnixSocketClient::new(SocketPath::with_path(&path));
+        assert!(!client.is_daemon_running().await);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn missing_socket_is_not_running() {
+        let dir = tempfile::tempdir().unwrap();
+        let client = UnixSocketClient::new(SocketPath::with_path(dir.path().join("absent.sock")));
+        assert!(!client.is_daemon_running().await);
+    }
+
     #[test]
     fn socket_path_default_fallback() {
         let fallback = std::env::temp_dir().join("smart-scribe.sock");
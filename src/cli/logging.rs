@@ -0,0 +1,226 @@
+//! Diagnostic logging via `tracing`.
+//!
+//! Logs are for debugging the daemon and adapters; user-facing status lines
+//! still go through [`Presenter`](super::Presenter). The filter comes from
+//! `--log-level`, then `RUST_LOG`, then [`DEFAULT_LOG_LEVEL`], and accepts
+//! full `EnvFilter` directives (e.g. `smart_scribe::cli::ipc=trace`).
+//!
+//! A log file is rotated by size: past [`LOG_MAX_BYTES`] it is renamed to
+//! `<file>.1` (shifting older ones up to `<file>.<LOG_KEEP_FILES>`) and a
+//! fresh file is started, so a long-running daemon can't fill the disk.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use tracing_subscriber::EnvFilter;
+
+/// Filter used when neither `--log-level` nor `RUST_LOG` is set.
+pub const DEFAULT_LOG_LEVEL: &str = "warn";
+
+/// Default filter for the daemon, whose log is its only record of what
+/// happened while running in the background.
+pub const DAEMON_LOG_LEVEL: &str = "info";
+
+/// Size at which a log file is rotated.
+pub const LOG_MAX_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Rotated log files kept besides the current one.
+pub const LOG_KEEP_FILES: usize = 3;
+
+/// Environment variable consulted when `--log-level` is absent.
+const LOG_ENV: &str = "RUST_LOG";
+
+/// Where the daemon logs when no `--log-file` is given: the XDG state dir
+/// (`~/.local/state/smart-scribe/daemon.log`), else the local data dir.
+pub fn default_daemon_log_path() -> PathBuf {
+    dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .unwrap_or_else(std::env::temp_dir)
+        .join("smart-scribe")
+        .join("daemon.log")
+}
+
+/// Install the global subscriber, writing to `log_file` (appending, with
+/// rotation) or stderr. `default_level` applies when neither `level` nor
+/// `RUST_LOG` is set.
+pub fn init_logging(
+    level: Option<&str>,
+    log_file: Option<&Path>,
+    default_level: &str,
+) -> Result<(), String> {
+    let env = std::env::var(LOG_ENV).ok();
+    let filter = resolve_filter(level, env.as_deref(), default_level)?;
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+
+    // `try_init` only fails if a subscriber is already installed, which is
+    // harmless (tests, embedding), so its error is ignored.
+    match log_file {
+        Some(path) => {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Cannot create {}: {}", parent.display(), e))?;
+            }
+            let file = RotatingFile::open(path, LOG_MAX_BYTES, LOG_KEEP_FILES)
+                .map_err(|e| format!("Cannot open log file {}: {}", path.display(), e))?;
+            let _ = builder
+                .with_ansi(false)
+                .with_writer(Mutex::new(file))
+                .try_init();
+        }
+        None => {
+            let _ = builder
+                .with_ansi(
+                    std::io::stderr().is_terminal()
+                        && colored::control::SHOULD_COLORIZE.should_colorize(),
+                )
+                .with_writer(std::io::stderr)
+                .try_init();
+        }
+    }
+    Ok(())
+}
+
+/// Pick the filter directive: explicit flag, then env, then the default.
+fn resolve_filter(
+    flag: Option<&str>,
+    env: Option<&str>,
+    default: &str,
+) -> Result<EnvFilter, String> {
+    let (directive, source) = match (flag, env.filter(|e| !e.trim().is_empty())) {
+        (Some(f), _) => (f, "--log-level"),
+        (None, Some(e)) => (e, LOG_ENV),
+        (None, None) => (default, "default"),
+    };
+    EnvFilter::try_new(directive).map_err(|e| format!("Invalid {source} '{directive}': {e}"))
+}
+
+/// Append-only log file that rotates itself once it reaches `max_bytes`.
+struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_bytes: u64,
+    keep: usize,
+}
+
+impl RotatingFile {
+    fn open(path: &Path, max_bytes: u64, keep: usize) -> io::Result<Self> {
+        let file = open_append(path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            size,
+            max_bytes,
+            keep,
+        })
+    }
+
+    /// `<file>.<n>`
+    fn rotated(&self, n: usize) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{n}"));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.keep == 0 {
+            let _ = fs::remove_file(&self.path);
+        } else {
+            // The oldest is overwritten by the rename below.
+            for n in (1..self.keep).rev() {
+                let _ = fs::rename(self.rotated(n), self.rotated(n + 1));
+            }
+            fs::rename(&self.path, self.rotated(1))?;
+        }
+        self.file = open_append(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Each call is one formatted event, so lines are never split.
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flag_beats_env_beats_default() {
+        let f = resolve_filter(Some("debug"), Some("trace"), DEFAULT_LOG_LEVEL).unwrap();
+        assert_eq!(f.to_string(), "debug");
+        let f = resolve_filter(None, Some("trace"), DEFAULT_LOG_LEVEL).unwrap();
+        assert_eq!(f.to_string(), "trace");
+        let f = resolve_filter(None, Some("  "), DEFAULT_LOG_LEVEL).unwrap();
+        assert_eq!(f.to_string(), DEFAULT_LOG_LEVEL);
+        let f = resolve_filter(None, None, DEFAULT_LOG_LEVEL).unwrap();
+        assert_eq!(f.to_string(), DEFAULT_LOG_LEVEL);
+        let f = resolve_filter(None, None, DAEMON_LOG_LEVEL).unwrap();
+        assert_eq!(f.to_string(), DAEMON_LOG_LEVEL);
+    }
+
+    #[test]
+    fn accepts_per_module_directives() {
+        assert!(resolve_filter(
+            Some("warn,smart_scribe::cli::ipc=trace"),
+            None,
+            DEFAULT_LOG_LEVEL
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn rejects_garbage_level() {
+        let err = resolve_filter(Some("loud=verbose=yes"), None, DEFAULT_LOG_LEVEL).unwrap_err();
+        assert!(err.contains("--log-level"), "{err}");
+    }
+
+    #[test]
+    fn log_file_rotates_and_keeps_the_newest_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("daemon.log");
+        let mut log = RotatingFile::open(&path, 10, 2).unwrap();
+        for line in ["aaaaaaaa\n", "bbbbbbbb\n", "cccccccc\n", "dddddddd\n"] {
+            log.write_all(line.as_bytes()).unwrap();
+        }
+
+        let read = |p: &Path| fs::read_to_string(p).unwrap();
+        assert_eq!(read(&path), "dddddddd\n");
+        assert_eq!(read(&log.rotated(1)), "cccccccc\n");
+        assert_eq!(read(&log.rotated(2)), "bbbbbbbb\n");
+        assert!(!log.rotated(3).exists());
+    }
+
+    #[test]
+    fn reopened_log_counts_existing_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("daemon.log");
+        fs::write(&path, "0123456789").unwrap();
+
+        let mut log = RotatingFile::open(&path, 12, 1).unwrap();
+        log.write_all(b"abc\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "abc\n");
+        assert_eq!(fs::read_to_string(log.rotated(1)).unwrap(), "0123456789");
+    }
+}
@@ -6,25 +6,33 @@
 pub mod app;
 pub mod args;
 pub mod auth_cmd;
+pub mod batch_cmd;
+pub mod cache_cmd;
+pub mod completions;
 pub mod config_cmd;
 pub mod config_schema;
 pub mod daemon_app;
 pub mod daemon_cmd;
+pub mod devices_cmd;
 pub mod exit_codes;
+pub mod history_cmd;
 pub mod ipc;
+pub mod logging;
+pub mod models_cmd;
 pub mod output;
 pub mod pid_file;
 pub mod presenter;
+pub mod replay_cmd;
 pub mod runtime;
 pub mod signals;
 
 // Re-export commonly used types
 pub use app::run_oneshot;
-#[cfg(target_os = "linux")]
+#[cfg(any(target_os = "linux", target_os = "windows"))]
 pub use args::IndicatorPosition;
 pub use args::{
-    AuthAction, Cli, Commands, ConfigAction, DaemonAction, DaemonOptions, OutputFormatArg,
-    TranscribeOptions,
+    AuthAction, BatchOptions, CacheAction, Cli, Commands, ConfigAction, DaemonAction,
+    DaemonOptions, HistoryAction, ModelsAction, OutputFormatArg, TranscribeOptions,
 };
 pub use daemon_app::run_daemon;
 pub use daemon_cmd::handle_daemon_command;
@@ -0,0 +1,124 @@
+//! `models` subcommand handlers.
+
+use std::process::ExitCode;
+
+use crate::application::ports::TranscriptionError;
+use crate::domain::config::AppConfig;
+use crate::infrastructure::{create_model_lister, ModelInfo};
+
+use super::args::ModelsAction;
+use super::exit_codes;
+use super::presenter::Presenter;
+use super::runtime::BuildError;
+
+/// Handle a `models <action>` invocation.
+pub async fn run_models_command(
+    action: ModelsAction,
+    config: &AppConfig,
+    presenter: &Presenter,
+) -> ExitCode {
+    let ModelsAction::List { all } = action;
+
+    let lister = match create_model_lister(config) {
+        Ok(lister) => lister,
+        Err(e) => {
+            let e = BuildError::Transcriber(e);
+            presenter.error(&e.to_string());
+            return ExitCode::from(e.exit_code());
+        }
+    };
+    let models = match lister.list_models().await {
+        Ok(models) => models,
+        Err(e) => {
+            presenter.error(&format!("Could not list models: {e}"));
+            return ExitCode::from(exit_code(&e));
+        }
+    };
+    let models: Vec<ModelInfo> = models
+        .into_iter()
+        .filter(|m| all || m.transcription)
+        .collect();
+    let configured = config.openai_transcribe_model.as_str();
+
+    if presenter.is_json() {
+        presenter.output_json(&serde_json::json!({
+            "ok": true,
+            "action": "models_list",
+            "configured": configured,
+            "models": models,
+        }));
+        return ExitCode::from(exit_codes::SUCCESS);
+    }
+
+    if models.is_empty() {
+        presenter.info("No transcription models available (try --all)");
+    }
+    for model in &models {
+        presenter.output(&format_model_line(model, configured));
+    }
+    // A typo in the configured model otherwise only shows up as an API
+    // error on the first transcription.
+    if !models.iter().any(|m| m.id == configured) {
+        presenter.warn(&format!(
+            "Configured model '{configured}' is not in the list; check openai_transcribe_model"
+        ));
+    }
+    ExitCode::from(exit_codes::SUCCESS)
+}
+
+/// `id  owner` with a `*` marking the configured model.
+fn format_model_line(model: &ModelInfo, configured: &str) -> String {
+    let marker = if model.id == configured { '*' } else { ' ' };
+    match model.owned_by {
+        Some(ref owner) => format!("{marker} {:<32} {owner}", model.id),
+        None => format!("{marker} {}", model.id),
+    }
+}
+
+fn exit_code(error: &TranscriptionError) -> u8 {
+    match error {
+        TranscriptionError::InvalidApiKey | TranscriptionError::NotAuthenticated => {
+            exit_codes::AUTH
+        }
+        _ => exit_codes::TRANSCRIPTION,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(id: &str, owner: Option<&str>) -> ModelInfo {
+        ModelInfo {
+            id: id.to_string(),
+            owned_by: owner.map(str::to_string),
+            transcription: true,
+        }
+    }
+
+    #[test]
+    fn model_line_marks_configured_model() {
+        let line = format_model_line(
+            &model("gpt-4o-transcribe", Some("system")),
+            "gpt-4o-transcribe",
+        );
+        assert!(line.starts_with("* gpt-4o-transcribe"));
+        assert!(line.ends_with("system"));
+        assert_eq!(
+            format_model_line(&model("whisper-1", None), "gpt-4o-transcribe"),
+            "  whisper-1"
+        );
+    }
+
+    #[test]
+    fn auth_errors_map_to_auth_exit_code() {
+        assert_eq!(
+            exit_code(&TranscriptionError::InvalidApiKey),
+            exit_codes::AUTH
+        );
+        assert_eq!(
+            exit_code(&TranscriptionError::RequestFailed("dns".into())),
+            exit_codes::TRANSCRIPTION
+        );
+    }
+}
@@ -1,9 +1,16 @@
 //! Structured CLI output types
 
-use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
 
-use crate::application::{DaemonOutput, TranscribeOutput};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::application::{DaemonConfig, DaemonOutput, ReloadableSettings, TranscribeOutput};
+use crate::domain::config::validate_silence_threshold;
 use crate::domain::daemon::{DaemonState, StateUpdate};
+use crate::domain::recording::Duration;
+use crate::domain::transcription::Timings;
 
 /// Format a byte count as a short human-readable string
 /// (e.g. `"500 B"`, `"2.0 KB"`, `"2.0 MB"`).
@@ -20,36 +27,231 @@ pub fn format_audio_size(bytes: u64) -> String {
     }
 }
 
+/// Format a captured length in milliseconds as seconds (e.g. `"4.2s"`).
+pub fn format_audio_duration(ms: u64) -> String {
+    format!("{:.1}s", ms as f64 / 1000.0)
+}
+
+/// Short `"4.2s, 20.1 KB"` summary of a recording; the length is left out
+/// when unknown.
+pub fn format_audio_summary(size_bytes: u64, duration_ms: Option<u64>) -> String {
+    match duration_ms {
+        Some(ms) => format!(
+            "{}, {}",
+            format_audio_duration(ms),
+            format_audio_size(size_bytes)
+        ),
+        None => format_audio_size(size_bytes),
+    }
+}
+
+/// `"12 words, 64 characters"` summary of a transcript.
+pub fn format_text_summary(word_count: usize, char_count: usize) -> String {
+    let plural = |n: usize| if n == 1 { "" } else { "s" };
+    format!(
+        "{word_count} word{}, {char_count} character{}",
+        plural(word_count),
+        plural(char_count)
+    )
+}
+
+/// Compact `--timing` line, e.g. `"Timing: recording 4.80s, encoding
+/// 41ms, transcription 1.23s, total 6.10s"`. Stages that didn't run are
+/// left out.
+pub fn format_timings(timings: &Timings) -> String {
+    let stages = [
+        ("recording", timings.recording_ms),
+        ("encoding", timings.encoding_ms),
+        ("transcription", timings.transcription_ms),
+        ("total", Some(timings.total_ms)),
+    ];
+    let parts: Vec<String> = stages
+        .iter()
+        .filter_map(|(name, ms)| ms.map(|ms| format!("{name} {}", format_elapsed(ms))))
+        .collect();
+    format!("Timing: {}", parts.join(", "))
+}
+
+fn format_elapsed(ms: u64) -> String {
+    if ms < 1000 {
+        format!("{ms}ms")
+    } else {
+        format!("{:.2}s", ms as f64 / 1000.0)
+    }
+}
+
+/// Write a transcript to `path`, creating parent directories as needed.
+///
+/// Each transcript is terminated with a newline so repeated `--append` runs
+/// produce one entry per line.
+pub fn write_transcript_file(path: &Path, text: &str, append: bool) -> io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)?;
+    writeln!(file, "{text}")
+}
+
+/// Decoration for daemon transcriptions on stdout (text mode). The default
+/// prints the bare text.
+#[derive(Debug, Clone, Default)]
+pub struct DaemonOutputStyle {
+    /// Prefix with `[YYYY-MM-DDTHH:MM:SSZ]`.
+    pub timestamp: bool,
+    /// Line printed between entries.
+    pub separator: Option<String>,
+    /// Profile name to prefix with, if enabled and one is active.
+    pub profile: Option<String>,
+}
+
+impl DaemonOutputStyle {
+    /// Render one transcription completed at `unix_secs`. `first` is true
+    /// for the daemon's first entry, which gets no separator.
+    pub fn render(&self, text: &str, unix_secs: u64, first: bool) -> String {
+        let mut out = String::new();
+        if let Some(separator) = self.separator.as_deref().filter(|_| !first) {
+            out.push_str(separator);
+            out.push('\n');
+        }
+        if self.timestamp {
+            out.push_str(&format!("[{}] ", format_utc_timestamp(unix_secs)));
+        }
+        if let Some(profile) = &self.profile {
+            out.push_str(&format!("[{profile}] "));
+        }
+        out.push_str(text);
+        out
+    }
+}
+
+/// Format Unix seconds as an ISO 8601 UTC timestamp
+/// (`2024-03-09T14:05:00Z`).
+pub fn format_utc_timestamp(unix_secs: u64) -> String {
+    let days = (unix_secs / 86_400) as i64;
+    let secs = unix_secs % 86_400;
+    // Howard Hinnant's days-to-civil conversion.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct OneshotResponse {
     pub ok: bool,
     pub mode: &'static str,
     pub text: String,
     pub audio_size: String,
+    /// Length of the captured audio (`null` when not measured).
+    pub audio_duration_ms: Option<u64>,
+    pub word_count: usize,
+    pub char_count: usize,
+    /// Transcription model the request was sent with.
+    pub model: String,
+    /// Requested fixed recording duration (`null` when recording until Ctrl+C).
+    pub duration: Option<String>,
     pub clipboard_copied: bool,
+    /// Clipboard backend used (`null` when nothing was copied).
+    pub clipboard_backend: Option<String>,
     pub keystroke_sent: bool,
+    /// Keystroke tool used (`null` when nothing was typed).
+    pub keystroke_backend: Option<String>,
     pub paste_sent: bool,
+    pub timings: Timings,
 }
 
-impl From<TranscribeOutput> for OneshotResponse {
-    fn from(output: TranscribeOutput) -> Self {
+impl OneshotResponse {
+    pub fn new(output: TranscribeOutput, model: &str, duration: Option<Duration>) -> Self {
         Self {
             ok: true,
             mode: "oneshot",
             text: output.text,
             audio_size: format_audio_size(output.audio_size_bytes),
+            audio_duration_ms: output.audio_duration_ms,
+            word_count: output.word_count,
+            char_count: output.char_count,
+            model: model.to_string(),
+            duration: duration.map(|d| d.to_string()),
             clipboard_copied: output.clipboard_copied,
+            clipboard_backend: output.clipboard_backend,
             keystroke_sent: output.keystroke_sent,
+            keystroke_backend: output.keystroke_backend,
             paste_sent: output.paste_sent,
+            timings: output.timings,
         }
     }
 }
 
+/// `--dry-run` result: what was captured and what the transcription
+/// request would have used.
+#[derive(Debug, Clone, Serialize)]
+pub struct DryRunResponse {
+    pub ok: bool,
+    pub mode: &'static str,
+    pub audio_size: String,
+    pub audio_duration_ms: Option<u64>,
+    pub model: String,
+    pub prompt: Option<String>,
+    pub language: Option<String>,
+    pub duration: Option<String>,
+}
+
+impl DryRunResponse {
+    pub fn new(
+        output: &TranscribeOutput,
+        model: &str,
+        prompt: Option<&str>,
+        language: Option<&str>,
+        duration: Option<Duration>,
+    ) -> Self {
+        Self {
+            ok: true,
+            mode: "dry_run",
+            audio_size: format_audio_size(output.audio_size_bytes),
+            audio_duration_ms: output.audio_duration_ms,
+            model: model.to_string(),
+            prompt: prompt.map(str::to_string),
+            language: language.map(str::to_string),
+            duration: duration.map(|d| d.to_string()),
+        }
+    }
+}
+
+/// Failure written to stderr under `--json-errors`, in place of the human
+/// error line.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    /// Variant of the error that ended the run (`missing_api_key`,
+    /// `rate_limited`, `no_audio`, ...), else the exit code's category.
+    pub error_kind: &'static str,
+    pub message: String,
+    pub exit_code: u8,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct DaemonCommandAck {
     pub ok: bool,
     pub command: &'static str,
     pub accepted: bool,
+    /// Why the daemon ignored the command (`start` while recording, ...)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -60,6 +262,8 @@ pub struct DaemonStatusCommandResponse {
     pub elapsed_ms: u64,
 }
 
+/// Reply to the `status --json` IPC command: one line in the same shape as
+/// a [`StateUpdate`], e.g. `{"state":"recording","elapsed_ms":4200}`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DaemonStatusPayload {
     pub state: DaemonState,
@@ -70,6 +274,131 @@ impl DaemonStatusPayload {
     pub fn to_json_line(&self) -> String {
         format!("{}\n", serde_json::to_string(self).unwrap_or_default())
     }
+
+    /// Human-readable form: `idle`, or `recording (4.2s)` once time has elapsed.
+    pub fn describe(&self) -> String {
+        if self.elapsed_ms == 0 {
+            self.state.to_string()
+        } else {
+            format!("{} ({:.1}s)", self.state, self.elapsed_ms as f64 / 1000.0)
+        }
+    }
+}
+
+/// Reply to the `last` IPC command. `text` is `null` until the daemon has
+/// produced its first transcription.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonLastPayload {
+    pub text: Option<String>,
+}
+
+impl DaemonLastPayload {
+    pub fn to_json_line(&self) -> String {
+        format!("{}\n", serde_json::to_string(self).unwrap_or_default())
+    }
+}
+
+/// Reply to the `get-config` IPC command: the daemon's live settings.
+///
+/// `set-config` may change the reloadable ones (see [`DaemonConfigUpdate`]);
+/// `clipboard_append`, `paste` and `history` are fixed until a restart.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DaemonConfigPayload {
+    pub max_duration: Duration,
+    pub min_duration: Option<Duration>,
+    pub silence_threshold: Option<i32>,
+    pub clipboard: bool,
+    pub keystroke: bool,
+    pub notify: bool,
+    pub clipboard_append: Option<String>,
+    pub paste: bool,
+    pub history: bool,
+}
+
+impl DaemonConfigPayload {
+    pub fn to_json_line(&self) -> String {
+        format!("{}\n", serde_json::to_string(self).unwrap_or_default())
+    }
+}
+
+impl From<&DaemonConfig> for DaemonConfigPayload {
+    fn from(config: &DaemonConfig) -> Self {
+        Self {
+            max_duration: config.max_duration,
+            min_duration: config.min_duration,
+            silence_threshold: config.silence_threshold,
+            clipboard: config.enable_clipboard,
+            keystroke: config.enable_keystroke,
+            notify: config.enable_notify,
+            clipboard_append: config.clipboard_append.clone(),
+            paste: config.enable_paste,
+            history: config.history.is_some(),
+        }
+    }
+}
+
+/// Body of the `set-config <json>` IPC command: a partial update of the
+/// reloadable settings. Omitted fields keep their value; `null` turns
+/// `min_duration` / `silence_threshold` off. Any other field is rejected.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DaemonConfigUpdate {
+    pub max_duration: Option<Duration>,
+    #[serde(default, deserialize_with = "present")]
+    pub min_duration: Option<Option<Duration>>,
+    #[serde(default, deserialize_with = "present")]
+    pub silence_threshold: Option<Option<i32>>,
+    pub clipboard: Option<bool>,
+    pub keystroke: Option<bool>,
+    pub notify: Option<bool>,
+}
+
+/// Tell a field given as `null` (`Some(None)`) from one left out (`None`).
+fn present<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+impl DaemonConfigUpdate {
+    /// `current` with this update applied, checked the way the same keys
+    /// are checked in the config file.
+    pub fn apply(self, current: ReloadableSettings) -> Result<ReloadableSettings, String> {
+        let silence_threshold = match self.silence_threshold {
+            Some(Some(db)) => Some(
+                validate_silence_threshold(db).map_err(|m| format!("silence_threshold: {m}"))?,
+            ),
+            Some(None) => None,
+            None => current.silence_threshold,
+        };
+        Ok(ReloadableSettings {
+            max_duration: self.max_duration.unwrap_or(current.max_duration),
+            min_duration: self.min_duration.unwrap_or(current.min_duration),
+            silence_threshold,
+            enable_clipboard: self.clipboard.unwrap_or(current.enable_clipboard),
+            enable_keystroke: self.keystroke.unwrap_or(current.enable_keystroke),
+            enable_notify: self.notify.unwrap_or(current.enable_notify),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DaemonLastCommandResponse {
+    pub ok: bool,
+    pub command: &'static str,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DaemonVersionCommandResponse {
+    pub ok: bool,
+    pub command: &'static str,
+    /// Version of the running daemon
+    pub version: String,
+    /// Version of this client binary
+    pub client_version: &'static str,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,9 +412,18 @@ pub enum DaemonEvent {
     Result {
         text: String,
         audio_size: String,
+        audio_duration_ms: Option<u64>,
+        word_count: usize,
+        char_count: usize,
         clipboard_copied: bool,
+        #[serde(default)]
+        clipboard_backend: Option<String>,
         keystroke_sent: bool,
+        #[serde(default)]
+        keystroke_backend: Option<String>,
         paste_sent: bool,
+        #[serde(default)]
+        timings: Timings,
     },
     Cancelled,
     Error {
@@ -123,9 +461,15 @@ impl From<DaemonOutput> for DaemonEvent {
         Self::Result {
             text: output.text,
             audio_size: format_audio_size(output.audio_size_bytes),
+            audio_duration_ms: output.audio_duration_ms,
+            word_count: output.word_count,
+            char_count: output.char_count,
             clipboard_copied: output.clipboard_copied,
+            clipboard_backend: output.clipboard_backend,
             keystroke_sent: output.keystroke_sent,
+            keystroke_backend: output.keystroke_backend,
             paste_sent: output.paste_sent,
+            timings: output.timings,
         }
     }
 }
@@ -134,6 +478,86 @@ impl From<DaemonOutput> for DaemonEvent {
 mod tests {
     use super::*;
 
+    #[test]
+    fn utc_timestamp_formats_civil_dates() {
+        assert_eq!(format_utc_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_utc_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(format_utc_timestamp(1_710_000_000), "2024-03-09T16:00:00Z");
+    }
+
+    #[test]
+    fn daemon_output_style_defaults_to_plain_text() {
+        let style = DaemonOutputStyle::default();
+        assert_eq!(style.render("hello", 0, true), "hello");
+        assert_eq!(style.render("hello", 0, false), "hello");
+    }
+
+    #[test]
+    fn daemon_output_style_prefixes_and_separates() {
+        let style = DaemonOutputStyle {
+            timestamp: true,
+            separator: Some("---".to_string()),
+            profile: Some("meetings".to_string()),
+        };
+        assert_eq!(
+            style.render("hello", 0, true),
+            "[1970-01-01T00:00:00Z] [meetings] hello"
+        );
+        assert_eq!(
+            style.render("again", 60, false),
+            "---\n[1970-01-01T00:01:00Z] [meetings] again"
+        );
+    }
+
+    #[test]
+    fn status_payload_matches_state_update_shape() {
+        let payload = DaemonStatusPayload {
+            state: DaemonState::Recording,
+            elapsed_ms: 4200,
+        };
+        let line = payload.to_json_line();
+        assert_eq!(line, "{\"state\":\"recording\",\"elapsed_ms\":4200}\n");
+
+        let update: StateUpdate = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(update.state, DaemonState::Recording);
+        assert_eq!(update.elapsed_ms, 4200);
+    }
+
+    #[test]
+    fn status_payload_describe_includes_elapsed_seconds() {
+        let idle = DaemonStatusPayload {
+            state: DaemonState::Idle,
+            elapsed_ms: 0,
+        };
+        assert_eq!(idle.describe(), "idle");
+
+        let recording = DaemonStatusPayload {
+            state: DaemonState::Recording,
+            elapsed_ms: 4200,
+        };
+        assert_eq!(recording.describe(), "recording (4.2s)");
+    }
+
+    #[test]
+    fn last_payload_round_trips_multiline_text() {
+        let line = DaemonLastPayload {
+            text: Some("one\ntwo".to_string()),
+        }
+        .to_json_line();
+        assert_eq!(line.matches('\n').count(), 1);
+        let parsed: DaemonLastPayload = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(parsed.text.as_deref(), Some("one\ntwo"));
+
+        let empty = DaemonLastPayload { text: None }.to_json_line();
+        assert_eq!(empty, "{\"text\":null}\n");
+    }
+
+    #[test]
+    fn format_audio_summary_includes_known_duration() {
+        assert_eq!(format_audio_summary(2048, Some(4_250)), "4.2s, 2.0 KB");
+        assert_eq!(format_audio_summary(2048, None), "2.0 KB");
+    }
+
     #[test]
     fn format_audio_size_thresholds() {
         assert_eq!(format_audio_size(500), "500 B");
@@ -142,18 +566,110 @@ mod tests {
     }
 
     #[test]
-    fn oneshot_response_includes_transcript() {
-        let response = OneshotResponse::from(TranscribeOutput {
-            text: "hello".to_string(),
-            clipboard_copied: true,
+    fn write_transcript_file_creates_parents_and_appends() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes").join("today.txt");
+
+        write_transcript_file(&path, "first", false).unwrap();
+        write_transcript_file(&path, "second", true).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "first\nsecond\n");
+
+        write_transcript_file(&path, "third", false).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "third\n");
+    }
+
+    #[test]
+    fn dry_run_response_reports_request_settings() {
+        let output = TranscribeOutput {
+            text: String::new(),
+            clipboard_copied: false,
+            clipboard_backend: None,
             keystroke_sent: false,
+            keystroke_backend: None,
             paste_sent: false,
-            audio_size_bytes: 10 * 1024,
-        });
+            audio_size_bytes: 2048,
+            audio_duration_ms: Some(1_500),
+            word_count: 0,
+            char_count: 0,
+            timings: Timings::default(),
+        };
+        let response = DryRunResponse::new(&output, "whisper-1", Some("Rust"), None, None);
+
+        let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["mode"], "dry_run");
+        assert_eq!(json["audio_size"], "2.0 KB");
+        assert_eq!(json["audio_duration_ms"], 1_500);
+        assert_eq!(json["model"], "whisper-1");
+        assert_eq!(json["prompt"], "Rust");
+        assert!(json["language"].is_null());
+        assert!(json.get("text").is_none());
+    }
+
+    #[test]
+    fn oneshot_response_includes_transcript() {
+        let response = OneshotResponse::new(
+            TranscribeOutput {
+                text: "hello".to_string(),
+                clipboard_copied: true,
+                clipboard_backend: Some("wl-copy".to_string()),
+                keystroke_sent: false,
+                keystroke_backend: None,
+                paste_sent: false,
+                audio_size_bytes: 10 * 1024,
+                audio_duration_ms: Some(4_200),
+                word_count: 1,
+                char_count: 5,
+                timings: Timings {
+                    recording_ms: Some(4_300),
+                    encoding_ms: Some(40),
+                    transcription_ms: Some(900),
+                    total_ms: 5_300,
+                },
+            },
+            "whisper-1",
+            Some(Duration::from_secs(30)),
+        );
+
+        let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["text"], "hello");
+        assert_eq!(json["mode"], "oneshot");
+        assert_eq!(json["audio_size"], "10.0 KB");
+        assert_eq!(json["audio_duration_ms"], 4_200);
+        assert_eq!(json["model"], "whisper-1");
+        assert_eq!(json["duration"], "30s");
+        assert_eq!(json["word_count"], 1);
+        assert_eq!(json["char_count"], 5);
+        assert_eq!(json["clipboard_copied"], true);
+        assert_eq!(json["clipboard_backend"], "wl-copy");
+        assert_eq!(json["keystroke_sent"], false);
+        assert!(json["keystroke_backend"].is_null());
+        assert_eq!(json["paste_sent"], false);
+        assert_eq!(json["timings"]["recording_ms"], 4_300);
+        assert_eq!(json["timings"]["transcription_ms"], 900);
+        assert_eq!(json["timings"]["total_ms"], 5_300);
+    }
 
-        let json = serde_json::to_string(&response).unwrap();
-        assert!(json.contains("\"text\":\"hello\""));
-        assert!(json.contains("\"mode\":\"oneshot\""));
+    #[test]
+    fn timings_line_skips_stages_that_did_not_run() {
+        let recorded = Timings {
+            recording_ms: Some(4_800),
+            encoding_ms: Some(41),
+            transcription_ms: Some(1_234),
+            total_ms: 6_100,
+        };
+        assert_eq!(
+            format_timings(&recorded),
+            "Timing: recording 4.80s, encoding 41ms, transcription 1.23s, total 6.10s"
+        );
+        let stdin = Timings {
+            transcription_ms: Some(800),
+            total_ms: 812,
+            ..Default::default()
+        };
+        assert_eq!(
+            format_timings(&stdin),
+            "Timing: transcription 800ms, total 812ms"
+        );
     }
 
     #[test]
@@ -161,14 +677,81 @@ mod tests {
         let event = DaemonEvent::from(DaemonOutput {
             text: "transcript".to_string(),
             clipboard_copied: false,
+            clipboard_backend: None,
             keystroke_sent: true,
+            keystroke_backend: Some("ydotool".to_string()),
             paste_sent: false,
             audio_size_bytes: 42 * 1024,
+            audio_duration_ms: None,
+            word_count: 1,
+            char_count: 10,
+            timings: Timings::default(),
         });
 
         let json = serde_json::to_string(&event).unwrap();
         assert!(json.contains("\"type\":\"result\""));
         assert!(json.contains("\"text\":\"transcript\""));
+        assert!(json.contains("\"word_count\":1"));
+        assert!(json.contains("\"char_count\":10"));
+        assert!(json.contains("\"keystroke_backend\":\"ydotool\""));
+    }
+
+    #[test]
+    fn config_payload_serializes_live_settings() {
+        let config = DaemonConfig {
+            max_duration: "5m".parse().unwrap(),
+            silence_threshold: Some(-60),
+            enable_clipboard: true,
+            ..Default::default()
+        };
+        let line = DaemonConfigPayload::from(&config).to_json_line();
+        assert!(line.ends_with('\n'));
+        let json: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(json["max_duration"], "5m");
+        assert_eq!(json["min_duration"], serde_json::Value::Null);
+        assert_eq!(json["silence_threshold"], -60);
+        assert_eq!(json["clipboard"], true);
+        assert_eq!(json["history"], false);
+    }
+
+    #[test]
+    fn config_update_applies_only_given_fields() {
+        let current = DaemonConfig {
+            min_duration: Some("1s".parse().unwrap()),
+            silence_threshold: Some(-60),
+            ..Default::default()
+        }
+        .reloadable();
+        let update: DaemonConfigUpdate =
+            serde_json::from_str(r#"{"max_duration":"2m","silence_threshold":null,"notify":true}"#)
+                .unwrap();
+        let updated = update.apply(current).unwrap();
+        assert_eq!(updated.max_duration.to_string(), "2m");
+        assert_eq!(updated.min_duration, current.min_duration);
+        assert_eq!(updated.silence_threshold, None);
+        assert!(updated.enable_notify);
+        assert_eq!(updated.enable_clipboard, current.enable_clipboard);
+    }
+
+    #[test]
+    fn config_update_rejects_invalid_values_and_fixed_fields() {
+        let parse = serde_json::from_str::<DaemonConfigUpdate>;
+        let err = parse(r#"{"paste":true}"#).unwrap_err().to_string();
+        assert!(err.contains("unknown field `paste`"), "{err}");
+        assert!(parse(r#"{"max_duration":"soon"}"#).is_err());
+        assert!(parse(r#"{"clipboard":"yes"}"#).is_err());
+
+        let update = parse(r#"{"silence_threshold":10}"#).unwrap();
+        let err = update
+            .apply(DaemonConfig::default().reloadable())
+            .unwrap_err();
+        assert!(err.starts_with("silence_threshold:"), "{err}");
+    }
+
+    #[test]
+    fn text_summary_pluralizes_counts() {
+        assert_eq!(format_text_summary(12, 64), "12 words, 64 characters");
+        assert_eq!(format_text_summary(1, 1), "1 word, 1 character");
     }
 
     #[test]
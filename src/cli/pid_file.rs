@@ -2,7 +2,7 @@
 
 use std::fs::{self, File};
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process;
 
 #[cfg(unix)]
@@ -15,6 +15,11 @@ fn default_pid_path() -> PathBuf {
     std::env::temp_dir().join("smart-scribe.pid")
 }
 
+/// Lock file a one-shot run holds while it may be using the microphone.
+fn oneshot_lock_path() -> PathBuf {
+    std::env::temp_dir().join("smart-scribe-oneshot.pid")
+}
+
 /// Check if a process exists (cross-platform)
 #[cfg(unix)]
 fn process_exists(pid: u32) -> bool {
@@ -38,6 +43,66 @@ fn process_exists(pid: u32) -> bool {
     }
 }
 
+/// Read a PID file and return the PID if that process is alive, removing
+/// the file when it is stale.
+fn read_live_pid(path: &Path) -> Option<u32> {
+    let mut file = File::open(path).ok()?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).ok()?;
+    let pid: u32 = contents.trim().parse().ok()?;
+
+    // Check if process is still alive (platform-specific)
+    if process_exists(pid) {
+        Some(pid)
+    } else {
+        // Process doesn't exist - stale PID file
+        let _ = fs::remove_file(path);
+        None
+    }
+}
+
+/// PID of the running daemon, if any.
+///
+/// Unlike [`PidFile::is_running`] this needs no `PidFile` value, whose
+/// `Drop` would release (delete) the daemon's file from a client process.
+pub fn running_daemon_pid() -> Option<u32> {
+    read_live_pid(&default_pid_path())
+}
+
+/// PID of a live one-shot run, if any (see [`acquire_oneshot_lock`]).
+pub fn running_oneshot_pid() -> Option<u32> {
+    read_live_pid(&oneshot_lock_path())
+}
+
+/// Mark this process as a running one-shot so a daemon started meanwhile
+/// can warn about sharing the microphone. The lock is released when the
+/// returned guard drops.
+///
+/// Returns `None` if another one-shot already holds it or the file can't
+/// be written; the lock is advisory, so callers carry on either way.
+pub fn acquire_oneshot_lock() -> Option<PidFile> {
+    acquire_lock_at(oneshot_lock_path())
+}
+
+fn acquire_lock_at(path: PathBuf) -> Option<PidFile> {
+    // Checked before building the guard: dropping a `PidFile` deletes the
+    // file, which must not happen to another process's lock.
+    if read_live_pid(&path).is_some() {
+        return None;
+    }
+    let lock = PidFile::with_path(path);
+    match lock.acquire() {
+        Ok(()) => Some(lock),
+        Err(_) => None,
+    }
+}
+
+/// Ask a process to exit (SIGTERM). Returns whether the signal was delivered.
+#[cfg(unix)]
+pub fn terminate_process(pid: u32) -> bool {
+    kill(Pid::from_raw(pid as i32), Signal::SIGTERM).is_ok()
+}
+
 /// PID file for daemon mode
 pub struct PidFile {
     path: PathBuf,
@@ -63,34 +128,7 @@ impl PidFile {
 
     /// Check if another daemon is already running
     pub fn is_running(&self) -> Option<u32> {
-        if !self.path.exists() {
-            return None;
-        }
-
-        // Read existing PID
-        let mut file = match File::open(&self.path) {
-            Ok(f) => f,
-            Err(_) => return None,
-        };
-
-        let mut contents = String::new();
-        if file.read_to_string(&mut contents).is_err() {
-            return None;
-        }
-
-        let pid: u32 = match contents.trim().parse() {
-            Ok(p) => p,
-            Err(_) => return None,
-        };
-
-        // Check if process is still alive (platform-specific)
-        if process_exists(pid) {
-            Some(pid)
-        } else {
-            // Process doesn't exist - stale PID file
-            let _ = fs::remove_file(&self.path);
-            None
-        }
+        read_live_pid(&self.path)
     }
 
     /// Acquire the PID file (fails if another daemon is running)
@@ -170,4 +208,32 @@ mod tests {
         let pid_file = PidFile::with_path(temp_dir().join("nonexistent.pid"));
         assert!(pid_file.is_running().is_none());
     }
+
+    #[test]
+    fn lock_is_held_until_dropped_and_not_taken_twice() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("oneshot.pid");
+
+        let lock = acquire_lock_at(path.clone()).expect("free lock");
+        assert_eq!(read_live_pid(&path), Some(process::id()));
+        // A live holder (here: ourselves) keeps the file intact.
+        assert!(acquire_lock_at(path.clone()).is_none());
+        assert!(path.exists());
+
+        drop(lock);
+        assert!(read_live_pid(&path).is_none());
+    }
+
+    #[test]
+    fn read_live_pid_finds_self_and_ignores_garbage() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("d.pid");
+
+        fs::write(&path, process::id().to_string()).unwrap();
+        assert_eq!(read_live_pid(&path), Some(process::id()));
+
+        // Left in place: it may be a PID file still being written.
+        fs::write(&path, "not-a-pid").unwrap();
+        assert!(read_live_pid(&path).is_none());
+    }
 }
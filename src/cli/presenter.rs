@@ -1,20 +1,53 @@
 //! CLI presenter for output formatting
 
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
+use std::process::ExitCode;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use colored::*;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
 use serde::Serialize;
 
 use super::args::OutputFormatArg;
+use super::exit_codes;
+use super::output::ErrorResponse;
+use crate::application::ports::ProgressCallback;
+
+/// Maximum number of trailing transcript characters shown in the spinner.
+const PREVIEW_CHARS: usize = 60;
+
+/// Decide whether ANSI colors should be used: off with `--no-color`, a
+/// non-empty `NO_COLOR` (<https://no-color.org>), or when stderr is not a
+/// terminal.
+pub fn color_enabled(no_color_flag: bool) -> bool {
+    let no_color_env = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    !no_color_flag && !no_color_env && io::stderr().is_terminal()
+}
+
+/// Apply the color choice process-wide, so `colored` output outside the
+/// presenter (signal messages) and the log formatter follow it too.
+pub fn init_color(no_color_flag: bool) {
+    if !color_enabled(no_color_flag) {
+        colored::control::set_override(false);
+    }
+}
 
 /// Presenter for CLI output formatting
+///
+/// In quiet mode every status channel (info/success/warn, spinners, daemon
+/// status) is a no-op; only [`error`](Self::error) and stdout output remain.
 pub struct Presenter {
     output_format: OutputFormatArg,
+    quiet: bool,
+    color: bool,
     spinner: Option<ProgressBar>,
     is_spinner_active: Arc<AtomicBool>,
+    /// `--json-errors`: errors are held for [`report_failure`](Self::report_failure)
+    /// instead of printed.
+    json_errors: bool,
+    /// Last error message and, when known, its kind.
+    last_error: Mutex<Option<(Option<&'static str>, String)>>,
 }
 
 impl Presenter {
@@ -22,11 +55,40 @@ impl Presenter {
     pub fn new(output_format: OutputFormatArg) -> Self {
         Self {
             output_format,
+            quiet: false,
+            color: colored::control::SHOULD_COLORIZE.should_colorize(),
             spinner: None,
             is_spinner_active: Arc::new(AtomicBool::new(false)),
+            json_errors: false,
+            last_error: Mutex::new(None),
         }
     }
 
+    /// Builder: suppress all non-error status output.
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    /// Builder: report failures as one JSON object on stderr
+    /// ([`report_failure`](Self::report_failure)) instead of error lines.
+    pub fn with_json_errors(mut self, json_errors: bool) -> Self {
+        self.json_errors = json_errors;
+        self
+    }
+
+    /// Builder: force ANSI colors on or off (defaults to the process-wide
+    /// choice made by [`init_color`]).
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Whether status output is suppressed
+    pub const fn is_quiet(&self) -> bool {
+        self.quiet
+    }
+
     /// Selected output format
     pub const fn output_format(&self) -> OutputFormatArg {
         self.output_format
@@ -39,19 +101,43 @@ impl Presenter {
 
     /// Start a spinner with message
     pub fn start_spinner(&mut self, message: &str) {
+        if self.quiet {
+            return;
+        }
         let spinner = ProgressBar::new_spinner();
-        spinner.set_style(
-            ProgressStyle::default_spinner()
-                .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏")
-                .template("{spinner:.cyan} {msg}")
-                .unwrap(),
-        );
+        spinner.set_style(self.spinner_style());
         spinner.set_message(message.to_string());
         spinner.enable_steady_tick(std::time::Duration::from_millis(80));
         self.spinner = Some(spinner);
         self.is_spinner_active.store(true, Ordering::SeqCst);
     }
 
+    /// A spinner for use-case callbacks to reveal with
+    /// [`show_pending_spinner`] when a step starts while the presenter is out
+    /// of reach (transcribing a fixed-length recording).
+    ///
+    /// `None` when it would never be drawn: quiet or JSON mode, or stderr
+    /// not a terminal. Callers then print plain status lines instead.
+    pub fn pending_spinner(&self) -> Option<ProgressBar> {
+        if self.quiet || self.is_json() || !io::stderr().is_terminal() {
+            return None;
+        }
+        let spinner = ProgressBar::with_draw_target(None, ProgressDrawTarget::hidden());
+        spinner.set_style(self.spinner_style());
+        Some(spinner)
+    }
+
+    fn spinner_style(&self) -> ProgressStyle {
+        ProgressStyle::default_spinner()
+            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏")
+            .template(if self.color {
+                "{spinner:.cyan} {msg}"
+            } else {
+                "{spinner} {msg}"
+            })
+            .unwrap()
+    }
+
     /// Update spinner message
     pub fn update_spinner(&self, message: &str) {
         if let Some(ref spinner) = self.spinner {
@@ -61,46 +147,119 @@ impl Presenter {
 
     /// Mark spinner as success and finish
     pub fn spinner_success(&mut self, message: &str) {
-        if let Some(spinner) = self.spinner.take() {
-            spinner.finish_with_message(format!("{} {}", "✓".green(), message));
+        if let Some(spinner) = self.take_spinner() {
+            spinner.finish_with_message(format!("{} {}", self.paint("✓", Color::Green), message));
         }
         self.is_spinner_active.store(false, Ordering::SeqCst);
     }
 
     /// Mark spinner as failed and finish
     pub fn spinner_fail(&mut self, message: &str) {
-        if let Some(spinner) = self.spinner.take() {
-            spinner.finish_with_message(format!("{} {}", "✗".red(), message));
+        if let Some(spinner) = self.take_spinner() {
+            spinner.finish_with_message(format!("{} {}", self.paint("✗", Color::Red), message));
         }
         self.is_spinner_active.store(false, Ordering::SeqCst);
     }
 
     /// Stop spinner without status
     pub fn stop_spinner(&mut self) {
-        if let Some(spinner) = self.spinner.take() {
+        if let Some(spinner) = self.take_spinner() {
             spinner.finish_and_clear();
         }
         self.is_spinner_active.store(false, Ordering::SeqCst);
     }
 
+    /// Take the active spinner or progress bar, skipping one a callback
+    /// already finished so its final line isn't redrawn or cleared.
+    fn take_spinner(&mut self) -> Option<ProgressBar> {
+        self.spinner.take().filter(|s| !s.is_finished())
+    }
+
     /// Print info message to stderr
     pub fn info(&self, message: &str) {
-        eprintln!("{} {}", "ℹ".cyan(), message);
+        if self.quiet {
+            return;
+        }
+        eprintln!("{} {}", self.paint("ℹ", Color::Cyan), message);
     }
 
     /// Print success message to stderr
     pub fn success(&self, message: &str) {
-        eprintln!("{} {}", "✓".green(), message);
+        if self.quiet {
+            return;
+        }
+        eprintln!("{} {}", self.paint("✓", Color::Green), message);
     }
 
     /// Print warning message to stderr
     pub fn warn(&self, message: &str) {
-        eprintln!("{} {}", "⚠".yellow(), message);
+        if self.quiet {
+            return;
+        }
+        eprintln!("{} {}", self.paint("⚠", Color::Yellow), message);
     }
 
     /// Print error message to stderr
     pub fn error(&self, message: &str) {
-        eprintln!("{} {}", "✗".red(), message);
+        if !self.json_errors {
+            eprintln!("{} {}", self.paint("✗", Color::Red), message);
+        }
+        self.record_error(None, message);
+    }
+
+    /// Print an error whose kind (`missing_api_key`, `no_audio`, ...)
+    /// `--json-errors` should report.
+    pub fn error_with_kind(&self, kind: &'static str, message: &str) {
+        if !self.json_errors {
+            eprintln!("{} {}", self.paint("✗", Color::Red), message);
+        }
+        self.record_error(Some(kind), message);
+    }
+
+    /// Note why the run is failing without printing anything, for failures
+    /// already shown another way (e.g. as a warning).
+    pub fn record_failure(&self, kind: &'static str, message: &str) {
+        self.record_error(Some(kind), message);
+    }
+
+    fn record_error(&self, kind: Option<&'static str>, message: &str) {
+        *self.last_error.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some((kind, message.to_string()));
+    }
+
+    /// The most recent error message, if any was printed.
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .map(|(_, message)| message.clone())
+    }
+
+    /// With `--json-errors`, write the last error as an [`ErrorResponse`]
+    /// to stderr when the run ends with a failing `code`. Nothing is
+    /// written for a success or a failure that printed no error.
+    pub fn report_failure(&self, code: ExitCode) {
+        if let Some(response) = self.failure_response(code) {
+            eprintln!("{}", serde_json::to_string(&response).unwrap_or_default());
+        }
+    }
+
+    fn failure_response(&self, code: ExitCode) -> Option<ErrorResponse> {
+        let exit_code = exit_codes::code_of(code);
+        if !self.json_errors || exit_code == exit_codes::SUCCESS {
+            return None;
+        }
+        let (kind, message) = self
+            .last_error
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()?;
+        Some(ErrorResponse {
+            error_kind: kind.unwrap_or(exit_codes::kind_of(exit_code)),
+            message,
+            exit_code,
+        })
     }
 
     /// Output text to stdout
@@ -132,8 +291,6 @@ impl Presenter {
 
     /// Format recording progress bar
     pub fn format_progress(&self, elapsed_ms: u64, total_ms: u64) -> String {
-        let elapsed_secs = elapsed_ms / 1000;
-        let total_secs = total_ms / 1000;
         let percent = if total_ms > 0 {
             (elapsed_ms as f64 / total_ms as f64 * 100.0).min(100.0)
         } else {
@@ -146,33 +303,110 @@ impl Presenter {
         let empty = bar_width - filled;
 
         format!(
-            "[{}{}] {:>3}s / {}s",
-            "█".repeat(filled).cyan(),
+            "[{}{}] {}",
+            self.paint(&"█".repeat(filled), Color::Cyan),
             "░".repeat(empty),
-            elapsed_secs,
-            total_secs
+            progress_label(elapsed_ms, total_ms)
         )
     }
 
-    /// Show a progress bar for recording
-    pub fn show_recording_progress(&mut self, message: &str) {
-        self.start_spinner(message);
+    /// Show a determinate progress bar for a fixed-length recording of
+    /// `total_ms`, drawn like [`format_progress`](Self::format_progress).
+    /// Occupies the spinner slot, so the spinner methods finish it.
+    pub fn show_recording_progress(&mut self, total_ms: u64) {
+        if self.quiet {
+            return;
+        }
+        let bar = ProgressBar::new(total_ms);
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .template(if self.color {
+                    "Recording... [{bar:20.cyan}] {msg}"
+                } else {
+                    "Recording... [{bar:20}] {msg}"
+                })
+                .unwrap()
+                .progress_chars("█░"),
+        );
+        bar.set_message(progress_label(0, total_ms));
+        self.spinner = Some(bar);
+        self.is_spinner_active.store(true, Ordering::SeqCst);
     }
 
     /// Update recording progress
     pub fn update_recording_progress(&self, elapsed_ms: u64, total_ms: u64) {
-        let progress = self.format_progress(elapsed_ms, total_ms);
-        self.update_spinner(&format!("Recording... {}", progress));
+        if let Some(ref bar) = self.spinner {
+            set_recording_progress(bar, elapsed_ms, total_ms);
+        }
+    }
+
+    /// Return a recorder progress callback that advances the bar from
+    /// [`show_recording_progress`](Self::show_recording_progress), or
+    /// `None` when no bar is showing.
+    pub fn recording_progress_sink(&self) -> Option<ProgressCallback> {
+        let bar = self.spinner.clone()?;
+        Some(Arc::new(move |elapsed_ms, total_ms| {
+            set_recording_progress(&bar, elapsed_ms, total_ms);
+        }))
+    }
+
+    /// Like [`status_sink`](Self::status_sink), but the first line replaces
+    /// the recording progress bar (if one is showing) instead of printing
+    /// below it.
+    pub fn recording_done_sink(&self) -> Arc<dyn Fn(&str) + Send + Sync> {
+        let Some(bar) = self.spinner.clone() else {
+            return self.status_sink();
+        };
+        let status = self.status_sink();
+        Arc::new(move |line: &str| {
+            if bar.is_finished() {
+                status(line);
+                return;
+            }
+            bar.set_style(ProgressStyle::with_template("{msg}").unwrap());
+            bar.finish_with_message(line.to_string());
+        })
     }
 
     /// Print daemon status
     pub fn daemon_status(&self, state: &str) {
-        eprintln!("{} Daemon: {}", "●".cyan(), state);
+        if self.quiet {
+            return;
+        }
+        eprintln!("{} Daemon: {}", self.paint("●", Color::Cyan), state);
     }
 
     /// Print a key-value pair (for config list)
     pub fn key_value(&self, key: &str, value: &str) {
-        println!("{}: {}", key.cyan(), value);
+        println!("{}", self.format_key_value(key, value));
+    }
+
+    /// Format a `key: value` line as printed by [`key_value`](Self::key_value)
+    pub fn format_key_value(&self, key: &str, value: &str) -> String {
+        format!("{}: {}", self.paint(key, Color::Cyan), value)
+    }
+
+    /// Color `text` unless colors are disabled
+    fn paint(&self, text: &str, color: Color) -> String {
+        if self.color {
+            text.color(color).to_string()
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Return a delta callback that previews streamed transcript text in
+    /// the active spinner.
+    ///
+    /// `None` in JSON mode or when no spinner is running, in which case the
+    /// use case falls back to the non-streaming request.
+    pub fn transcript_preview_sink(
+        &self,
+    ) -> Option<crate::application::ports::TranscriptDeltaCallback> {
+        if self.is_json() {
+            return None;
+        }
+        self.spinner.clone().map(transcript_preview_on)
     }
 
     /// Return a [`WarningSink`](crate::application::WarningSink) closure that
@@ -184,10 +418,67 @@ impl Presenter {
         // The closure only needs to write "⚠ msg" to stderr; it does not
         // share any Presenter state, so we don't need the Presenter to be
         // Clone (or Sync).
+        if self.quiet {
+            return std::sync::Arc::new(|_: &str| {});
+        }
         std::sync::Arc::new(|msg: &str| {
             eprintln!("{} {}", "⚠".yellow(), msg);
         })
     }
+
+    /// Return a closure that prints pre-formatted status lines to stderr,
+    /// for callbacks that outlive a borrow of the presenter. No-op when quiet.
+    pub fn status_sink(&self) -> Arc<dyn Fn(&str) + Send + Sync> {
+        if self.quiet {
+            return Arc::new(|_: &str| {});
+        }
+        Arc::new(|line: &str| eprintln!("{}", line))
+    }
+}
+
+/// Draw a spinner from [`Presenter::pending_spinner`] on stderr with
+/// `message`.
+pub fn show_pending_spinner(spinner: &ProgressBar, message: &str) {
+    spinner.set_draw_target(ProgressDrawTarget::stderr());
+    spinner.set_message(message.to_string());
+    spinner.enable_steady_tick(std::time::Duration::from_millis(80));
+}
+
+/// Delta callback previewing the streamed transcript on `spinner`.
+pub fn transcript_preview_on(
+    spinner: ProgressBar,
+) -> crate::application::ports::TranscriptDeltaCallback {
+    let text = Arc::new(Mutex::new(String::new()));
+    Arc::new(move |delta: &str| {
+        let mut text = text.lock().unwrap_or_else(|e| e.into_inner());
+        text.push_str(delta);
+        spinner.set_message(format!("Transcribing... {}", preview_tail(&text)));
+    })
+}
+
+/// `"  3s / 10s"` label shared by the text and indicatif progress bars.
+fn progress_label(elapsed_ms: u64, total_ms: u64) -> String {
+    format!("{:>3}s / {}s", elapsed_ms / 1000, total_ms / 1000)
+}
+
+fn set_recording_progress(bar: &ProgressBar, elapsed_ms: u64, total_ms: u64) {
+    bar.set_length(total_ms);
+    bar.set_position(elapsed_ms.min(total_ms));
+    bar.set_message(progress_label(elapsed_ms, total_ms));
+}
+
+/// Last [`PREVIEW_CHARS`] characters of `text` on a single line.
+fn preview_tail(text: &str) -> String {
+    let flat: String = text
+        .chars()
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .collect();
+    let count = flat.chars().count();
+    if count <= PREVIEW_CHARS {
+        return flat.trim_start().to_string();
+    }
+    let tail: String = flat.chars().skip(count - PREVIEW_CHARS).collect();
+    format!("…{}", tail.trim_start())
 }
 
 impl Default for Presenter {
@@ -200,6 +491,35 @@ impl Default for Presenter {
 mod tests {
     use super::*;
 
+    #[test]
+    fn last_error_tracks_latest_message() {
+        let presenter = Presenter::new(OutputFormatArg::Text).with_quiet(true);
+        assert_eq!(presenter.last_error(), None);
+        presenter.error("first");
+        presenter.error("second");
+        assert_eq!(presenter.last_error().as_deref(), Some("second"));
+    }
+
+    #[test]
+    fn json_errors_report_kind_message_and_code() {
+        let presenter = Presenter::new(OutputFormatArg::Text).with_json_errors(true);
+        assert!(presenter.failure_response(ExitCode::from(3)).is_none());
+        presenter.error("boom");
+        let generic = presenter.failure_response(ExitCode::from(3)).unwrap();
+        assert_eq!(generic.error_kind, "auth");
+        presenter.error_with_kind("missing_api_key", "Missing OpenAI API key");
+        let response = presenter.failure_response(ExitCode::from(3)).unwrap();
+        assert_eq!(
+            serde_json::to_string(&response).unwrap(),
+            r#"{"error_kind":"missing_api_key","message":"Missing OpenAI API key","exit_code":3}"#
+        );
+        assert!(presenter.failure_response(ExitCode::SUCCESS).is_none());
+
+        let human = Presenter::new(OutputFormatArg::Text).with_quiet(true);
+        human.error("boom");
+        assert!(human.failure_response(ExitCode::from(1)).is_none());
+    }
+
     #[test]
     fn format_progress_at_start() {
         let presenter = Presenter::new(OutputFormatArg::Text);
@@ -221,6 +541,78 @@ mod tests {
         assert!(progress.contains("10s / 10s"));
     }
 
+    #[test]
+    fn preview_tail_flattens_and_truncates() {
+        assert_eq!(preview_tail("hello\nworld"), "hello world");
+        let long = "x".repeat(PREVIEW_CHARS + 10);
+        let tail = preview_tail(&long);
+        assert!(tail.starts_with('…'));
+        assert_eq!(tail.chars().count(), PREVIEW_CHARS + 1);
+    }
+
+    #[test]
+    fn transcript_preview_sink_requires_text_spinner() {
+        let mut presenter = Presenter::new(OutputFormatArg::Text);
+        assert!(presenter.transcript_preview_sink().is_none());
+        presenter.start_spinner("Transcribing...");
+        assert!(presenter.transcript_preview_sink().is_some());
+        presenter.stop_spinner();
+        assert!(Presenter::new(OutputFormatArg::Json)
+            .transcript_preview_sink()
+            .is_none());
+    }
+
+    #[test]
+    fn quiet_presenter_never_starts_spinner() {
+        let mut presenter = Presenter::new(OutputFormatArg::Text).with_quiet(true);
+        assert!(presenter.is_quiet());
+        presenter.start_spinner("Transcribing...");
+        assert!(presenter.transcript_preview_sink().is_none());
+        presenter.spinner_success("done");
+    }
+
+    #[test]
+    fn disabled_color_emits_no_escape_sequences() {
+        let presenter = Presenter::new(OutputFormatArg::Text).with_color(false);
+        let progress = presenter.format_progress(5000, 10000);
+        assert!(!progress.contains('\x1b'), "{progress:?}");
+        assert!(progress.contains("█"));
+        assert_eq!(
+            presenter.format_key_value("model", "whisper-1"),
+            "model: whisper-1"
+        );
+    }
+
+    #[test]
+    fn no_color_flag_disables_color() {
+        assert!(!color_enabled(true));
+    }
+
+    #[test]
+    fn recording_progress_bar_tracks_elapsed_and_finishes_once() {
+        let mut presenter = Presenter::new(OutputFormatArg::Text);
+        assert!(presenter.recording_progress_sink().is_none());
+
+        presenter.show_recording_progress(10_000);
+        let progress = presenter.recording_progress_sink().unwrap();
+        progress(4_000, 10_000);
+        progress(12_000, 10_000);
+        let bar = presenter.spinner.clone().unwrap();
+        assert_eq!(bar.position(), 10_000);
+
+        presenter.recording_done_sink()("✓ Recording complete");
+        assert!(bar.is_finished());
+        // The finished bar is not picked up again by the spinner methods.
+        assert!(presenter.take_spinner().is_none());
+    }
+
+    #[test]
+    fn quiet_presenter_shows_no_progress_bar() {
+        let mut presenter = Presenter::new(OutputFormatArg::Text).with_quiet(true);
+        presenter.show_recording_progress(10_000);
+        assert!(presenter.recording_progress_sink().is_none());
+    }
+
     #[test]
     fn presenter_tracks_json_mode() {
         let presenter = Presenter::new(OutputFormatArg::Json);
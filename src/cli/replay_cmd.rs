@@ -0,0 +1,102 @@
+//! `replay` subcommand: re-transcribe the last captured audio.
+//!
+//! The audio of the most recent transcription is kept next to the history
+//! file, so a wrong transcript can be retried with another model, prompt
+//! or language without recording again. `--model`, `--prompt` and
+//! `--language` reach this module already merged into the config.
+
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::application::ports::{HistoryStore, Transcriber};
+use crate::application::{HistoryLog, TranscribeError};
+use crate::domain::config::AppConfig;
+use crate::domain::transcription::{char_count, word_count};
+use crate::infrastructure::{create_transcriber, JsonlHistoryStore};
+
+use super::exit_codes;
+use super::output::{format_audio_size, format_text_summary};
+use super::presenter::Presenter;
+use super::runtime::BuildError;
+
+#[derive(Debug, Clone, Serialize)]
+struct ReplayResponse {
+    ok: bool,
+    action: &'static str,
+    text: String,
+    audio_size: String,
+    word_count: usize,
+    char_count: usize,
+    model: String,
+    prompt: Option<String>,
+    language: Option<String>,
+}
+
+/// Run `smart-scribe replay`.
+pub async fn run_replay(config: &AppConfig, presenter: &Presenter) -> ExitCode {
+    let store = Arc::new(JsonlHistoryStore::new(config.history_max_entries));
+    let audio = match store.last_audio().await {
+        Ok(Some(audio)) => audio,
+        Ok(None) => {
+            presenter.error(
+                "No saved audio to replay; transcribe a recording first \
+                 (audio is only kept while history is enabled)",
+            );
+            return ExitCode::from(exit_codes::ERROR);
+        }
+        Err(e) => {
+            presenter.error(&format!("Could not load the last audio: {e}"));
+            return ExitCode::from(exit_codes::ERROR);
+        }
+    };
+    let transcriber = match create_transcriber(config) {
+        Ok(t) => t,
+        Err(e) => {
+            let e = BuildError::Transcriber(e);
+            presenter.error(&e.to_string());
+            return ExitCode::from(e.exit_code());
+        }
+    };
+
+    let model = config.openai_transcribe_model.as_str();
+    presenter.info(&format!(
+        "Re-transcribing last audio ({}) with {model}",
+        format_audio_size(audio.size_bytes() as u64)
+    ));
+    let text = match transcriber.transcribe(&audio).await {
+        Ok(text) => text,
+        Err(e) => {
+            presenter.error(&format!("Transcription failed: {e}"));
+            return ExitCode::from(exit_codes::for_transcribe_error(
+                &TranscribeError::Transcription(e),
+            ));
+        }
+    };
+
+    if config.history {
+        HistoryLog::new(store, model)
+            .record(&text, &audio, Some(&presenter.warning_sink()))
+            .await;
+    }
+
+    let (words, chars) = (word_count(&text), char_count(&text));
+    if presenter.is_json() {
+        presenter.output_json(&ReplayResponse {
+            ok: true,
+            action: "replay",
+            audio_size: format_audio_size(audio.size_bytes() as u64),
+            word_count: words,
+            char_count: chars,
+            model: model.to_string(),
+            prompt: config.transcribe_prompt_some().map(str::to_string),
+            language: config.transcribe_language_some().map(str::to_string),
+            text,
+        });
+    } else {
+        presenter.output(&text);
+        presenter.info(&format_text_summary(words, chars));
+    }
+    ExitCode::from(exit_codes::SUCCESS)
+}
@@ -15,14 +15,19 @@
 
 use std::sync::Arc;
 
-use crate::application::ports::{AudioCue, Clipboard, Keystroke, Notifier, SmartPaste};
+use crate::application::ports::{
+    AudioCue, Clipboard, Keystroke, Notifier, PostProcessor, SmartPaste,
+};
+use crate::application::HistoryLog;
 use crate::domain::config::AppConfig;
 use crate::infrastructure::{
-    create_audio_cue, create_clipboard, create_keystroke, create_notifier, create_recorder,
-    create_smart_paste, create_transcriber, CpalRecorder, KeystrokeToolPreference, NoOpKeystroke,
-    NoOpSmartPaste, Transcriber,
+    create_audio_cue, create_clipboard, create_keystroke, create_notifier, create_post_processor,
+    create_recorder, create_smart_paste, create_transcriber, CpalRecorder, JsonlHistoryStore,
+    KeystrokeToolPreference, NoOpKeystroke, NoOpSmartPaste, RawResponseSink, Transcriber,
+    TranscriberSetupError,
 };
 
+use super::exit_codes;
 use super::presenter::Presenter;
 
 /// Runtime knobs that affect adapter construction but not the validated
@@ -36,6 +41,8 @@ pub struct RuntimeOptions {
     pub keystroke_tool: Option<String>,
     pub paste: bool,
     pub audio_cue: bool,
+    /// Dump raw transcription responses to stderr (`--raw`).
+    pub raw: bool,
 }
 
 impl From<&super::args::TranscribeOptions> for RuntimeOptions {
@@ -46,6 +53,7 @@ impl From<&super::args::TranscribeOptions> for RuntimeOptions {
             keystroke_tool: o.keystroke_tool.clone(),
             paste: o.paste,
             audio_cue: o.audio_cue,
+            raw: o.raw,
         }
     }
 }
@@ -58,6 +66,7 @@ impl From<&super::args::DaemonOptions> for RuntimeOptions {
             keystroke_tool: o.keystroke_tool.clone(),
             paste: o.paste,
             audio_cue: o.audio_cue,
+            raw: false,
         }
     }
 }
@@ -71,15 +80,18 @@ pub struct AdapterBundle {
     pub notifier: Box<dyn Notifier>,
     pub smart_paste: Box<dyn SmartPaste>,
     pub audio_cue: Arc<dyn AudioCue>,
+    /// `None` when history is disabled (`history = false` / `--no-history`).
+    pub history: Option<HistoryLog>,
+    /// `None` without `--post-command` / `post_command`.
+    pub post_processor: Option<Arc<dyn PostProcessor>>,
 }
 
 /// Failure to construct one of the runtime adapters. Variants are split so
-/// the caller can pick a sensible exit code (`USAGE_ERROR` for missing
-/// credentials, `ERROR` for environmental failures).
+/// the caller can pick a sensible exit code (see [`Self::exit_code`]).
 #[derive(Debug)]
 pub enum BuildError {
     /// Transcriber could not be initialised (missing key, broken token store).
-    Transcriber(String),
+    Transcriber(TranscriberSetupError),
     /// Smart-paste was requested but not available on this system.
     SmartPaste(String),
 }
@@ -93,6 +105,25 @@ impl std::fmt::Display for BuildError {
     }
 }
 
+impl BuildError {
+    /// `error_kind` reported by `--json-errors`.
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Transcriber(TranscriberSetupError::MissingApiKey) => "missing_api_key",
+            Self::Transcriber(_) => "transcriber_setup",
+            Self::SmartPaste(_) => "paste_unavailable",
+        }
+    }
+
+    /// Process exit code for a runner that could not start.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::Transcriber(TranscriberSetupError::MissingApiKey) => exit_codes::AUTH,
+            Self::Transcriber(_) | Self::SmartPaste(_) => exit_codes::ERROR,
+        }
+    }
+}
+
 /// Build the full adapter set for a runner.
 ///
 /// Emits informational lines (`Clipboard: using ...`, `Keystroke: using ...`)
@@ -102,8 +133,18 @@ pub async fn build_adapters(
     opts: &RuntimeOptions,
     presenter: &Presenter,
 ) -> Result<AdapterBundle, BuildError> {
-    let transcriber = create_transcriber(config).map_err(BuildError::Transcriber)?;
-    let recorder = create_recorder();
+    let mut transcriber = create_transcriber(config).map_err(BuildError::Transcriber)?;
+    if opts.raw {
+        // Straight to stderr, bypassing the presenter: the dump must
+        // survive --quiet and never mix into stdout.
+        let sink: RawResponseSink = Arc::new(|body: &str| eprintln!("{body}"));
+        transcriber = transcriber.with_raw_sink(sink);
+    }
+    let recorder = create_recorder(
+        config.audio_device.clone(),
+        config.sample_rate,
+        config.sample_format,
+    );
     let notifier = create_notifier();
 
     let (clipboard, clipboard_tool) = create_clipboard().await;
@@ -135,7 +176,24 @@ pub async fn build_adapters(
     // platforms (or when the flag is off) we plug in the no-op.
     let smart_paste: Box<dyn SmartPaste> = build_smart_paste(opts.paste, presenter).await?;
 
-    let audio_cue: Arc<dyn AudioCue> = Arc::from(create_audio_cue(opts.audio_cue));
+    let (audio_cue, cue_warnings) =
+        create_audio_cue(opts.audio_cue, &config.cue_sounds, config.cue_volume);
+    for warning in &cue_warnings {
+        presenter.warn(warning);
+    }
+    let audio_cue: Arc<dyn AudioCue> = Arc::from(audio_cue);
+
+    let history = config.history.then(|| {
+        HistoryLog::new(
+            Arc::new(JsonlHistoryStore::new(config.history_max_entries)),
+            &config.openai_transcribe_model,
+        )
+    });
+
+    let post_processor = create_post_processor(config.post_command.as_deref());
+    if let Some(ref command) = config.post_command {
+        presenter.info(&format!("Post-command: {}", command));
+    }
 
     Ok(AdapterBundle {
         recorder,
@@ -145,6 +203,8 @@ pub async fn build_adapters(
         notifier,
         smart_paste,
         audio_cue,
+        history,
+        post_processor,
     })
 }
 
@@ -11,6 +11,8 @@ use tokio::sync::mpsc;
 /// Shutdown signal for one-shot mode
 pub struct ShutdownSignal {
     shutdown: Arc<AtomicBool>,
+    /// Extra flags raised alongside `shutdown` (e.g. a recording's stop flag).
+    linked: Vec<Arc<AtomicBool>>,
 }
 
 impl ShutdownSignal {
@@ -18,9 +20,16 @@ impl ShutdownSignal {
     pub fn new() -> Self {
         Self {
             shutdown: Arc::new(AtomicBool::new(false)),
+            linked: Vec::new(),
         }
     }
 
+    /// Builder: also raise `flag` when the signal arrives.
+    pub fn with_linked_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.linked.push(flag);
+        self
+    }
+
     /// Get a clone of the shutdown flag
     pub fn flag(&self) -> Arc<AtomicBool> {
         Arc::clone(&self.shutdown)
@@ -33,7 +42,13 @@ impl ShutdownSignal {
 
     /// Setup signal handler
     pub async fn setup(&self) -> Result<(), std::io::Error> {
-        let shutdown = Arc::clone(&self.shutdown);
+        let mut flags = self.linked.clone();
+        flags.push(Arc::clone(&self.shutdown));
+        let raise = move || {
+            for flag in &flags {
+                flag.store(true, Ordering::SeqCst);
+            }
+        };
 
         #[cfg(unix)]
         {
@@ -41,7 +56,7 @@ impl ShutdownSignal {
             let mut sigint = signal(SignalKind::interrupt())?;
             tokio::spawn(async move {
                 sigint.recv().await;
-                shutdown.store(true, Ordering::SeqCst);
+                raise();
             });
         }
 
@@ -50,7 +65,7 @@ impl ShutdownSignal {
             // Handle Ctrl+C on Windows
             tokio::spawn(async move {
                 tokio::signal::ctrl_c().await.ok();
-                shutdown.store(true, Ordering::SeqCst);
+                raise();
             });
         }
 
@@ -69,15 +84,22 @@ impl Default for ShutdownSignal {
 pub enum DaemonSignal {
     /// Toggle recording
     Toggle,
+    /// Start recording if idle (explicit `start`; a no-op otherwise)
+    Start,
+    /// Stop and transcribe if recording (explicit `stop`; a no-op otherwise)
+    Stop,
     /// Cancel recording
     Cancel,
     /// Shutdown daemon (SIGINT/SIGTERM)
     Shutdown,
+    /// Re-read the config and apply reloadable settings (SIGHUP)
+    Reload,
 }
 
 /// Daemon signal handler
 ///
-/// Handles OS shutdown signals (SIGINT/SIGTERM) and provides a channel
+/// Handles OS shutdown signals (SIGINT/SIGTERM), config reload (SIGHUP on
+/// Unix) and provides a channel
 /// for receiving daemon commands from other sources (e.g., socket server).
 pub struct DaemonSignalHandler {
     receiver: mpsc::Receiver<DaemonSignal>,
@@ -110,6 +132,17 @@ impl DaemonSignalHandler {
                 eprintln!("{} Received SIGTERM (shutdown)", "↓".cyan());
                 let _ = tx_term.send(DaemonSignal::Shutdown).await;
             });
+
+            // Setup SIGHUP handler (reload); unlike the others it repeats.
+            let tx_hup = tx.clone();
+            let mut sighup = signal(SignalKind::hangup())?;
+            tokio::spawn(async move {
+                while sighup.recv().await.is_some() {
+                    if tx_hup.send(DaemonSignal::Reload).await.is_err() {
+                        break;
+                    }
+                }
+            });
         }
 
         #[cfg(windows)]
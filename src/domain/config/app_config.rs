@@ -8,8 +8,11 @@ use std::fmt;
 use std::str::FromStr;
 
 use crate::domain::error::ConfigError;
-use crate::domain::recording::Duration;
+use crate::domain::recording::{Duration, SampleFormatPreference, DEFAULT_VAD_SILENCE_MS};
+use crate::domain::transcription::TextTemplate;
 
+use super::cues::{validate_cue_volume, CueSounds, DEFAULT_CUE_VOLUME};
+use super::indicator::IndicatorStyle;
 use super::platform::PlatformConfig;
 use super::raw::RawAppConfig;
 
@@ -21,6 +24,29 @@ use super::raw::RawAppConfig;
 /// pay the same per-minute rate as `whisper-1`.
 pub const DEFAULT_OPENAI_TRANSCRIBE_MODEL: &str = "gpt-4o-transcribe";
 
+/// Default OpenAI API base URL (API-key auth only).
+///
+/// Overridable via `openai_base_url` / `OPENAI_BASE_URL` so requests can be
+/// routed through a corporate proxy or an OpenAI-compatible gateway.
+pub const DEFAULT_OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+
+/// Default cap on transcription history entries.
+pub const DEFAULT_HISTORY_MAX_ENTRIES: u32 = 500;
+
+/// Default recording sample rate (Hz); speech models are trained at 16 kHz.
+pub const DEFAULT_SAMPLE_RATE: u32 = 16_000;
+
+/// Rates accepted by `sample_rate`, narrowband up to fullband.
+pub const SUPPORTED_SAMPLE_RATES: [u32; 5] = [8_000, 12_000, 16_000, 24_000, 48_000];
+
+/// Default peak level (dBFS) at or below which a recording is treated as
+/// silent. Well under quiet speech, but above a muted or dead microphone.
+pub const DEFAULT_SILENCE_THRESHOLD_DB: i32 = -60;
+
+/// Quietest accepted `silence_threshold`; 16-bit audio bottoms out near
+/// -90 dBFS anyway.
+const MIN_SILENCE_THRESHOLD_DB: i32 = -120;
+
 /// Auth mode selecting which transcription backend to use.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AuthMode {
@@ -71,17 +97,67 @@ pub struct AppConfig {
     pub auth: AuthMode,
     pub openai_api_key: Option<String>,
     pub openai_transcribe_model: String,
+    /// Base URL for the API-key transcriber, without a trailing slash.
+    pub openai_base_url: String,
+    /// Explicit outbound proxy (`http://`, `https://`, `socks5://`,
+    /// `socks5h://`). When unset, the standard proxy env vars apply.
+    pub proxy: Option<String>,
     pub transcribe_prompt: Option<String>,
     pub transcribe_language: Option<String>,
+    /// Text wrapped around each transcript before it is output.
+    pub text_template: TextTemplate,
+    /// Shell command each transcript is piped through before output.
+    pub post_command: Option<String>,
     /// User-supplied one-shot recording duration, if any.
     pub duration: Option<Duration>,
     /// User-supplied maximum duration / daemon safety limit, if any.
     pub max_duration: Option<Duration>,
+    /// Recordings shorter than this are discarded without transcribing.
+    pub min_duration: Option<Duration>,
+    /// Peak level (dBFS) at or below which a recording is rejected as
+    /// silent before transcribing. `None` disables the check.
+    pub silence_threshold: Option<i32>,
+    /// Input device to record from; `None` uses the system default.
+    pub audio_device: Option<String>,
+    /// Rate (Hz) recordings are resampled to before encoding.
+    pub sample_rate: u32,
+    /// Sample format preferred when opening the input device.
+    pub sample_format: SampleFormatPreference,
     pub clipboard: bool,
     pub keystroke: bool,
     pub notify: bool,
+    /// Notify when a one-shot recording or transcription fails.
+    pub notify_on_error: bool,
     pub audio_cue: bool,
+    /// Playback volume for audio cues (`1.0` = unchanged).
+    pub cue_volume: f32,
+    /// Custom sound files for audio cues.
+    pub cue_sounds: CueSounds,
+    /// Reuse transcripts for identical audio + request parameters.
+    pub cache: bool,
+    /// Append successful transcriptions to the history file.
+    pub history: bool,
+    /// Oldest history entries beyond this count are dropped.
+    pub history_max_entries: usize,
+    /// Shared secret IPC clients must present (`auth <token>`) before any
+    /// daemon command. `None` leaves control unauthenticated.
+    pub ipc_token: Option<String>,
+    /// Prefix each daemon transcription on stdout with its UTC time.
+    pub daemon_output_timestamp: bool,
+    /// Line printed between daemon transcriptions; `None` prints none.
+    pub daemon_output_separator: Option<String>,
+    /// Prefix each daemon transcription with the active profile name.
+    pub daemon_output_profile: bool,
+    /// Stop a daemon recording by itself once speech is followed by
+    /// [`daemon_vad_silence`](Self::daemon_vad_silence) of quiet.
+    pub daemon_vad: bool,
+    pub daemon_vad_silence: Duration,
     pub platform: PlatformConfig,
+    /// Recording-indicator geometry and colours.
+    pub indicator: IndicatorStyle,
+    /// Problems found while resolving `[indicator]`; the affected fields
+    /// fell back to their defaults.
+    pub indicator_warnings: Vec<String>,
 }
 
 impl Default for AppConfig {
@@ -90,15 +166,38 @@ impl Default for AppConfig {
             auth: AuthMode::default(),
             openai_api_key: None,
             openai_transcribe_model: DEFAULT_OPENAI_TRANSCRIBE_MODEL.to_string(),
+            openai_base_url: DEFAULT_OPENAI_BASE_URL.to_string(),
+            proxy: None,
             transcribe_prompt: None,
             transcribe_language: None,
+            text_template: TextTemplate::default(),
+            post_command: None,
             duration: None,
             max_duration: None,
+            min_duration: None,
+            silence_threshold: Some(DEFAULT_SILENCE_THRESHOLD_DB),
+            audio_device: None,
+            sample_rate: DEFAULT_SAMPLE_RATE,
+            sample_format: SampleFormatPreference::default(),
             clipboard: false,
             keystroke: false,
             notify: false,
+            notify_on_error: false,
             audio_cue: false,
+            cue_volume: DEFAULT_CUE_VOLUME,
+            cue_sounds: CueSounds::default(),
+            cache: false,
+            history: true,
+            history_max_entries: DEFAULT_HISTORY_MAX_ENTRIES as usize,
+            ipc_token: None,
+            daemon_output_timestamp: false,
+            daemon_output_separator: None,
+            daemon_output_profile: false,
+            daemon_vad: false,
+            daemon_vad_silence: Duration::from_millis(DEFAULT_VAD_SILENCE_MS),
             platform: PlatformConfig::defaults(),
+            indicator: IndicatorStyle::default(),
+            indicator_warnings: Vec::new(),
         }
     }
 }
@@ -141,6 +240,61 @@ impl TryFrom<RawAppConfig> for AppConfig {
         // --- durations ---------------------------------------------------
         let duration = parse_duration(raw.duration.as_deref(), "duration")?;
         let max_duration = parse_duration(raw.max_duration.as_deref(), "max_duration")?;
+        let min_duration = parse_duration(raw.min_duration.as_deref(), "min_duration")?;
+        let daemon_vad_silence =
+            parse_duration(raw.daemon_vad_silence.as_deref(), "daemon_vad_silence")?
+                .unwrap_or(Duration::from_millis(DEFAULT_VAD_SILENCE_MS));
+
+        // --- silence guard -----------------------------------------------
+        let silence_threshold = validate_silence_threshold(
+            raw.silence_threshold
+                .unwrap_or(DEFAULT_SILENCE_THRESHOLD_DB),
+        )
+        .map_err(|message| ConfigError::ValidationError {
+            key: "silence_threshold".to_string(),
+            message,
+        })?;
+        let silence_threshold = raw
+            .silence_check
+            .unwrap_or(true)
+            .then_some(silence_threshold);
+
+        let sample_rate = validate_sample_rate(raw.sample_rate.unwrap_or(DEFAULT_SAMPLE_RATE))
+            .map_err(|message| ConfigError::ValidationError {
+                key: "sample_rate".to_string(),
+                message,
+            })?;
+        let sample_format = match raw.sample_format.as_deref() {
+            None | Some("") => SampleFormatPreference::default(),
+            Some(s) => s
+                .parse()
+                .map_err(|message: String| ConfigError::ValidationError {
+                    key: "sample_format".to_string(),
+                    message,
+                })?,
+        };
+
+        // --- audio cues --------------------------------------------------
+        let cue_volume = validate_cue_volume(raw.cue_volume.unwrap_or(DEFAULT_CUE_VOLUME))
+            .map_err(|message| ConfigError::ValidationError {
+                key: "cue_volume".to_string(),
+                message,
+            })?;
+        let cue_sounds = CueSounds::from_raw(raw.cues.as_ref());
+
+        // --- API key -----------------------------------------------------
+        // Only validated when it is actually used; an OAuth user with a
+        // stale key in the file should not be locked out.
+        let openai_api_key = match raw.openai_api_key.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(key) if auth == AuthMode::ApiKey => Some(normalize_api_key(key).map_err(
+                |message| ConfigError::ValidationError {
+                    key: "openai_api_key".to_string(),
+                    message,
+                },
+            )?),
+            Some(key) => Some(key.to_string()),
+        };
 
         // --- model -------------------------------------------------------
         let openai_transcribe_model = raw
@@ -148,6 +302,28 @@ impl TryFrom<RawAppConfig> for AppConfig {
             .filter(|s| !s.is_empty())
             .unwrap_or_else(|| DEFAULT_OPENAI_TRANSCRIBE_MODEL.to_string());
 
+        // --- base URL ----------------------------------------------------
+        let openai_base_url = match raw.openai_base_url.as_deref().map(str::trim) {
+            None | Some("") => DEFAULT_OPENAI_BASE_URL.to_string(),
+            Some(s) => normalize_base_url(s).map_err(|message| ConfigError::ValidationError {
+                key: "openai_base_url".to_string(),
+                message,
+            })?,
+        };
+
+        // --- proxy -------------------------------------------------------
+        let proxy = match raw.proxy.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(s) => {
+                Some(
+                    validate_proxy_url(s).map_err(|message| ConfigError::ValidationError {
+                        key: "proxy".to_string(),
+                        message,
+                    })?,
+                )
+            }
+        };
+
         // --- platform sub-config (flat shape) ----------------------------
         let defaults = PlatformConfig::defaults();
         let linux = raw.linux.unwrap_or_default();
@@ -160,27 +336,173 @@ impl TryFrom<RawAppConfig> for AppConfig {
             indicator_position: linux
                 .indicator_position
                 .unwrap_or(defaults.indicator_position),
+            indicator_clickable: linux.indicator_clickable.unwrap_or(false),
             linux_paste: linux.paste.unwrap_or(false),
+            windows_indicator_position: windows
+                .indicator_position
+                .unwrap_or(defaults.windows_indicator_position),
             windows_show_balloon: windows.show_balloon.unwrap_or(false),
         };
 
+        // --- indicator (lenient: bad values warn, never fail) ------------
+        let (indicator, indicator_warnings) = IndicatorStyle::from_raw(raw.indicator.as_ref());
+
         Ok(Self {
             auth,
-            openai_api_key: raw.openai_api_key.filter(|s| !s.is_empty()),
+            openai_api_key,
             openai_transcribe_model,
+            openai_base_url,
+            proxy,
             transcribe_prompt: raw.transcribe_prompt,
             transcribe_language: raw.transcribe_language,
+            text_template: TextTemplate::new(
+                raw.text_prepend.as_deref(),
+                raw.text_append.as_deref(),
+            ),
+            post_command: raw
+                .post_command
+                .map(|c| c.trim().to_string())
+                .filter(|c| !c.is_empty()),
             duration,
             max_duration,
+            min_duration,
+            silence_threshold,
+            audio_device: raw
+                .audio_device
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty() && d != "default"),
+            sample_rate,
+            sample_format,
             clipboard: raw.clipboard.unwrap_or(false),
             keystroke: raw.keystroke.unwrap_or(false),
             notify: raw.notify.unwrap_or(false),
+            notify_on_error: raw.notify_on_error.unwrap_or(false),
             audio_cue: raw.audio_cue.unwrap_or(false),
+            cue_volume,
+            cue_sounds,
+            cache: raw.cache.unwrap_or(false),
+            history: raw.history.unwrap_or(true),
+            history_max_entries: raw
+                .history_max_entries
+                .unwrap_or(DEFAULT_HISTORY_MAX_ENTRIES) as usize,
+            ipc_token: raw
+                .ipc_token
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty()),
+            daemon_output_timestamp: raw.daemon_output_timestamp.unwrap_or(false),
+            daemon_output_separator: raw.daemon_output_separator.filter(|s| !s.is_empty()),
+            daemon_output_profile: raw.daemon_output_profile.unwrap_or(false),
+            daemon_vad: raw.daemon_vad.unwrap_or(false),
+            daemon_vad_silence,
             platform,
+            indicator,
+            indicator_warnings,
         })
     }
 }
 
+/// Validate an API base URL and strip any trailing slash.
+///
+/// Only `http` and `https` are accepted, and a host is required. Paths are
+/// kept as-is so gateways that mount the API under a prefix
+/// (`https://gw.example.com/openai/v1`) work.
+pub fn normalize_base_url(input: &str) -> Result<String, String> {
+    let trimmed = input.trim().trim_end_matches('/');
+    let rest = trimmed
+        .strip_prefix("https://")
+        .or_else(|| trimmed.strip_prefix("http://"))
+        .ok_or_else(|| {
+            format!("Invalid base URL '{input}': must start with http:// or https://")
+        })?;
+    let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+    if host.is_empty() || host.contains(char::is_whitespace) {
+        return Err(format!(
+            "Invalid base URL '{input}': missing or malformed host"
+        ));
+    }
+    if rest.contains(['?', '#']) {
+        return Err(format!(
+            "Invalid base URL '{input}': query strings and fragments are not allowed"
+        ));
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Shortest value accepted as an API key. Deliberately loose: real OpenAI
+/// keys are far longer, but local servers behind `openai_base_url` accept
+/// placeholder keys, and future key formats should not be rejected.
+pub const MIN_API_KEY_LEN: usize = 8;
+
+/// Trim a pasted API key and reject values too short to be one.
+pub fn normalize_api_key(input: &str) -> Result<String, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err("API key cannot be empty".to_string());
+    }
+    if trimmed.chars().count() < MIN_API_KEY_LEN {
+        return Err(format!(
+            "API key is too short ({} characters, need at least {MIN_API_KEY_LEN}); was it truncated when pasted?",
+            trimmed.chars().count()
+        ));
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Describe suspicious whitespace inside an (already trimmed) API key.
+/// Keys never contain spaces or line breaks, so one usually means the key
+/// was wrapped or joined with other text when copied.
+pub fn api_key_warning(key: &str) -> Option<String> {
+    key.contains(char::is_whitespace).then(|| {
+        "API key contains spaces or line breaks; check it was copied in one piece".to_string()
+    })
+}
+
+/// Check a `silence_threshold` is a dBFS level in `-120..=0`.
+pub fn validate_silence_threshold(db: i32) -> Result<i32, String> {
+    if (MIN_SILENCE_THRESHOLD_DB..=0).contains(&db) {
+        Ok(db)
+    } else {
+        Err(format!(
+            "Value must be a level in dBFS between {MIN_SILENCE_THRESHOLD_DB} and 0"
+        ))
+    }
+}
+
+/// Check a `sample_rate` is one of [`SUPPORTED_SAMPLE_RATES`].
+pub fn validate_sample_rate(rate: u32) -> Result<u32, String> {
+    if SUPPORTED_SAMPLE_RATES.contains(&rate) {
+        Ok(rate)
+    } else {
+        let rates: Vec<String> = SUPPORTED_SAMPLE_RATES.iter().map(u32::to_string).collect();
+        Err(format!("Value must be one of {} (Hz)", rates.join(", ")))
+    }
+}
+
+/// Schemes accepted by [`validate_proxy_url`].
+const PROXY_SCHEMES: &[&str] = &["http", "https", "socks5", "socks5h"];
+
+/// Validate an explicit proxy URL (`scheme://[user:pass@]host[:port]`).
+pub fn validate_proxy_url(input: &str) -> Result<String, String> {
+    let trimmed = input.trim();
+    let (scheme, rest) = trimmed
+        .split_once("://")
+        .ok_or_else(|| format!("Invalid proxy URL '{input}': missing scheme"))?;
+    if !PROXY_SCHEMES.contains(&scheme.to_ascii_lowercase().as_str()) {
+        return Err(format!(
+            "Invalid proxy URL '{input}': scheme must be one of {}",
+            PROXY_SCHEMES.join(", ")
+        ));
+    }
+    let authority = rest.trim_end_matches('/');
+    let host = authority.rsplit('@').next().unwrap_or("");
+    if host.is_empty() || host.contains(['/', '?', '#']) || host.contains(char::is_whitespace) {
+        return Err(format!(
+            "Invalid proxy URL '{input}': missing or malformed host"
+        ));
+    }
+    Ok(trimmed.to_string())
+}
+
 fn parse_duration(input: Option<&str>, key: &str) -> Result<Option<Duration>, ConfigError> {
     match input {
         None => Ok(None),
@@ -238,6 +560,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_raw_trims_api_key() {
+        let raw = RawAppConfig {
+            auth: Some("api_key".into()),
+            openai_api_key: Some("  sk-test-1234567890\n".into()),
+            ..Default::default()
+        };
+        let config = AppConfig::try_from(raw).unwrap();
+        assert_eq!(config.openai_api_key.as_deref(), Some("sk-test-1234567890"));
+    }
+
+    #[test]
+    fn from_raw_rejects_short_api_key_only_in_api_key_mode() {
+        let raw = RawAppConfig {
+            auth: Some("api_key".into()),
+            openai_api_key: Some("sk-12".into()),
+            ..Default::default()
+        };
+        match AppConfig::try_from(raw.clone()).unwrap_err() {
+            ConfigError::ValidationError { key, message } => {
+                assert_eq!(key, "openai_api_key");
+                assert!(message.contains("too short"), "{message}");
+            }
+            other => panic!("expected ValidationError, got {other:?}"),
+        }
+
+        let oauth = RawAppConfig {
+            auth: Some("oauth".into()),
+            ..raw
+        };
+        assert!(AppConfig::try_from(oauth).is_ok());
+    }
+
+    #[test]
+    fn api_key_warning_flags_inner_whitespace() {
+        assert!(api_key_warning("sk-abc def").is_some());
+        assert!(api_key_warning("sk-abc\ndef").is_some());
+        assert!(api_key_warning("sk-abcdef").is_none());
+    }
+
     #[test]
     fn from_raw_rejects_invalid_duration() {
         let raw = RawAppConfig {
@@ -251,6 +613,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn from_raw_parses_daemon_vad_silence() {
+        let config = AppConfig::try_from(RawAppConfig::default()).unwrap();
+        assert!(!config.daemon_vad);
+        assert_eq!(
+            config.daemon_vad_silence.as_millis(),
+            DEFAULT_VAD_SILENCE_MS
+        );
+
+        let raw = RawAppConfig {
+            daemon_vad: Some(true),
+            daemon_vad_silence: Some("2s".to_string()),
+            ..Default::default()
+        };
+        let config = AppConfig::try_from(raw).unwrap();
+        assert!(config.daemon_vad);
+        assert_eq!(config.daemon_vad_silence.as_millis(), 2_000);
+
+        let raw = RawAppConfig {
+            daemon_vad_silence: Some("soon".to_string()),
+            ..Default::default()
+        };
+        assert!(AppConfig::try_from(raw).is_err());
+    }
+
+    #[test]
+    fn from_raw_accepts_only_supported_sample_rates() {
+        let config = AppConfig::try_from(RawAppConfig::default()).unwrap();
+        assert_eq!(config.sample_rate, DEFAULT_SAMPLE_RATE);
+
+        let raw = RawAppConfig {
+            sample_rate: Some(48_000),
+            ..Default::default()
+        };
+        assert_eq!(AppConfig::try_from(raw).unwrap().sample_rate, 48_000);
+
+        let raw = RawAppConfig {
+            sample_rate: Some(44_100),
+            ..Default::default()
+        };
+        match AppConfig::try_from(raw).unwrap_err() {
+            ConfigError::ValidationError { key, message } => {
+                assert_eq!(key, "sample_rate");
+                assert!(message.contains("24000"), "{message}");
+            }
+            other => panic!("expected ValidationError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn from_raw_parses_sample_format() {
+        let config = AppConfig::try_from(RawAppConfig::default()).unwrap();
+        assert_eq!(config.sample_format, SampleFormatPreference::Auto);
+
+        let raw = RawAppConfig {
+            sample_format: Some("f32".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            AppConfig::try_from(raw).unwrap().sample_format,
+            SampleFormatPreference::F32
+        );
+
+        let raw = RawAppConfig {
+            sample_format: Some("u8".into()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            AppConfig::try_from(raw),
+            Err(ConfigError::ValidationError { key, .. }) if key == "sample_format"
+        ));
+    }
+
     #[test]
     fn from_raw_parses_duration() {
         let raw = RawAppConfig {
@@ -281,6 +716,85 @@ mod tests {
         assert!(config.openai_api_key.is_none());
     }
 
+    #[test]
+    fn from_raw_treats_default_audio_device_as_unset() {
+        for device in ["", "  ", "default"] {
+            let raw = RawAppConfig {
+                audio_device: Some(device.into()),
+                ..Default::default()
+            };
+            assert_eq!(AppConfig::try_from(raw).unwrap().audio_device, None);
+        }
+        let raw = RawAppConfig {
+            audio_device: Some(" pipewire ".into()),
+            ..Default::default()
+        };
+        let config = AppConfig::try_from(raw).unwrap();
+        assert_eq!(config.audio_device.as_deref(), Some("pipewire"));
+    }
+
+    #[test]
+    fn from_raw_defaults_base_url() {
+        let config = AppConfig::try_from(RawAppConfig::empty()).unwrap();
+        assert_eq!(config.openai_base_url, DEFAULT_OPENAI_BASE_URL);
+    }
+
+    #[test]
+    fn from_raw_normalizes_base_url() {
+        let raw = RawAppConfig {
+            openai_base_url: Some("https://gw.example.com/openai/v1/".into()),
+            ..Default::default()
+        };
+        let config = AppConfig::try_from(raw).unwrap();
+        assert_eq!(config.openai_base_url, "https://gw.example.com/openai/v1");
+    }
+
+    #[test]
+    fn from_raw_rejects_malformed_base_url() {
+        for bad in [
+            "gw.example.com",
+            "ftp://gw.example.com",
+            "https://",
+            "https://h/v1?x=1",
+        ] {
+            let raw = RawAppConfig {
+                openai_base_url: Some(bad.into()),
+                ..Default::default()
+            };
+            match AppConfig::try_from(raw).unwrap_err() {
+                ConfigError::ValidationError { key, .. } => assert_eq!(key, "openai_base_url"),
+                other => panic!("expected ValidationError for {bad}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn from_raw_accepts_socks_proxy() {
+        let raw = RawAppConfig {
+            proxy: Some("socks5h://user:pw@127.0.0.1:1080".into()),
+            ..Default::default()
+        };
+        let config = AppConfig::try_from(raw).unwrap();
+        assert_eq!(
+            config.proxy.as_deref(),
+            Some("socks5h://user:pw@127.0.0.1:1080")
+        );
+    }
+
+    #[test]
+    fn from_raw_rejects_bad_proxy() {
+        for bad in ["127.0.0.1:3128", "ftp://proxy:21", "http://"] {
+            let raw = RawAppConfig {
+                proxy: Some(bad.into()),
+                ..Default::default()
+            };
+            match AppConfig::try_from(raw).unwrap_err() {
+                ConfigError::ValidationError { key, .. } => assert_eq!(key, "proxy"),
+                other => panic!("expected ValidationError for {bad}, got {other:?}"),
+            }
+        }
+    }
+
     #[test]
     fn auth_mode_parses() {
         assert_eq!(AuthMode::from_str("oauth"), Ok(AuthMode::Oauth));
@@ -0,0 +1,82 @@
+//! Audio-cue sounds (`[cues]` table) and playback volume.
+//!
+//! Sound paths are only resolved here; whether a file exists and decodes
+//! is the audio adapter's call, and a bad file falls back to the built-in
+//! tone with a warning rather than failing startup.
+
+use std::path::PathBuf;
+
+use super::raw::RawCuesConfig;
+
+/// Default cue volume (rodio sink multiplier; `1.0` = unchanged).
+pub const DEFAULT_CUE_VOLUME: f32 = 1.0;
+/// Loudest accepted `cue_volume`; above `1.0` amplifies quiet files.
+pub const MAX_CUE_VOLUME: f32 = 2.0;
+
+/// User-supplied sound file per cue; `None` keeps the built-in tone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CueSounds {
+    pub start: Option<PathBuf>,
+    pub stop: Option<PathBuf>,
+    pub cancel: Option<PathBuf>,
+}
+
+impl CueSounds {
+    /// Resolve the raw table, treating blank paths as unset.
+    pub fn from_raw(raw: Option<&RawCuesConfig>) -> Self {
+        let Some(raw) = raw else {
+            return Self::default();
+        };
+        let path = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(PathBuf::from)
+        };
+        Self {
+            start: path(&raw.start_sound),
+            stop: path(&raw.stop_sound),
+            cancel: path(&raw.cancel_sound),
+        }
+    }
+}
+
+/// Check a `cue_volume` lies in `0.0..=2.0`.
+pub fn validate_cue_volume(volume: f32) -> Result<f32, String> {
+    if (0.0..=MAX_CUE_VOLUME).contains(&volume) {
+        Ok(volume)
+    } else {
+        Err(format!(
+            "Value must be a number between 0.0 and {MAX_CUE_VOLUME:.1}"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_raw_keeps_set_paths_and_drops_blank_ones() {
+        let raw = RawCuesConfig {
+            start_sound: Some("/sounds/start.wav".into()),
+            stop_sound: Some("  ".into()),
+            cancel_sound: None,
+        };
+        let sounds = CueSounds::from_raw(Some(&raw));
+        assert_eq!(sounds.start, Some(PathBuf::from("/sounds/start.wav")));
+        assert_eq!(sounds.stop, None);
+        assert_eq!(sounds.cancel, None);
+        assert_eq!(CueSounds::from_raw(None), CueSounds::default());
+    }
+
+    #[test]
+    fn cue_volume_range() {
+        assert_eq!(validate_cue_volume(0.0), Ok(0.0));
+        assert_eq!(validate_cue_volume(1.5), Ok(1.5));
+        assert!(validate_cue_volume(-0.1).is_err());
+        assert!(validate_cue_volume(2.5).is_err());
+        assert!(validate_cue_volume(f32::NAN).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+//! `${VAR}` expansion for string values read from the config file.
+//!
+//! Lets secrets live outside `config.toml`
+//! (`openai_api_key = "${OPENAI_KEY_FROM_VAULT}"`). Rules:
+//!
+//! - `${NAME}` is replaced by the variable's value; `NAME` is
+//!   `[A-Za-z_][A-Za-z0-9_]*`. A variable that is set but empty expands to
+//!   the empty string.
+//! - An unset variable is an error rather than a silent empty value, so a
+//!   missing secret fails loudly at startup.
+//! - `$$` is a literal `$`. Any other `$` is kept as-is.
+
+/// Expand `${NAME}` references in `value` using `lookup`.
+///
+/// Returns a human-readable message on failure; callers attach the key.
+pub fn expand_env_vars(
+    value: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+
+        if let Some(tail) = after.strip_prefix('$') {
+            out.push('$');
+            rest = tail;
+        } else if let Some(tail) = after.strip_prefix('{') {
+            let end = tail
+                .find('}')
+                .ok_or_else(|| format!("Unterminated '${{' in \"{}\"", value))?;
+            let name = &tail[..end];
+            if !is_valid_name(name) {
+                return Err(format!("Invalid variable name '{}'", name));
+            }
+            let resolved =
+                lookup(name).ok_or_else(|| format!("Environment variable {} is not set", name))?;
+            out.push_str(&resolved);
+            rest = &tail[end + 1..];
+        } else {
+            out.push('$');
+            rest = after;
+        }
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "SECRET" => Some("sk-123".into()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn expands_set_variable() {
+        assert_eq!(expand_env_vars("${SECRET}", &env).unwrap(), "sk-123");
+        assert_eq!(
+            expand_env_vars("key=${SECRET};", &env).unwrap(),
+            "key=sk-123;"
+        );
+        assert_eq!(expand_env_vars("${EMPTY}", &env).unwrap(), "");
+    }
+
+    #[test]
+    fn unset_variable_is_an_error() {
+        let err = expand_env_vars("${MISSING}", &env).unwrap_err();
+        assert!(err.contains("MISSING"));
+    }
+
+    #[test]
+    fn double_dollar_is_literal() {
+        assert_eq!(expand_env_vars("a$$b", &env).unwrap(), "a$b");
+        assert_eq!(expand_env_vars("$${SECRET}", &env).unwrap(), "${SECRET}");
+    }
+
+    #[test]
+    fn other_dollars_pass_through() {
+        assert_eq!(expand_env_vars("cost $5", &env).unwrap(), "cost $5");
+        assert_eq!(expand_env_vars("$", &env).unwrap(), "$");
+        assert_eq!(expand_env_vars("no vars", &env).unwrap(), "no vars");
+    }
+
+    #[test]
+    fn malformed_references_are_errors() {
+        assert!(expand_env_vars("${SECRET", &env).is_err());
+        assert!(expand_env_vars("${}", &env).is_err());
+        assert!(expand_env_vars("${1A}", &env).is_err());
+    }
+}
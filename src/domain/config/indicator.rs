@@ -0,0 +1,189 @@
+//! Recording-indicator appearance (`[indicator]` table).
+//!
+//! Unlike the rest of [`super::AppConfig`], a malformed value here is not
+//! fatal: a typo in a colour should not stop the daemon from recording, so
+//! [`IndicatorStyle::from_raw`] falls back to the default for that field and
+//! reports a warning instead.
+
+use super::raw::RawIndicatorConfig;
+
+/// Default overlay size (compact, time-only display).
+pub const DEFAULT_INDICATOR_WIDTH: u32 = 100;
+pub const DEFAULT_INDICATOR_HEIGHT: u32 = 44;
+/// Default distance from the screen edge.
+pub const DEFAULT_INDICATOR_MARGIN: u32 = 20;
+
+/// Smallest size that still fits the state dot and `m:ss` label.
+pub const MIN_INDICATOR_WIDTH: u32 = 80;
+pub const MIN_INDICATOR_HEIGHT: u32 = 24;
+/// Upper bound for width, height and margin.
+pub const MAX_INDICATOR_DIMENSION: u32 = 1000;
+
+const DEFAULT_BACKGROUND: Rgba = [30, 30, 30, 220];
+const DEFAULT_RECORDING: Rgba = [220, 50, 50, 255];
+const DEFAULT_PROCESSING: Rgba = [255, 180, 50, 255];
+
+/// Straight (non-premultiplied) RGBA colour.
+pub type Rgba = [u8; 4];
+
+/// Resolved indicator geometry and colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndicatorStyle {
+    pub width: u32,
+    pub height: u32,
+    pub margin: u32,
+    pub background: Rgba,
+    pub recording: Rgba,
+    pub processing: Rgba,
+}
+
+impl Default for IndicatorStyle {
+    fn default() -> Self {
+        Self {
+            width: DEFAULT_INDICATOR_WIDTH,
+            height: DEFAULT_INDICATOR_HEIGHT,
+            margin: DEFAULT_INDICATOR_MARGIN,
+            background: DEFAULT_BACKGROUND,
+            recording: DEFAULT_RECORDING,
+            processing: DEFAULT_PROCESSING,
+        }
+    }
+}
+
+impl IndicatorStyle {
+    /// Resolve the raw table, replacing malformed fields with defaults.
+    ///
+    /// Returns the style plus one human-readable warning per rejected field.
+    pub fn from_raw(raw: Option<&RawIndicatorConfig>) -> (Self, Vec<String>) {
+        let mut style = Self::default();
+        let mut warnings = Vec::new();
+        let Some(raw) = raw else {
+            return (style, warnings);
+        };
+
+        let mut dimension = |key: &str, value: Option<u32>, min: u32, slot: &mut u32| {
+            if let Some(v) = value {
+                match validate_dimension(v, min) {
+                    Ok(v) => *slot = v,
+                    Err(e) => warnings.push(format!("indicator.{key}: {e}; using {}", *slot)),
+                }
+            }
+        };
+        dimension("width", raw.width, MIN_INDICATOR_WIDTH, &mut style.width);
+        dimension(
+            "height",
+            raw.height,
+            MIN_INDICATOR_HEIGHT,
+            &mut style.height,
+        );
+        dimension("margin", raw.margin, 0, &mut style.margin);
+
+        let mut color = |key: &str, value: Option<&str>, slot: &mut Rgba| {
+            if let Some(v) = value {
+                match parse_hex_color(v) {
+                    Ok(c) => *slot = c,
+                    Err(e) => warnings.push(format!("indicator.{key}: {e}; using default")),
+                }
+            }
+        };
+        color(
+            "background",
+            raw.background.as_deref(),
+            &mut style.background,
+        );
+        color(
+            "recording_color",
+            raw.recording_color.as_deref(),
+            &mut style.recording,
+        );
+        color(
+            "processing_color",
+            raw.processing_color.as_deref(),
+            &mut style.processing,
+        );
+
+        (style, warnings)
+    }
+}
+
+/// Check an indicator dimension lies within `min..=MAX_INDICATOR_DIMENSION`.
+pub fn validate_dimension(value: u32, min: u32) -> Result<u32, String> {
+    if (min..=MAX_INDICATOR_DIMENSION).contains(&value) {
+        Ok(value)
+    } else {
+        Err(format!(
+            "{value} is out of range ({min}-{MAX_INDICATOR_DIMENSION})"
+        ))
+    }
+}
+
+/// Parse `#RRGGBB` or `#RRGGBBAA` (the `#` is optional).
+pub fn parse_hex_color(input: &str) -> Result<Rgba, String> {
+    let hex = input.trim().trim_start_matches('#');
+    if !matches!(hex.len(), 6 | 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!(
+            "Invalid color '{input}': expected #RRGGBB or #RRGGBBAA"
+        ));
+    }
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or(0);
+    let alpha = if hex.len() == 8 { byte(6) } else { 255 };
+    Ok([byte(0), byte(2), byte(4), alpha])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_hex_color_accepts_rgb_and_rgba() {
+        assert_eq!(parse_hex_color("#dc3232").unwrap(), [220, 50, 50, 255]);
+        assert_eq!(parse_hex_color("1E1E1EDC").unwrap(), [30, 30, 30, 220]);
+    }
+
+    #[test]
+    fn parse_hex_color_rejects_malformed() {
+        for bad in ["", "#fff", "#12345", "#gg0000", "#1234567", "red"] {
+            assert!(parse_hex_color(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn from_raw_without_table_is_default() {
+        let (style, warnings) = IndicatorStyle::from_raw(None);
+        assert_eq!(style, IndicatorStyle::default());
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn from_raw_applies_valid_fields() {
+        let raw = RawIndicatorConfig {
+            width: Some(140),
+            margin: Some(0),
+            recording_color: Some("#00ff00".into()),
+            ..Default::default()
+        };
+        let (style, warnings) = IndicatorStyle::from_raw(Some(&raw));
+        assert!(warnings.is_empty());
+        assert_eq!(style.width, 140);
+        assert_eq!(style.height, DEFAULT_INDICATOR_HEIGHT);
+        assert_eq!(style.margin, 0);
+        assert_eq!(style.recording, [0, 255, 0, 255]);
+    }
+
+    #[test]
+    fn from_raw_falls_back_on_malformed_fields() {
+        let raw = RawIndicatorConfig {
+            width: Some(10),
+            background: Some("#nothex".into()),
+            processing_color: Some("#112233".into()),
+            ..Default::default()
+        };
+        let (style, warnings) = IndicatorStyle::from_raw(Some(&raw));
+        assert_eq!(style.width, DEFAULT_INDICATOR_WIDTH);
+        assert_eq!(style.background, DEFAULT_BACKGROUND);
+        assert_eq!(style.processing, [0x11, 0x22, 0x33, 255]);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].starts_with("indicator.width"));
+        assert!(warnings[1].starts_with("indicator.background"));
+    }
+}
@@ -10,9 +10,25 @@
 //!   validation happens.
 
 mod app_config;
+mod cues;
+mod expand;
+mod indicator;
 mod platform;
 mod raw;
 
-pub use app_config::{AppConfig, AuthMode, DEFAULT_OPENAI_TRANSCRIBE_MODEL};
+pub use app_config::{
+    api_key_warning, normalize_api_key, normalize_base_url, validate_proxy_url,
+    validate_sample_rate, validate_silence_threshold, AppConfig, AuthMode,
+    DEFAULT_HISTORY_MAX_ENTRIES, DEFAULT_OPENAI_BASE_URL, DEFAULT_OPENAI_TRANSCRIBE_MODEL,
+    DEFAULT_SAMPLE_RATE, DEFAULT_SILENCE_THRESHOLD_DB, MIN_API_KEY_LEN, SUPPORTED_SAMPLE_RATES,
+};
+pub use cues::{validate_cue_volume, CueSounds, DEFAULT_CUE_VOLUME, MAX_CUE_VOLUME};
+pub use indicator::{
+    parse_hex_color, validate_dimension, IndicatorStyle, Rgba, MIN_INDICATOR_HEIGHT,
+    MIN_INDICATOR_WIDTH,
+};
 pub use platform::PlatformConfig;
-pub use raw::{RawAppConfig, RawLinuxConfig, RawWindowsConfig};
+pub use raw::{
+    RawAppConfig, RawCuesConfig, RawIndicatorConfig, RawLinuxConfig, RawWindowsConfig,
+    CONFIG_VERSION,
+};
@@ -10,7 +10,7 @@
 #[derive(Debug, Clone)]
 pub struct PlatformConfig {
     /// Preferred keystroke backend (`enigo`, `xdotool`, `wtype`, `ydotool`,
-    /// or `auto`). Default `enigo`.
+    /// `kdotool`, or `auto`). Default `enigo`.
     pub keystroke_tool: String,
     /// Show the platform-native recording indicator (Wayland overlay on
     /// Linux, system tray on Windows).
@@ -18,9 +18,15 @@ pub struct PlatformConfig {
     /// Indicator anchor for the Linux overlay (`top-right`,
     /// `bottom-left`, …). Ignored on other platforms.
     pub indicator_position: String,
+    /// Let a click on the Linux overlay stop the recording. Off by default,
+    /// leaving the overlay click-through.
+    pub indicator_clickable: bool,
     /// Smart paste (capture-then-paste) on Linux KDE Wayland.
     /// `false` and ignored on non-Linux.
     pub linux_paste: bool,
+    /// Indicator corner for the Windows overlay. Ignored on other
+    /// platforms.
+    pub windows_indicator_position: String,
     /// Show Windows balloon notifications. `false` and ignored on
     /// non-Windows.
     pub windows_show_balloon: bool,
@@ -33,7 +39,9 @@ impl PlatformConfig {
             keystroke_tool: "enigo".to_string(),
             indicator: false,
             indicator_position: "top-right".to_string(),
+            indicator_clickable: false,
             linux_paste: false,
+            windows_indicator_position: "top-right".to_string(),
             windows_show_balloon: false,
         }
     }
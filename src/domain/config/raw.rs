@@ -12,10 +12,23 @@
 //! by the infrastructure adapter, but the TOML crate itself never leaks into
 //! the domain.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
-use super::app_config::DEFAULT_OPENAI_TRANSCRIBE_MODEL;
+use super::app_config::{
+    DEFAULT_HISTORY_MAX_ENTRIES, DEFAULT_OPENAI_TRANSCRIBE_MODEL, DEFAULT_SAMPLE_RATE,
+    DEFAULT_SILENCE_THRESHOLD_DB,
+};
+use super::cues::DEFAULT_CUE_VOLUME;
+use super::expand::expand_env_vars;
 use super::AuthMode;
+use crate::domain::error::ConfigError;
+use crate::domain::recording::SampleFormatPreference;
+
+/// Layout version of the config file, bumped whenever keys move. Files
+/// without a `version` predate it; `config migrate` upgrades them.
+pub const CONFIG_VERSION: u32 = 1;
 
 /// Linux-specific raw configuration (all fields optional).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -23,6 +36,7 @@ pub struct RawLinuxConfig {
     pub keystroke_tool: Option<String>,
     pub indicator: Option<bool>,
     pub indicator_position: Option<String>,
+    pub indicator_clickable: Option<bool>,
     pub paste: Option<bool>,
 }
 
@@ -30,9 +44,33 @@ pub struct RawLinuxConfig {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RawWindowsConfig {
     pub indicator: Option<bool>,
+    pub indicator_position: Option<String>,
     pub show_balloon: Option<bool>,
 }
 
+/// Recording-indicator appearance (all fields optional).
+///
+/// Resolved leniently by [`IndicatorStyle::from_raw`](super::IndicatorStyle::from_raw).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RawIndicatorConfig {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub margin: Option<u32>,
+    pub background: Option<String>,
+    pub recording_color: Option<String>,
+    pub processing_color: Option<String>,
+}
+
+/// Custom audio-cue sound files (all fields optional).
+///
+/// Resolved by [`CueSounds::from_raw`](super::CueSounds::from_raw).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RawCuesConfig {
+    pub start_sound: Option<String>,
+    pub stop_sound: Option<String>,
+    pub cancel_sound: Option<String>,
+}
+
 /// Raw (unvalidated) application configuration as persisted to disk.
 ///
 /// All fields are optional to support partial configs and layered merging
@@ -40,46 +78,114 @@ pub struct RawWindowsConfig {
 /// with [`AppConfig::try_from`](super::AppConfig::try_from).
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RawAppConfig {
+    /// File layout version ([`CONFIG_VERSION`]); not a setting.
+    pub version: Option<u32>,
     pub auth: Option<String>,
     pub openai_api_key: Option<String>,
     pub openai_transcribe_model: Option<String>,
+    pub openai_base_url: Option<String>,
+    pub proxy: Option<String>,
     pub transcribe_prompt: Option<String>,
     pub transcribe_language: Option<String>,
+    /// Text placed before / after each transcript; see
+    /// [`TextTemplate`](crate::domain::transcription::TextTemplate).
+    pub text_prepend: Option<String>,
+    pub text_append: Option<String>,
+    /// Shell command each transcript is piped through before output.
+    pub post_command: Option<String>,
     pub duration: Option<String>,
     pub max_duration: Option<String>,
+    pub min_duration: Option<String>,
+    pub silence_check: Option<bool>,
+    pub silence_threshold: Option<i32>,
+    /// Input device name; unset or `default` uses the system default.
+    pub audio_device: Option<String>,
+    /// Recording sample rate in Hz (one of `SUPPORTED_SAMPLE_RATES`).
+    pub sample_rate: Option<u32>,
+    /// Capture sample format preference: `auto`, `i16` or `f32`.
+    pub sample_format: Option<String>,
     pub clipboard: Option<bool>,
     pub keystroke: Option<bool>,
     pub notify: Option<bool>,
+    pub notify_on_error: Option<bool>,
     pub audio_cue: Option<bool>,
+    pub cue_volume: Option<f32>,
+    pub cache: Option<bool>,
+    pub history: Option<bool>,
+    pub history_max_entries: Option<u32>,
+    pub ipc_token: Option<String>,
+    /// Prefix each daemon transcription with its UTC completion time.
+    pub daemon_output_timestamp: Option<bool>,
+    /// Line printed between daemon transcriptions.
+    pub daemon_output_separator: Option<String>,
+    /// Prefix each daemon transcription with the active `--profile`.
+    pub daemon_output_profile: Option<bool>,
+    /// Stop a daemon recording by itself once speech is followed by silence.
+    pub daemon_vad: Option<bool>,
+    /// Silence after speech that ends a `daemon_vad` recording.
+    pub daemon_vad_silence: Option<String>,
     pub linux: Option<RawLinuxConfig>,
     pub windows: Option<RawWindowsConfig>,
+    pub indicator: Option<RawIndicatorConfig>,
+    pub cues: Option<RawCuesConfig>,
+    /// Named overrides (`[profiles.<name>]`), applied over the rest of the
+    /// file by [`Self::select_profile`].
+    pub profiles: Option<BTreeMap<String, RawAppConfig>>,
 }
 
 impl RawAppConfig {
     /// Static default values, used as the bottom layer of the merge stack.
     pub fn defaults() -> Self {
         Self {
+            version: Some(CONFIG_VERSION),
             auth: Some(AuthMode::default().to_string()),
             openai_api_key: None,
             openai_transcribe_model: Some(DEFAULT_OPENAI_TRANSCRIBE_MODEL.to_string()),
+            openai_base_url: None,
+            proxy: None,
             transcribe_prompt: None,
             transcribe_language: None,
+            text_prepend: None,
+            text_append: None,
+            post_command: None,
             duration: None,
             max_duration: None,
+            min_duration: None,
+            silence_check: Some(true),
+            silence_threshold: Some(DEFAULT_SILENCE_THRESHOLD_DB),
+            audio_device: None,
+            sample_rate: Some(DEFAULT_SAMPLE_RATE),
+            sample_format: Some(SampleFormatPreference::default().to_string()),
             clipboard: Some(false),
             keystroke: Some(false),
             notify: Some(false),
+            notify_on_error: Some(false),
             audio_cue: Some(false),
+            cue_volume: Some(DEFAULT_CUE_VOLUME),
+            cache: Some(false),
+            history: Some(true),
+            history_max_entries: Some(DEFAULT_HISTORY_MAX_ENTRIES),
+            ipc_token: None,
+            daemon_output_timestamp: Some(false),
+            daemon_output_separator: None,
+            daemon_output_profile: Some(false),
+            daemon_vad: Some(false),
+            daemon_vad_silence: None,
             linux: Some(RawLinuxConfig {
                 keystroke_tool: Some("enigo".to_string()),
                 indicator: Some(false),
                 indicator_position: Some("top-right".to_string()),
+                indicator_clickable: Some(false),
                 paste: Some(false),
             }),
             windows: Some(RawWindowsConfig {
                 indicator: Some(false),
+                indicator_position: Some("top-right".to_string()),
                 show_balloon: Some(false),
             }),
+            indicator: None,
+            cues: None,
+            profiles: None,
         }
     }
 
@@ -93,23 +199,240 @@ impl RawAppConfig {
     /// Designed for layered configuration (`defaults → file → env → CLI`).
     pub fn merge(self, other: Self) -> Self {
         Self {
+            version: other.version.or(self.version),
             auth: other.auth.or(self.auth),
             openai_api_key: other.openai_api_key.or(self.openai_api_key),
             openai_transcribe_model: other
                 .openai_transcribe_model
                 .or(self.openai_transcribe_model),
+            openai_base_url: other.openai_base_url.or(self.openai_base_url),
+            proxy: other.proxy.or(self.proxy),
             transcribe_prompt: other.transcribe_prompt.or(self.transcribe_prompt),
             transcribe_language: other.transcribe_language.or(self.transcribe_language),
+            text_prepend: other.text_prepend.or(self.text_prepend),
+            text_append: other.text_append.or(self.text_append),
+            post_command: other.post_command.or(self.post_command),
             duration: other.duration.or(self.duration),
             max_duration: other.max_duration.or(self.max_duration),
+            min_duration: other.min_duration.or(self.min_duration),
+            silence_check: other.silence_check.or(self.silence_check),
+            silence_threshold: other.silence_threshold.or(self.silence_threshold),
+            audio_device: other.audio_device.or(self.audio_device),
+            sample_rate: other.sample_rate.or(self.sample_rate),
+            sample_format: other.sample_format.or(self.sample_format),
             clipboard: other.clipboard.or(self.clipboard),
             keystroke: other.keystroke.or(self.keystroke),
             notify: other.notify.or(self.notify),
+            notify_on_error: other.notify_on_error.or(self.notify_on_error),
             audio_cue: other.audio_cue.or(self.audio_cue),
+            cue_volume: other.cue_volume.or(self.cue_volume),
+            cache: other.cache.or(self.cache),
+            history: other.history.or(self.history),
+            history_max_entries: other.history_max_entries.or(self.history_max_entries),
+            ipc_token: other.ipc_token.or(self.ipc_token),
+            daemon_output_timestamp: other
+                .daemon_output_timestamp
+                .or(self.daemon_output_timestamp),
+            daemon_output_separator: other
+                .daemon_output_separator
+                .or(self.daemon_output_separator),
+            daemon_output_profile: other.daemon_output_profile.or(self.daemon_output_profile),
+            daemon_vad: other.daemon_vad.or(self.daemon_vad),
+            daemon_vad_silence: other.daemon_vad_silence.or(self.daemon_vad_silence),
             linux: merge_linux(self.linux, other.linux),
             windows: merge_windows(self.windows, other.windows),
+            indicator: merge_indicator(self.indicator, other.indicator),
+            cues: merge_cues(self.cues, other.cues),
+            profiles: other.profiles.or(self.profiles),
+        }
+    }
+
+    /// Overlay the `[profiles.<name>]` table on the rest of this (file)
+    /// layer, so the profile sits between the file and env layers.
+    ///
+    /// The returned config carries no `profiles`; profiles don't nest.
+    pub fn select_profile(mut self, name: &str) -> Result<Self, ConfigError> {
+        let mut profiles = self.profiles.take().unwrap_or_default();
+        match profiles.remove(name) {
+            Some(mut profile) => {
+                profile.profiles = None;
+                Ok(self.merge(profile))
+            }
+            None => Err(ConfigError::UnknownProfile {
+                name: name.to_string(),
+                available: if profiles.is_empty() {
+                    "(none)".to_string()
+                } else {
+                    profiles.keys().cloned().collect::<Vec<_>>().join(", ")
+                },
+            }),
         }
     }
+
+    /// Expand `${VAR}` references in every string field.
+    ///
+    /// Applied to the file layer only; env and CLI values are taken
+    /// literally. See [`expand_env_vars`] for the rules. Left alone:
+    /// `post_command`, which runs through a shell that expands variables
+    /// itself, and `profiles`, which are expanded once selected.
+    ///
+    /// Every field is named below, so a new one doesn't compile until it
+    /// is either expanded or added to the exclusions above.
+    pub fn expand_env(self, lookup: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let expand = |key: &str, value: Option<String>| -> Result<Option<String>, ConfigError> {
+            value
+                .map(|v| {
+                    expand_env_vars(&v, &lookup).map_err(|message| ConfigError::ValidationError {
+                        key: key.to_string(),
+                        message,
+                    })
+                })
+                .transpose()
+        };
+
+        let Self {
+            version,
+            auth,
+            openai_api_key,
+            openai_transcribe_model,
+            openai_base_url,
+            proxy,
+            transcribe_prompt,
+            transcribe_language,
+            text_prepend,
+            text_append,
+            post_command,
+            duration,
+            max_duration,
+            min_duration,
+            silence_check,
+            silence_threshold,
+            audio_device,
+            sample_rate,
+            sample_format,
+            clipboard,
+            keystroke,
+            notify,
+            notify_on_error,
+            audio_cue,
+            cue_volume,
+            cache,
+            history,
+            history_max_entries,
+            ipc_token,
+            daemon_output_timestamp,
+            daemon_output_separator,
+            daemon_output_profile,
+            daemon_vad,
+            daemon_vad_silence,
+            linux,
+            windows,
+            indicator,
+            cues,
+            profiles,
+        } = self;
+
+        let linux = match linux {
+            Some(RawLinuxConfig {
+                keystroke_tool,
+                indicator,
+                indicator_position,
+                indicator_clickable,
+                paste,
+            }) => Some(RawLinuxConfig {
+                keystroke_tool: expand("linux.keystroke_tool", keystroke_tool)?,
+                indicator,
+                indicator_position: expand("linux.indicator_position", indicator_position)?,
+                indicator_clickable,
+                paste,
+            }),
+            None => None,
+        };
+        let windows = match windows {
+            Some(RawWindowsConfig {
+                indicator,
+                indicator_position,
+                show_balloon,
+            }) => Some(RawWindowsConfig {
+                indicator,
+                indicator_position: expand("windows.indicator_position", indicator_position)?,
+                show_balloon,
+            }),
+            None => None,
+        };
+        let indicator = match indicator {
+            Some(RawIndicatorConfig {
+                width,
+                height,
+                margin,
+                background,
+                recording_color,
+                processing_color,
+            }) => Some(RawIndicatorConfig {
+                width,
+                height,
+                margin,
+                background: expand("indicator.background", background)?,
+                recording_color: expand("indicator.recording_color", recording_color)?,
+                processing_color: expand("indicator.processing_color", processing_color)?,
+            }),
+            None => None,
+        };
+        let cues = match cues {
+            Some(RawCuesConfig {
+                start_sound,
+                stop_sound,
+                cancel_sound,
+            }) => Some(RawCuesConfig {
+                start_sound: expand("cues.start_sound", start_sound)?,
+                stop_sound: expand("cues.stop_sound", stop_sound)?,
+                cancel_sound: expand("cues.cancel_sound", cancel_sound)?,
+            }),
+            None => None,
+        };
+
+        Ok(Self {
+            version,
+            auth: expand("auth", auth)?,
+            openai_api_key: expand("openai_api_key", openai_api_key)?,
+            openai_transcribe_model: expand("openai_transcribe_model", openai_transcribe_model)?,
+            openai_base_url: expand("openai_base_url", openai_base_url)?,
+            proxy: expand("proxy", proxy)?,
+            transcribe_prompt: expand("transcribe_prompt", transcribe_prompt)?,
+            transcribe_language: expand("transcribe_language", transcribe_language)?,
+            text_prepend: expand("text_prepend", text_prepend)?,
+            text_append: expand("text_append", text_append)?,
+            post_command,
+            duration: expand("duration", duration)?,
+            max_duration: expand("max_duration", max_duration)?,
+            min_duration: expand("min_duration", min_duration)?,
+            silence_check,
+            silence_threshold,
+            audio_device: expand("audio_device", audio_device)?,
+            sample_rate,
+            sample_format: expand("sample_format", sample_format)?,
+            clipboard,
+            keystroke,
+            notify,
+            notify_on_error,
+            audio_cue,
+            cue_volume,
+            cache,
+            history,
+            history_max_entries,
+            ipc_token: expand("ipc_token", ipc_token)?,
+            daemon_output_timestamp,
+            daemon_output_separator: expand("daemon_output_separator", daemon_output_separator)?,
+            daemon_output_profile,
+            daemon_vad,
+            daemon_vad_silence: expand("daemon_vad_silence", daemon_vad_silence)?,
+            linux,
+            windows,
+            indicator,
+            cues,
+            profiles,
+        })
+    }
 }
 
 fn merge_linux(
@@ -124,6 +447,7 @@ fn merge_linux(
             keystroke_tool: o.keystroke_tool.or(b.keystroke_tool),
             indicator: o.indicator.or(b.indicator),
             indicator_position: o.indicator_position.or(b.indicator_position),
+            indicator_clickable: o.indicator_clickable.or(b.indicator_clickable),
             paste: o.paste.or(b.paste),
         }),
     }
@@ -139,11 +463,44 @@ fn merge_windows(
         (None, Some(o)) => Some(o),
         (Some(b), Some(o)) => Some(RawWindowsConfig {
             indicator: o.indicator.or(b.indicator),
+            indicator_position: o.indicator_position.or(b.indicator_position),
             show_balloon: o.show_balloon.or(b.show_balloon),
         }),
     }
 }
 
+fn merge_cues(base: Option<RawCuesConfig>, other: Option<RawCuesConfig>) -> Option<RawCuesConfig> {
+    match (base, other) {
+        (None, None) => None,
+        (Some(b), None) => Some(b),
+        (None, Some(o)) => Some(o),
+        (Some(b), Some(o)) => Some(RawCuesConfig {
+            start_sound: o.start_sound.or(b.start_sound),
+            stop_sound: o.stop_sound.or(b.stop_sound),
+            cancel_sound: o.cancel_sound.or(b.cancel_sound),
+        }),
+    }
+}
+
+fn merge_indicator(
+    base: Option<RawIndicatorConfig>,
+    other: Option<RawIndicatorConfig>,
+) -> Option<RawIndicatorConfig> {
+    match (base, other) {
+        (None, None) => None,
+        (Some(b), None) => Some(b),
+        (None, Some(o)) => Some(o),
+        (Some(b), Some(o)) => Some(RawIndicatorConfig {
+            width: o.width.or(b.width),
+            height: o.height.or(b.height),
+            margin: o.margin.or(b.margin),
+            background: o.background.or(b.background),
+            recording_color: o.recording_color.or(b.recording_color),
+            processing_color: o.processing_color.or(b.processing_color),
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,6 +524,138 @@ mod tests {
         assert!(config.linux.is_none());
     }
 
+    fn with_profiles() -> RawAppConfig {
+        let meetings = RawAppConfig {
+            max_duration: Some("1h".into()),
+            clipboard: Some(true),
+            ..Default::default()
+        };
+        RawAppConfig {
+            max_duration: Some("2m".into()),
+            keystroke: Some(true),
+            profiles: Some(BTreeMap::from([("meetings".to_string(), meetings)])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn select_profile_overrides_base_values() {
+        let config = with_profiles().select_profile("meetings").unwrap();
+        assert_eq!(config.max_duration.as_deref(), Some("1h"));
+        assert_eq!(config.clipboard, Some(true));
+        // Untouched base values survive.
+        assert_eq!(config.keystroke, Some(true));
+        assert!(config.profiles.is_none());
+    }
+
+    #[test]
+    fn select_profile_rejects_unknown_name() {
+        let err = with_profiles().select_profile("coding").unwrap_err();
+        match err {
+            ConfigError::UnknownProfile { name, available } => {
+                assert_eq!(name, "coding");
+                assert_eq!(available, "meetings");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn expand_env_substitutes_string_fields() {
+        let config = RawAppConfig {
+            openai_api_key: Some("${KEY}".into()),
+            proxy: Some("socks5h://$${literal}".into()),
+            clipboard: Some(true),
+            linux: Some(RawLinuxConfig {
+                keystroke_tool: Some("${TOOL}".into()),
+                paste: Some(true),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+        .expand_env(|name| match name {
+            "KEY" => Some("sk-abc".into()),
+            "TOOL" => Some("wtype".into()),
+            _ => None,
+        })
+        .unwrap();
+
+        assert_eq!(config.openai_api_key.as_deref(), Some("sk-abc"));
+        assert_eq!(config.proxy.as_deref(), Some("socks5h://${literal}"));
+        assert_eq!(config.clipboard, Some(true));
+        let linux = config.linux.unwrap();
+        assert_eq!(linux.keystroke_tool.as_deref(), Some("wtype"));
+        assert_eq!(linux.paste, Some(true));
+    }
+
+    /// Walks every `Option<String>` field (found by which keys accept a
+    /// string) and checks it is expanded unless deliberately excluded.
+    #[test]
+    fn expand_env_covers_every_string_field() {
+        const EXCLUDED: &[&str] = &["post_command"];
+        let all_tables = RawAppConfig {
+            linux: Some(RawLinuxConfig::default()),
+            windows: Some(RawWindowsConfig::default()),
+            indicator: Some(RawIndicatorConfig::default()),
+            cues: Some(RawCuesConfig::default()),
+            ..Default::default()
+        };
+        let template = serde_json::to_value(&all_tables).unwrap();
+
+        let mut paths = Vec::new();
+        for (key, value) in template.as_object().unwrap() {
+            match value {
+                serde_json::Value::Object(table) => {
+                    paths.extend(table.keys().map(|k| vec![key.clone(), k.clone()]))
+                }
+                _ => paths.push(vec![key.clone()]),
+            }
+        }
+
+        let mut checked = 0;
+        for path in paths {
+            let mut probe = template.clone();
+            let mut slot = &mut probe;
+            for key in &path {
+                slot = &mut slot[key];
+            }
+            *slot = "${PROBE}".into();
+            let Ok(config) = serde_json::from_value::<RawAppConfig>(probe) else {
+                continue; // not a string field
+            };
+            checked += 1;
+
+            let expanded =
+                serde_json::to_value(config.expand_env(|_| Some("expanded".into())).unwrap())
+                    .unwrap();
+            let value = path.iter().fold(&expanded, |v, key| &v[key]);
+            let name = path.join(".");
+            if EXCLUDED.contains(&name.as_str()) {
+                assert_eq!(value, "${PROBE}", "{name} should be left alone");
+            } else {
+                assert_eq!(value, "expanded", "{name} is not expanded");
+            }
+        }
+        assert!(checked > 20, "only {checked} string fields found");
+    }
+
+    #[test]
+    fn expand_env_reports_key_of_unset_variable() {
+        let err = RawAppConfig {
+            openai_api_key: Some("${NOT_SET}".into()),
+            ..Default::default()
+        }
+        .expand_env(|_| None)
+        .unwrap_err();
+        match err {
+            ConfigError::ValidationError { key, message } => {
+                assert_eq!(key, "openai_api_key");
+                assert!(message.contains("NOT_SET"));
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
     #[test]
     fn merge_other_takes_precedence() {
         let base = RawAppConfig {
@@ -228,6 +717,7 @@ mod tests {
             windows: Some(RawWindowsConfig {
                 indicator: Some(false),
                 show_balloon: Some(false),
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -235,6 +725,7 @@ mod tests {
             windows: Some(RawWindowsConfig {
                 indicator: Some(true),
                 show_balloon: None,
+                ..Default::default()
             }),
             ..Default::default()
         };
@@ -243,4 +734,49 @@ mod tests {
         assert_eq!(w.indicator, Some(true));
         assert_eq!(w.show_balloon, Some(false));
     }
+
+    #[test]
+    fn merge_indicator_fields() {
+        let base = RawAppConfig {
+            indicator: Some(RawIndicatorConfig {
+                width: Some(120),
+                background: Some("#000000".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let other = RawAppConfig {
+            indicator: Some(RawIndicatorConfig {
+                background: Some("#ffffff".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let i = base.merge(other).indicator.unwrap();
+        assert_eq!(i.width, Some(120));
+        assert_eq!(i.background.as_deref(), Some("#ffffff"));
+    }
+
+    #[test]
+    fn merge_cue_sounds_per_field() {
+        let base = RawAppConfig {
+            cues: Some(RawCuesConfig {
+                start_sound: Some("a.wav".into()),
+                stop_sound: Some("b.wav".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let other = RawAppConfig {
+            cues: Some(RawCuesConfig {
+                stop_sound: Some("c.ogg".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let c = base.merge(other).cues.unwrap();
+        assert_eq!(c.start_sound.as_deref(), Some("a.wav"));
+        assert_eq!(c.stop_sound.as_deref(), Some("c.ogg"));
+        assert_eq!(c.cancel_sound, None);
+    }
 }
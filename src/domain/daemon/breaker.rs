@@ -0,0 +1,190 @@
+//! Circuit breaker for the daemon's transcription requests
+
+use std::fmt;
+
+use crate::domain::recording::Duration;
+
+/// Consecutive failures that open the breaker.
+pub const BREAKER_FAILURE_THRESHOLD: usize = 3;
+
+/// Failures older than this no longer count towards the threshold.
+pub const BREAKER_WINDOW_MS: u64 = 120_000;
+
+/// How long an open breaker turns recordings away.
+pub const BREAKER_COOLDOWN_MS: u64 = 60_000;
+
+/// Breaker states
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    /// Requests go through.
+    Closed,
+    /// Too many recent failures; requests are refused until the cooldown
+    /// ends.
+    Open,
+    /// The cooldown is over; the next request decides whether to close the
+    /// breaker or open it again.
+    HalfOpen,
+}
+
+impl fmt::Display for BreakerState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Closed => "closed",
+            Self::Open => "open",
+            Self::HalfOpen => "half-open",
+        })
+    }
+}
+
+/// Stops the daemon from recording while the transcription service keeps
+/// failing, so every toggle doesn't cost a recording, a request and an
+/// error notification.
+///
+/// Times are milliseconds on any monotonic clock the caller picks.
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    threshold: usize,
+    window_ms: u64,
+    cooldown_ms: u64,
+    state: BreakerState,
+    /// Times of the consecutive failures still inside the window.
+    failures: Vec<u64>,
+    opened_at_ms: u64,
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(
+            BREAKER_FAILURE_THRESHOLD,
+            Duration::from_millis(BREAKER_WINDOW_MS),
+            Duration::from_millis(BREAKER_COOLDOWN_MS),
+        )
+    }
+}
+
+impl CircuitBreaker {
+    /// Breaker opening after `threshold` consecutive failures within
+    /// `window`, for `cooldown`.
+    pub fn new(threshold: usize, window: Duration, cooldown: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            window_ms: window.as_millis(),
+            cooldown_ms: cooldown.as_millis(),
+            state: BreakerState::Closed,
+            failures: Vec::new(),
+            opened_at_ms: 0,
+        }
+    }
+
+    /// Current state, as of the last call.
+    pub fn state(&self) -> BreakerState {
+        self.state
+    }
+
+    /// Ask to start a request at `now_ms`. While open this returns the
+    /// cooldown left; once it has passed the breaker goes half-open and
+    /// lets the request through as a trial.
+    pub fn check(&mut self, now_ms: u64) -> Result<(), Duration> {
+        if self.state == BreakerState::Open {
+            let reopens_at = self.opened_at_ms.saturating_add(self.cooldown_ms);
+            if now_ms < reopens_at {
+                return Err(Duration::from_millis(reopens_at - now_ms));
+            }
+            self.state = BreakerState::HalfOpen;
+        }
+        Ok(())
+    }
+
+    /// A request succeeded: close the breaker and forget past failures.
+    pub fn record_success(&mut self) {
+        self.state = BreakerState::Closed;
+        self.failures.clear();
+    }
+
+    /// A request failed at `now_ms`. A failed trial reopens the breaker
+    /// straight away.
+    pub fn record_failure(&mut self, now_ms: u64) {
+        if self.state == BreakerState::HalfOpen {
+            self.open(now_ms);
+            return;
+        }
+        let window_ms = self.window_ms;
+        self.failures
+            .retain(|&at| now_ms.saturating_sub(at) < window_ms);
+        self.failures.push(now_ms);
+        if self.failures.len() >= self.threshold {
+            self.open(now_ms);
+        }
+    }
+
+    fn open(&mut self, now_ms: u64) {
+        self.state = BreakerState::Open;
+        self.opened_at_ms = now_ms;
+        self.failures.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker::new(
+            3,
+            Duration::from_millis(10_000),
+            Duration::from_millis(5_000),
+        )
+    }
+
+    #[test]
+    fn opens_after_threshold_failures_in_window() {
+        let mut b = breaker();
+        for t in [0, 1_000] {
+            b.record_failure(t);
+            assert_eq!(b.state(), BreakerState::Closed);
+            assert!(b.check(t).is_ok());
+        }
+        b.record_failure(2_000);
+        assert_eq!(b.state(), BreakerState::Open);
+        assert_eq!(b.check(3_000), Err(Duration::from_millis(4_000)));
+    }
+
+    #[test]
+    fn failures_outside_window_or_before_a_success_dont_count() {
+        let mut b = breaker();
+        b.record_failure(0);
+        b.record_failure(1_000);
+        b.record_failure(11_500);
+        assert_eq!(b.state(), BreakerState::Closed);
+
+        b.record_success();
+        b.record_failure(12_000);
+        b.record_failure(12_500);
+        assert_eq!(b.state(), BreakerState::Closed);
+    }
+
+    #[test]
+    fn half_open_trial_closes_on_success() {
+        let mut b = breaker();
+        (0..3).for_each(|t| b.record_failure(t));
+        assert!(b.check(4_999).is_err());
+        assert!(b.check(5_002).is_ok());
+        assert_eq!(b.state(), BreakerState::HalfOpen);
+        b.record_success();
+        assert_eq!(b.state(), BreakerState::Closed);
+        b.record_failure(6_000);
+        assert_eq!(b.state(), BreakerState::Closed);
+    }
+
+    #[test]
+    fn half_open_trial_reopens_on_failure() {
+        let mut b = breaker();
+        (0..3).for_each(|t| b.record_failure(t));
+        assert!(b.check(6_000).is_ok());
+        b.record_failure(7_000);
+        assert_eq!(b.state(), BreakerState::Open);
+        assert_eq!(b.check(8_000), Err(Duration::from_millis(4_000)));
+        assert!(b.check(12_000).is_ok());
+        assert_eq!(b.state(), BreakerState::HalfOpen);
+    }
+}
@@ -1,5 +1,9 @@
 //! Daemon domain module
 
+mod breaker;
 mod session;
 
+pub use breaker::{
+    BreakerState, CircuitBreaker, BREAKER_COOLDOWN_MS, BREAKER_FAILURE_THRESHOLD, BREAKER_WINDOW_MS,
+};
 pub use session::{DaemonSession, DaemonState, InvalidStateTransition, StateUpdate};
@@ -24,12 +24,35 @@ pub enum DaemonState {
 pub struct StateUpdate {
     pub state: DaemonState,
     pub elapsed_ms: u64,
+    /// Input level in `0.0..=1.0` while recording, if the recorder reports one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<f32>,
+    /// Time left before the daemon's `max_duration` auto-stop, while recording.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining_ms: Option<u64>,
 }
 
 impl StateUpdate {
     /// Create a new state update
     pub fn new(state: DaemonState, elapsed_ms: u64) -> Self {
-        Self { state, elapsed_ms }
+        Self {
+            state,
+            elapsed_ms,
+            level: None,
+            remaining_ms: None,
+        }
+    }
+
+    /// Builder: attach the current input level
+    pub fn with_level(mut self, level: Option<f32>) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Builder: attach the time left before the recording auto-stops
+    pub fn with_remaining(mut self, remaining_ms: Option<u64>) -> Self {
+        self.remaining_ms = remaining_ms;
+        self
     }
 }
 
@@ -309,12 +332,39 @@ mod tests {
         assert!(json.contains("\"elapsed_ms\":1500"));
     }
 
+    #[test]
+    fn state_update_level_is_optional_on_the_wire() {
+        let update = StateUpdate::new(DaemonState::Recording, 1500);
+        let json = serde_json::to_string(&update).unwrap();
+        assert!(!json.contains("level"));
+
+        let update = update.with_level(Some(0.5));
+        let json = serde_json::to_string(&update).unwrap();
+        assert!(json.contains("\"level\":0.5"));
+        let parsed: StateUpdate = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.level, Some(0.5));
+    }
+
+    #[test]
+    fn state_update_remaining_is_optional_on_the_wire() {
+        let update = StateUpdate::new(DaemonState::Recording, 1500);
+        assert!(!serde_json::to_string(&update)
+            .unwrap()
+            .contains("remaining_ms"));
+
+        let json = serde_json::to_string(&update.with_remaining(Some(8500))).unwrap();
+        assert!(json.contains("\"remaining_ms\":8500"));
+        let parsed: StateUpdate = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.remaining_ms, Some(8500));
+    }
+
     #[test]
     fn state_update_json_deserialization() {
         let json = r#"{"state":"processing","elapsed_ms":3000}"#;
         let update: StateUpdate = serde_json::from_str(json).unwrap();
         assert_eq!(update.state, DaemonState::Processing);
         assert_eq!(update.elapsed_ms, 3000);
+        assert_eq!(update.level, None);
     }
 
     #[test]
@@ -4,11 +4,31 @@ use thiserror::Error;
 
 /// Error when parsing a duration string
 #[derive(Debug, Clone, Error)]
-#[error("Invalid duration format: \"{input}\". Expected format: <number>s, <number>m, or <number>m<number>s (e.g., 30s, 1m, 2m30s)")]
+#[error("Invalid duration format: \"{input}\". Expected <number> with a unit of h, m, s or ms (e.g., 30s, 2m30s, 1h5m, 500ms)")]
 pub struct DurationParseError {
     pub input: String,
 }
 
+/// Error when audio bytes match no supported container format
+#[derive(Debug, Clone, Error)]
+#[error("Unsupported audio format{}", .extension.as_deref().map(|e| format!(" (.{e})")).unwrap_or_default())]
+pub struct UnsupportedAudioFormat {
+    pub extension: Option<String>,
+}
+
+/// Error when saving or loading an audio file
+#[derive(Debug, Clone, Error)]
+pub enum AudioFileError {
+    #[error("Failed to read audio file {path}: {message}")]
+    Read { path: String, message: String },
+
+    #[error("Failed to write audio file {path}: {message}")]
+    Write { path: String, message: String },
+
+    #[error(transparent)]
+    Unsupported(#[from] UnsupportedAudioFormat),
+}
+
 /// Error when configuration fails
 #[derive(Debug, Clone, Error)]
 pub enum ConfigError {
@@ -26,4 +46,10 @@ pub enum ConfigError {
 
     #[error("Config file already exists at: {0}")]
     AlreadyExists(String),
+
+    #[error("Failed to run editor: {0}")]
+    EditorError(String),
+
+    #[error("Unknown profile '{name}'. Available profiles: {available}")]
+    UnknownProfile { name: String, available: String },
 }
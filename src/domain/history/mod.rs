@@ -0,0 +1,70 @@
+//! Transcription history domain module.
+//!
+//! A [`HistoryEntry`] is one successful transcription as persisted by the
+//! history store, so earlier dictations can be listed and re-copied.
+
+use serde::{Deserialize, Serialize};
+
+/// One recorded transcription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Unix timestamp (seconds) when the transcription completed.
+    pub timestamp: u64,
+    /// Transcription model that produced the text.
+    pub model: String,
+    /// The transcribed text.
+    pub text: String,
+    /// Size of the uploaded audio in bytes.
+    pub audio_size_bytes: u64,
+}
+
+impl HistoryEntry {
+    /// Create a new entry.
+    pub fn new(
+        timestamp: u64,
+        model: impl Into<String>,
+        text: impl Into<String>,
+        audio_size_bytes: u64,
+    ) -> Self {
+        Self {
+            timestamp,
+            model: model.into(),
+            text: text.into(),
+            audio_size_bytes,
+        }
+    }
+}
+
+/// Render how long ago `timestamp` was relative to `now` (both Unix
+/// seconds), e.g. `just now`, `5m ago`, `3h ago`, `2d ago`.
+pub fn format_age(timestamp: u64, now: u64) -> String {
+    let secs = now.saturating_sub(timestamp);
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3_599 => format!("{}m ago", secs / 60),
+        3_600..=86_399 => format!("{}h ago", secs / 3_600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_age_buckets() {
+        assert_eq!(format_age(1_000, 1_030), "just now");
+        assert_eq!(format_age(1_000, 1_000 + 5 * 60), "5m ago");
+        assert_eq!(format_age(1_000, 1_000 + 3 * 3_600 + 10), "3h ago");
+        assert_eq!(format_age(1_000, 1_000 + 2 * 86_400), "2d ago");
+        // Clock skew: future timestamps read as "just now".
+        assert_eq!(format_age(2_000, 1_000), "just now");
+    }
+
+    #[test]
+    fn entry_round_trips_through_json() {
+        let entry = HistoryEntry::new(1_700_000_000, "gpt-4o-transcribe", "hello", 42);
+        let json = serde_json::to_string(&entry).unwrap();
+        assert_eq!(serde_json::from_str::<HistoryEntry>(&json).unwrap(), entry);
+    }
+}
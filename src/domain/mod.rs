@@ -6,6 +6,7 @@
 pub mod config;
 pub mod daemon;
 pub mod error;
+pub mod history;
 pub mod recording;
 pub mod transcription;
 
@@ -4,6 +4,8 @@ use std::fmt;
 use std::str::FromStr;
 use std::time::Duration as StdDuration;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use crate::domain::error::DurationParseError;
 
 /// Default recording duration (10 seconds)
@@ -62,51 +64,54 @@ impl FromStr for Duration {
     type Err = DurationParseError;
 
     /// Parse a duration string into a Duration value object.
-    /// Supported formats: "30s", "1m", "2m30s", "90s"
+    /// Supported units: `h`, `m`, `s`, `ms`, optionally combined
+    /// (e.g. "30s", "1m", "2m30s", "1h5m", "500ms").
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let input = s.trim().to_lowercase();
+        let invalid = || DurationParseError {
+            input: s.to_string(),
+        };
 
-        // Regex-like pattern matching for formats like "30s", "1m", "2m30s"
-        let mut minutes: u64 = 0;
-        let mut seconds: u64 = 0;
+        let mut total_ms: u64 = 0;
         let mut current_num = String::new();
         let mut found_any = false;
+        let mut chars = input.chars().peekable();
 
-        for ch in input.chars() {
+        while let Some(ch) = chars.next() {
             if ch.is_ascii_digit() {
                 current_num.push(ch);
-            } else if ch == 'm' && !current_num.is_empty() {
-                minutes = current_num.parse().map_err(|_| DurationParseError {
-                    input: s.to_string(),
-                })?;
-                current_num.clear();
-                found_any = true;
-            } else if ch == 's' && !current_num.is_empty() {
-                seconds = current_num.parse().map_err(|_| DurationParseError {
-                    input: s.to_string(),
-                })?;
-                current_num.clear();
-                found_any = true;
-            } else {
-                return Err(DurationParseError {
-                    input: s.to_string(),
-                });
+                continue;
+            }
+            if current_num.is_empty() {
+                return Err(invalid());
             }
+
+            let unit_ms: u64 = match ch {
+                'h' => 3_600_000,
+                'm' if chars.peek() == Some(&'s') => {
+                    chars.next();
+                    1
+                }
+                'm' => 60_000,
+                's' => 1000,
+                _ => return Err(invalid()),
+            };
+            let value: u64 = current_num.parse().map_err(|_| invalid())?;
+            total_ms = value
+                .checked_mul(unit_ms)
+                .and_then(|ms| total_ms.checked_add(ms))
+                .ok_or_else(invalid)?;
+            current_num.clear();
+            found_any = true;
         }
 
         // Handle case where there's leftover numbers (invalid format)
         if !current_num.is_empty() || !found_any {
-            return Err(DurationParseError {
-                input: s.to_string(),
-            });
+            return Err(invalid());
         }
 
-        let total_ms = (minutes * 60 + seconds) * 1000;
-
         if total_ms == 0 {
-            return Err(DurationParseError {
-                input: s.to_string(),
-            });
+            return Err(invalid());
         }
 
         Ok(Self {
@@ -118,16 +123,42 @@ impl FromStr for Duration {
 impl fmt::Display for Duration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let total_secs = self.as_secs();
-        let minutes = total_secs / 60;
+        let hours = total_secs / 3600;
+        let minutes = (total_secs % 3600) / 60;
         let seconds = total_secs % 60;
+        let millis = self.milliseconds % 1000;
 
-        if minutes == 0 {
-            write!(f, "{}s", seconds)
-        } else if seconds == 0 {
-            write!(f, "{}m", minutes)
-        } else {
-            write!(f, "{}m{}s", minutes, seconds)
+        if self.milliseconds == 0 {
+            return write!(f, "0s");
+        }
+        if hours > 0 {
+            write!(f, "{}h", hours)?;
+        }
+        if minutes > 0 {
+            write!(f, "{}m", minutes)?;
         }
+        if seconds > 0 {
+            write!(f, "{}s", seconds)?;
+        }
+        if millis > 0 {
+            write!(f, "{}ms", millis)?;
+        }
+        Ok(())
+    }
+}
+
+/// Serialized as its display string (`"2m30s"`), so typed fields read and
+/// write the same values config files already use.
+impl Serialize for Duration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Duration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
     }
 }
 
@@ -208,6 +239,78 @@ mod tests {
         assert_eq!(d.to_string(), "2m30s");
     }
 
+    #[test]
+    fn parse_hours_only() {
+        let d: Duration = "1h".parse().unwrap();
+        assert_eq!(d.as_secs(), 3600);
+    }
+
+    #[test]
+    fn parse_hours_and_minutes() {
+        let d: Duration = "1h30m".parse().unwrap();
+        assert_eq!(d.as_secs(), 5400);
+    }
+
+    #[test]
+    fn parse_milliseconds() {
+        let d: Duration = "90ms".parse().unwrap();
+        assert_eq!(d.as_millis(), 90);
+        let d: Duration = "1s500MS".parse().unwrap();
+        assert_eq!(d.as_millis(), 1500);
+    }
+
+    #[test]
+    fn parse_hours_minutes_seconds() {
+        let d: Duration = "1h2m3s".parse().unwrap();
+        assert_eq!(d.as_secs(), 3723);
+    }
+
+    #[test]
+    fn parse_invalid_units() {
+        assert!("0ms".parse::<Duration>().is_err());
+        assert!("1h5".parse::<Duration>().is_err());
+        assert!("h".parse::<Duration>().is_err());
+        assert!("99999999999999999999h".parse::<Duration>().is_err());
+    }
+
+    #[test]
+    fn display_hours() {
+        assert_eq!(Duration::from_secs(3600).to_string(), "1h");
+        assert_eq!(Duration::from_secs(5400).to_string(), "1h30m");
+        assert_eq!(Duration::from_secs(3723).to_string(), "1h2m3s");
+    }
+
+    #[test]
+    fn display_milliseconds() {
+        assert_eq!(Duration::from_millis(500).to_string(), "500ms");
+        assert_eq!(Duration::from_millis(1500).to_string(), "1s500ms");
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in ["45s", "2m30s", "1h", "1h5m", "250ms", "1h2m3s"] {
+            let d: Duration = input.parse().unwrap();
+            assert_eq!(d.to_string(), input);
+        }
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        for input in ["30s", "2m30s"] {
+            let json = format!("\"{}\"", input);
+            let d: Duration = serde_json::from_str(&json).unwrap();
+            assert_eq!(d.to_string(), input);
+            assert_eq!(serde_json::to_string(&d).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn serde_rejects_invalid_value() {
+        let err = serde_json::from_str::<Duration>("\"soon\"").unwrap_err();
+        assert!(err.to_string().contains("Invalid duration format"));
+        assert!(serde_json::from_str::<Duration>("30").is_err());
+    }
+
     #[test]
     fn as_std_duration() {
         let d = Duration::from_secs(30);
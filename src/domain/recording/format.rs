@@ -0,0 +1,69 @@
+//! Capture sample format preference (`sample_format`)
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Sample format to capture in when the input device offers a choice.
+///
+/// Recordings are stored as 16-bit PCM, so capturing in `i16` skips the
+/// float round-trip on devices that support it natively; `f32` keeps the
+/// device's own conversion on those where it is the better path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SampleFormatPreference {
+    /// Take whichever supported config suits the sample rate best.
+    #[default]
+    Auto,
+    /// Prefer 16-bit integer configs.
+    I16,
+    /// Prefer 32-bit float configs.
+    F32,
+}
+
+impl SampleFormatPreference {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::I16 => "i16",
+            Self::F32 => "f32",
+        }
+    }
+}
+
+impl fmt::Display for SampleFormatPreference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SampleFormatPreference {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "i16" => Ok(Self::I16),
+            "f32" => Ok(Self::F32),
+            other => Err(format!(
+                "Invalid sample format '{other}'. Valid options: auto, i16, f32"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_round_trips() {
+        for pref in [
+            SampleFormatPreference::Auto,
+            SampleFormatPreference::I16,
+            SampleFormatPreference::F32,
+        ] {
+            assert_eq!(pref.to_string().parse::<SampleFormatPreference>(), Ok(pref));
+        }
+        assert_eq!(" F32 ".parse(), Ok(SampleFormatPreference::F32));
+        assert!("s24".parse::<SampleFormatPreference>().is_err());
+    }
+}
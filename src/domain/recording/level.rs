@@ -0,0 +1,121 @@
+//! Signal-level helpers for captured PCM
+
+/// Peak level of `samples` in dBFS (0 = full scale).
+///
+/// Empty or all-zero input yields negative infinity.
+pub fn peak_dbfs(samples: &[i16]) -> f32 {
+    let peak = samples.iter().map(|s| s.unsigned_abs()).max().unwrap_or(0);
+    if peak == 0 {
+        return f32::NEG_INFINITY;
+    }
+    20.0 * (peak as f32 / i16::MAX as f32).log10()
+}
+
+/// Whether no sample in `samples` rises above `threshold_db` dBFS.
+///
+/// Peak rather than RMS on purpose: a single word in an otherwise quiet
+/// clip is enough to count as audio.
+pub fn is_silent(samples: &[i16], threshold_db: i32) -> bool {
+    peak_dbfs(samples) <= threshold_db as f32
+}
+
+/// Magnitude at or above which a sample counts as clipped. A hair under
+/// full scale, since some converters saturate at ±32766 rather than the
+/// exact i16 limits.
+const CLIP_LEVEL: u16 = 32_700;
+
+/// Share of clipped samples above which a recording counts as clipping.
+/// A few isolated peaks are harmless; sustained saturation is not.
+pub const CLIPPING_WARN_RATIO: f32 = 0.001;
+
+/// Fraction (`0.0..=1.0`) of `samples` at or near the i16 extremes.
+///
+/// Empty input yields `0.0`.
+pub fn clipped_ratio(samples: &[i16]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let clipped = samples
+        .iter()
+        .filter(|s| s.unsigned_abs() >= CLIP_LEVEL)
+        .count();
+    clipped as f32 / samples.len() as f32
+}
+
+/// Whether a clipped-sample `ratio` is high enough to hurt transcription.
+pub fn is_clipping(ratio: f32) -> bool {
+    ratio > CLIPPING_WARN_RATIO
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peak_of_silence_is_negative_infinity() {
+        assert_eq!(peak_dbfs(&[]), f32::NEG_INFINITY);
+        assert_eq!(peak_dbfs(&[0; 16]), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn peak_of_full_scale_is_zero() {
+        assert!(peak_dbfs(&[0, i16::MAX, 0]).abs() < 0.01);
+        // i16::MIN is one step past full scale; still reads as ~0 dBFS.
+        assert!(peak_dbfs(&[i16::MIN]).abs() < 0.01);
+    }
+
+    #[test]
+    fn peak_tracks_loudest_sample() {
+        // 3277 / 32767 ≈ -20 dBFS
+        let peak = peak_dbfs(&[10, -3277, 200]);
+        assert!((peak + 20.0).abs() < 0.01, "{peak}");
+    }
+
+    #[test]
+    fn silent_recording_triggers_guard() {
+        let samples: Vec<i16> = vec![0; 16_000];
+        assert!(is_silent(&samples, -60));
+    }
+
+    #[test]
+    fn low_noise_floor_counts_as_silent() {
+        // ±10 ≈ -70 dBFS
+        let samples: Vec<i16> = (0..16_000)
+            .map(|i| if i % 2 == 0 { 10 } else { -10 })
+            .collect();
+        assert!(is_silent(&samples, -60));
+    }
+
+    #[test]
+    fn speech_level_is_not_silent() {
+        let mut samples: Vec<i16> = vec![0; 16_000];
+        samples[8_000] = 3277;
+        assert!(!is_silent(&samples, -60));
+    }
+
+    #[test]
+    fn saturated_buffer_is_clipping() {
+        // A loud sine driven 4x past full scale: flat tops at both rails.
+        let samples: Vec<i16> = (0..16_000)
+            .map(|i| {
+                let t = i as f32 / 16_000.0;
+                let s = f32::sin(2.0 * std::f32::consts::PI * 220.0 * t) * 4.0 * 32_767.0;
+                s.clamp(i16::MIN as f32, i16::MAX as f32) as i16
+            })
+            .collect();
+        let ratio = clipped_ratio(&samples);
+        assert!(ratio > 0.5, "{ratio}");
+        assert!(is_clipping(ratio));
+    }
+
+    #[test]
+    fn isolated_peaks_are_not_clipping() {
+        let mut samples: Vec<i16> = vec![3277; 16_000];
+        samples[100] = i16::MAX;
+        samples[200] = i16::MIN;
+        let ratio = clipped_ratio(&samples);
+        assert!((ratio - 2.0 / 16_000.0).abs() < 1e-6, "{ratio}");
+        assert!(!is_clipping(ratio));
+        assert_eq!(clipped_ratio(&[]), 0.0);
+    }
+}
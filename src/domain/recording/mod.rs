@@ -1,5 +1,11 @@
 //! Recording domain module
 
 mod duration;
+mod format;
+mod level;
+mod vad;
 
 pub use duration::Duration;
+pub use format::SampleFormatPreference;
+pub use level::{clipped_ratio, is_clipping, is_silent, peak_dbfs, CLIPPING_WARN_RATIO};
+pub use vad::{EndOfSpeech, DEFAULT_VAD_SILENCE_MS, VAD_SPEECH_LEVEL};
@@ -0,0 +1,101 @@
+//! End-of-speech detection from input levels
+
+use super::Duration;
+
+/// Input level (`0.0..=1.0`, as reported by the recorder's meter) at or
+/// above which a tick counts as speech. Roughly -45 dBFS RMS: above a quiet
+/// room, below soft speech.
+pub const VAD_SPEECH_LEVEL: f32 = 0.25;
+
+/// Default quiet stretch after speech that ends a recording.
+pub const DEFAULT_VAD_SILENCE_MS: u64 = 1_500;
+
+/// Decides when a recording has gone quiet for good.
+///
+/// Fed one level reading per tick. It arms only once speech has been heard,
+/// so the silence before the first word never ends a recording, and any
+/// speech restarts the window, so pauses shorter than it don't cut a
+/// sentence.
+#[derive(Debug, Clone)]
+pub struct EndOfSpeech {
+    silence: Duration,
+    heard_speech: bool,
+    quiet_since_ms: Option<u64>,
+}
+
+impl EndOfSpeech {
+    /// Detector ending a recording after `silence` without speech.
+    pub const fn new(silence: Duration) -> Self {
+        Self {
+            silence,
+            heard_speech: false,
+            quiet_since_ms: None,
+        }
+    }
+
+    /// Forget everything heard, for a new recording.
+    pub fn reset(&mut self) {
+        self.heard_speech = false;
+        self.quiet_since_ms = None;
+    }
+
+    /// Record the `level` read at `elapsed_ms` into the recording; returns
+    /// `true` once speech has been followed by a full silence window.
+    pub fn update(&mut self, level: f32, elapsed_ms: u64) -> bool {
+        if level >= VAD_SPEECH_LEVEL {
+            self.heard_speech = true;
+            self.quiet_since_ms = None;
+            return false;
+        }
+        if !self.heard_speech {
+            return false;
+        }
+        let since = *self.quiet_since_ms.get_or_insert(elapsed_ms);
+        elapsed_ms.saturating_sub(since) >= self.silence.as_millis()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detector() -> EndOfSpeech {
+        EndOfSpeech::new(Duration::from_millis(1_000))
+    }
+
+    #[test]
+    fn silence_before_speech_never_stops() {
+        let mut vad = detector();
+        assert!(!(0..100).any(|t| vad.update(0.0, t * 100)));
+    }
+
+    #[test]
+    fn stops_after_a_full_window_of_silence() {
+        let mut vad = detector();
+        assert!(!vad.update(0.6, 0));
+        assert!(!vad.update(0.1, 500));
+        assert!(!vad.update(0.1, 1_400));
+        assert!(vad.update(0.1, 1_500));
+    }
+
+    #[test]
+    fn brief_pauses_restart_the_window() {
+        let mut vad = detector();
+        vad.update(0.6, 0);
+        assert!(!vad.update(0.0, 200));
+        assert!(!vad.update(0.0, 1_100));
+        assert!(!vad.update(0.5, 1_150));
+        assert!(!vad.update(0.0, 1_300));
+        assert!(!vad.update(0.0, 2_200));
+        assert!(vad.update(0.0, 2_300));
+    }
+
+    #[test]
+    fn reset_disarms_until_speech_again() {
+        let mut vad = detector();
+        vad.update(0.6, 0);
+        vad.reset();
+        assert!(!vad.update(0.0, 0));
+        assert!(!vad.update(0.0, 5_000));
+    }
+}
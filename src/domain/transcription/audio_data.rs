@@ -1,6 +1,11 @@
 //! Audio data value object
 
 use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::domain::error::{AudioFileError, UnsupportedAudioFormat};
+use crate::domain::recording::is_clipping;
 
 /// Supported audio MIME types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -10,6 +15,7 @@ pub enum AudioMimeType {
     Wav,
     Webm,
     Mp4,
+    Ogg,
     #[default]
     Flac,
 }
@@ -23,6 +29,7 @@ impl AudioMimeType {
             Self::Wav => "audio/wav",
             Self::Webm => "audio/webm",
             Self::Mp4 => "audio/mp4",
+            Self::Ogg => "audio/ogg",
             Self::Flac => "audio/flac",
         }
     }
@@ -34,9 +41,98 @@ impl AudioMimeType {
             Self::Wav => "wav",
             Self::Webm => "webm",
             Self::Mp4 => "mp4",
+            Self::Ogg => "ogg",
             Self::Flac => "flac",
         }
     }
+
+    /// Recognise an audio file extension (case-insensitive), e.g. `m4a`
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "mp3" => Some(Self::Mp3),
+            "mpeg" | "mpga" => Some(Self::Mpeg),
+            "wav" => Some(Self::Wav),
+            "webm" => Some(Self::Webm),
+            "mp4" | "m4a" => Some(Self::Mp4),
+            "ogg" | "oga" | "opus" => Some(Self::Ogg),
+            "flac" => Some(Self::Flac),
+            _ => None,
+        }
+    }
+
+    /// Recognise a container from its leading magic bytes
+    fn from_magic_bytes(data: &[u8]) -> Option<Self> {
+        match data {
+            [b'O', b'g', b'g', b'S', ..] => Some(Self::Ogg),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'A', b'V', b'E', ..] => Some(Self::Wav),
+            [b'f', b'L', b'a', b'C', ..] => Some(Self::Flac),
+            [b'I', b'D', b'3', ..] => Some(Self::Mp3),
+            // MPEG-1/2 Layer III frame sync, with or without CRC
+            [0xFF, 0xFB | 0xFA | 0xF3 | 0xF2, ..] => Some(Self::Mp3),
+            [0x1A, 0x45, 0xDF, 0xA3, ..] => Some(Self::Webm),
+            [_, _, _, _, b'f', b't', b'y', b'p', ..] => Some(Self::Mp4),
+            _ => None,
+        }
+    }
+}
+
+/// Extensions of video containers; only their audio track is uploaded.
+const VIDEO_EXTENSIONS: &[&str] = &[
+    "mp4", "m4v", "mov", "mkv", "webm", "avi", "wmv", "flv", "3gp",
+];
+
+/// Audio formats the transcription endpoints don't take as-is.
+const CONVERTIBLE_EXTENSIONS: &[&str] = &["aac", "wma", "aif", "aiff", "amr", "ac3", "caf"];
+
+/// How an input file becomes uploadable audio.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioSource {
+    /// A supported audio format, uploaded as-is.
+    Upload(AudioMimeType),
+    /// A video container or an unsupported codec: the audio has to be
+    /// extracted and re-encoded before upload.
+    Extract,
+}
+
+impl AudioSource {
+    /// Whether files with `extension` are audio or video this can take in
+    /// (case-insensitive).
+    pub fn accepts_extension(extension: &str) -> bool {
+        let lower = extension.to_ascii_lowercase();
+        AudioMimeType::from_extension(&lower).is_some()
+            || VIDEO_EXTENSIONS.contains(&lower.as_str())
+            || CONVERTIBLE_EXTENSIONS.contains(&lower.as_str())
+    }
+
+    /// Whether the extension alone marks a video or an unsupported codec
+    /// (case-insensitive).
+    pub fn extension_needs_extraction(extension: Option<&str>) -> bool {
+        extension.map(str::to_ascii_lowercase).is_some_and(|e| {
+            VIDEO_EXTENSIONS.contains(&e.as_str()) || CONVERTIBLE_EXTENSIONS.contains(&e.as_str())
+        })
+    }
+
+    /// Decide from a file's contents and extension. WebM and MP4 count as
+    /// video unless the extension says audio (`.weba`, `.m4a`), since the
+    /// container alone doesn't tell.
+    pub fn detect(data: &[u8], extension: Option<&str>) -> Result<Self, UnsupportedAudioFormat> {
+        if Self::extension_needs_extraction(extension) {
+            return Ok(Self::Extract);
+        }
+        let extension = extension.map(str::to_ascii_lowercase);
+        match AudioMimeType::from_magic_bytes(data) {
+            Some(AudioMimeType::Webm | AudioMimeType::Mp4)
+                if extension
+                    .as_deref()
+                    .and_then(AudioMimeType::from_extension)
+                    .is_none() =>
+            {
+                Ok(Self::Extract)
+            }
+            _ => AudioData::detect_mime_from_magic_bytes(data, extension.as_deref())
+                .map(Self::Upload),
+        }
+    }
 }
 
 impl fmt::Display for AudioMimeType {
@@ -52,6 +148,10 @@ pub struct AudioData {
     data: Vec<u8>,
     mime_type: AudioMimeType,
     duration_ms: Option<u64>,
+    peak_dbfs: Option<f32>,
+    clipped_ratio: Option<f32>,
+    truncated: bool,
+    encode_ms: Option<u64>,
 }
 
 impl AudioData {
@@ -61,6 +161,10 @@ impl AudioData {
             data,
             mime_type,
             duration_ms: None,
+            peak_dbfs: None,
+            clipped_ratio: None,
+            truncated: false,
+            encode_ms: None,
         }
     }
 
@@ -70,9 +174,28 @@ impl AudioData {
             data: data.to_vec(),
             mime_type,
             duration_ms: None,
+            peak_dbfs: None,
+            clipped_ratio: None,
+            truncated: false,
+            encode_ms: None,
         }
     }
 
+    /// Work out the MIME type of a file's contents. The leading magic bytes
+    /// win over the extension, since a `.mp3` that is really a WAV would
+    /// otherwise be uploaded with the wrong type; the extension is only
+    /// consulted when the bytes are not recognised.
+    pub fn detect_mime_from_magic_bytes(
+        data: &[u8],
+        extension: Option<&str>,
+    ) -> Result<AudioMimeType, UnsupportedAudioFormat> {
+        AudioMimeType::from_magic_bytes(data)
+            .or_else(|| extension.and_then(AudioMimeType::from_extension))
+            .ok_or_else(|| UnsupportedAudioFormat {
+                extension: extension.map(str::to_string),
+            })
+    }
+
     /// Set the duration in milliseconds
     pub fn with_duration_ms(mut self, duration_ms: u64) -> Self {
         self.duration_ms = Some(duration_ms);
@@ -84,6 +207,63 @@ impl AudioData {
         self.duration_ms
     }
 
+    /// Set the peak level of the captured signal in dBFS
+    pub fn with_peak_dbfs(mut self, peak_dbfs: f32) -> Self {
+        self.peak_dbfs = Some(peak_dbfs);
+        self
+    }
+
+    /// Get the peak level in dBFS, if known
+    pub fn peak_dbfs(&self) -> Option<f32> {
+        self.peak_dbfs
+    }
+
+    /// Whether the signal never rose above `threshold_db` dBFS. Audio with
+    /// an unknown peak (e.g. loaded from a file) is never considered silent.
+    pub fn is_silent(&self, threshold_db: i32) -> bool {
+        self.peak_dbfs
+            .is_some_and(|peak| peak <= threshold_db as f32)
+    }
+
+    /// Set the fraction of captured samples at or near full scale
+    pub fn with_clipped_ratio(mut self, ratio: f32) -> Self {
+        self.clipped_ratio = Some(ratio);
+        self
+    }
+
+    /// Get the fraction of clipped samples, if known
+    pub fn clipped_ratio(&self) -> Option<f32> {
+        self.clipped_ratio
+    }
+
+    /// Whether enough of the signal saturated to hurt transcription.
+    /// Audio with an unknown ratio (e.g. loaded from a file) never is.
+    pub fn is_clipping(&self) -> bool {
+        self.clipped_ratio.is_some_and(is_clipping)
+    }
+
+    /// Mark whether the recorder dropped audio past its length limit
+    pub fn with_truncated(mut self, truncated: bool) -> Self {
+        self.truncated = truncated;
+        self
+    }
+
+    /// Whether the recording was cut short at the recorder's length limit
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Set how long the recorder took to encode the capture
+    pub fn with_encode_ms(mut self, encode_ms: u64) -> Self {
+        self.encode_ms = Some(encode_ms);
+        self
+    }
+
+    /// Get the encoding time in milliseconds, if the recorder measured it
+    pub fn encode_ms(&self) -> Option<u64> {
+        self.encode_ms
+    }
+
     /// Get the raw audio data
     pub fn data(&self) -> &[u8] {
         &self.data
@@ -103,6 +283,32 @@ impl AudioData {
     pub fn size_bytes(&self) -> usize {
         self.data.len()
     }
+
+    /// Write the bytes to `path`, adding the extension of the MIME type when
+    /// `path` has none. Returns the path actually written.
+    pub fn save(&self, path: &Path) -> Result<PathBuf, AudioFileError> {
+        let path = match path.extension() {
+            Some(_) => path.to_path_buf(),
+            None => path.with_extension(self.mime_type.extension()),
+        };
+        fs::write(&path, &self.data).map_err(|e| AudioFileError::Write {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        Ok(path)
+    }
+
+    /// Read an audio file, detecting its format from the contents (see
+    /// [`detect_mime_from_magic_bytes`](Self::detect_mime_from_magic_bytes)).
+    pub fn load(path: &Path) -> Result<Self, AudioFileError> {
+        let data = fs::read(path).map_err(|e| AudioFileError::Read {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        let extension = path.extension().and_then(|e| e.to_str());
+        let mime_type = Self::detect_mime_from_magic_bytes(&data, extension)?;
+        Ok(Self::new(data, mime_type))
+    }
 }
 
 #[cfg(test)]
@@ -123,6 +329,138 @@ mod tests {
         assert_eq!(AudioMimeType::Wav.extension(), "wav");
     }
 
+    #[test]
+    fn mime_type_from_extension() {
+        assert_eq!(
+            AudioMimeType::from_extension("ogg"),
+            Some(AudioMimeType::Ogg)
+        );
+        assert_eq!(
+            AudioMimeType::from_extension("M4A"),
+            Some(AudioMimeType::Mp4)
+        );
+        assert_eq!(
+            AudioMimeType::from_extension("flac"),
+            Some(AudioMimeType::Flac)
+        );
+        assert_eq!(AudioMimeType::from_extension("txt"), None);
+    }
+
+    #[test]
+    fn detects_mime_from_magic_bytes() {
+        let cases: [(&[u8], AudioMimeType); 8] = [
+            (b"OggS\x00\x02", AudioMimeType::Ogg),
+            (b"RIFF\x24\x08\x00\x00WAVEfmt ", AudioMimeType::Wav),
+            (b"fLaC\x00\x00\x00\x22", AudioMimeType::Flac),
+            (b"ID3\x04\x00", AudioMimeType::Mp3),
+            (&[0xFF, 0xFB, 0x90, 0x64], AudioMimeType::Mp3),
+            (&[0x1A, 0x45, 0xDF, 0xA3, 0x9F], AudioMimeType::Webm),
+            (b"\x00\x00\x00\x20ftypM4A ", AudioMimeType::Mp4),
+            (b"\x00\x00\x00\x18ftypisom", AudioMimeType::Mp4),
+        ];
+        for (bytes, expected) in cases {
+            assert_eq!(
+                AudioData::detect_mime_from_magic_bytes(bytes, None).unwrap(),
+                expected,
+                "{bytes:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn magic_bytes_win_over_extension() {
+        let mime = AudioData::detect_mime_from_magic_bytes(b"OggS\x00", Some("mp3")).unwrap();
+        assert_eq!(mime, AudioMimeType::Ogg);
+    }
+
+    #[test]
+    fn unknown_bytes_fall_back_to_extension_then_error() {
+        let mime = AudioData::detect_mime_from_magic_bytes(b"\x00\x01", Some("m4a")).unwrap();
+        assert_eq!(mime, AudioMimeType::Mp4);
+
+        let err = AudioData::detect_mime_from_magic_bytes(b"hello", Some("txt")).unwrap_err();
+        assert_eq!(err.to_string(), "Unsupported audio format (.txt)");
+        // A RIFF container that is not WAVE (e.g. AVI) is not audio we know
+        assert!(AudioData::detect_mime_from_magic_bytes(b"RIFF\0\0\0\0AVI ", None).is_err());
+    }
+
+    #[test]
+    fn video_and_unsupported_codecs_need_extraction() {
+        let webm: &[u8] = &[0x1A, 0x45, 0xDF, 0xA3, 0x9F];
+        let mp4: &[u8] = b"\x00\x00\x00\x18ftypisom";
+        let cases: [(&[u8], Option<&str>, AudioSource); 10] = [
+            // Video containers, by extension or by unlabelled contents
+            (mp4, Some("mp4"), AudioSource::Extract),
+            (mp4, Some("MOV"), AudioSource::Extract),
+            (webm, Some("webm"), AudioSource::Extract),
+            (webm, Some("mkv"), AudioSource::Extract),
+            (mp4, None, AudioSource::Extract),
+            (b"RIFF\0\0\0\0AVI ", Some("avi"), AudioSource::Extract),
+            // Audio the endpoint doesn't take
+            (b"\xFF\xF1\x50\x80", Some("aac"), AudioSource::Extract),
+            // Supported audio is uploaded untouched
+            (mp4, Some("m4a"), AudioSource::Upload(AudioMimeType::Mp4)),
+            (
+                b"OggS\x00\x02",
+                None,
+                AudioSource::Upload(AudioMimeType::Ogg),
+            ),
+            (
+                b"\x00\x01",
+                Some("mp3"),
+                AudioSource::Upload(AudioMimeType::Mp3),
+            ),
+        ];
+        for (bytes, extension, expected) in cases {
+            assert_eq!(
+                AudioSource::detect(bytes, extension).unwrap(),
+                expected,
+                "{extension:?}"
+            );
+        }
+        assert!(AudioSource::detect(b"hello", Some("txt")).is_err());
+        assert!(AudioSource::accepts_extension("MKV"));
+        assert!(AudioSource::accepts_extension("opus"));
+        assert!(!AudioSource::accepts_extension("txt"));
+    }
+
+    #[test]
+    fn save_then_load_round_trips_bytes_and_type() {
+        let dir = tempfile::tempdir().unwrap();
+        let flac = AudioData::new(b"fLaC\x00\x00\x00\x22rest".to_vec(), AudioMimeType::Flac);
+
+        let written = flac.save(&dir.path().join("clip")).unwrap();
+        assert_eq!(written, dir.path().join("clip.flac"));
+        let loaded = AudioData::load(&written).unwrap();
+        assert_eq!(loaded.data(), flac.data());
+        assert_eq!(loaded.mime_type(), AudioMimeType::Flac);
+
+        // An explicit extension is kept, and the contents still decide the type.
+        let wav = AudioData::new(b"RIFF\0\0\0\0WAVEfmt ".to_vec(), AudioMimeType::Wav);
+        let written = wav.save(&dir.path().join("clip.bin")).unwrap();
+        assert_eq!(written, dir.path().join("clip.bin"));
+        let loaded = AudioData::load(&written).unwrap();
+        assert_eq!(loaded.data(), wav.data());
+        assert_eq!(loaded.mime_type(), AudioMimeType::Wav);
+    }
+
+    #[test]
+    fn load_reports_missing_and_unrecognised_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing.ogg");
+        assert!(matches!(
+            AudioData::load(&missing),
+            Err(AudioFileError::Read { .. })
+        ));
+
+        let text = dir.path().join("notes.txt");
+        fs::write(&text, "hello").unwrap();
+        assert!(matches!(
+            AudioData::load(&text),
+            Err(AudioFileError::Unsupported(_))
+        ));
+    }
+
     #[test]
     fn audio_data_size() {
         let data = AudioData::new(vec![0u8; 1024], AudioMimeType::Flac);
@@ -137,6 +475,26 @@ mod tests {
         assert_eq!(data.mime_type(), AudioMimeType::Mp3);
     }
 
+    #[test]
+    fn silence_needs_a_known_peak() {
+        let unknown = AudioData::new(vec![0u8; 16], AudioMimeType::Flac);
+        assert!(!unknown.is_silent(-60));
+
+        let silent = unknown.clone().with_peak_dbfs(f32::NEG_INFINITY);
+        assert!(silent.is_silent(-60));
+
+        let speech = unknown.with_peak_dbfs(-20.0);
+        assert!(!speech.is_silent(-60));
+    }
+
+    #[test]
+    fn clipping_needs_a_known_ratio() {
+        let unknown = AudioData::new(vec![0u8; 16], AudioMimeType::Flac);
+        assert!(!unknown.is_clipping());
+        assert!(unknown.clone().with_clipped_ratio(0.05).is_clipping());
+        assert!(!unknown.with_clipped_ratio(0.0).is_clipping());
+    }
+
     #[test]
     fn default_mime_type_is_flac() {
         assert_eq!(AudioMimeType::default(), AudioMimeType::Flac);
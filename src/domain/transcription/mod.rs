@@ -1,5 +1,13 @@
 //! Transcription domain module
 
 mod audio_data;
+mod segment;
+mod stats;
+mod template;
+mod timings;
 
-pub use audio_data::{AudioData, AudioMimeType};
+pub use audio_data::{AudioData, AudioMimeType, AudioSource};
+pub use segment::{format_timestamp, format_timestamped, TimedTranscript, TranscriptSegment};
+pub use stats::{char_count, word_count};
+pub use template::{TextTemplate, TEXT_PLACEHOLDER};
+pub use timings::Timings;
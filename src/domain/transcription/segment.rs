@@ -0,0 +1,81 @@
+//! Timed transcript segments
+
+/// A stretch of transcript and where it starts in the recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptSegment {
+    pub start_ms: u64,
+    pub text: String,
+}
+
+/// A transcript plus per-segment timing, when the backend provides it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedTranscript {
+    pub text: String,
+    /// `None` when the backend or model has no segment timing.
+    pub segments: Option<Vec<TranscriptSegment>>,
+}
+
+impl TimedTranscript {
+    /// A transcript without timing information
+    pub fn untimed(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            segments: None,
+        }
+    }
+}
+
+/// Recording-relative time as `mm:ss`, or `h:mm:ss` from the first hour on.
+pub fn format_timestamp(ms: u64) -> String {
+    let secs = ms / 1000;
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+/// One `[mm:ss] text` line per non-empty segment.
+pub fn format_timestamped(segments: &[TranscriptSegment]) -> String {
+    segments
+        .iter()
+        .filter(|s| !s.text.trim().is_empty())
+        .map(|s| format!("[{}] {}", format_timestamp(s.start_ms), s.text.trim()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamp_switches_to_hours() {
+        assert_eq!(format_timestamp(0), "00:00");
+        assert_eq!(format_timestamp(83_900), "01:23");
+        assert_eq!(format_timestamp(3_725_000), "1:02:05");
+    }
+
+    #[test]
+    fn timestamped_lines_skip_blank_segments() {
+        let segments = [
+            TranscriptSegment {
+                start_ms: 0,
+                text: " Hello there.".into(),
+            },
+            TranscriptSegment {
+                start_ms: 4_200,
+                text: "  ".into(),
+            },
+            TranscriptSegment {
+                start_ms: 65_000,
+                text: "Next item.".into(),
+            },
+        ];
+        assert_eq!(
+            format_timestamped(&segments),
+            "[00:00] Hello there.\n[01:05] Next item."
+        );
+    }
+}
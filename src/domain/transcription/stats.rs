@@ -0,0 +1,32 @@
+//! Transcript length statistics
+
+/// Number of whitespace-separated words; runs of spaces, tabs and
+/// newlines count as a single break.
+pub fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// Number of characters (Unicode scalar values, not bytes).
+pub fn char_count(text: &str) -> usize {
+    text.chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_whitespace_and_newlines_separate_words_once() {
+        assert_eq!(word_count("hello   world"), 2);
+        assert_eq!(word_count("one\ntwo\n\nthree\t four "), 4);
+        assert_eq!(word_count("  \n\t "), 0);
+        assert_eq!(word_count(""), 0);
+    }
+
+    #[test]
+    fn characters_are_counted_not_bytes() {
+        assert_eq!(char_count("héllo"), 5);
+        assert_eq!(char_count("a b\nc"), 5);
+        assert_eq!(char_count(""), 0);
+    }
+}
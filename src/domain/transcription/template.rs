@@ -0,0 +1,103 @@
+//! Text placed around each transcript (`--prepend` / `--append-text`)
+
+/// Placeholder replaced by the transcript.
+pub const TEXT_PLACEHOLDER: &str = "{text}";
+
+/// Fixed text wrapped around a transcript before it is output.
+///
+/// Both parts take `\n`, `\t` and `\\` escapes. Without a `{text}`
+/// placeholder the transcript goes between them; with one (in either part)
+/// the two parts form a single template and the transcript goes wherever
+/// `{text}` appears.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextTemplate {
+    prepend: String,
+    append: String,
+}
+
+impl TextTemplate {
+    /// Template from the raw (unescaped) `prepend` and `append` strings.
+    pub fn new(prepend: Option<&str>, append: Option<&str>) -> Self {
+        Self {
+            prepend: prepend.map(unescape).unwrap_or_default(),
+            append: append.map(unescape).unwrap_or_default(),
+        }
+    }
+
+    /// Whether applying the template leaves every transcript unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.prepend.is_empty() && self.append.is_empty()
+    }
+
+    /// `text` with the template applied.
+    pub fn apply(&self, text: &str) -> String {
+        let whole = format!("{}{}", self.prepend, self.append);
+        if whole.contains(TEXT_PLACEHOLDER) {
+            whole.replace(TEXT_PLACEHOLDER, text)
+        } else {
+            format!("{}{}{}", self.prepend, text, self.append)
+        }
+    }
+}
+
+/// Resolve `\n`, `\t` and `\\`; any other backslash is kept as typed.
+fn unescape(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('\\') => out.push('\\'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_template_keeps_text() {
+        let template = TextTemplate::new(None, Some(""));
+        assert!(template.is_empty());
+        assert_eq!(template.apply("hello"), "hello");
+    }
+
+    #[test]
+    fn parts_wrap_the_text() {
+        let template = TextTemplate::new(Some("Note: "), Some("\\n"));
+        assert_eq!(template.apply("buy milk"), "Note: buy milk\n");
+        let quoted = TextTemplate::new(Some("\""), Some("\""));
+        assert_eq!(quoted.apply("hi"), "\"hi\"");
+    }
+
+    #[test]
+    fn placeholder_only_template_keeps_text() {
+        let template = TextTemplate::new(Some("{text}"), None);
+        assert!(!template.is_empty());
+        assert_eq!(template.apply("hello"), "hello");
+    }
+
+    #[test]
+    fn placeholder_places_the_text() {
+        let template = TextTemplate::new(Some("> {text}\\t"), Some("({text})"));
+        assert_eq!(template.apply("hi"), "> hi\t(hi)");
+    }
+
+    #[test]
+    fn escapes_resolve_and_unknown_ones_stay() {
+        assert_eq!(unescape("a\\nb\\tc\\\\n"), "a\nb\tc\\n");
+        assert_eq!(unescape("C:\\Users\\"), "C:\\Users\\");
+    }
+}
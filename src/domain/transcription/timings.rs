@@ -0,0 +1,31 @@
+//! Where the time of one transcription went
+
+use serde::{Deserialize, Serialize};
+
+/// Wall-clock time spent in each stage of one transcription, in
+/// milliseconds, measured with a monotonic clock.
+///
+/// A stage the run skipped (no recording for stdin input, no request on
+/// `--dry-run`) or that the recorder didn't measure is `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timings {
+    /// Capturing audio, not counting the encoding.
+    pub recording_ms: Option<u64>,
+    /// Encoding the capture for upload.
+    pub encoding_ms: Option<u64>,
+    /// The transcription request.
+    pub transcription_ms: Option<u64>,
+    /// Start of the run (recording, else the transcription step) to the
+    /// last output action.
+    pub total_ms: u64,
+}
+
+impl Timings {
+    /// Sum of the measured stages; never more than `total_ms`.
+    pub fn stages_ms(&self) -> u64 {
+        [self.recording_ms, self.encoding_ms, self.transcription_ms]
+            .into_iter()
+            .flatten()
+            .sum()
+    }
+}
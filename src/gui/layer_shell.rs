@@ -2,18 +2,25 @@
 //!
 //! Uses smithay-client-toolkit to create a layer-shell surface that:
 //! - Renders on the overlay layer (always on top)
-//! - Has no keyboard interactivity (click-through)
+//! - Has no keyboard interactivity and an empty input region (click-through),
+//!   unless clicks are opted into, in which case a left click while recording
+//!   sends `toggle` to the daemon (still without taking keyboard focus)
 //! - Doesn't appear in taskbar
 //! - Properly positions in screen corners
 
 use std::sync::mpsc;
 
 use smithay_client_toolkit::{
-    compositor::{CompositorHandler, CompositorState},
-    delegate_compositor, delegate_layer, delegate_output, delegate_registry, delegate_shm,
+    compositor::{CompositorHandler, CompositorState, Region},
+    delegate_compositor, delegate_layer, delegate_output, delegate_pointer, delegate_registry,
+    delegate_seat, delegate_shm,
     output::{OutputHandler, OutputState},
     registry::{ProvidesRegistryState, RegistryState},
     registry_handlers,
+    seat::{
+        pointer::{PointerEvent, PointerEventKind, PointerHandler},
+        Capability, SeatHandler, SeatState,
+    },
     shell::{
         wlr_layer::{
             Anchor, KeyboardInteractivity, Layer, LayerShell, LayerShellHandler, LayerSurface,
@@ -26,39 +33,21 @@ use smithay_client_toolkit::{
         Shm, ShmHandler,
     },
 };
-use tiny_skia::{Color, FillRule, Paint, PathBuilder, Pixmap, Transform};
 use tokio::sync::broadcast;
 use wayland_client::{
     globals::registry_queue_init,
-    protocol::{wl_output, wl_shm, wl_surface},
+    protocol::{wl_output, wl_pointer, wl_seat, wl_shm, wl_surface},
     Connection, QueueHandle,
 };
 
+use super::render::{write_bgra, IndicatorRenderer};
 use crate::cli::args::IndicatorPosition;
+use crate::cli::signals::DaemonSignal;
+use crate::domain::config::IndicatorStyle;
 use crate::domain::daemon::{DaemonState, StateUpdate};
 
-/// Window dimensions (compact for time-only display)
-const WIDTH: u32 = 100;
-const HEIGHT: u32 = 44;
-
-/// Margin from screen edge
-const MARGIN: i32 = 20;
-
-/// Embedded 7-segment LCD font (DSEG7 Classic Bold, OFL license)
-const FONT_DATA: &[u8] = include_bytes!("../../assets/DSEG7Classic-Bold.ttf");
-
-/// Color helpers (Color::from_rgba8 is not const)
-fn bg_color() -> Color {
-    Color::from_rgba8(30, 30, 30, 220)
-}
-
-fn recording_color() -> Color {
-    Color::from_rgba8(220, 50, 50, 255)
-}
-
-fn processing_color() -> Color {
-    Color::from_rgba8(255, 180, 50, 255)
-}
+/// Linux input event code for the primary mouse button (`BTN_LEFT`).
+const BTN_LEFT: u32 = 0x110;
 
 /// Error type for layer shell indicator
 #[derive(Debug, thiserror::Error)]
@@ -85,18 +74,11 @@ pub enum LayerShellError {
 /// Returns Err if Wayland/layer-shell is not available (caller should fallback).
 pub fn run_indicator(
     position: IndicatorPosition,
+    style: IndicatorStyle,
     state_rx: broadcast::Receiver<StateUpdate>,
+    click_tx: Option<tokio::sync::mpsc::Sender<DaemonSignal>>,
 ) -> Result<(), LayerShellError> {
-    // Bridge broadcast to mpsc for blocking receive
-    let (tx, rx) = mpsc::channel();
-    std::thread::spawn(move || {
-        let mut state_rx = state_rx;
-        while let Ok(update) = state_rx.blocking_recv() {
-            if tx.send(update).is_err() {
-                break;
-            }
-        }
-    });
+    let rx = super::bridge_state_updates(state_rx);
 
     // Connect to Wayland
     let conn = Connection::connect_to_env()?;
@@ -104,7 +86,7 @@ pub fn run_indicator(
     let qh = event_queue.handle();
 
     // Create app state
-    let mut app = LayerShellIndicator::new(&globals, &qh, position, rx)?;
+    let mut app = LayerShellIndicator::new(&globals, &qh, position, style, rx, click_tx)?;
 
     // Initial roundtrip to get outputs
     event_queue.roundtrip(&mut app)?;
@@ -120,7 +102,7 @@ pub fn run_indicator(
         // If surface is mapped and dirty, redraw
         if app.surface_mapped && app.dirty {
             if let Err(e) = app.draw(&qh) {
-                eprintln!("Layer-shell draw error: {}", e);
+                tracing::warn!("Layer-shell draw error: {}", e);
             }
             app.dirty = false;
         }
@@ -158,13 +140,20 @@ struct LayerShellIndicator {
     compositor_state: CompositorState,
     shm: Shm,
     layer_shell: LayerShell,
+    seat_state: SeatState,
+    pointer: Option<wl_pointer::WlPointer>,
+    /// Set when clicks are opted into; receives `Toggle` on a left click.
+    click_tx: Option<tokio::sync::mpsc::Sender<DaemonSignal>>,
 
     position: IndicatorPosition,
+    style: IndicatorStyle,
     state_rx: mpsc::Receiver<StateUpdate>,
 
     // Current daemon state
     daemon_state: DaemonState,
     elapsed_ms: u64,
+    remaining_ms: Option<u64>,
+    level: Option<f32>,
 
     // Surface state
     layer_surface: Option<LayerSurface>,
@@ -175,8 +164,7 @@ struct LayerShellIndicator {
     pool: SlotPool,
     buffer: Option<Buffer>,
 
-    // Font for rendering text
-    font: fontdue::Font,
+    renderer: IndicatorRenderer,
 
     // Track if we've ever created a surface
     surface_created: bool,
@@ -187,7 +175,9 @@ impl LayerShellIndicator {
         globals: &wayland_client::globals::GlobalList,
         qh: &QueueHandle<Self>,
         position: IndicatorPosition,
+        style: IndicatorStyle,
         state_rx: mpsc::Receiver<StateUpdate>,
+        click_tx: Option<tokio::sync::mpsc::Sender<DaemonSignal>>,
     ) -> Result<Self, LayerShellError> {
         let registry_state = RegistryState::new(globals);
         let output_state = OutputState::new(globals, qh);
@@ -198,29 +188,31 @@ impl LayerShellIndicator {
             LayerShell::bind(globals, qh).map_err(|_| LayerShellError::LayerShellNotAvailable)?;
 
         // Create buffer pool for rendering
-        let pool = SlotPool::new((WIDTH * HEIGHT * 4) as usize, &shm)
+        let pool = SlotPool::new((style.width * style.height * 4) as usize, &shm)
             .map_err(|e| LayerShellError::BufferPool(e.to_string()))?;
 
-        // Load embedded 7-segment LCD font
-        let font = fontdue::Font::from_bytes(FONT_DATA, fontdue::FontSettings::default())
-            .expect("Failed to load embedded font");
-
         Ok(Self {
             registry_state,
             output_state,
             compositor_state,
             shm,
             layer_shell,
+            seat_state: SeatState::new(globals, qh),
+            pointer: None,
+            click_tx,
             position,
+            style,
             state_rx,
             daemon_state: DaemonState::Idle,
             elapsed_ms: 0,
+            remaining_ms: None,
+            level: None,
             layer_surface: None,
             surface_mapped: false,
             dirty: false,
             pool,
             buffer: None,
-            font,
+            renderer: IndicatorRenderer::new(&style),
             surface_created: false,
         })
     }
@@ -230,6 +222,8 @@ impl LayerShellIndicator {
             let state_changed = self.daemon_state != update.state;
             self.daemon_state = update.state;
             self.elapsed_ms = update.elapsed_ms;
+            self.remaining_ms = update.remaining_ms;
+            self.level = update.level;
 
             // Mark dirty if state changed or we're recording (timer updates)
             if state_changed || self.daemon_state == DaemonState::Recording {
@@ -277,29 +271,39 @@ impl LayerShellIndicator {
         layer_surface.set_anchor(anchor);
 
         // Set margins from screen edge
+        let margin = self.style.margin as i32;
         match self.position {
             IndicatorPosition::TopRight => {
-                layer_surface.set_margin(MARGIN, MARGIN, 0, 0);
+                layer_surface.set_margin(margin, margin, 0, 0);
             }
             IndicatorPosition::TopLeft => {
-                layer_surface.set_margin(MARGIN, 0, 0, MARGIN);
+                layer_surface.set_margin(margin, 0, 0, margin);
             }
             IndicatorPosition::TopCenter => {
-                layer_surface.set_margin(MARGIN, 0, 0, 0);
+                layer_surface.set_margin(margin, 0, 0, 0);
             }
             IndicatorPosition::BottomCenter => {
-                layer_surface.set_margin(0, 0, MARGIN, 0);
+                layer_surface.set_margin(0, 0, margin, 0);
             }
             IndicatorPosition::BottomRight => {
-                layer_surface.set_margin(0, MARGIN, MARGIN, 0);
+                layer_surface.set_margin(0, margin, margin, 0);
             }
             IndicatorPosition::BottomLeft => {
-                layer_surface.set_margin(0, 0, MARGIN, MARGIN);
+                layer_surface.set_margin(0, 0, margin, margin);
             }
         }
 
         // Set size
-        layer_surface.set_size(WIDTH, HEIGHT);
+        layer_surface.set_size(self.style.width, self.style.height);
+
+        // Empty input region lets clicks fall through to whatever is below
+        if self.click_tx.is_none() {
+            if let Ok(region) = Region::new(&self.compositor_state) {
+                layer_surface
+                    .wl_surface()
+                    .set_input_region(Some(region.wl_region()));
+            }
+        }
 
         // No keyboard interactivity (click-through)
         layer_surface.set_keyboard_interactivity(KeyboardInteractivity::None);
@@ -329,29 +333,22 @@ impl LayerShellIndicator {
         }
 
         // Render to pixmap first (before borrowing pool)
-        let pixmap = self.render();
+        let pixmap = self.renderer.render(
+            self.daemon_state,
+            self.elapsed_ms,
+            self.remaining_ms,
+            self.level,
+        );
 
         // Allocate buffer
+        let (width, height) = (self.style.width as i32, self.style.height as i32);
         let (buffer, canvas) = self
             .pool
-            .create_buffer(
-                WIDTH as i32,
-                HEIGHT as i32,
-                (WIDTH * 4) as i32,
-                wl_shm::Format::Argb8888,
-            )
+            .create_buffer(width, height, width * 4, wl_shm::Format::Argb8888)
             .map_err(|e| LayerShellError::BufferPool(e.to_string()))?;
 
-        // Copy pixmap data to buffer (convert RGBA to ARGB)
-        let src = pixmap.data();
-        for (i, chunk) in canvas.chunks_exact_mut(4).enumerate() {
-            let si = i * 4;
-            // tiny-skia uses RGBA, wayland expects ARGB (actually BGRA on little-endian)
-            chunk[0] = src[si + 2]; // B
-            chunk[1] = src[si + 1]; // G
-            chunk[2] = src[si]; // R
-            chunk[3] = src[si + 3]; // A
-        }
+        // Copy pixmap data to buffer (tiny-skia RGBA -> wl_shm ARGB8888)
+        write_bgra(&pixmap, canvas);
 
         // Now access layer_surface for attaching
         let layer_surface = self.layer_surface.as_ref().unwrap();
@@ -364,7 +361,7 @@ impl LayerShellIndicator {
         // Damage the entire surface
         layer_surface
             .wl_surface()
-            .damage_buffer(0, 0, WIDTH as i32, HEIGHT as i32);
+            .damage_buffer(0, 0, width, height);
 
         // Commit the surface
         layer_surface.commit();
@@ -374,170 +371,6 @@ impl LayerShellIndicator {
 
         Ok(())
     }
-
-    fn render(&self) -> Pixmap {
-        let mut pixmap = Pixmap::new(WIDTH, HEIGHT).unwrap();
-
-        // Fill with transparent
-        pixmap.fill(Color::TRANSPARENT);
-
-        // Draw rounded background
-        let mut paint = Paint::default();
-        paint.set_color(bg_color());
-        paint.anti_alias = true;
-
-        let radius = 8.0;
-        let rect_path = {
-            let mut pb = PathBuilder::new();
-            pb.move_to(radius, 0.0);
-            pb.line_to(WIDTH as f32 - radius, 0.0);
-            pb.quad_to(WIDTH as f32, 0.0, WIDTH as f32, radius);
-            pb.line_to(WIDTH as f32, HEIGHT as f32 - radius);
-            pb.quad_to(
-                WIDTH as f32,
-                HEIGHT as f32,
-                WIDTH as f32 - radius,
-                HEIGHT as f32,
-            );
-            pb.line_to(radius, HEIGHT as f32);
-            pb.quad_to(0.0, HEIGHT as f32, 0.0, HEIGHT as f32 - radius);
-            pb.line_to(0.0, radius);
-            pb.quad_to(0.0, 0.0, radius, 0.0);
-            pb.close();
-            pb.finish().unwrap()
-        };
-
-        pixmap.fill_path(
-            &rect_path,
-            &paint,
-            FillRule::Winding,
-            Transform::identity(),
-            None,
-        );
-
-        // Get color based on state (red = recording, orange = processing)
-        let indicator_color = match self.daemon_state {
-            DaemonState::Recording => recording_color(),
-            DaemonState::Processing => processing_color(),
-            DaemonState::Idle => return pixmap, // Should not reach here
-        };
-
-        // Draw colored circle indicator
-        paint.set_color(indicator_color);
-        let circle_x = 16.0;
-        let circle_y = HEIGHT as f32 / 2.0;
-        let circle_radius = 7.0;
-
-        let circle_path = {
-            let mut pb = PathBuilder::new();
-            pb.push_circle(circle_x, circle_y, circle_radius);
-            pb.finish().unwrap()
-        };
-        pixmap.fill_path(
-            &circle_path,
-            &paint,
-            FillRule::Winding,
-            Transform::identity(),
-            None,
-        );
-
-        // Draw time in LCD style (same color as indicator)
-        let time_text = self.format_elapsed();
-        self.draw_time(&mut pixmap, &time_text, indicator_color);
-
-        pixmap
-    }
-
-    fn draw_time(&self, pixmap: &mut Pixmap, text: &str, color: Color) {
-        let font_size = 18.0;
-        // Get actual glyph height from a representative digit for proper centering
-        let (metrics, _) = self.font.rasterize('0', font_size);
-        let glyph_height = metrics.height as f32;
-        let y_baseline = (HEIGHT as f32 + glyph_height) / 2.0;
-
-        // Calculate total text width for horizontal centering
-        // Text area starts after the indicator dot (circle at x=16, radius=7, plus margin)
-        let text_area_start = 26.0;
-        let text_area_width = WIDTH as f32 - text_area_start;
-        let text_width: f32 = text
-            .chars()
-            .map(|ch| self.font.rasterize(ch, font_size).0.advance_width)
-            .sum();
-        let mut x = text_area_start + (text_area_width - text_width) / 2.0;
-        for ch in text.chars() {
-            let (metrics, bitmap) = self.font.rasterize(ch, font_size);
-            if bitmap.is_empty() {
-                x += metrics.advance_width;
-                continue;
-            }
-
-            let glyph_x = x + metrics.xmin as f32;
-            let glyph_y = y_baseline - metrics.height as f32 - metrics.ymin as f32;
-
-            // Draw each pixel of the glyph
-            for gy in 0..metrics.height {
-                for gx in 0..metrics.width {
-                    let coverage = bitmap[gy * metrics.width + gx];
-                    if coverage == 0 {
-                        continue;
-                    }
-
-                    let px = (glyph_x + gx as f32) as i32;
-                    let py = (glyph_y + gy as f32) as i32;
-
-                    if px >= 0 && px < WIDTH as i32 && py >= 0 && py < HEIGHT as i32 {
-                        let alpha = (coverage as f32 / 255.0) * color.alpha();
-                        let pixel_color =
-                            Color::from_rgba(color.red(), color.green(), color.blue(), alpha)
-                                .unwrap_or(color);
-
-                        // Blend with existing pixel
-                        if let Some(existing) = pixmap.pixel(px as u32, py as u32) {
-                            let blended = blend_pixel(existing, pixel_color);
-                            pixmap.pixels_mut()[(py as u32 * WIDTH + px as u32) as usize] = blended;
-                        }
-                    }
-                }
-            }
-
-            x += metrics.advance_width;
-        }
-    }
-
-    fn format_elapsed(&self) -> String {
-        let secs = self.elapsed_ms / 1000;
-        let mins = secs / 60;
-        let secs = secs % 60;
-        format!("{}:{:02}", mins, secs)
-    }
-}
-
-/// Blend two pixels using alpha compositing
-fn blend_pixel(
-    dst: tiny_skia::PremultipliedColorU8,
-    src: Color,
-) -> tiny_skia::PremultipliedColorU8 {
-    let src_a = src.alpha();
-    let dst_a = dst.alpha() as f32 / 255.0;
-
-    let out_a = src_a + dst_a * (1.0 - src_a);
-    if out_a == 0.0 {
-        return tiny_skia::PremultipliedColorU8::from_rgba(0, 0, 0, 0).unwrap();
-    }
-
-    let blend = |src_c: f32, dst_c: u8| -> u8 {
-        let dst_c = dst_c as f32 / 255.0;
-        let out_c = (src_c * src_a + dst_c * dst_a * (1.0 - src_a)) / out_a;
-        (out_c * 255.0).clamp(0.0, 255.0) as u8
-    };
-
-    tiny_skia::PremultipliedColorU8::from_rgba(
-        blend(src.red(), dst.red()),
-        blend(src.green(), dst.green()),
-        blend(src.blue(), dst.blue()),
-        (out_a * 255.0) as u8,
-    )
-    .unwrap()
 }
 
 // SCTK delegate implementations
@@ -644,6 +477,73 @@ impl LayerShellHandler for LayerShellIndicator {
     }
 }
 
+impl SeatHandler for LayerShellIndicator {
+    fn seat_state(&mut self) -> &mut SeatState {
+        &mut self.seat_state
+    }
+
+    fn new_seat(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _seat: wl_seat::WlSeat) {}
+
+    fn new_capability(
+        &mut self,
+        _conn: &Connection,
+        qh: &QueueHandle<Self>,
+        seat: wl_seat::WlSeat,
+        capability: Capability,
+    ) {
+        // Only bind a pointer when clicks are wanted; never a keyboard.
+        if capability == Capability::Pointer && self.click_tx.is_some() && self.pointer.is_none() {
+            match self.seat_state.get_pointer(qh, &seat) {
+                Ok(pointer) => self.pointer = Some(pointer),
+                Err(e) => tracing::warn!("Failed to bind pointer for indicator: {}", e),
+            }
+        }
+    }
+
+    fn remove_capability(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _seat: wl_seat::WlSeat,
+        capability: Capability,
+    ) {
+        if capability == Capability::Pointer {
+            if let Some(pointer) = self.pointer.take() {
+                pointer.release();
+            }
+        }
+    }
+
+    fn remove_seat(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _seat: wl_seat::WlSeat) {
+    }
+}
+
+impl PointerHandler for LayerShellIndicator {
+    fn pointer_frame(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _pointer: &wl_pointer::WlPointer,
+        events: &[PointerEvent],
+    ) {
+        let Some(surface) = self.layer_surface.as_ref().map(|l| l.wl_surface().clone()) else {
+            return;
+        };
+        for event in events {
+            if event.surface != surface {
+                continue;
+            }
+            if let PointerEventKind::Press { button, .. } = event.kind {
+                if button == BTN_LEFT && self.daemon_state == DaemonState::Recording {
+                    if let Some(tx) = &self.click_tx {
+                        let _ = tx.try_send(DaemonSignal::Toggle);
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl ShmHandler for LayerShellIndicator {
     fn shm_state(&mut self) -> &mut Shm {
         &mut self.shm
@@ -655,7 +555,7 @@ impl ProvidesRegistryState for LayerShellIndicator {
         &mut self.registry_state
     }
 
-    registry_handlers![OutputState];
+    registry_handlers![OutputState, SeatState];
 }
 
 delegate_compositor!(LayerShellIndicator);
@@ -663,3 +563,5 @@ delegate_output!(LayerShellIndicator);
 delegate_shm!(LayerShellIndicator);
 delegate_layer!(LayerShellIndicator);
 delegate_registry!(LayerShellIndicator);
+delegate_seat!(LayerShellIndicator);
+delegate_pointer!(LayerShellIndicator);
@@ -1,14 +1,197 @@
 //! GUI module for recording indicator.
 //!
-//! Linux: Wayland layer-shell overlay (`gui::layer_shell`).
-//! Windows: System tray icon (`gui::tray`).
+//! Linux: Wayland layer-shell overlay (`gui::layer_shell`), falling back to
+//! an X11 window (`gui::x11`) when no Wayland display is available.
+//! Windows: System tray icon (`gui::tray`) plus a layered overlay
+//! (`gui::overlay`).
+//! macOS: out of scope. AppKit windows must live on the main thread, which
+//! the tokio runtime owns, so there is no `run_indicator` and `--indicator`
+//! is compiled out.
 
 #[cfg(target_os = "linux")]
 pub mod layer_shell;
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+mod render;
 #[cfg(target_os = "linux")]
-pub use layer_shell::run_indicator;
+pub mod x11;
 
+#[cfg(target_os = "windows")]
+pub mod overlay;
 #[cfg(target_os = "windows")]
 pub mod tray;
+
+/// Forward the daemon's broadcast [`StateUpdate`]s into a std channel on a
+/// helper thread, so the indicator loops can block on it without an async
+/// context.
+///
+/// A bridge that falls behind skips to the newest updates rather than
+/// stopping; only the latest state matters for drawing, and stopping would
+/// freeze the on-screen timer for the rest of the session.
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+fn bridge_state_updates(
+    mut state_rx: tokio::sync::broadcast::Receiver<crate::domain::daemon::StateUpdate>,
+) -> std::sync::mpsc::Receiver<crate::domain::daemon::StateUpdate> {
+    use tokio::sync::broadcast::error::RecvError;
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || loop {
+        match state_rx.blocking_recv() {
+            Ok(update) => {
+                if tx.send(update).is_err() {
+                    break;
+                }
+            }
+            Err(RecvError::Lagged(skipped)) => {
+                tracing::debug!(skipped, "indicator fell behind; skipping stale updates");
+            }
+            Err(RecvError::Closed) => break,
+        }
+    });
+    rx
+}
+
+#[cfg(all(test, any(target_os = "linux", target_os = "windows")))]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::sync::broadcast;
+
+    use super::*;
+    use crate::domain::daemon::{DaemonState, StateUpdate};
+
+    #[test]
+    fn bridge_survives_lag_and_ends_when_daemon_closes() {
+        let (tx, rx) = broadcast::channel(2);
+        for elapsed_ms in 0..5 {
+            tx.send(StateUpdate::new(DaemonState::Recording, elapsed_ms))
+                .unwrap();
+        }
+
+        let bridged = bridge_state_updates(rx);
+        let wait = Duration::from_secs(5);
+        // The three oldest were overwritten; the bridge keeps going with
+        // what is left instead of giving up.
+        assert_eq!(bridged.recv_timeout(wait).unwrap().elapsed_ms, 3);
+        assert_eq!(bridged.recv_timeout(wait).unwrap().elapsed_ms, 4);
+        tx.send(StateUpdate::new(DaemonState::Idle, 0)).unwrap();
+        assert_eq!(bridged.recv_timeout(wait).unwrap().state, DaemonState::Idle);
+
+        drop(tx);
+        assert!(matches!(
+            bridged.recv_timeout(wait),
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected)
+        ));
+    }
+}
+
+/// Run the Windows indicators: the overlay on its own thread (best effort)
+/// and the tray icon, which owns the control menu, on the calling thread.
 #[cfg(target_os = "windows")]
-pub use tray::run_indicator;
+pub fn run_indicator(
+    position: crate::cli::args::IndicatorPosition,
+    style: crate::domain::config::IndicatorStyle,
+    state_rx: tokio::sync::broadcast::Receiver<crate::domain::daemon::StateUpdate>,
+    signal_tx: tokio::sync::mpsc::Sender<crate::cli::signals::DaemonSignal>,
+) -> Result<(), tray::TrayError> {
+    let overlay_rx = state_rx.resubscribe();
+    std::thread::spawn(move || {
+        if let Err(e) = overlay::run_overlay(position, style, overlay_rx) {
+            tracing::warn!("Overlay indicator unavailable: {}", e);
+        }
+    });
+    tray::run_indicator(state_rx, signal_tx)
+}
+
+#[cfg(target_os = "linux")]
+pub use linux::{run_indicator, IndicatorError};
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::ffi::OsString;
+
+    use tokio::sync::broadcast;
+
+    use super::layer_shell::LayerShellError;
+    use super::x11::X11Error;
+    use crate::cli::args::IndicatorPosition;
+    use crate::cli::signals::DaemonSignal;
+    use crate::domain::config::IndicatorStyle;
+    use crate::domain::daemon::StateUpdate;
+
+    /// Error from whichever Linux indicator backend was selected
+    #[derive(Debug, thiserror::Error)]
+    pub enum IndicatorError {
+        #[error(transparent)]
+        LayerShell(#[from] LayerShellError),
+        #[error(transparent)]
+        X11(#[from] X11Error),
+        #[error("No display server found (neither WAYLAND_DISPLAY nor DISPLAY is set)")]
+        NoDisplay,
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Backend {
+        Wayland,
+        X11,
+    }
+
+    /// Wayland wins when both are set (XWayland sessions export `DISPLAY` too).
+    fn select_backend(
+        wayland_display: Option<OsString>,
+        display: Option<OsString>,
+    ) -> Option<Backend> {
+        let present = |v: Option<OsString>| v.is_some_and(|v| !v.is_empty());
+        if present(wayland_display) {
+            Some(Backend::Wayland)
+        } else if present(display) {
+            Some(Backend::X11)
+        } else {
+            None
+        }
+    }
+
+    /// Run the recording indicator on the session's display server
+    ///
+    /// With `click_tx`, the indicator accepts clicks and sends
+    /// [`DaemonSignal::Toggle`] to stop a recording; otherwise it is
+    /// click-through.
+    ///
+    /// Returns Err if no usable display server is found (caller should fallback).
+    pub fn run_indicator(
+        position: IndicatorPosition,
+        style: IndicatorStyle,
+        state_rx: broadcast::Receiver<StateUpdate>,
+        click_tx: Option<tokio::sync::mpsc::Sender<DaemonSignal>>,
+    ) -> Result<(), IndicatorError> {
+        match select_backend(
+            std::env::var_os("WAYLAND_DISPLAY"),
+            std::env::var_os("DISPLAY"),
+        ) {
+            Some(Backend::Wayland) => Ok(super::layer_shell::run_indicator(
+                position, style, state_rx, click_tx,
+            )?),
+            Some(Backend::X11) => Ok(super::x11::run_indicator(
+                position, style, state_rx, click_tx,
+            )?),
+            None => Err(IndicatorError::NoDisplay),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn prefers_wayland_then_x11() {
+            let some = |s: &str| Some(OsString::from(s));
+            assert_eq!(
+                select_backend(some("wayland-0"), some(":0")),
+                Some(Backend::Wayland)
+            );
+            assert_eq!(select_backend(None, some(":0")), Some(Backend::X11));
+            assert_eq!(select_backend(some(""), some(":0")), Some(Backend::X11));
+            assert_eq!(select_backend(None, some("")), None);
+            assert_eq!(select_backend(None, None), None);
+        }
+    }
+}
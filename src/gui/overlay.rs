@@ -0,0 +1,305 @@
+//! Windows on-screen recording indicator
+//!
+//! Complements the tray icon with the same dot + elapsed-time overlay the
+//! Linux backends show. Creates a small layered window that:
+//! - Is topmost, borderless and hidden from the taskbar (`WS_EX_TOOLWINDOW`)
+//! - Never takes focus (`WS_EX_NOACTIVATE`, shown with `SW_SHOWNOACTIVATE`)
+//! - Is click-through (`WS_EX_TRANSPARENT`)
+//! - Gets per-pixel alpha from `UpdateLayeredWindow`, which takes the
+//!   premultiplied BGRA that [`write_bgra`] produces
+//!
+//! Frames come from the same [`IndicatorRenderer`] as the Linux backends.
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+use windows_sys::Win32::Foundation::{HWND, POINT, RECT, SIZE};
+use windows_sys::Win32::Graphics::Gdi::{
+    CreateCompatibleDC, CreateDIBSection, DeleteDC, DeleteObject, SelectObject, AC_SRC_ALPHA,
+    AC_SRC_OVER, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, BLENDFUNCTION, DIB_RGB_COLORS, HBITMAP, HDC,
+    HGDIOBJ,
+};
+use windows_sys::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows_sys::Win32::UI::WindowsAndMessaging::{
+    CreateWindowExW, DefWindowProcW, DestroyWindow, DispatchMessageW, PeekMessageW, RegisterClassW,
+    ShowWindow, SystemParametersInfoW, TranslateMessage, UpdateLayeredWindow, MSG, PM_REMOVE,
+    SPI_GETWORKAREA, SW_HIDE, SW_SHOWNOACTIVATE, ULW_ALPHA, WM_QUIT, WNDCLASSW, WS_EX_LAYERED,
+    WS_EX_NOACTIVATE, WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT, WS_POPUP,
+};
+
+use super::render::{window_origin, write_bgra, IndicatorRenderer};
+use crate::cli::args::IndicatorPosition;
+use crate::domain::config::IndicatorStyle;
+use crate::domain::daemon::{DaemonState, StateUpdate};
+
+/// How long to wait for a state update before pumping window messages.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Error type for the Windows overlay
+#[derive(Debug, thiserror::Error)]
+pub enum OverlayError {
+    #[error("Failed to create overlay window: {0}")]
+    Window(std::io::Error),
+    #[error("Failed to allocate overlay bitmap: {0}")]
+    Bitmap(std::io::Error),
+}
+
+/// Run the overlay indicator
+///
+/// Blocks the calling thread on its own message pump. Returns Ok(()) when
+/// the daemon's state channel closes.
+pub fn run_overlay(
+    position: IndicatorPosition,
+    style: IndicatorStyle,
+    state_rx: broadcast::Receiver<StateUpdate>,
+) -> Result<(), OverlayError> {
+    let rx = super::bridge_state_updates(state_rx);
+
+    let mut overlay = Overlay::new(position, style)?;
+
+    loop {
+        match rx.recv_timeout(POLL_INTERVAL) {
+            Ok(update) => overlay.apply(update),
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+        while let Ok(update) = rx.try_recv() {
+            overlay.apply(update);
+        }
+
+        overlay.update_visibility();
+        if overlay.visible && overlay.dirty {
+            overlay.draw();
+            overlay.dirty = false;
+        }
+
+        // SAFETY: standard Win32 message pump on the thread owning the window.
+        unsafe {
+            let mut msg: MSG = std::mem::zeroed();
+            while PeekMessageW(&mut msg, std::ptr::null_mut(), 0, 0, PM_REMOVE) != 0 {
+                if msg.message == WM_QUIT {
+                    return Ok(());
+                }
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+    }
+}
+
+/// Overlay window plus the DIB section frames are rendered into
+struct Overlay {
+    hwnd: HWND,
+    dc: HDC,
+    bitmap: HBITMAP,
+    previous: HGDIOBJ,
+    bits: *mut u8,
+    origin: POINT,
+    width: i32,
+    height: i32,
+
+    daemon_state: DaemonState,
+    elapsed_ms: u64,
+    remaining_ms: Option<u64>,
+    level: Option<f32>,
+    visible: bool,
+    dirty: bool,
+
+    renderer: IndicatorRenderer,
+}
+
+impl Overlay {
+    fn new(position: IndicatorPosition, style: IndicatorStyle) -> Result<Self, OverlayError> {
+        let (width, height) = (style.width as i32, style.height as i32);
+        let class_name = wide("SmartScribeIndicator");
+
+        // SAFETY: plain Win32 calls with valid, NUL-terminated strings and
+        // zero-initialised out-structs; every handle is checked before use.
+        unsafe {
+            // Position within the primary monitor's work area (excludes the
+            // taskbar), falling back to the origin if the query fails.
+            let mut area: RECT = std::mem::zeroed();
+            SystemParametersInfoW(SPI_GETWORKAREA, 0, &mut area as *mut RECT as *mut _, 0);
+            let (x, y) = window_origin(
+                position,
+                &style,
+                area.right - area.left,
+                area.bottom - area.top,
+            );
+            let origin = POINT {
+                x: area.left + x,
+                y: area.top + y,
+            };
+
+            let instance = GetModuleHandleW(std::ptr::null());
+            let class = WNDCLASSW {
+                lpfnWndProc: Some(DefWindowProcW),
+                hInstance: instance,
+                lpszClassName: class_name.as_ptr(),
+                ..std::mem::zeroed()
+            };
+            // Fails harmlessly if the class is already registered.
+            RegisterClassW(&class);
+
+            let hwnd = CreateWindowExW(
+                WS_EX_LAYERED
+                    | WS_EX_TRANSPARENT
+                    | WS_EX_TOPMOST
+                    | WS_EX_TOOLWINDOW
+                    | WS_EX_NOACTIVATE,
+                class_name.as_ptr(),
+                class_name.as_ptr(),
+                WS_POPUP,
+                origin.x,
+                origin.y,
+                width,
+                height,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                instance,
+                std::ptr::null(),
+            );
+            if hwnd.is_null() {
+                return Err(OverlayError::Window(std::io::Error::last_os_error()));
+            }
+
+            let dc = CreateCompatibleDC(std::ptr::null_mut());
+            let info = BITMAPINFO {
+                bmiHeader: BITMAPINFOHEADER {
+                    biSize: std::mem::size_of::<BITMAPINFOHEADER>() as u32,
+                    biWidth: width,
+                    // Negative height = top-down rows, matching tiny-skia.
+                    biHeight: -height,
+                    biPlanes: 1,
+                    biBitCount: 32,
+                    biCompression: BI_RGB,
+                    ..std::mem::zeroed()
+                },
+                ..std::mem::zeroed()
+            };
+            let mut bits: *mut std::ffi::c_void = std::ptr::null_mut();
+            let bitmap = CreateDIBSection(
+                dc,
+                &info,
+                DIB_RGB_COLORS,
+                &mut bits,
+                std::ptr::null_mut(),
+                0,
+            );
+            if bitmap.is_null() || bits.is_null() {
+                let err = std::io::Error::last_os_error();
+                DeleteDC(dc);
+                DestroyWindow(hwnd);
+                return Err(OverlayError::Bitmap(err));
+            }
+            let previous = SelectObject(dc, bitmap);
+
+            Ok(Self {
+                hwnd,
+                dc,
+                bitmap,
+                previous,
+                bits: bits as *mut u8,
+                origin,
+                width,
+                height,
+                daemon_state: DaemonState::Idle,
+                elapsed_ms: 0,
+                remaining_ms: None,
+                level: None,
+                visible: false,
+                dirty: false,
+                renderer: IndicatorRenderer::new(&style),
+            })
+        }
+    }
+
+    fn apply(&mut self, update: StateUpdate) {
+        let state_changed = self.daemon_state != update.state;
+        self.daemon_state = update.state;
+        self.elapsed_ms = update.elapsed_ms;
+        self.remaining_ms = update.remaining_ms;
+        self.level = update.level;
+
+        // Mark dirty if state changed or we're recording (timer updates)
+        if state_changed || self.daemon_state == DaemonState::Recording {
+            self.dirty = true;
+        }
+    }
+
+    fn update_visibility(&mut self) {
+        let should_be_visible = self.daemon_state != DaemonState::Idle;
+
+        if should_be_visible && !self.visible {
+            // Draw before showing so the first frame is never blank.
+            self.draw();
+            // SAFETY: `hwnd` is a live window owned by this thread.
+            unsafe { ShowWindow(self.hwnd, SW_SHOWNOACTIVATE) };
+            self.visible = true;
+            self.dirty = false;
+        } else if !should_be_visible && self.visible {
+            // SAFETY: as above.
+            unsafe { ShowWindow(self.hwnd, SW_HIDE) };
+            self.visible = false;
+        }
+    }
+
+    fn draw(&mut self) {
+        let pixmap = self.renderer.render(
+            self.daemon_state,
+            self.elapsed_ms,
+            self.remaining_ms,
+            self.level,
+        );
+
+        let len = self.width as usize * self.height as usize * 4;
+        // SAFETY: `bits` points at the DIB section's `width * height` 32-bit
+        // pixels, which live until `Drop` deletes the bitmap.
+        let out = unsafe { std::slice::from_raw_parts_mut(self.bits, len) };
+        write_bgra(&pixmap, out);
+
+        let size = SIZE {
+            cx: self.width,
+            cy: self.height,
+        };
+        let source = POINT { x: 0, y: 0 };
+        let blend = BLENDFUNCTION {
+            BlendOp: AC_SRC_OVER as u8,
+            BlendFlags: 0,
+            SourceConstantAlpha: 255,
+            AlphaFormat: AC_SRC_ALPHA as u8,
+        };
+        // SAFETY: all handles are live; pointers reference locals.
+        unsafe {
+            UpdateLayeredWindow(
+                self.hwnd,
+                std::ptr::null_mut(),
+                &self.origin,
+                &size,
+                self.dc,
+                &source,
+                0,
+                &blend,
+                ULW_ALPHA,
+            );
+        }
+    }
+}
+
+impl Drop for Overlay {
+    fn drop(&mut self) {
+        // SAFETY: handles were created in `new` and are released exactly once.
+        unsafe {
+            SelectObject(self.dc, self.previous);
+            DeleteObject(self.bitmap);
+            DeleteDC(self.dc);
+            DestroyWindow(self.hwnd);
+        }
+    }
+}
+
+/// NUL-terminated UTF-16 copy of `s` for Win32 `W` APIs.
+fn wide(s: &str) -> Vec<u16> {
+    s.encode_utf16().chain(std::iter::once(0)).collect()
+}
@@ -0,0 +1,476 @@
+//! Backend-independent drawing for the recording indicator.
+//!
+//! Both the Wayland layer-shell and X11 backends render the same tiny-skia
+//! pixmap (sized and coloured by [`IndicatorStyle`]) and only differ in how
+//! they get it on screen.
+
+use tiny_skia::{Color, FillRule, Paint, PathBuilder, Pixmap, Rect, Transform};
+
+use crate::cli::args::IndicatorPosition;
+use crate::domain::config::{IndicatorStyle, Rgba};
+use crate::domain::daemon::DaemonState;
+
+/// Embedded 7-segment LCD font (DSEG7 Classic Bold, OFL license)
+const FONT_DATA: &[u8] = include_bytes!("../../assets/DSEG7Classic-Bold.ttf");
+
+/// Number of segments in the level meter.
+const LEVEL_SEGMENTS: u32 = 10;
+
+/// Smallest indicator height that leaves room for the level meter.
+const LEVEL_MIN_HEIGHT: u32 = 36;
+
+/// Remaining time below which the label counts down to the auto-stop.
+const COUNTDOWN_MS: u64 = 30_000;
+
+/// Hue the recording color fades toward as the auto-stop approaches.
+fn warning_color() -> Color {
+    Color::from_rgba8(255, 220, 0, 255)
+}
+
+/// Convert a configured straight-alpha colour for tiny-skia.
+fn color([r, g, b, a]: Rgba) -> Color {
+    Color::from_rgba8(r, g, b, a)
+}
+
+/// Rasterises the indicator (background pill, state dot, elapsed time).
+pub(crate) struct IndicatorRenderer {
+    font: fontdue::Font,
+    width: u32,
+    height: u32,
+    background: Color,
+    recording: Color,
+    processing: Color,
+}
+
+impl IndicatorRenderer {
+    pub(crate) fn new(style: &IndicatorStyle) -> Self {
+        // Load embedded 7-segment LCD font
+        let font = fontdue::Font::from_bytes(FONT_DATA, fontdue::FontSettings::default())
+            .expect("Failed to load embedded font");
+        Self {
+            font,
+            width: style.width,
+            height: style.height,
+            background: color(style.background),
+            recording: color(style.recording),
+            processing: color(style.processing),
+        }
+    }
+
+    /// Draw the indicator for `state` into a pixmap of the configured size.
+    ///
+    /// `remaining_ms` (daemon `max_duration` limit) switches the label to a
+    /// countdown and fades toward a warning hue near the auto-stop. `level`
+    /// adds an input meter along the bottom edge. Without either, only the
+    /// state dot and elapsed time are drawn.
+    pub(crate) fn render(
+        &self,
+        state: DaemonState,
+        elapsed_ms: u64,
+        remaining_ms: Option<u64>,
+        level: Option<f32>,
+    ) -> Pixmap {
+        let (width, height) = (self.width as f32, self.height as f32);
+        let mut pixmap = Pixmap::new(self.width, self.height).unwrap();
+
+        // Fill with transparent
+        pixmap.fill(Color::TRANSPARENT);
+
+        // Draw rounded background
+        let mut paint = Paint::default();
+        paint.set_color(self.background);
+        paint.anti_alias = true;
+
+        let radius = 8.0;
+        let rect_path = {
+            let mut pb = PathBuilder::new();
+            pb.move_to(radius, 0.0);
+            pb.line_to(width - radius, 0.0);
+            pb.quad_to(width, 0.0, width, radius);
+            pb.line_to(width, height - radius);
+            pb.quad_to(width, height, width - radius, height);
+            pb.line_to(radius, height);
+            pb.quad_to(0.0, height, 0.0, height - radius);
+            pb.line_to(0.0, radius);
+            pb.quad_to(0.0, 0.0, radius, 0.0);
+            pb.close();
+            pb.finish().unwrap()
+        };
+
+        pixmap.fill_path(
+            &rect_path,
+            &paint,
+            FillRule::Winding,
+            Transform::identity(),
+            None,
+        );
+
+        // Get color based on state (red = recording, orange = processing)
+        let indicator_color = match state {
+            DaemonState::Recording => match remaining_ms {
+                Some(remaining) => mix(self.recording, warning_color(), warning_amount(remaining)),
+                None => self.recording,
+            },
+            DaemonState::Processing => self.processing,
+            DaemonState::Idle => return pixmap, // Should not reach here
+        };
+
+        // Draw colored circle indicator
+        paint.set_color(indicator_color);
+        let circle_x = 16.0;
+        let circle_y = height / 2.0;
+        let circle_radius = 7.0;
+
+        let circle_path = {
+            let mut pb = PathBuilder::new();
+            pb.push_circle(circle_x, circle_y, circle_radius);
+            pb.finish().unwrap()
+        };
+        pixmap.fill_path(
+            &circle_path,
+            &paint,
+            FillRule::Winding,
+            Transform::identity(),
+            None,
+        );
+
+        // Draw time in LCD style (same color as indicator)
+        let time_text = match remaining_ms {
+            Some(remaining) if state == DaemonState::Recording && in_countdown(remaining) => {
+                format_countdown(remaining)
+            }
+            _ => format_elapsed(elapsed_ms),
+        };
+        self.draw_time(&mut pixmap, &time_text, indicator_color);
+
+        if let Some(level) = level {
+            self.draw_level(&mut pixmap, level, indicator_color);
+        }
+
+        pixmap
+    }
+
+    /// Segmented level meter under the time, lit segments in `color`.
+    fn draw_level(&self, pixmap: &mut Pixmap, level: f32, color: Color) {
+        // Too short to fit below the text without overlapping it.
+        if self.height < LEVEL_MIN_HEIGHT {
+            return;
+        }
+        let inset = 10.0;
+        let bar_height = 3.0;
+        let gap = 2.0;
+        let top = self.height as f32 - 7.0;
+        let segment_width = (self.width as f32 - 2.0 * inset - gap * (LEVEL_SEGMENTS - 1) as f32)
+            / LEVEL_SEGMENTS as f32;
+        let lit = lit_segments(level);
+
+        let mut unlit = color;
+        unlit.apply_opacity(0.25);
+        let mut paint = Paint::default();
+        for i in 0..LEVEL_SEGMENTS {
+            paint.set_color(if i < lit { color } else { unlit });
+            let x = inset + i as f32 * (segment_width + gap);
+            if let Some(rect) = Rect::from_xywh(x, top, segment_width, bar_height) {
+                pixmap.fill_rect(rect, &paint, Transform::identity(), None);
+            }
+        }
+    }
+
+    fn draw_time(&self, pixmap: &mut Pixmap, text: &str, color: Color) {
+        let font_size = 18.0;
+        // Get actual glyph height from a representative digit for proper centering
+        let (metrics, _) = self.font.rasterize('0', font_size);
+        let glyph_height = metrics.height as f32;
+        let y_baseline = (self.height as f32 + glyph_height) / 2.0;
+
+        // Calculate total text width for horizontal centering
+        // Text area starts after the indicator dot (circle at x=16, radius=7, plus margin)
+        let text_area_start = 26.0;
+        let text_area_width = self.width as f32 - text_area_start;
+        let text_width: f32 = text
+            .chars()
+            .map(|ch| self.font.rasterize(ch, font_size).0.advance_width)
+            .sum();
+        let mut x = text_area_start + (text_area_width - text_width) / 2.0;
+        for ch in text.chars() {
+            let (metrics, bitmap) = self.font.rasterize(ch, font_size);
+            if bitmap.is_empty() {
+                x += metrics.advance_width;
+                continue;
+            }
+
+            let glyph_x = x + metrics.xmin as f32;
+            let glyph_y = y_baseline - metrics.height as f32 - metrics.ymin as f32;
+
+            // Draw each pixel of the glyph
+            for gy in 0..metrics.height {
+                for gx in 0..metrics.width {
+                    let coverage = bitmap[gy * metrics.width + gx];
+                    if coverage == 0 {
+                        continue;
+                    }
+
+                    let px = (glyph_x + gx as f32) as i32;
+                    let py = (glyph_y + gy as f32) as i32;
+
+                    if px >= 0 && px < self.width as i32 && py >= 0 && py < self.height as i32 {
+                        let alpha = (coverage as f32 / 255.0) * color.alpha();
+                        let pixel_color =
+                            Color::from_rgba(color.red(), color.green(), color.blue(), alpha)
+                                .unwrap_or(color);
+
+                        // Blend with existing pixel
+                        if let Some(existing) = pixmap.pixel(px as u32, py as u32) {
+                            let blended = blend_pixel(existing, pixel_color);
+                            pixmap.pixels_mut()[(py as u32 * self.width + px as u32) as usize] =
+                                blended;
+                        }
+                    }
+                }
+            }
+
+            x += metrics.advance_width;
+        }
+    }
+}
+
+/// Blend two pixels using alpha compositing
+fn blend_pixel(
+    dst: tiny_skia::PremultipliedColorU8,
+    src: Color,
+) -> tiny_skia::PremultipliedColorU8 {
+    let src_a = src.alpha();
+    let dst_a = dst.alpha() as f32 / 255.0;
+
+    let out_a = src_a + dst_a * (1.0 - src_a);
+    if out_a == 0.0 {
+        return tiny_skia::PremultipliedColorU8::from_rgba(0, 0, 0, 0).unwrap();
+    }
+
+    let blend = |src_c: f32, dst_c: u8| -> u8 {
+        let dst_c = dst_c as f32 / 255.0;
+        let out_c = (src_c * src_a + dst_c * dst_a * (1.0 - src_a)) / out_a;
+        (out_c * 255.0).clamp(0.0, 255.0) as u8
+    };
+
+    tiny_skia::PremultipliedColorU8::from_rgba(
+        blend(src.red(), dst.red()),
+        blend(src.green(), dst.green()),
+        blend(src.blue(), dst.blue()),
+        (out_a * 255.0) as u8,
+    )
+    .unwrap()
+}
+
+/// Top-left corner of a `style`-sized indicator at `position` within an
+/// `area_w`x`area_h` screen area, `style.margin` from its edges and clamped
+/// so it never starts off-screen.
+pub(crate) fn window_origin(
+    position: IndicatorPosition,
+    style: &IndicatorStyle,
+    area_w: i32,
+    area_h: i32,
+) -> (i32, i32) {
+    let (w, h) = (style.width as i32, style.height as i32);
+    let margin = style.margin as i32;
+    let left = margin;
+    let right = area_w - w - margin;
+    let center = (area_w - w) / 2;
+    let top = margin;
+    let bottom = area_h - h - margin;
+
+    let (x, y) = match position {
+        IndicatorPosition::TopRight => (right, top),
+        IndicatorPosition::TopLeft => (left, top),
+        IndicatorPosition::TopCenter => (center, top),
+        IndicatorPosition::BottomCenter => (center, bottom),
+        IndicatorPosition::BottomRight => (right, bottom),
+        IndicatorPosition::BottomLeft => (left, bottom),
+    };
+    (x.max(0), y.max(0))
+}
+
+/// Copy a pixmap into a little-endian 32-bit buffer (`B, G, R, A` bytes),
+/// the layout both `wl_shm::Format::Argb8888` and X11 ZPixmaps expect.
+pub(crate) fn write_bgra(pixmap: &Pixmap, out: &mut [u8]) {
+    let src = pixmap.data();
+    for (i, chunk) in out.chunks_exact_mut(4).enumerate() {
+        let si = i * 4;
+        chunk[0] = src[si + 2]; // B
+        chunk[1] = src[si + 1]; // G
+        chunk[2] = src[si]; // R
+        chunk[3] = src[si + 3]; // A
+    }
+}
+
+/// How many meter segments `level` (`0.0..=1.0`) lights up.
+fn lit_segments(level: f32) -> u32 {
+    (level.clamp(0.0, 1.0) * LEVEL_SEGMENTS as f32).round() as u32
+}
+
+/// Whether `remaining_ms` is inside the countdown window.
+fn in_countdown(remaining_ms: u64) -> bool {
+    remaining_ms <= COUNTDOWN_MS
+}
+
+/// How far (`0.0..=1.0`) to fade toward [`warning_color`].
+fn warning_amount(remaining_ms: u64) -> f32 {
+    if in_countdown(remaining_ms) {
+        1.0 - remaining_ms as f32 / COUNTDOWN_MS as f32
+    } else {
+        0.0
+    }
+}
+
+/// Linear blend from `from` to `to`.
+fn mix(from: Color, to: Color, t: f32) -> Color {
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    Color::from_rgba(
+        lerp(from.red(), to.red()),
+        lerp(from.green(), to.green()),
+        lerp(from.blue(), to.blue()),
+        lerp(from.alpha(), to.alpha()),
+    )
+    .unwrap_or(from)
+}
+
+/// `-m:ss` label for the time left, rounded up so it reads `-0:00` only
+/// at the auto-stop.
+fn format_countdown(remaining_ms: u64) -> String {
+    format!("-{}", format_elapsed(remaining_ms.div_ceil(1000) * 1000))
+}
+
+/// `m:ss` label for the elapsed recording time.
+fn format_elapsed(elapsed_ms: u64) -> String {
+    let secs = elapsed_ms / 1000;
+    let mins = secs / 60;
+    let secs = secs % 60;
+    format!("{}:{:02}", mins, secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_elapsed_is_minutes_and_seconds() {
+        assert_eq!(format_elapsed(0), "0:00");
+        assert_eq!(format_elapsed(4_200), "0:04");
+        assert_eq!(format_elapsed(125_000), "2:05");
+    }
+
+    #[test]
+    fn render_uses_configured_size_and_colors() {
+        let style = IndicatorStyle {
+            width: 120,
+            height: 30,
+            background: [0, 0, 255, 255],
+            ..IndicatorStyle::default()
+        };
+        let pixmap = IndicatorRenderer::new(&style).render(DaemonState::Recording, 0, None, None);
+        assert_eq!((pixmap.width(), pixmap.height()), (120, 30));
+        // Right edge, vertically centred: inside the pill, away from dot and text.
+        let px = pixmap.pixel(118, 15).unwrap();
+        assert_eq!((px.red(), px.green(), px.blue()), (0, 0, 255));
+    }
+
+    #[test]
+    fn lit_segments_scales_and_clamps() {
+        assert_eq!(lit_segments(0.0), 0);
+        assert_eq!(lit_segments(0.44), 4);
+        assert_eq!(lit_segments(1.0), LEVEL_SEGMENTS);
+        assert_eq!(lit_segments(7.0), LEVEL_SEGMENTS);
+        assert_eq!(lit_segments(f32::NAN), 0);
+    }
+
+    #[test]
+    fn level_meter_only_drawn_with_level() {
+        let renderer = IndicatorRenderer::new(&IndicatorStyle::default());
+        let recording = IndicatorStyle::default().recording;
+        // First segment, inside the bottom strip.
+        let probe = |p: &Pixmap| {
+            let px = p.pixel(12, 38).unwrap();
+            (px.red(), px.green(), px.blue())
+        };
+
+        let without = renderer.render(DaemonState::Recording, 0, None, None);
+        let with = renderer.render(DaemonState::Recording, 0, None, Some(1.0));
+        assert_ne!(probe(&without), probe(&with));
+        assert_eq!(probe(&with), (recording[0], recording[1], recording[2]));
+    }
+
+    #[test]
+    fn countdown_only_near_the_limit() {
+        assert!(!in_countdown(COUNTDOWN_MS + 1));
+        assert!(in_countdown(COUNTDOWN_MS));
+        assert_eq!(format_countdown(9_001), "-0:10");
+        assert_eq!(format_countdown(9_000), "-0:09");
+        assert_eq!(format_countdown(0), "-0:00");
+    }
+
+    #[test]
+    fn warning_amount_ramps_to_one() {
+        assert_eq!(warning_amount(120_000), 0.0);
+        assert_eq!(warning_amount(COUNTDOWN_MS), 0.0);
+        assert_eq!(warning_amount(COUNTDOWN_MS / 2), 0.5);
+        assert_eq!(warning_amount(0), 1.0);
+    }
+
+    #[test]
+    fn window_origin_respects_margin_in_each_corner() {
+        let style = IndicatorStyle::default();
+        let (sw, sh) = (1920, 1080);
+        let (w, h, m) = (style.width as i32, style.height as i32, style.margin as i32);
+        let right = 1920 - w - m;
+        let bottom = 1080 - h - m;
+
+        assert_eq!(
+            window_origin(IndicatorPosition::TopLeft, &style, sw, sh),
+            (m, m)
+        );
+        assert_eq!(
+            window_origin(IndicatorPosition::TopRight, &style, sw, sh),
+            (right, m)
+        );
+        assert_eq!(
+            window_origin(IndicatorPosition::BottomRight, &style, sw, sh),
+            (right, bottom)
+        );
+        assert_eq!(
+            window_origin(IndicatorPosition::BottomCenter, &style, sw, sh),
+            ((1920 - w) / 2, bottom)
+        );
+    }
+
+    #[test]
+    fn window_origin_uses_configured_geometry() {
+        let style = IndicatorStyle {
+            width: 200,
+            height: 50,
+            margin: 0,
+            ..IndicatorStyle::default()
+        };
+        assert_eq!(
+            window_origin(IndicatorPosition::BottomRight, &style, 1920, 1080),
+            (1720, 1030)
+        );
+    }
+
+    #[test]
+    fn window_origin_clamps_on_tiny_screens() {
+        let style = IndicatorStyle::default();
+        assert_eq!(
+            window_origin(IndicatorPosition::BottomRight, &style, 50, 20),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn write_bgra_swaps_red_and_blue() {
+        let mut pixmap = Pixmap::new(1, 1).unwrap();
+        pixmap.fill(Color::from_rgba8(10, 20, 30, 255));
+        let mut out = [0u8; 4];
+        write_bgra(&pixmap, &mut out);
+        assert_eq!(out, [30, 20, 10, 255]);
+    }
+}
@@ -4,7 +4,6 @@
 //! right-click context menu reflecting the daemon state. Runs its own Win32
 //! message pump on a dedicated thread.
 
-use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
 use tokio::sync::broadcast;
@@ -43,17 +42,7 @@ pub fn run_indicator(
     state_rx: broadcast::Receiver<StateUpdate>,
     signal_tx: tokio::sync::mpsc::Sender<DaemonSignal>,
 ) -> Result<(), TrayError> {
-    // Bridge the async broadcast channel into a sync mpsc the message loop can
-    // drain without an async context (same pattern as `layer_shell::run_indicator`).
-    let (state_sync_tx, state_sync_rx) = mpsc::channel::<StateUpdate>();
-    std::thread::spawn(move || {
-        let mut rx = state_rx;
-        while let Ok(update) = rx.blocking_recv() {
-            if state_sync_tx.send(update).is_err() {
-                break;
-            }
-        }
-    });
+    let state_sync_rx = super::bridge_state_updates(state_rx);
 
     // Cache the two visible-state icons once.
     let recording_icon = make_icon([220, 50, 50, 255])?;
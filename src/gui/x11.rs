@@ -0,0 +1,326 @@
+//! X11 fallback recording indicator
+//!
+//! Used when no Wayland compositor is available (i3, GNOME on Xorg, ...).
+//! Creates a small window that:
+//! - Is override-redirect (no decorations, not managed or focused by the WM)
+//! - Is typed `_NET_WM_WINDOW_TYPE_NOTIFICATION` for compositors that care
+//! - Has an empty input shape (click-through), unless clicks are opted into,
+//!   in which case a left click while recording sends `toggle` to the daemon
+//!   (override-redirect windows never take keyboard focus)
+//! - Uses a 32-bit ARGB visual when available so the rounded corners blend
+//!
+//! Frames come from the same [`IndicatorRenderer`] as the layer-shell
+//! backend and are blitted via an X pixmap.
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+use x11rb::connection::{Connection, RequestConnection};
+use x11rb::protocol::shape::{self, ConnectionExt as _};
+use x11rb::protocol::xproto::{
+    AtomEnum, ClipOrdering, ColormapAlloc, ConfigureWindowAux, ConnectionExt as _, CreateGCAux,
+    CreateWindowAux, EventMask, ImageFormat, PropMode, Screen, StackMode, VisualClass, Window,
+    WindowClass,
+};
+use x11rb::protocol::Event;
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+
+use super::render::{window_origin, write_bgra, IndicatorRenderer};
+use crate::cli::args::IndicatorPosition;
+use crate::cli::signals::DaemonSignal;
+use crate::domain::config::IndicatorStyle;
+use crate::domain::daemon::{DaemonState, StateUpdate};
+
+/// X11 button number of the primary mouse button.
+const BUTTON_LEFT: u8 = 1;
+
+/// How long to wait for a state update before servicing X events.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Error type for the X11 indicator
+#[derive(Debug, thiserror::Error)]
+pub enum X11Error {
+    #[error("Failed to connect to X server: {0}")]
+    Connect(#[from] x11rb::errors::ConnectError),
+    #[error("X11 connection error: {0}")]
+    Connection(#[from] x11rb::errors::ConnectionError),
+    #[error("X11 request failed: {0}")]
+    Reply(#[from] x11rb::errors::ReplyError),
+    #[error("X11 request failed: {0}")]
+    ReplyOrId(#[from] x11rb::errors::ReplyOrIdError),
+}
+
+/// Run the X11 indicator
+///
+/// Returns Ok(()) when the daemon's state channel closes.
+/// Returns Err if the X server is unreachable (caller should fallback).
+pub fn run_indicator(
+    position: IndicatorPosition,
+    style: IndicatorStyle,
+    state_rx: broadcast::Receiver<StateUpdate>,
+    click_tx: Option<tokio::sync::mpsc::Sender<DaemonSignal>>,
+) -> Result<(), X11Error> {
+    let rx = super::bridge_state_updates(state_rx);
+
+    let (conn, screen_num) = x11rb::connect(None)?;
+    let mut app = X11Indicator::new(conn, screen_num, position, style, click_tx.is_some())?;
+
+    loop {
+        match rx.recv_timeout(POLL_INTERVAL) {
+            Ok(update) => app.apply(update),
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+        while let Ok(update) = rx.try_recv() {
+            app.apply(update);
+        }
+
+        while let Some(event) = app.conn.poll_for_event()? {
+            match event {
+                Event::Expose(_) => app.dirty = true,
+                Event::ButtonPress(press)
+                    if press.detail == BUTTON_LEFT
+                        && app.daemon_state == DaemonState::Recording =>
+                {
+                    if let Some(tx) = &click_tx {
+                        let _ = tx.try_send(DaemonSignal::Toggle);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        app.update_visibility()?;
+        if app.mapped && app.dirty {
+            app.draw()?;
+            app.dirty = false;
+        }
+    }
+}
+
+/// X11 indicator state
+struct X11Indicator {
+    conn: RustConnection,
+    window: Window,
+    pixmap: u32,
+    gc: u32,
+    depth: u8,
+    width: u16,
+    height: u16,
+
+    daemon_state: DaemonState,
+    elapsed_ms: u64,
+    remaining_ms: Option<u64>,
+    level: Option<f32>,
+    mapped: bool,
+    dirty: bool,
+
+    renderer: IndicatorRenderer,
+}
+
+impl X11Indicator {
+    fn new(
+        conn: RustConnection,
+        screen_num: usize,
+        position: IndicatorPosition,
+        style: IndicatorStyle,
+        clickable: bool,
+    ) -> Result<Self, X11Error> {
+        let screen = conn.setup().roots[screen_num].clone();
+        // On multi-monitor setups the root spans every output, so corners
+        // are those of the combined desktop.
+        let (x, y) = window_origin(
+            position,
+            &style,
+            screen.width_in_pixels as i32,
+            screen.height_in_pixels as i32,
+        );
+        let (x, y) = (x as i16, y as i16);
+        // Validated to at most `MAX_INDICATOR_DIMENSION`, so these fit.
+        let (width, height) = (style.width as u16, style.height as u16);
+
+        let window = conn.generate_id()?;
+        let event_mask = if clickable {
+            EventMask::EXPOSURE | EventMask::BUTTON_PRESS
+        } else {
+            EventMask::EXPOSURE
+        };
+        let mut aux = CreateWindowAux::new()
+            .override_redirect(1)
+            .background_pixel(0)
+            .border_pixel(0)
+            .event_mask(event_mask);
+
+        // Prefer an ARGB visual for real transparency; fall back to the root
+        // visual (corners then show the background colour's RGB).
+        let (depth, visual) = match argb_visual(&screen) {
+            Some(visual) => {
+                let colormap = conn.generate_id()?;
+                conn.create_colormap(ColormapAlloc::NONE, colormap, screen.root, visual)?;
+                aux = aux.colormap(colormap);
+                (32, visual)
+            }
+            None => (screen.root_depth, screen.root_visual),
+        };
+
+        conn.create_window(
+            depth,
+            window,
+            screen.root,
+            x,
+            y,
+            width,
+            height,
+            0,
+            WindowClass::INPUT_OUTPUT,
+            visual,
+            &aux,
+        )?;
+
+        conn.change_property8(
+            PropMode::REPLACE,
+            window,
+            AtomEnum::WM_NAME,
+            AtomEnum::STRING,
+            b"smart-scribe-indicator",
+        )?;
+        let wm_type = conn
+            .intern_atom(false, b"_NET_WM_WINDOW_TYPE")?
+            .reply()?
+            .atom;
+        let notification = conn
+            .intern_atom(false, b"_NET_WM_WINDOW_TYPE_NOTIFICATION")?
+            .reply()?
+            .atom;
+        conn.change_property32(
+            PropMode::REPLACE,
+            window,
+            wm_type,
+            AtomEnum::ATOM,
+            &[notification],
+        )?;
+
+        // Empty input region = clicks fall through to whatever is below.
+        if !clickable
+            && conn
+                .extension_information(shape::X11_EXTENSION_NAME)?
+                .is_some()
+        {
+            conn.shape_rectangles(
+                shape::SO::SET,
+                shape::SK::INPUT,
+                ClipOrdering::UNSORTED,
+                window,
+                0,
+                0,
+                &[],
+            )?;
+        }
+
+        let pixmap = conn.generate_id()?;
+        conn.create_pixmap(depth, pixmap, window, width, height)?;
+        let gc = conn.generate_id()?;
+        conn.create_gc(gc, pixmap, &CreateGCAux::new())?;
+        conn.flush()?;
+
+        Ok(Self {
+            conn,
+            window,
+            pixmap,
+            gc,
+            depth,
+            width,
+            height,
+            daemon_state: DaemonState::Idle,
+            elapsed_ms: 0,
+            remaining_ms: None,
+            level: None,
+            mapped: false,
+            dirty: false,
+            renderer: IndicatorRenderer::new(&style),
+        })
+    }
+
+    fn apply(&mut self, update: StateUpdate) {
+        let state_changed = self.daemon_state != update.state;
+        self.daemon_state = update.state;
+        self.elapsed_ms = update.elapsed_ms;
+        self.remaining_ms = update.remaining_ms;
+        self.level = update.level;
+
+        // Mark dirty if state changed or we're recording (timer updates)
+        if state_changed || self.daemon_state == DaemonState::Recording {
+            self.dirty = true;
+        }
+    }
+
+    fn update_visibility(&mut self) -> Result<(), X11Error> {
+        let should_be_visible = self.daemon_state != DaemonState::Idle;
+
+        if should_be_visible && !self.mapped {
+            self.conn.map_window(self.window)?;
+            self.conn.configure_window(
+                self.window,
+                &ConfigureWindowAux::new().stack_mode(StackMode::ABOVE),
+            )?;
+            self.conn.flush()?;
+            self.mapped = true;
+            self.dirty = true;
+        } else if !should_be_visible && self.mapped {
+            self.conn.unmap_window(self.window)?;
+            self.conn.flush()?;
+            self.mapped = false;
+        }
+        Ok(())
+    }
+
+    fn draw(&mut self) -> Result<(), X11Error> {
+        let pixmap = self.renderer.render(
+            self.daemon_state,
+            self.elapsed_ms,
+            self.remaining_ms,
+            self.level,
+        );
+        let mut data = vec![0u8; self.width as usize * self.height as usize * 4];
+        write_bgra(&pixmap, &mut data);
+
+        self.conn.put_image(
+            ImageFormat::Z_PIXMAP,
+            self.pixmap,
+            self.gc,
+            self.width,
+            self.height,
+            0,
+            0,
+            0,
+            self.depth,
+            &data,
+        )?;
+        self.conn.copy_area(
+            self.pixmap,
+            self.window,
+            self.gc,
+            0,
+            0,
+            0,
+            0,
+            self.width,
+            self.height,
+        )?;
+        self.conn.flush()?;
+        Ok(())
+    }
+}
+
+/// First 32-bit TrueColor visual on `screen`, if the server offers one.
+fn argb_visual(screen: &Screen) -> Option<u32> {
+    screen
+        .allowed_depths
+        .iter()
+        .filter(|d| d.depth == 32)
+        .flat_map(|d| d.visuals.iter())
+        .find(|v| v.class == VisualClass::TRUE_COLOR)
+        .map(|v| v.visual_id)
+}
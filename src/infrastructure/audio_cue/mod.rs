@@ -6,15 +6,24 @@ mod noop;
 mod rodio;
 
 pub use noop::NoOpAudioCue;
-pub use rodio::RodioAudioCue;
+pub use rodio::{play_audio_bytes, RodioAudioCue};
 
 use crate::application::ports::AudioCue;
+use crate::domain::config::CueSounds;
 
-/// Create an audio cue adapter based on whether audio cues are enabled
-pub fn create_audio_cue(enabled: bool) -> Box<dyn AudioCue> {
+/// Create an audio cue adapter based on whether audio cues are enabled.
+///
+/// Returns warnings for custom sounds that could not be loaded; those cues
+/// fall back to the built-in tones.
+pub fn create_audio_cue(
+    enabled: bool,
+    sounds: &CueSounds,
+    volume: f32,
+) -> (Box<dyn AudioCue>, Vec<String>) {
     if enabled {
-        Box::new(RodioAudioCue::new())
+        let (cue, warnings) = RodioAudioCue::with_sounds(sounds, volume);
+        (Box::new(cue), warnings)
     } else {
-        Box::new(NoOpAudioCue::new())
+        (Box::new(NoOpAudioCue::new()), Vec::new())
     }
 }
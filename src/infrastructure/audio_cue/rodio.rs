@@ -1,25 +1,88 @@
 //! Rodio-based audio cue adapter
 //!
-//! Generates and plays synthesized tones for audio feedback.
+//! Plays synthesized tones for audio feedback, or user-supplied sound files
+//! from the `[cues]` config table.
 
+use std::io::Cursor;
+use std::path::Path;
+use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use rodio::source::{SineWave, Source};
-use rodio::{OutputStream, Sink};
+use rodio::{Decoder, OutputStream, Sink};
 
 use crate::application::ports::{AudioCue, AudioCueError, AudioCueType};
+use crate::domain::config::{CueSounds, DEFAULT_CUE_VOLUME};
+
+/// Encoded bytes of a custom sound, shared with the playback thread.
+type SoundBytes = Arc<[u8]>;
 
 /// Audio cue implementation using rodio
-pub struct RodioAudioCue;
+pub struct RodioAudioCue {
+    start: Option<SoundBytes>,
+    stop: Option<SoundBytes>,
+    cancel: Option<SoundBytes>,
+    volume: f32,
+}
 
 impl RodioAudioCue {
-    /// Create a new rodio-based audio cue
+    /// Create a new rodio-based audio cue using the built-in tones
     pub fn new() -> Self {
-        Self
+        Self {
+            start: None,
+            stop: None,
+            cancel: None,
+            volume: DEFAULT_CUE_VOLUME,
+        }
+    }
+
+    /// Create an audio cue playing `sounds` at `volume`.
+    ///
+    /// Each file is read and test-decoded up front. One that is missing or
+    /// not a supported format (WAV, FLAC, MP3, Vorbis) keeps the built-in
+    /// tone and yields a warning instead of an error.
+    pub fn with_sounds(sounds: &CueSounds, volume: f32) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let mut load = |key: &str, path: &Option<std::path::PathBuf>| {
+            let path = path.as_deref()?;
+            match load_sound(path) {
+                Ok(bytes) => Some(bytes),
+                Err(e) => {
+                    warnings.push(format!(
+                        "cues.{key}: {}: {e}; using the built-in sound",
+                        path.display()
+                    ));
+                    None
+                }
+            }
+        };
+        let cue = Self {
+            start: load("start_sound", &sounds.start),
+            stop: load("stop_sound", &sounds.stop),
+            cancel: load("cancel_sound", &sounds.cancel),
+            volume,
+        };
+        (cue, warnings)
+    }
+
+    fn sound_for(&self, cue_type: AudioCueType) -> Option<SoundBytes> {
+        match cue_type {
+            AudioCueType::RecordingStart => self.start.clone(),
+            AudioCueType::RecordingStop => self.stop.clone(),
+            AudioCueType::RecordingCancel => self.cancel.clone(),
+            AudioCueType::CountdownTick => None,
+        }
     }
 }
 
+/// Read `path` and check rodio can decode it.
+fn load_sound(path: &Path) -> Result<SoundBytes, String> {
+    let bytes: SoundBytes = std::fs::read(path).map_err(|e| e.to_string())?.into();
+    Decoder::new(Cursor::new(Arc::clone(&bytes))).map_err(|e| e.to_string())?;
+    Ok(bytes)
+}
+
 impl Default for RodioAudioCue {
     fn default() -> Self {
         Self::new()
@@ -29,13 +92,30 @@ impl Default for RodioAudioCue {
 #[async_trait]
 impl AudioCue for RodioAudioCue {
     async fn play(&self, cue_type: AudioCueType) -> Result<(), AudioCueError> {
+        let sound = self.sound_for(cue_type);
+        let volume = self.volume;
         // Run audio playback in blocking thread to avoid blocking the async runtime
-        tokio::task::spawn_blocking(move || play_cue_sync(cue_type))
+        tokio::task::spawn_blocking(move || play_cue_sync(cue_type, sound, volume))
             .await
             .map_err(|e| AudioCueError::PlaybackFailed(format!("Task join error: {}", e)))?
     }
 }
 
+/// Play encoded audio (WAV, FLAC, MP3, Vorbis) through the default output
+/// device and block until it ends. Used by `devices test` to play back a
+/// freshly captured clip; call it from a blocking task.
+pub fn play_audio_bytes(bytes: Vec<u8>) -> Result<(), AudioCueError> {
+    let (_stream, stream_handle) = OutputStream::try_default()
+        .map_err(|e| AudioCueError::DeviceNotAvailable(e.to_string()))?;
+    let sink =
+        Sink::try_new(&stream_handle).map_err(|e| AudioCueError::PlaybackFailed(e.to_string()))?;
+    let source = Decoder::new(Cursor::new(bytes))
+        .map_err(|e| AudioCueError::PlaybackFailed(e.to_string()))?;
+    sink.append(source);
+    sink.sleep_until_end();
+    Ok(())
+}
+
 /// Create a gentle tone with fade in/out for a smoother sound
 fn gentle_tone(freq: f32, duration_ms: u64, amplitude: f32) -> impl Source<Item = f32> + Send {
     let fade_ms = (duration_ms / 5).min(30); // 20% fade or max 30ms
@@ -45,14 +125,28 @@ fn gentle_tone(freq: f32, duration_ms: u64, amplitude: f32) -> impl Source<Item
         .amplify(amplitude)
 }
 
-/// Play a cue synchronously (called from spawn_blocking)
-fn play_cue_sync(cue_type: AudioCueType) -> Result<(), AudioCueError> {
+/// Play a cue synchronously (called from spawn_blocking). A custom `sound`
+/// replaces the built-in tone for this cue.
+fn play_cue_sync(
+    cue_type: AudioCueType,
+    sound: Option<SoundBytes>,
+    volume: f32,
+) -> Result<(), AudioCueError> {
     // Get output stream
     let (_stream, stream_handle) = OutputStream::try_default()
         .map_err(|e| AudioCueError::DeviceNotAvailable(e.to_string()))?;
 
     let sink =
         Sink::try_new(&stream_handle).map_err(|e| AudioCueError::PlaybackFailed(e.to_string()))?;
+    sink.set_volume(volume);
+
+    if let Some(bytes) = sound {
+        let source = Decoder::new(Cursor::new(bytes))
+            .map_err(|e| AudioCueError::PlaybackFailed(e.to_string()))?;
+        sink.append(source);
+        sink.sleep_until_end();
+        return Ok(());
+    }
 
     // Softer amplitude for pleasant sound
     const AMP: f32 = 0.3;
@@ -83,6 +177,10 @@ fn play_cue_sync(cue_type: AudioCueType) -> Result<(), AudioCueError> {
             sink.append(silence);
             sink.append(tone2);
         }
+        AudioCueType::CountdownTick => {
+            // Brief A4 tap, quieter than the start chime that follows
+            sink.append(gentle_tone(440.0, 50, AMP * 0.6));
+        }
     }
 
     // Wait for playback to complete
@@ -95,6 +193,51 @@ fn play_cue_sync(cue_type: AudioCueType) -> Result<(), AudioCueError> {
 mod tests {
     use super::*;
 
+    /// Minimal mono 16-bit PCM WAV holding `samples` zero samples.
+    fn silent_wav(samples: u32) -> Vec<u8> {
+        let data_len = samples * 2;
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+        wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+        wav.extend_from_slice(&8000u32.to_le_bytes());
+        wav.extend_from_slice(&16000u32.to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        wav.resize(wav.len() + data_len as usize, 0);
+        wav
+    }
+
+    #[test]
+    fn with_sounds_loads_valid_files_and_warns_on_bad_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("start.wav");
+        std::fs::write(&good, silent_wav(80)).unwrap();
+        let garbage = dir.path().join("stop.wav");
+        std::fs::write(&garbage, b"not audio").unwrap();
+
+        let sounds = CueSounds {
+            start: Some(good),
+            stop: Some(garbage),
+            cancel: Some(dir.path().join("missing.wav")),
+        };
+        let (cue, warnings) = RodioAudioCue::with_sounds(&sounds, 0.5);
+
+        assert!(cue.sound_for(AudioCueType::RecordingStart).is_some());
+        assert!(cue.sound_for(AudioCueType::RecordingStop).is_none());
+        assert!(cue.sound_for(AudioCueType::RecordingCancel).is_none());
+        assert!(cue.sound_for(AudioCueType::CountdownTick).is_none());
+        assert_eq!(warnings.len(), 2, "{warnings:?}");
+        assert!(warnings[0].starts_with("cues.stop_sound:"));
+        assert!(warnings[1].starts_with("cues.cancel_sound:"));
+        assert_eq!(cue.volume, 0.5);
+    }
+
     // Note: These tests require audio hardware and may not work in CI
     // They are marked as ignored by default
 
@@ -0,0 +1,163 @@
+//! Audio file loading, extracting the audio of video files with ffmpeg
+//!
+//! Supported audio formats are uploaded untouched. Video containers and
+//! codecs the endpoints don't take (see [`AudioSource`]) are transcoded by
+//! ffmpeg to mono FLAC at the recorder's 16 kHz, the same format a live
+//! recording is uploaded in. MP4 and WebM videos are accepted by the
+//! endpoints as they are, so without ffmpeg they are uploaded whole.
+
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::Stdio;
+
+use thiserror::Error;
+use tokio::process::Command;
+
+use crate::domain::error::AudioFileError;
+use crate::domain::transcription::{AudioData, AudioMimeType, AudioSource};
+use crate::infrastructure::recording::TARGET_SAMPLE_RATE;
+
+/// Errors loading an input file
+#[derive(Debug, Clone, Error)]
+pub enum LoadAudioError {
+    #[error(transparent)]
+    File(#[from] AudioFileError),
+
+    #[error("{path} is a video or an unsupported audio codec and needs ffmpeg to extract its audio; install ffmpeg or convert the file first")]
+    FfmpegNotFound { path: String },
+
+    #[error("ffmpeg could not extract the audio of {path}: {message}")]
+    ExtractFailed { path: String, message: String },
+}
+
+/// Read `path` into uploadable audio, extracting the audio track first when
+/// the format calls for it.
+pub async fn load_audio_file(path: &Path) -> Result<AudioData, LoadAudioError> {
+    let extension = path.extension().and_then(|e| e.to_str());
+    let audio = match AudioData::load(path) {
+        Ok(audio) => audio,
+        // Not an upload format at all; only ffmpeg can make something of it.
+        Err(AudioFileError::Unsupported(_))
+            if AudioSource::extension_needs_extraction(extension) =>
+        {
+            return extract_audio(path).await;
+        }
+        Err(e) => return Err(e.into()),
+    };
+    if AudioSource::detect(audio.data(), extension).ok() != Some(AudioSource::Extract) {
+        return Ok(audio);
+    }
+    match extract_audio(path).await {
+        Err(LoadAudioError::FfmpegNotFound { .. }) if uploadable_as_is(&audio, extension) => {
+            tracing::warn!(
+                "ffmpeg not found; uploading {} with its video track",
+                path.display()
+            );
+            Ok(audio)
+        }
+        result => result,
+    }
+}
+
+/// Whether `audio` is a container the endpoints accept, under a name that
+/// says so (`.mp4`, `.webm`, or none); `.mov` and `.mkv` share the magic
+/// bytes but are not accepted.
+fn uploadable_as_is(audio: &AudioData, extension: Option<&str>) -> bool {
+    matches!(audio.mime_type(), AudioMimeType::Mp4 | AudioMimeType::Webm)
+        && extension.is_none_or(|e| AudioMimeType::from_extension(e).is_some())
+}
+
+/// Transcode the audio of `path` to FLAC with ffmpeg.
+async fn extract_audio(path: &Path) -> Result<AudioData, LoadAudioError> {
+    let output = Command::new("ffmpeg")
+        .args(ffmpeg_args(path))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .output()
+        .await
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                LoadAudioError::FfmpegNotFound {
+                    path: path.display().to_string(),
+                }
+            } else {
+                LoadAudioError::ExtractFailed {
+                    path: path.display().to_string(),
+                    message: e.to_string(),
+                }
+            }
+        })?;
+
+    if !output.status.success() || output.stdout.is_empty() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // ffmpeg's last line names the actual problem ("does not contain
+        // any stream", "Invalid data found ...").
+        let message = stderr
+            .lines()
+            .rev()
+            .find(|l| !l.trim().is_empty())
+            .map(str::trim)
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("ffmpeg exited with {}", output.status));
+        return Err(LoadAudioError::ExtractFailed {
+            path: path.display().to_string(),
+            message,
+        });
+    }
+    Ok(AudioData::new(output.stdout, AudioMimeType::Flac))
+}
+
+/// Arguments for ffmpeg: drop any video, mix down to mono 16 kHz, write FLAC
+/// to stdout.
+fn ffmpeg_args(path: &Path) -> Vec<OsString> {
+    let rate = TARGET_SAMPLE_RATE.to_string();
+    let input = ["-nostdin", "-hide_banner", "-loglevel", "error", "-i"];
+    let output = [
+        "-vn", "-ac", "1", "-ar", &rate, "-c:a", "flac", "-f", "flac",
+    ];
+    input
+        .iter()
+        .map(OsString::from)
+        .chain([path.as_os_str().to_owned()])
+        .chain(output.iter().map(OsString::from))
+        .chain([OsString::from("pipe:1")])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ffmpeg_drops_video_and_writes_flac_to_stdout() {
+        let args = ffmpeg_args(Path::new("talk.mp4"));
+        let args: Vec<&str> = args.iter().map(|a| a.to_str().unwrap()).collect();
+        assert_eq!(&args[4..6], ["-i", "talk.mp4"]);
+        assert!(args.contains(&"-vn"));
+        assert!(args.windows(2).any(|w| w == ["-ar", "16000"]));
+        assert_eq!(args.last(), Some(&"pipe:1"));
+    }
+
+    #[test]
+    fn only_accepted_containers_upload_without_ffmpeg() {
+        let mp4 = AudioData::new(b"\0\0\0\x18ftypisom".to_vec(), AudioMimeType::Mp4);
+        assert!(uploadable_as_is(&mp4, Some("mp4")));
+        assert!(uploadable_as_is(&mp4, None));
+        assert!(!uploadable_as_is(&mp4, Some("mov")));
+        let webm = AudioData::new(b"\x1A\x45\xDF\xA3".to_vec(), AudioMimeType::Webm);
+        assert!(uploadable_as_is(&webm, Some("WEBM")));
+        assert!(!uploadable_as_is(&webm, Some("mkv")));
+    }
+
+    #[tokio::test]
+    async fn supported_audio_is_loaded_as_is() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("memo.ogg");
+        std::fs::write(&path, b"OggS\x00\x02rest").unwrap();
+        let audio = load_audio_file(&path).await.unwrap();
+        assert_eq!(audio.mime_type(), AudioMimeType::Ogg);
+        assert_eq!(audio.data(), b"OggS\x00\x02rest");
+    }
+}
@@ -30,8 +30,8 @@ pub fn codex_auth_path() -> Option<PathBuf> {
 }
 
 /// Read Codex's auth file, extract the refresh token, refresh once and return
-/// the fresh credentials. The Codex file is never written.
-pub async fn import_from_codex() -> Result<OAuthToken, OAuthError> {
+/// the fresh credentials over `client`. The Codex file is never written.
+pub async fn import_from_codex(client: &reqwest::Client) -> Result<OAuthToken, OAuthError> {
     let path = codex_auth_path().ok_or(OAuthError::CodexAuthMissing)?;
     let bytes = std::fs::read(&path)
         .map_err(|e| OAuthError::CodexAuthMalformed(format!("read {}: {e}", path.display())))?;
@@ -42,7 +42,7 @@ pub async fn import_from_codex() -> Result<OAuthToken, OAuthError> {
         .and_then(|t| t.refresh_token)
         .ok_or_else(|| OAuthError::CodexAuthMalformed("missing tokens.refresh_token".into()))?;
 
-    refresh(&refresh_token).await
+    refresh(client, &refresh_token).await
 }
 
 #[derive(Debug, Deserialize)]
@@ -58,8 +58,9 @@ pub(crate) fn build_authorize_url(code_challenge: &str, state: &str) -> String {
 }
 
 /// Run the full PKCE browser flow. Opens the browser, listens on localhost:1455,
-/// and returns the freshly-issued [`OAuthToken`].
-pub async fn run_pkce_login() -> Result<OAuthToken, OAuthError> {
+/// and returns the freshly-issued [`OAuthToken`]. The code is exchanged over
+/// `client`, so a configured proxy applies.
+pub async fn run_pkce_login(client: &reqwest::Client) -> Result<OAuthToken, OAuthError> {
     let verifier = generate_verifier();
     let challenge = challenge_from_verifier(&verifier);
     let state = generate_state();
@@ -98,11 +99,15 @@ pub async fn run_pkce_login() -> Result<OAuthToken, OAuthError> {
         return Err(OAuthError::StateMismatch);
     }
 
-    exchange_code(&callback.code, &verifier).await
+    exchange_code(client, &callback.code, &verifier).await
 }
 
 /// Exchange an authorization code for tokens.
-pub async fn exchange_code(code: &str, verifier: &str) -> Result<OAuthToken, OAuthError> {
+pub async fn exchange_code(
+    client: &reqwest::Client,
+    code: &str,
+    verifier: &str,
+) -> Result<OAuthToken, OAuthError> {
     let form = [
         ("grant_type", "authorization_code"),
         ("client_id", CLIENT_ID),
@@ -110,26 +115,36 @@ pub async fn exchange_code(code: &str, verifier: &str) -> Result<OAuthToken, OAu
         ("redirect_uri", REDIRECT_URI),
         ("code_verifier", verifier),
     ];
-    post_token(&form).await
+    post_token(client, &form).await
 }
 
 /// Refresh an access token using its refresh token.
-pub async fn refresh(refresh_token: &str) -> Result<OAuthToken, OAuthError> {
+///
+/// Takes the caller's `client` so the refresh goes through the same proxy
+/// as the requests that use the token.
+pub async fn refresh(
+    client: &reqwest::Client,
+    refresh_token: &str,
+) -> Result<OAuthToken, OAuthError> {
     let form = [
         ("grant_type", "refresh_token"),
         ("client_id", CLIENT_ID),
         ("refresh_token", refresh_token),
         ("scope", SCOPES),
     ];
-    post_token(&form).await
+    post_token(client, &form).await
 }
 
-async fn post_token(form: &[(&str, &str)]) -> Result<OAuthToken, OAuthError> {
-    let client = reqwest::Client::builder()
+async fn post_token(
+    client: &reqwest::Client,
+    form: &[(&str, &str)],
+) -> Result<OAuthToken, OAuthError> {
+    let response = client
+        .post(TOKEN_ENDPOINT)
         .timeout(StdDuration::from_secs(30))
-        .build()?;
-
-    let response = client.post(TOKEN_ENDPOINT).form(form).send().await?;
+        .form(form)
+        .send()
+        .await?;
     let status = response.status();
     let body = response.text().await.unwrap_or_default();
 
@@ -42,6 +42,25 @@ impl Clipboard for ArboardClipboard {
         .await
         .map_err(|e| ClipboardError::CopyFailed(format!("Task join error: {}", e)))?
     }
+
+    async fn read(&self) -> Result<Option<String>, ClipboardError> {
+        tokio::task::spawn_blocking(|| {
+            let mut clipboard =
+                arboard::Clipboard::new().map_err(|e| ClipboardError::BackendUnavailable {
+                    tool: "arboard".to_string(),
+                    reason: e.to_string(),
+                })?;
+
+            match clipboard.get_text() {
+                Ok(text) => Ok(Some(text)),
+                // Empty, or an image/file list rather than text.
+                Err(arboard::Error::ContentNotAvailable) => Ok(None),
+                Err(e) => Err(ClipboardError::ReadFailed(e.to_string())),
+            }
+        })
+        .await
+        .map_err(|e| ClipboardError::ReadFailed(format!("Task join error: {}", e)))?
+    }
 }
 
 #[cfg(test)]
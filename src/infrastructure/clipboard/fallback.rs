@@ -0,0 +1,169 @@
+//! Clipboard adapter that tries several backends in order.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+
+use crate::application::ports::{Clipboard, ClipboardError};
+
+/// Tries each backend in turn, moving on only when one reports
+/// [`ClipboardError::BackendUnavailable`] (no display, tool missing). Any
+/// other error is the backend's real answer and is returned as is.
+///
+/// The first backend that works is remembered, so later calls skip the
+/// ones already known to be unavailable.
+pub struct FallbackClipboard {
+    backends: Vec<(&'static str, Box<dyn Clipboard>)>,
+    active: AtomicUsize,
+}
+
+impl FallbackClipboard {
+    /// Chain `backends`, most preferred first.
+    pub fn new(backends: Vec<(&'static str, Box<dyn Clipboard>)>) -> Self {
+        Self {
+            backends,
+            active: AtomicUsize::new(0),
+        }
+    }
+
+    /// Label of the backend calls currently start from.
+    pub fn active_label(&self) -> Option<&'static str> {
+        self.backends
+            .get(self.active.load(Ordering::Relaxed))
+            .map(|(label, _)| *label)
+    }
+
+    /// Run `op` on each backend from the active one on, stopping at the
+    /// first that isn't unavailable. Reads yield the text; copies `None`.
+    async fn try_each(&self, op: Op<'_>) -> Result<Option<String>, ClipboardError> {
+        let start = self.active.load(Ordering::Relaxed);
+        let mut last_unavailable = None;
+        for (index, (label, backend)) in self.backends.iter().enumerate().skip(start) {
+            let result = match op {
+                Op::Copy(text) => backend.copy(text).await.map(|()| None),
+                Op::Read => backend.read().await,
+            };
+            match result {
+                Err(e @ ClipboardError::BackendUnavailable { .. }) => {
+                    tracing::debug!(backend = label, "clipboard backend unavailable: {e}");
+                    last_unavailable = Some(e);
+                }
+                result => {
+                    if index != start {
+                        self.active.store(index, Ordering::Relaxed);
+                        tracing::warn!(
+                            "Clipboard: {} unavailable, using {label}",
+                            self.backends[start].0
+                        );
+                    }
+                    return result;
+                }
+            }
+        }
+        Err(
+            last_unavailable.unwrap_or_else(|| ClipboardError::BackendUnavailable {
+                tool: "clipboard".to_string(),
+                reason: "no clipboard backend configured".to_string(),
+            }),
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Op<'t> {
+    Copy(&'t str),
+    Read,
+}
+
+#[async_trait]
+impl Clipboard for FallbackClipboard {
+    async fn copy(&self, text: &str) -> Result<(), ClipboardError> {
+        self.try_each(Op::Copy(text)).await.map(|_| ())
+    }
+
+    async fn read(&self) -> Result<Option<String>, ClipboardError> {
+        self.try_each(Op::Read).await
+    }
+
+    fn backend(&self) -> Option<&str> {
+        self.active_label()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// Records copies; fails every call with `error` when set.
+    struct Fake {
+        error: Option<ClipboardError>,
+        copied: Arc<Mutex<Vec<String>>>,
+    }
+
+    fn fake(error: Option<ClipboardError>) -> (Box<dyn Clipboard>, Arc<Mutex<Vec<String>>>) {
+        let copied = Arc::new(Mutex::new(Vec::new()));
+        let clipboard = Fake {
+            error,
+            copied: Arc::clone(&copied),
+        };
+        (Box::new(clipboard), copied)
+    }
+
+    fn unavailable() -> Option<ClipboardError> {
+        Some(ClipboardError::BackendUnavailable {
+            tool: "fake".to_string(),
+            reason: "no display".to_string(),
+        })
+    }
+
+    #[async_trait]
+    impl Clipboard for Fake {
+        async fn copy(&self, text: &str) -> Result<(), ClipboardError> {
+            if let Some(ref e) = self.error {
+                return Err(e.clone());
+            }
+            self.copied.lock().unwrap().push(text.to_string());
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn unavailable_primary_falls_back_and_sticks() {
+        let (primary, primary_copied) = fake(unavailable());
+        let (secondary, secondary_copied) = fake(None);
+        let clipboard = FallbackClipboard::new(vec![("arboard", primary), ("wl-copy", secondary)]);
+
+        clipboard.copy("one").await.unwrap();
+        assert_eq!(clipboard.active_label(), Some("wl-copy"));
+        assert_eq!(clipboard.backend(), Some("wl-copy"));
+        clipboard.copy("two").await.unwrap();
+
+        assert!(primary_copied.lock().unwrap().is_empty());
+        assert_eq!(*secondary_copied.lock().unwrap(), vec!["one", "two"]);
+    }
+
+    #[tokio::test]
+    async fn real_copy_failure_is_not_retried_elsewhere() {
+        let (primary, _) = fake(Some(ClipboardError::CopyFailed("denied".into())));
+        let (secondary, secondary_copied) = fake(None);
+        let clipboard = FallbackClipboard::new(vec![("arboard", primary), ("wl-copy", secondary)]);
+
+        let err = clipboard.copy("text").await.unwrap_err();
+        assert!(matches!(err, ClipboardError::CopyFailed(_)));
+        assert!(secondary_copied.lock().unwrap().is_empty());
+        assert_eq!(clipboard.active_label(), Some("arboard"));
+    }
+
+    #[tokio::test]
+    async fn all_unavailable_reports_the_last_backend() {
+        let (primary, _) = fake(unavailable());
+        let (secondary, _) = fake(unavailable());
+        let clipboard = FallbackClipboard::new(vec![("arboard", primary), ("xclip", secondary)]);
+
+        let err = clipboard.copy("text").await.unwrap_err();
+        assert!(matches!(err, ClipboardError::BackendUnavailable { .. }));
+        assert_eq!(clipboard.active_label(), Some("arboard"));
+    }
+}
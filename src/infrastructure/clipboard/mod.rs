@@ -1,19 +1,69 @@
 //! Clipboard infrastructure module
 //!
 //! Provides cross-platform clipboard support using arboard (primary)
-//! or wl-copy as the preferred option on Wayland.
+//! or wl-copy as the preferred option on Wayland. On Linux the other
+//! installed backends (wl-copy, xclip) stand by as fallbacks for when the
+//! preferred one can't reach a display.
 
 #[cfg(target_os = "linux")]
 use std::process::Stdio;
 
 mod arboard;
+mod fallback;
 mod wayland;
+mod xclip;
 
 pub use arboard::ArboardClipboard;
+pub use fallback::FallbackClipboard;
 pub use wayland::WaylandClipboard;
+pub use xclip::XclipClipboard;
 
 use crate::application::ports::Clipboard;
 
+/// A clipboard backend the factory can pick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ClipboardBackend {
+    WlCopy,
+    Arboard,
+    Xclip,
+}
+
+impl ClipboardBackend {
+    const fn label(self) -> &'static str {
+        match self {
+            Self::WlCopy => "wl-copy",
+            Self::Arboard => "arboard",
+            Self::Xclip => "xclip",
+        }
+    }
+
+    fn build(self) -> Box<dyn Clipboard> {
+        match self {
+            Self::WlCopy => Box::new(WaylandClipboard::new()),
+            Self::Arboard => Box::new(ArboardClipboard::new()),
+            Self::Xclip => Box::new(XclipClipboard::new()),
+        }
+    }
+}
+
+/// Backends to try, most preferred first. wl-copy leads on Wayland since
+/// its copies outlive the process; otherwise it and xclip only back up
+/// arboard, and only when installed.
+fn backend_order(wayland: bool, wl_copy: bool, xclip: bool) -> Vec<ClipboardBackend> {
+    let mut order = Vec::new();
+    if wayland && wl_copy {
+        order.push(ClipboardBackend::WlCopy);
+    }
+    order.push(ClipboardBackend::Arboard);
+    if !wayland && wl_copy {
+        order.push(ClipboardBackend::WlCopy);
+    }
+    if xclip {
+        order.push(ClipboardBackend::Xclip);
+    }
+    order
+}
+
 /// Check if running under a Wayland session
 #[cfg(target_os = "linux")]
 fn is_wayland() -> bool {
@@ -22,11 +72,11 @@ fn is_wayland() -> bool {
         .unwrap_or(false)
 }
 
-/// Check if wl-copy is available
+/// Check if `command` is on the PATH
 #[cfg(target_os = "linux")]
-async fn is_wl_copy_available() -> bool {
+async fn is_command_available(command: &str) -> bool {
     tokio::process::Command::new("which")
-        .arg("wl-copy")
+        .arg(command)
         .stdin(Stdio::null())
         .stdout(Stdio::null())
         .stderr(Stdio::null())
@@ -39,15 +89,59 @@ async fn is_wl_copy_available() -> bool {
 /// Create the default clipboard adapter for the current platform.
 ///
 /// On Wayland: prefers wl-copy (persists clipboard after process exits).
-/// Otherwise: uses arboard (cross-platform).
+/// Otherwise: uses arboard (cross-platform). On Linux, any other installed
+/// backend is chained behind it (see [`FallbackClipboard`]).
 ///
-/// Returns the adapter and a label describing which backend was selected.
-pub async fn create_clipboard() -> (Box<dyn Clipboard>, &'static str) {
+/// Returns the adapter and a label describing which backend was selected,
+/// plus its fallbacks if any.
+pub async fn create_clipboard() -> (Box<dyn Clipboard>, String) {
     #[cfg(target_os = "linux")]
-    {
-        if is_wayland() && is_wl_copy_available().await {
-            return (Box::new(WaylandClipboard::new()), "wl-copy");
-        }
+    let order = backend_order(
+        is_wayland(),
+        is_command_available("wl-copy").await,
+        is_command_available("xclip").await,
+    );
+    #[cfg(not(target_os = "linux"))]
+    let order = backend_order(false, false, false);
+
+    let label = match order.split_first() {
+        Some((primary, [])) => primary.label().to_string(),
+        Some((primary, rest)) => format!(
+            "{} (fallback: {})",
+            primary.label(),
+            rest.iter()
+                .map(|b| b.label())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        None => unreachable!("arboard is always in the order"),
+    };
+    // Even a lone backend goes through the chain, so the backend reported
+    // after a copy carries the same label as the one returned here.
+    let chain = order.into_iter().map(|b| (b.label(), b.build())).collect();
+    (Box::new(FallbackClipboard::new(chain)), label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ClipboardBackend::{Arboard, WlCopy, Xclip};
+
+    #[test]
+    fn wl_copy_leads_only_on_wayland() {
+        assert_eq!(
+            backend_order(true, true, true),
+            vec![WlCopy, Arboard, Xclip]
+        );
+        assert_eq!(
+            backend_order(false, true, true),
+            vec![Arboard, WlCopy, Xclip]
+        );
+    }
+
+    #[test]
+    fn missing_tools_are_left_out() {
+        assert_eq!(backend_order(true, false, false), vec![Arboard]);
+        assert_eq!(backend_order(false, false, true), vec![Arboard, Xclip]);
     }
-    (Box::new(ArboardClipboard::new()), "arboard")
 }
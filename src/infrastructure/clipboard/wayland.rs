@@ -1,4 +1,4 @@
-//! Wayland clipboard adapter using wl-copy
+//! Wayland clipboard adapter using wl-copy / wl-paste
 
 use std::process::Stdio;
 
@@ -8,7 +8,7 @@ use tokio::process::Command;
 
 use crate::application::ports::{Clipboard, ClipboardError};
 
-/// Wayland clipboard adapter using wl-copy
+/// Wayland clipboard adapter using wl-copy (and wl-paste for reads)
 pub struct WaylandClipboard;
 
 impl WaylandClipboard {
@@ -27,6 +27,14 @@ impl Default for WaylandClipboard {
 #[async_trait]
 impl Clipboard for WaylandClipboard {
     async fn copy(&self, text: &str) -> Result<(), ClipboardError> {
+        // Without a compositor wl-copy can only fail; report it as
+        // unavailable so a fallback backend gets a turn.
+        if std::env::var_os("WAYLAND_DISPLAY").is_none_or(|v| v.is_empty()) {
+            return Err(ClipboardError::BackendUnavailable {
+                tool: "wl-copy".to_string(),
+                reason: "no Wayland display (WAYLAND_DISPLAY is unset)".to_string(),
+            });
+        }
         let mut child = Command::new("wl-copy")
             .stdin(Stdio::piped())
             .stdout(Stdio::null())
@@ -66,4 +74,30 @@ impl Clipboard for WaylandClipboard {
 
         Ok(())
     }
+
+    async fn read(&self) -> Result<Option<String>, ClipboardError> {
+        let output = Command::new("wl-paste")
+            .args(["--no-newline", "--type", "text"])
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .await
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    ClipboardError::BackendUnavailable {
+                        tool: "wl-paste".to_string(),
+                        reason: "command not found; install wl-clipboard".to_string(),
+                    }
+                } else {
+                    ClipboardError::ReadFailed(e.to_string())
+                }
+            })?;
+
+        // wl-paste exits non-zero for an empty clipboard or one with no
+        // text type on offer.
+        if !output.status.success() {
+            return Ok(None);
+        }
+        Ok(Some(String::from_utf8_lossy(&output.stdout).into_owned()))
+    }
 }
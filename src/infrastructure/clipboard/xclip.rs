@@ -0,0 +1,104 @@
+//! X11 clipboard adapter using xclip
+
+use std::process::Stdio;
+
+use async_trait::async_trait;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::application::ports::{Clipboard, ClipboardError};
+
+/// X11 clipboard adapter shelling out to `xclip -selection clipboard`.
+///
+/// Useful where arboard can't connect (e.g. over SSH with X forwarding)
+/// but an xclip binary still reaches the display.
+pub struct XclipClipboard;
+
+impl XclipClipboard {
+    /// Create a new xclip clipboard adapter
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for XclipClipboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// xclip needs an X display; without one it can only fail.
+fn check_display() -> Result<(), ClipboardError> {
+    match std::env::var_os("DISPLAY") {
+        Some(v) if !v.is_empty() => Ok(()),
+        _ => Err(ClipboardError::BackendUnavailable {
+            tool: "xclip".to_string(),
+            reason: "no X display (DISPLAY is unset)".to_string(),
+        }),
+    }
+}
+
+/// Map a spawn failure; a missing binary means the backend is unavailable.
+fn spawn_error(e: std::io::Error, wrap: fn(String) -> ClipboardError) -> ClipboardError {
+    if e.kind() == std::io::ErrorKind::NotFound {
+        ClipboardError::BackendUnavailable {
+            tool: "xclip".to_string(),
+            reason: "command not found; install xclip".to_string(),
+        }
+    } else {
+        wrap(e.to_string())
+    }
+}
+
+#[async_trait]
+impl Clipboard for XclipClipboard {
+    async fn copy(&self, text: &str) -> Result<(), ClipboardError> {
+        check_display()?;
+        // stdout/stderr stay detached: xclip forks to serve the selection
+        // and the child would hold captured pipes open indefinitely.
+        let mut child = Command::new("xclip")
+            .args(["-selection", "clipboard"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| spawn_error(e, ClipboardError::CopyFailed))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(text.as_bytes())
+                .await
+                .map_err(|e| ClipboardError::CopyFailed(e.to_string()))?;
+        }
+
+        let status = child
+            .wait()
+            .await
+            .map_err(|e| ClipboardError::CopyFailed(e.to_string()))?;
+        if !status.success() {
+            return Err(ClipboardError::CopyFailed(format!(
+                "xclip exited with status: {}",
+                status
+            )));
+        }
+        Ok(())
+    }
+
+    async fn read(&self) -> Result<Option<String>, ClipboardError> {
+        check_display()?;
+        let output = Command::new("xclip")
+            .args(["-selection", "clipboard", "-o"])
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .await
+            .map_err(|e| spawn_error(e, ClipboardError::ReadFailed))?;
+
+        // xclip exits non-zero for an empty clipboard or one with no text
+        // target on offer.
+        if !output.status.success() {
+            return Ok(None);
+        }
+        Ok(Some(String::from_utf8_lossy(&output.stdout).into_owned()))
+    }
+}
@@ -0,0 +1,476 @@
+//! Upgrades older config files to the current layout.
+//!
+//! Every file carries a top-level `version`; files written before it
+//! existed count as version 0. [`migrate`] applies each step newer than the
+//! file's version in order and then records the current version, so a
+//! second run changes nothing.
+
+use toml::{Table, Value};
+
+use crate::domain::config::CONFIG_VERSION;
+
+/// Legacy keys removed in the OpenAI-only rewrite.
+pub(super) const LEGACY_KEYS: &[&str] = &["api_key", "backend", "chatgpt_cookie_file", "domain"];
+
+/// Flat keys of the pre-table layout, with the `[table]` and key they live
+/// under now. `indicator` and `indicator_position` exist on both platforms
+/// and move to the table of the platform this build runs on; the flat
+/// `indicator` switch comes before the `[indicator]` style moves, which
+/// need its name for the table.
+const TABLE_MOVES: &[(&str, &str, &str)] = &[
+    ("keystroke_tool", "linux", "keystroke_tool"),
+    ("paste", "linux", "paste"),
+    ("indicator_clickable", "linux", "indicator_clickable"),
+    ("show_balloon", "windows", "show_balloon"),
+    ("indicator", PLATFORM_TABLE, "indicator"),
+    ("indicator_position", PLATFORM_TABLE, "indicator_position"),
+    ("indicator_width", "indicator", "width"),
+    ("indicator_height", "indicator", "height"),
+    ("indicator_margin", "indicator", "margin"),
+    ("indicator_background", "indicator", "background"),
+    ("indicator_recording_color", "indicator", "recording_color"),
+    (
+        "indicator_processing_color",
+        "indicator",
+        "processing_color",
+    ),
+    ("start_sound", "cues", "start_sound"),
+    ("stop_sound", "cues", "stop_sound"),
+    ("cancel_sound", "cues", "cancel_sound"),
+];
+
+#[cfg(target_os = "windows")]
+const PLATFORM_TABLE: &str = "windows";
+#[cfg(not(target_os = "windows"))]
+const PLATFORM_TABLE: &str = "linux";
+
+/// Upgrade `table` in place to [`CONFIG_VERSION`].
+///
+/// Returns the version the file was at and one line per change made; no
+/// changes means the file was already current.
+pub(super) fn migrate(table: &mut Table) -> (u32, Vec<String>) {
+    let from_version = table
+        .get("version")
+        .and_then(Value::as_integer)
+        .and_then(|v| u32::try_from(v).ok())
+        .unwrap_or(0);
+    let mut changes = Vec::new();
+    if from_version >= CONFIG_VERSION {
+        return (from_version, changes);
+    }
+
+    if from_version < 1 {
+        to_v1(table, "", &mut changes);
+        if let Some(Value::Table(profiles)) = table.get_mut("profiles") {
+            for (name, profile) in profiles.iter_mut() {
+                if let Value::Table(profile) = profile {
+                    to_v1(profile, &format!("[profiles.{name}] "), &mut changes);
+                }
+            }
+        }
+    }
+
+    table.insert("version".into(), Value::Integer(CONFIG_VERSION.into()));
+    changes.push(format!("set version = {CONFIG_VERSION}"));
+    (from_version, changes)
+}
+
+/// Version 1: drop keys of removed backends and move flat keys into the
+/// `[linux]`, `[windows]`, `[indicator]` and `[cues]` tables.
+fn to_v1(table: &mut Table, scope: &str, changes: &mut Vec<String>) {
+    for &key in LEGACY_KEYS {
+        if table.remove(key).is_some() {
+            changes.push(format!("{scope}removed `{key}` (no longer supported)"));
+        }
+    }
+
+    for &(flat, section, key) in TABLE_MOVES {
+        // `[indicator]` is a table by now; only a flat value moves, and
+        // only into a table.
+        if table.get(flat).is_none_or(Value::is_table)
+            || table.get(section).is_some_and(|v| !v.is_table())
+        {
+            continue;
+        }
+        let value = table.remove(flat).expect("checked above");
+        let Value::Table(target) = table
+            .entry(section)
+            .or_insert_with(|| Value::Table(Table::new()))
+        else {
+            unreachable!("checked above");
+        };
+        if target.contains_key(key) {
+            changes.push(format!(
+                "{scope}removed `{flat}` (already set as `{section}.{key}`)"
+            ));
+        } else {
+            target.insert(key.to_string(), value);
+            changes.push(format!("{scope}moved `{flat}` to `{section}.{key}`"));
+        }
+    }
+}
+
+/// Apply the upgrade [`migrate`] made to `expected` to the file text
+/// instead, moving whole lines so comments, blank lines and key order
+/// survive.
+///
+/// Returns `None` when the edited text doesn't parse back to `expected`
+/// (a value spanning several lines, dotted keys and the like); the caller
+/// then re-serialises the table.
+pub(super) fn migrate_text(content: &str, from_version: u32, expected: &Table) -> Option<String> {
+    let mut doc = Document::parse(content);
+    if from_version < 1 {
+        let profiles: Vec<String> = doc
+            .sections
+            .iter()
+            .filter_map(|s| s.name.strip_prefix("profiles."))
+            .filter(|name| !name.contains('.'))
+            .map(|name| format!("profiles.{name}"))
+            .collect();
+        text_to_v1(&mut doc, "");
+        for scope in profiles {
+            text_to_v1(&mut doc, &scope);
+        }
+    }
+    doc.set_top_level("version", &format!(" {CONFIG_VERSION}\n"));
+
+    let text = doc.render();
+    let parsed: Table = toml::from_str(&text).ok()?;
+    (&parsed == expected).then_some(text)
+}
+
+/// Line-based counterpart of [`to_v1`] for one scope: the top level (`""`)
+/// or a `profiles.<name>` section.
+fn text_to_v1(doc: &mut Document, scope: &str) {
+    let qualify = |name: &str| {
+        if scope.is_empty() {
+            name.to_string()
+        } else {
+            format!("{scope}.{name}")
+        }
+    };
+
+    for &key in LEGACY_KEYS {
+        doc.take(scope, key);
+    }
+    for &(flat, section, key) in TABLE_MOVES {
+        let target = qualify(section);
+        if doc
+            .value(scope, flat)
+            .as_deref()
+            .is_none_or(is_inline_table)
+            || doc.value(scope, section).is_some()
+        {
+            continue;
+        }
+        let value = doc.take(scope, flat).expect("checked above");
+        if doc.value(&target, key).is_none() {
+            doc.insert(scope, &target, key, &value);
+        }
+    }
+}
+
+fn is_inline_table(value: &str) -> bool {
+    value.trim_start().starts_with('{')
+}
+
+/// A TOML file split at its `[section]` headers, kept as raw lines.
+struct Document {
+    sections: Vec<Section>,
+}
+
+struct Section {
+    /// Dotted table name; empty for the lines before the first header.
+    name: String,
+    /// Raw lines, each ending in a newline; the header line comes first
+    /// for every section but the top level.
+    lines: Vec<String>,
+    /// Created by the migration rather than read from the file.
+    added: bool,
+}
+
+impl Document {
+    fn parse(content: &str) -> Self {
+        let mut sections = vec![Section {
+            name: String::new(),
+            lines: Vec::new(),
+            added: false,
+        }];
+        for line in content.split_inclusive('\n') {
+            let mut line = line.to_string();
+            if !line.ends_with('\n') {
+                line.push('\n');
+            }
+            if let Some(name) = header_name(&line) {
+                sections.push(Section {
+                    name,
+                    lines: vec![line],
+                    added: false,
+                });
+            } else {
+                sections.last_mut().expect("top level").lines.push(line);
+            }
+        }
+        Self { sections }
+    }
+
+    fn render(&self) -> String {
+        self.sections
+            .iter()
+            .flat_map(|s| s.lines.iter())
+            .cloned()
+            .collect()
+    }
+
+    fn find(&self, name: &str) -> Option<usize> {
+        self.sections.iter().position(|s| s.name == name)
+    }
+
+    fn line_of(&self, section: usize, key: &str) -> Option<usize> {
+        self.sections[section]
+            .lines
+            .iter()
+            .position(|line| split_key(line).is_some_and(|(k, _)| k == key))
+    }
+
+    /// Raw text after the `=` of `key` in section `name`.
+    fn value(&self, name: &str, key: &str) -> Option<String> {
+        let section = self.find(name)?;
+        let line = self.line_of(section, key)?;
+        split_key(&self.sections[section].lines[line]).map(|(_, value)| value.to_string())
+    }
+
+    /// Remove the `key` line from section `name`, returning its raw value.
+    fn take(&mut self, name: &str, key: &str) -> Option<String> {
+        let value = self.value(name, key)?;
+        let section = self.find(name)?;
+        let line = self.line_of(section, key)?;
+        self.sections[section].lines.remove(line);
+        Some(value)
+    }
+
+    /// Add `key` to section `target`, creating the section after `scope`'s
+    /// own lines when it doesn't exist yet.
+    fn insert(&mut self, scope: &str, target: &str, key: &str, value: &str) {
+        let line = format!("{key} ={value}");
+        if let Some(section) = self.find(target) {
+            let lines = &mut self.sections[section].lines;
+            // After the last key, before any trailing blank lines.
+            let at = lines
+                .iter()
+                .rposition(|l| !l.trim().is_empty())
+                .map_or(lines.len(), |i| i + 1);
+            lines.insert(at, line);
+            return;
+        }
+        // Right after the scope's own lines, in creation order.
+        let scope_at = self.find(scope).expect("scope exists");
+        let at = scope_at
+            + 1
+            + self.sections[scope_at + 1..]
+                .iter()
+                .take_while(|s| s.added)
+                .count();
+        // The blank lines that separated the previous section from the
+        // next one now follow the new section instead.
+        let previous = &mut self.sections[at - 1].lines;
+        let content_end = previous
+            .iter()
+            .rposition(|l| !l.trim().is_empty())
+            .map_or(0, |i| i + 1);
+        let trailing = previous.split_off(content_end);
+        let mut lines = Vec::new();
+        if !previous.is_empty() {
+            lines.push("\n".to_string());
+        }
+        lines.push(format!("[{target}]\n"));
+        lines.push(line);
+        lines.extend(trailing);
+        self.sections.insert(
+            at,
+            Section {
+                name: target.to_string(),
+                lines,
+                added: true,
+            },
+        );
+    }
+
+    /// Replace or add a top-level key; a new key goes first in the file.
+    fn set_top_level(&mut self, key: &str, value: &str) {
+        let line = format!("{key} ={value}");
+        match self.line_of(0, key) {
+            Some(i) => self.sections[0].lines[i] = line,
+            None => self.sections[0].lines.insert(0, line),
+        }
+    }
+}
+
+/// Table name of a `[section]` header line, with spaces around the dots
+/// removed. Array-of-tables headers (`[[...]]`) count as sections too.
+fn header_name(line: &str) -> Option<String> {
+    let trimmed = line.trim();
+    let inner = trimmed.strip_prefix('[')?;
+    let inner = inner.split(']').next()?.trim_start_matches('[');
+    Some(
+        inner
+            .split('.')
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join("."),
+    )
+}
+
+/// Key and raw value (text after the `=`) of a `key = value` line.
+fn split_key(line: &str) -> Option<(&str, &str)> {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with('#') {
+        return None;
+    }
+    let (key, value) = trimmed.split_once('=')?;
+    Some((key.trim(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::config::RawAppConfig;
+
+    const OLD_CONFIG: &str = r#"
+backend = "gemini"
+domain = "dev"
+auth = "oauth"
+keystroke_tool = "wtype"
+indicator = true
+indicator_position = "bottom-left"
+indicator_width = 140
+start_sound = "/sounds/start.wav"
+
+[linux]
+paste = true
+
+[profiles.coding]
+keystroke_tool = "ydotool"
+"#;
+
+    fn parse(content: &str) -> Table {
+        toml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn old_layout_is_moved_into_tables() {
+        let mut table = parse(OLD_CONFIG);
+        let (from, changes) = migrate(&mut table);
+        assert_eq!(from, 0);
+        assert!(changes.contains(&"removed `backend` (no longer supported)".to_string()));
+        assert!(changes.contains(&"moved `indicator_width` to `indicator.width`".to_string()));
+        assert!(changes
+            .iter()
+            .any(|c| c.starts_with("[profiles.coding] moved `keystroke_tool`")));
+
+        let raw: RawAppConfig = toml::from_str(&toml::to_string(&table).unwrap()).unwrap();
+        assert_eq!(raw.version, Some(CONFIG_VERSION));
+        assert_eq!(raw.auth.as_deref(), Some("oauth"));
+        assert_eq!(raw.indicator.unwrap().width, Some(140));
+        assert_eq!(
+            raw.cues.unwrap().start_sound.as_deref(),
+            Some("/sounds/start.wav")
+        );
+        let platform = table[PLATFORM_TABLE].as_table().unwrap();
+        assert_eq!(platform["indicator"].as_bool(), Some(true));
+        assert_eq!(platform["indicator_position"].as_str(), Some("bottom-left"));
+        let linux = raw.linux.unwrap();
+        assert_eq!(linux.keystroke_tool.as_deref(), Some("wtype"));
+        assert_eq!(linux.paste, Some(true));
+        let coding = &raw.profiles.unwrap()["coding"];
+        assert_eq!(
+            coding.linux.as_ref().unwrap().keystroke_tool.as_deref(),
+            Some("ydotool")
+        );
+        for key in ["backend", "domain", "keystroke_tool", "indicator_width"] {
+            assert!(!table.contains_key(key), "{key} left at top level");
+        }
+    }
+
+    #[test]
+    fn migration_is_idempotent() {
+        let mut table = parse(OLD_CONFIG);
+        migrate(&mut table);
+        let once = table.clone();
+        let (from, changes) = migrate(&mut table);
+        assert_eq!(from, CONFIG_VERSION);
+        assert!(changes.is_empty(), "{changes:?}");
+        assert_eq!(table, once);
+    }
+
+    #[test]
+    fn table_value_wins_over_flat_duplicate() {
+        let mut table = parse("paste = false\n[linux]\npaste = true\n");
+        let (_, changes) = migrate(&mut table);
+        assert_eq!(table["linux"]["paste"].as_bool(), Some(true));
+        assert!(!table.contains_key("paste"));
+        assert_eq!(changes[0], "removed `paste` (already set as `linux.paste`)");
+    }
+
+    fn migrated_text(content: &str) -> Option<String> {
+        let mut table = parse(content);
+        let (from, _) = migrate(&mut table);
+        migrate_text(content, from, &table)
+    }
+
+    #[test]
+    fn text_migration_keeps_comments_and_order() {
+        let content = r#"# my config
+auth = "oauth" # signed in
+backend = "gemini"
+
+# typing
+keystroke_tool = "wtype"
+indicator_width = 140
+
+[linux]
+# paste instead of typing
+paste = true
+
+[profiles.coding]
+keystroke_tool = "ydotool"
+"#;
+        let expected = format!(
+            r#"version = {CONFIG_VERSION}
+# my config
+auth = "oauth" # signed in
+
+# typing
+
+[indicator]
+width = 140
+
+[linux]
+# paste instead of typing
+paste = true
+keystroke_tool = "wtype"
+
+[profiles.coding]
+
+[profiles.coding.linux]
+keystroke_tool = "ydotool"
+"#
+        );
+        assert_eq!(migrated_text(content).as_deref(), Some(expected.as_str()));
+        assert!(migrated_text(OLD_CONFIG).is_some());
+    }
+
+    #[test]
+    fn text_migration_gives_up_on_multi_line_values() {
+        let content = "start_sound = \"\"\"\n/sounds/start.wav\"\"\"\n";
+        assert_eq!(migrated_text(content), None);
+    }
+
+    #[test]
+    fn current_file_only_gains_a_version() {
+        let mut table = parse("auth = \"oauth\"\n[indicator]\nwidth = 90\n");
+        let (_, changes) = migrate(&mut table);
+        assert_eq!(changes, vec![format!("set version = {CONFIG_VERSION}")]);
+        assert_eq!(table["indicator"]["width"].as_integer(), Some(90));
+    }
+}
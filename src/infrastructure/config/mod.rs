@@ -1,5 +1,6 @@
 //! Configuration infrastructure module
 
+mod migrate;
 mod xdg;
 
 pub use xdg::XdgConfigStore;
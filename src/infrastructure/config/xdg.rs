@@ -1,21 +1,15 @@
 //! XDG config store adapter
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
 use tokio::fs;
 
-use crate::application::ports::ConfigStore;
+use super::migrate::{migrate, migrate_text, LEGACY_KEYS};
+use crate::application::ports::{ConfigMigration, ConfigStore};
 use crate::domain::config::RawAppConfig;
 use crate::domain::error::ConfigError;
 
-/// Legacy keys removed in the OpenAI-only rewrite.
-///
-/// When [`XdgConfigStore::load`] sees any of these at the top level it strips
-/// them in-place (preserving comments and unrelated lines) and prints a
-/// one-line notice on stderr. Subsequent loads then run silently.
-const LEGACY_KEYS: &[&str] = &["api_key", "backend", "chatgpt_cookie_file", "domain"];
-
 /// XDG-compliant config store
 pub struct XdgConfigStore {
     path: PathBuf,
@@ -45,6 +39,12 @@ impl XdgConfigStore {
         Self { path: path.into() }
     }
 
+    /// Use `path` when one was given (`--config` / `SMART_SCRIBE_CONFIG`),
+    /// otherwise the XDG default
+    pub fn at(path: Option<&Path>) -> Self {
+        path.map_or_else(Self::new, Self::with_path)
+    }
+
     /// Parse TOML content into [`RawAppConfig`].
     ///
     /// Legacy keys are silently dropped here (they no longer exist in the
@@ -56,6 +56,9 @@ impl XdgConfigStore {
 
     /// Surgically remove top-level legacy keys from a TOML document.
     ///
+    /// [`Self::load`] does this on every read and prints a one-line notice
+    /// on stderr, so subsequent loads run silently.
+    ///
     /// Works on a line-by-line basis to preserve comments, blank lines, and
     /// any unrelated formatting the user added by hand. Keys inside a
     /// `[table]` section are left alone (they can't be the deprecated
@@ -93,6 +96,13 @@ impl XdgConfigStore {
     }
 }
 
+/// `config.toml` -> `config.toml.bak`, whatever the file is called.
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".bak");
+    PathBuf::from(name)
+}
+
 impl Default for XdgConfigStore {
     fn default() -> Self {
         Self::new()
@@ -165,6 +175,45 @@ impl ConfigStore for XdgConfigStore {
         let defaults = RawAppConfig::defaults();
         self.save(&defaults).await
     }
+
+    async fn migrate(&self) -> Result<ConfigMigration, ConfigError> {
+        let content = fs::read_to_string(&self.path)
+            .await
+            .map_err(|e| ConfigError::ReadError(e.to_string()))?;
+        let mut table: toml::Table =
+            toml::from_str(&content).map_err(|e| ConfigError::ParseError(e.to_string()))?;
+
+        let (from_version, changes) = migrate(&mut table);
+        if changes.is_empty() {
+            return Ok(ConfigMigration {
+                from_version,
+                changes,
+                backup: None,
+            });
+        }
+
+        let migrated = match migrate_text(&content, from_version, &table) {
+            Some(text) => text,
+            None => toml::to_string_pretty(&table)
+                .map_err(|e| ConfigError::WriteError(e.to_string()))?,
+        };
+        // Never replace a loadable file with one that isn't.
+        Self::parse_toml(&migrated)?;
+
+        let backup = backup_path(&self.path);
+        fs::copy(&self.path, &backup)
+            .await
+            .map_err(|e| ConfigError::WriteError(e.to_string()))?;
+        fs::write(&self.path, migrated)
+            .await
+            .map_err(|e| ConfigError::WriteError(e.to_string()))?;
+
+        Ok(ConfigMigration {
+            from_version,
+            changes,
+            backup: Some(backup),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -185,6 +234,18 @@ mod tests {
         assert_eq!(store.path(), PathBuf::from("/custom/path/config.toml"));
     }
 
+    #[test]
+    fn backup_appends_to_the_file_name() {
+        assert_eq!(
+            backup_path(Path::new("/etc/scribe/config.toml")),
+            PathBuf::from("/etc/scribe/config.toml.bak")
+        );
+        assert_eq!(
+            backup_path(Path::new("/etc/scribe/scribe.conf")),
+            PathBuf::from("/etc/scribe/scribe.conf.bak")
+        );
+    }
+
     #[test]
     fn parse_toml_flat_format() {
         let content = r#"
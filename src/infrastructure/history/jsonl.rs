@@ -0,0 +1,336 @@
+//! JSON-lines history store adapter
+//!
+//! One [`HistoryEntry`] per line, oldest first, under the user data dir
+//! (`~/.local/share/smart-scribe/history.jsonl` on Linux). Appends are a
+//! single `write` in append mode; the file is rewritten only when it grows
+//! past the entry cap. Both happen under an advisory lock on
+//! `history.jsonl.lock`, so a rewrite never drops another process's append.
+//!
+//! The audio of the latest transcription sits next to it as
+//! `last-audio.<ext>`, overwritten on each save, for `smart-scribe replay`.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+use crate::application::ports::{HistoryError, HistoryStore};
+use crate::domain::config::DEFAULT_HISTORY_MAX_ENTRIES;
+use crate::domain::history::HistoryEntry;
+use crate::domain::transcription::{AudioData, AudioMimeType};
+
+/// File stem of the saved last capture; the extension names its format.
+const LAST_AUDIO_STEM: &str = "last-audio";
+
+/// JSONL-backed history store
+pub struct JsonlHistoryStore {
+    path: PathBuf,
+    max_entries: usize,
+}
+
+impl JsonlHistoryStore {
+    /// Store at the platform data dir with the given entry cap.
+    pub fn new(max_entries: usize) -> Self {
+        let data_dir = dirs::data_dir()
+            .or_else(|| dirs::home_dir().map(|home| home.join(".local").join("share")))
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("smart-scribe");
+        Self::with_path(data_dir.join("history.jsonl"), max_entries)
+    }
+
+    /// Create with custom path
+    pub fn with_path(path: impl Into<PathBuf>, max_entries: usize) -> Self {
+        Self {
+            path: path.into(),
+            max_entries: max_entries.max(1),
+        }
+    }
+
+    /// Get the history file path
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Directory holding the history file and the last capture.
+    fn dir(&self) -> &Path {
+        self.path.parent().unwrap_or_else(|| Path::new("."))
+    }
+
+    /// Saved last captures (normally at most one), with their formats.
+    async fn last_audio_files(&self) -> Result<Vec<(PathBuf, AudioMimeType)>, HistoryError> {
+        let mut dir = match fs::read_dir(self.dir()).await {
+            Ok(d) => d,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(HistoryError::Io(e.to_string())),
+        };
+        let mut files = Vec::new();
+        while let Some(entry) = dir
+            .next_entry()
+            .await
+            .map_err(|e| HistoryError::Io(e.to_string()))?
+        {
+            let path = entry.path();
+            let is_last = path.file_stem().is_some_and(|s| s == LAST_AUDIO_STEM);
+            let mime = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .and_then(AudioMimeType::from_extension);
+            if let (true, Some(mime)) = (is_last, mime) {
+                files.push((path, mime));
+            }
+        }
+        Ok(files)
+    }
+
+    /// All entries, oldest first. A missing file is an empty history.
+    ///
+    /// Lines that don't parse (a torn write, a hand edit) are skipped with a
+    /// warning rather than hiding every other entry.
+    async fn load_all(&self) -> Result<Vec<HistoryEntry>, HistoryError> {
+        let content = match fs::read_to_string(&self.path).await {
+            Ok(c) => c,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(HistoryError::Io(e.to_string())),
+        };
+        let entries = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .filter_map(|(i, line)| match serde_json::from_str(line) {
+                Ok(entry) => Some(entry),
+                Err(e) => {
+                    tracing::warn!(
+                        path = %self.path.display(),
+                        line = i + 1,
+                        error = %e,
+                        "skipping corrupt history entry"
+                    );
+                    None
+                }
+            })
+            .collect();
+        Ok(entries)
+    }
+
+    /// Take the writer lock, waiting for another process's append or
+    /// rewrite to finish. Released when the returned file is dropped.
+    ///
+    /// `None` when the lock file can't be used; like the microphone lock,
+    /// it is a safety net, not a reason to lose the entry.
+    async fn lock(&self) -> Option<std::fs::File> {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".lock");
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(path)
+                .ok()?;
+            file.lock().ok()?;
+            Some(file)
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+
+    /// Rewrite the file keeping only the newest `max_entries`.
+    async fn truncate(&self, entries: &[HistoryEntry]) -> Result<(), HistoryError> {
+        let keep = &entries[entries.len().saturating_sub(self.max_entries)..];
+        let mut body = String::new();
+        for entry in keep {
+            body.push_str(&encode(entry)?);
+        }
+        // Write-then-rename so a crash never leaves a half-written history.
+        let tmp = self.path.with_extension("jsonl.tmp");
+        fs::write(&tmp, body)
+            .await
+            .map_err(|e| HistoryError::Io(e.to_string()))?;
+        fs::rename(&tmp, &self.path)
+            .await
+            .map_err(|e| HistoryError::Io(e.to_string()))
+    }
+}
+
+impl Default for JsonlHistoryStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_MAX_ENTRIES as usize)
+    }
+}
+
+fn encode(entry: &HistoryEntry) -> Result<String, HistoryError> {
+    let mut line = serde_json::to_string(entry).map_err(|e| HistoryError::Io(e.to_string()))?;
+    line.push('\n');
+    Ok(line)
+}
+
+#[async_trait]
+impl HistoryStore for JsonlHistoryStore {
+    async fn append(&self, entry: &HistoryEntry) -> Result<(), HistoryError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .map_err(|e| HistoryError::Io(e.to_string()))?;
+        }
+        // Held across the append and any rewrite.
+        let _lock = self.lock().await;
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(|e| HistoryError::Io(e.to_string()))?;
+        file.write_all(encode(entry)?.as_bytes())
+            .await
+            .map_err(|e| HistoryError::Io(e.to_string()))?;
+        drop(file);
+
+        let entries = self.load_all().await?;
+        if entries.len() > self.max_entries {
+            self.truncate(&entries).await?;
+        }
+        Ok(())
+    }
+
+    async fn recent(&self, limit: usize) -> Result<Vec<HistoryEntry>, HistoryError> {
+        let entries = self.load_all().await?;
+        Ok(entries.into_iter().rev().take(limit).collect())
+    }
+
+    async fn save_last_audio(&self, audio: &AudioData) -> Result<(), HistoryError> {
+        fs::create_dir_all(self.dir())
+            .await
+            .map_err(|e| HistoryError::Io(e.to_string()))?;
+        let path = self.dir().join(format!(
+            "{LAST_AUDIO_STEM}.{}",
+            audio.mime_type().extension()
+        ));
+        // A previous capture in another format would otherwise shadow this one.
+        for (old, _) in self.last_audio_files().await? {
+            if old != path {
+                let _ = fs::remove_file(old).await;
+            }
+        }
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, audio.data())
+            .await
+            .map_err(|e| HistoryError::Io(e.to_string()))?;
+        fs::rename(&tmp, &path)
+            .await
+            .map_err(|e| HistoryError::Io(e.to_string()))
+    }
+
+    async fn last_audio(&self) -> Result<Option<AudioData>, HistoryError> {
+        let Some((path, mime)) = self.last_audio_files().await?.into_iter().next() else {
+            return Ok(None);
+        };
+        let data = fs::read(&path)
+            .await
+            .map_err(|e| HistoryError::Io(e.to_string()))?;
+        Ok(Some(AudioData::new(data, mime)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(n: u64) -> HistoryEntry {
+        HistoryEntry::new(n, "gpt-4o-transcribe", format!("text {n}"), n * 10)
+    }
+
+    #[tokio::test]
+    async fn missing_file_is_empty_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = JsonlHistoryStore::with_path(dir.path().join("h.jsonl"), 10);
+        assert!(store.recent(5).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn recent_returns_newest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = JsonlHistoryStore::with_path(dir.path().join("sub").join("h.jsonl"), 10);
+        for n in 1..=3 {
+            store.append(&entry(n)).await.unwrap();
+        }
+        let recent = store.recent(2).await.unwrap();
+        assert_eq!(recent, vec![entry(3), entry(2)]);
+    }
+
+    #[tokio::test]
+    async fn append_enforces_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("h.jsonl");
+        let store = JsonlHistoryStore::with_path(&path, 3);
+        for n in 1..=5 {
+            store.append(&entry(n)).await.unwrap();
+        }
+        let all = store.recent(usize::MAX).await.unwrap();
+        assert_eq!(all, vec![entry(5), entry(4), entry(3)]);
+        let lines = std::fs::read_to_string(&path).unwrap().lines().count();
+        assert_eq!(lines, 3);
+    }
+
+    #[tokio::test]
+    async fn corrupt_lines_are_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("h.jsonl");
+        let store = JsonlHistoryStore::with_path(&path, 10);
+        store.append(&entry(1)).await.unwrap();
+        let mut content = std::fs::read_to_string(&path).unwrap();
+        content.push_str("{\"timestamp\": 2, \"text\n");
+        std::fs::write(&path, content).unwrap();
+        store.append(&entry(3)).await.unwrap();
+
+        let all = store.recent(usize::MAX).await.unwrap();
+        assert_eq!(all, vec![entry(3), entry(1)]);
+    }
+
+    #[tokio::test]
+    async fn concurrent_appends_keep_the_newest_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("h.jsonl");
+        let tasks: Vec<_> = (1..=20)
+            .map(|n| {
+                let path = path.clone();
+                tokio::spawn(async move {
+                    JsonlHistoryStore::with_path(path, 5)
+                        .append(&entry(n))
+                        .await
+                        .unwrap();
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let store = JsonlHistoryStore::with_path(&path, 5);
+        assert_eq!(store.recent(usize::MAX).await.unwrap().len(), 5);
+        let lines = std::fs::read_to_string(&path).unwrap().lines().count();
+        assert_eq!(lines, 5);
+    }
+
+    #[tokio::test]
+    async fn last_audio_is_replaced_by_each_save() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = JsonlHistoryStore::with_path(dir.path().join("sub").join("h.jsonl"), 10);
+        assert!(store.last_audio().await.unwrap().is_none());
+
+        store
+            .save_last_audio(&AudioData::new(vec![1, 2, 3], AudioMimeType::Flac))
+            .await
+            .unwrap();
+        store
+            .save_last_audio(&AudioData::new(vec![4, 5], AudioMimeType::Wav))
+            .await
+            .unwrap();
+
+        let audio = store.last_audio().await.unwrap().unwrap();
+        assert_eq!(audio.data(), &[4, 5]);
+        assert_eq!(audio.mime_type(), AudioMimeType::Wav);
+        assert!(!dir.path().join("sub").join("last-audio.flac").exists());
+    }
+}
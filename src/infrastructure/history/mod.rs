@@ -0,0 +1,5 @@
+//! Transcription history infrastructure module
+
+mod jsonl;
+
+pub use jsonl::JsonlHistoryStore;
@@ -3,6 +3,8 @@
 use std::fmt;
 use std::str::FromStr;
 
+use async_trait::async_trait;
+
 use crate::application::ports::{Keystroke, KeystrokeError};
 #[cfg(target_os = "linux")]
 use crate::infrastructure::util::tool_detect::{
@@ -11,6 +13,8 @@ use crate::infrastructure::util::tool_detect::{
 
 use super::enigo::EnigoKeystroke;
 #[cfg(target_os = "linux")]
+use super::kdotool::KdotoolKeystroke;
+#[cfg(target_os = "linux")]
 use super::wtype::WtypeKeystroke;
 #[cfg(target_os = "linux")]
 use super::xdotool::XdotoolKeystroke;
@@ -28,12 +32,15 @@ pub enum KeystrokeTool {
     Wtype,
     /// Linux: xdotool (X11)
     Xdotool,
+    /// Linux: kdotool (KDE Plasma / KWin Wayland)
+    Kdotool,
 }
 
 /// User preference for keystroke tool selection.
 ///
 /// - All platforms support `Enigo` (the default).
-/// - Linux additionally supports `Auto`, `Ydotool`, `Xdotool`, and `Wtype`.
+/// - Linux additionally supports `Auto`, `Ydotool`, `Xdotool`, `Wtype`, and
+///   `Kdotool`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum KeystrokeToolPreference {
     /// Use cross-platform enigo library (default on all platforms)
@@ -51,6 +58,9 @@ pub enum KeystrokeToolPreference {
     /// Use wtype (Linux only, Wayland native)
     #[cfg(target_os = "linux")]
     Wtype,
+    /// Use kdotool (Linux only, KDE Plasma / KWin Wayland)
+    #[cfg(target_os = "linux")]
+    Kdotool,
 }
 
 impl fmt::Display for KeystrokeToolPreference {
@@ -65,6 +75,8 @@ impl fmt::Display for KeystrokeToolPreference {
             KeystrokeToolPreference::Xdotool => write!(f, "xdotool"),
             #[cfg(target_os = "linux")]
             KeystrokeToolPreference::Wtype => write!(f, "wtype"),
+            #[cfg(target_os = "linux")]
+            KeystrokeToolPreference::Kdotool => write!(f, "kdotool"),
         }
     }
 }
@@ -102,10 +114,12 @@ impl FromStr for KeystrokeToolPreference {
             "xdotool" => Ok(KeystrokeToolPreference::Xdotool),
             #[cfg(target_os = "linux")]
             "wtype" => Ok(KeystrokeToolPreference::Wtype),
+            #[cfg(target_os = "linux")]
+            "kdotool" => Ok(KeystrokeToolPreference::Kdotool),
             _ => Err(ParseKeystrokeToolError {
                 value: s.to_string(),
                 #[cfg(target_os = "linux")]
-                valid_options: "enigo, auto, ydotool, xdotool, wtype",
+                valid_options: "enigo, auto, ydotool, xdotool, wtype, kdotool",
                 #[cfg(not(target_os = "linux"))]
                 valid_options: "enigo",
             }),
@@ -113,27 +127,75 @@ impl FromStr for KeystrokeToolPreference {
     }
 }
 
-impl fmt::Display for KeystrokeTool {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl KeystrokeTool {
+    pub const fn as_str(&self) -> &'static str {
         match self {
-            KeystrokeTool::Enigo => write!(f, "enigo"),
-            KeystrokeTool::Ydotool => write!(f, "ydotool"),
-            KeystrokeTool::Wtype => write!(f, "wtype"),
-            KeystrokeTool::Xdotool => write!(f, "xdotool"),
+            KeystrokeTool::Enigo => "enigo",
+            KeystrokeTool::Ydotool => "ydotool",
+            KeystrokeTool::Wtype => "wtype",
+            KeystrokeTool::Xdotool => "xdotool",
+            KeystrokeTool::Kdotool => "kdotool",
         }
     }
 }
 
+impl fmt::Display for KeystrokeTool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Adapter picked by [`create_keystroke`], reporting the tool it resolved
+/// to as its backend so the adapters don't each name themselves.
+struct ResolvedKeystroke {
+    inner: Box<dyn Keystroke>,
+    tool: KeystrokeTool,
+}
+
+#[async_trait]
+impl Keystroke for ResolvedKeystroke {
+    async fn type_text(&self, text: &str) -> Result<(), KeystrokeError> {
+        self.inner.type_text(text).await
+    }
+
+    fn backend(&self) -> Option<&str> {
+        Some(self.tool.as_str())
+    }
+}
+
 /// Check if ydotool is available (binary on PATH + daemon socket present).
 #[cfg(target_os = "linux")]
 async fn is_ydotool_available() -> bool {
-    is_tool_available("ydotool").await && is_ydotool_socket_available()
+    ydotool_readiness(
+        is_tool_available("ydotool").await,
+        is_ydotool_socket_available(),
+    )
+    .is_ok()
+}
+
+/// Explain why ydotool can't be used, telling a missing binary apart from a
+/// stopped `ydotoold` (the binary alone types nothing).
+#[cfg(target_os = "linux")]
+fn ydotool_readiness(binary_found: bool, socket_found: bool) -> Result<(), KeystrokeError> {
+    if !binary_found {
+        return Err(KeystrokeError::BackendUnavailable {
+            tool: "ydotool".to_string(),
+            reason: "command not found on PATH".to_string(),
+        });
+    }
+    if !socket_found {
+        return Err(KeystrokeError::DaemonNotRunning {
+            tool: "ydotool".to_string(),
+            daemon: "ydotoold".to_string(),
+        });
+    }
+    Ok(())
 }
 
 /// Detect the best available keystroke tool
 ///
 /// On Windows/macOS: Always uses Enigo
-/// On Linux: Priority is ydotool → wtype → xdotool → Enigo
+/// On Linux: Priority is ydotool → wtype → kdotool → xdotool → Enigo
 pub async fn detect_keystroke_tool() -> Option<KeystrokeTool> {
     // On non-Linux platforms, use Enigo
     #[cfg(not(target_os = "linux"))]
@@ -154,6 +216,11 @@ pub async fn detect_keystroke_tool() -> Option<KeystrokeTool> {
             return Some(KeystrokeTool::Wtype);
         }
 
+        // Check kdotool (KWin Wayland)
+        if is_tool_available("kdotool").await {
+            return Some(KeystrokeTool::Kdotool);
+        }
+
         // Check xdotool (X11 fallback)
         if is_tool_available("xdotool").await {
             return Some(KeystrokeTool::Xdotool);
@@ -171,6 +238,13 @@ pub async fn detect_keystroke_tool() -> Option<KeystrokeTool> {
 /// On non-Linux platforms, always uses Enigo regardless of preference.
 pub async fn create_keystroke(
     preference: KeystrokeToolPreference,
+) -> Result<(Box<dyn Keystroke>, KeystrokeTool), KeystrokeError> {
+    let (inner, tool) = resolve_keystroke(preference).await?;
+    Ok((Box::new(ResolvedKeystroke { inner, tool }), tool))
+}
+
+async fn resolve_keystroke(
+    preference: KeystrokeToolPreference,
 ) -> Result<(Box<dyn Keystroke>, KeystrokeTool), KeystrokeError> {
     #[cfg(not(target_os = "linux"))]
     {
@@ -197,17 +271,14 @@ pub async fn create_keystroke(
                 }
             }
             KeystrokeToolPreference::Ydotool => {
-                if is_ydotool_available().await {
-                    Ok((
-                        Box::new(YdotoolKeystroke::new()) as Box<dyn Keystroke>,
-                        KeystrokeTool::Ydotool,
-                    ))
-                } else {
-                    Err(KeystrokeError::BackendUnavailable {
-                        tool: "ydotool".to_string(),
-                        reason: "command not found or ydotoold socket missing".to_string(),
-                    })
-                }
+                ydotool_readiness(
+                    is_tool_available("ydotool").await,
+                    is_ydotool_socket_available(),
+                )?;
+                Ok((
+                    Box::new(YdotoolKeystroke::new()) as Box<dyn Keystroke>,
+                    KeystrokeTool::Ydotool,
+                ))
             }
             KeystrokeToolPreference::Xdotool => {
                 if is_tool_available("xdotool").await {
@@ -235,6 +306,19 @@ pub async fn create_keystroke(
                     })
                 }
             }
+            KeystrokeToolPreference::Kdotool => {
+                if is_tool_available("kdotool").await {
+                    Ok((
+                        Box::new(KdotoolKeystroke::new()) as Box<dyn Keystroke>,
+                        KeystrokeTool::Kdotool,
+                    ))
+                } else {
+                    Err(KeystrokeError::BackendUnavailable {
+                        tool: "kdotool".to_string(),
+                        reason: "command not found on PATH".to_string(),
+                    })
+                }
+            }
         }
     }
 }
@@ -261,6 +345,10 @@ fn create_specific_tool(
             Box::new(XdotoolKeystroke::new()) as Box<dyn Keystroke>,
             KeystrokeTool::Xdotool,
         )),
+        KeystrokeTool::Kdotool => Ok((
+            Box::new(KdotoolKeystroke::new()) as Box<dyn Keystroke>,
+            KeystrokeTool::Kdotool,
+        )),
     }
 }
 
@@ -274,6 +362,15 @@ mod tests {
         assert_eq!(KeystrokeTool::Ydotool.to_string(), "ydotool");
         assert_eq!(KeystrokeTool::Wtype.to_string(), "wtype");
         assert_eq!(KeystrokeTool::Xdotool.to_string(), "xdotool");
+        assert_eq!(KeystrokeTool::Kdotool.to_string(), "kdotool");
+    }
+
+    #[tokio::test]
+    async fn created_adapter_reports_the_resolved_tool() {
+        let (keystroke, tool) = create_keystroke(KeystrokeToolPreference::Enigo)
+            .await
+            .unwrap();
+        assert_eq!(keystroke.backend(), Some(tool.as_str()));
     }
 
     #[test]
@@ -285,6 +382,7 @@ mod tests {
             assert_eq!(KeystrokeToolPreference::Ydotool.to_string(), "ydotool");
             assert_eq!(KeystrokeToolPreference::Xdotool.to_string(), "xdotool");
             assert_eq!(KeystrokeToolPreference::Wtype.to_string(), "wtype");
+            assert_eq!(KeystrokeToolPreference::Kdotool.to_string(), "kdotool");
         }
     }
 
@@ -316,6 +414,10 @@ mod tests {
                 "wtype".parse::<KeystrokeToolPreference>().unwrap(),
                 KeystrokeToolPreference::Wtype
             );
+            assert_eq!(
+                "kdotool".parse::<KeystrokeToolPreference>().unwrap(),
+                KeystrokeToolPreference::Kdotool
+            );
         }
     }
 
@@ -325,6 +427,25 @@ mod tests {
         assert_eq!(err.value, "invalid");
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn ydotool_readiness_distinguishes_missing_binary_from_stopped_daemon() {
+        assert!(ydotool_readiness(true, true).is_ok());
+
+        let err = ydotool_readiness(true, false).unwrap_err();
+        assert!(
+            matches!(err, KeystrokeError::DaemonNotRunning { ref daemon, .. } if daemon == "ydotoold")
+        );
+        assert!(err.to_string().contains("start ydotoold"), "{err}");
+
+        for socket_found in [true, false] {
+            let err = ydotool_readiness(false, socket_found).unwrap_err();
+            assert!(
+                matches!(err, KeystrokeError::BackendUnavailable { ref tool, .. } if tool == "ydotool")
+            );
+        }
+    }
+
     #[test]
     fn keystroke_tool_preference_default() {
         assert_eq!(
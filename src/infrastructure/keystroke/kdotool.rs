@@ -0,0 +1,63 @@
+//! Kdotool keystroke adapter for KDE Plasma (KWin) on Wayland
+
+use std::process::Stdio;
+
+use async_trait::async_trait;
+use tokio::process::Command;
+
+use crate::application::ports::{Keystroke, KeystrokeError};
+
+/// Kdotool keystroke adapter for KWin Wayland sessions
+///
+/// Uses kdotool, which drives KWin through its scripting interface and so
+/// avoids the virtual-keyboard gaps wtype and ydotool have under KWin.
+pub struct KdotoolKeystroke;
+
+impl KdotoolKeystroke {
+    /// Create a new kdotool keystroke adapter
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for KdotoolKeystroke {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Keystroke for KdotoolKeystroke {
+    async fn type_text(&self, text: &str) -> Result<(), KeystrokeError> {
+        let status = Command::new("kdotool")
+            .args(["type", "--", text])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    KeystrokeError::BackendUnavailable {
+                        tool: "kdotool".to_string(),
+                        reason: "command not found; install kdotool for KDE Wayland support"
+                            .to_string(),
+                    }
+                } else {
+                    KeystrokeError::TypeFailed {
+                        tool: "kdotool".to_string(),
+                        reason: e.to_string(),
+                    }
+                }
+            })?;
+
+        if !status.success() {
+            return Err(KeystrokeError::TypeFailed {
+                tool: "kdotool".to_string(),
+                reason: format!("exited with status: {}", status),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@
 
 mod enigo;
 mod factory;
+mod kdotool;
 mod noop;
 mod wtype;
 mod xdotool;
@@ -15,6 +16,7 @@ pub use factory::{
     create_keystroke, detect_keystroke_tool, KeystrokeTool, KeystrokeToolPreference,
     ParseKeystrokeToolError,
 };
+pub use kdotool::KdotoolKeystroke;
 pub use noop::NoOpKeystroke;
 pub use wtype::WtypeKeystroke;
 pub use xdotool::XdotoolKeystroke;
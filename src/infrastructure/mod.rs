@@ -4,28 +4,38 @@
 //! external systems like the ChatGPT/OpenAI APIs, audio capture, clipboard, etc.
 
 pub mod audio_cue;
+pub mod audio_file;
 pub mod auth;
 pub mod clipboard;
 pub mod config;
+pub mod history;
 pub mod keystroke;
 pub mod notification;
+pub mod post_process;
 pub mod recording;
 pub mod smart_paste;
 pub mod transcription;
 pub mod util;
 
 // Re-export adapters
-pub use audio_cue::{create_audio_cue, NoOpAudioCue, RodioAudioCue};
+pub use audio_cue::{create_audio_cue, play_audio_bytes, NoOpAudioCue, RodioAudioCue};
+pub use audio_file::{load_audio_file, LoadAudioError};
 pub use auth::{OAuthError, OAuthStore, OAuthToken};
-pub use clipboard::{create_clipboard, ArboardClipboard, WaylandClipboard};
+pub use clipboard::{
+    create_clipboard, ArboardClipboard, FallbackClipboard, WaylandClipboard, XclipClipboard,
+};
 pub use config::XdgConfigStore;
+pub use history::JsonlHistoryStore;
 pub use keystroke::{
     create_keystroke, detect_keystroke_tool, EnigoKeystroke, KeystrokeTool,
     KeystrokeToolPreference, NoOpKeystroke, ParseKeystrokeToolError, YdotoolKeystroke,
 };
 pub use notification::{create_notifier, NotifyRustNotifier};
+pub use post_process::{create_post_processor, CommandPostProcessor};
 pub use recording::{create_recorder, CpalRecorder};
 pub use smart_paste::{create_smart_paste, NoOpSmartPaste};
 pub use transcription::{
-    create_transcriber, ChatGptOAuthTranscriber, OpenAiApiTranscriber, Transcriber,
+    create_http_client, create_model_lister, create_transcriber, CachingTranscriber,
+    ChatGptOAuthTranscriber, ModelInfo, OpenAiApiTranscriber, RawResponseSink, Transcriber,
+    TranscriberSetupError, TranscriptCache,
 };
@@ -4,6 +4,8 @@
 
 use async_trait::async_trait;
 
+#[cfg(all(unix, not(target_os = "macos")))]
+use crate::application::ports::{notifier::preview, NotificationAction};
 use crate::application::ports::{NotificationError, NotificationIcon, Notifier};
 
 /// Cross-platform notifier using notify-rust
@@ -62,8 +64,62 @@ impl Notifier for NotifyRustNotifier {
         .await
         .map_err(|e| NotificationError::SendFailed(format!("Task join error: {}", e)))?
     }
+
+    /// On Linux the notification gets a "Copy" action. Waiting for the
+    /// click blocks until the notification closes, so it happens on a
+    /// detached thread rather than a blocking task: a pending notification
+    /// must not hold up runtime shutdown.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    async fn notify_result(
+        &self,
+        title: &str,
+        text: &str,
+        on_copy: Option<NotificationAction>,
+    ) -> Result<(), NotificationError> {
+        let Some(on_copy) = on_copy else {
+            return self
+                .notify(title, &preview(text), NotificationIcon::Success)
+                .await;
+        };
+
+        let mut notification = notify_rust::Notification::new();
+        notification
+            .appname(&self.app_name)
+            .summary(title)
+            .body(&preview(text))
+            .icon(NotificationIcon::Success.icon_name())
+            .action(COPY_ACTION, "Copy");
+
+        let runtime = tokio::runtime::Handle::current();
+        let (shown_tx, shown_rx) = tokio::sync::oneshot::channel();
+        std::thread::spawn(move || {
+            let handle = match notification.show() {
+                Ok(handle) => {
+                    let _ = shown_tx.send(Ok(()));
+                    handle
+                }
+                Err(e) => {
+                    let _ = shown_tx.send(Err(NotificationError::SendFailed(e.to_string())));
+                    return;
+                }
+            };
+            handle.wait_for_action(|action| {
+                if action == COPY_ACTION {
+                    runtime.block_on(on_copy());
+                }
+            });
+        });
+
+        shown_rx.await.map_err(|_| {
+            NotificationError::SendFailed("Notification thread exited early".to_string())
+        })?
+    }
 }
 
+/// Action identifier for the "Copy" button.
+#[cfg(all(unix, not(target_os = "macos")))]
+const COPY_ACTION: &str = "copy";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,155 @@
+//! Post-processing adapter that pipes transcripts through a shell command
+
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::application::ports::{PostProcessError, PostProcessor};
+
+/// How long a post-command may run before the raw transcript is used.
+pub const POST_COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Runs a shell command with the transcript on stdin and takes its stdout
+/// as the new text.
+pub struct CommandPostProcessor {
+    command: String,
+    timeout: Duration,
+}
+
+impl CommandPostProcessor {
+    /// Post-processor running `command` through the platform shell.
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            timeout: POST_COMMAND_TIMEOUT,
+        }
+    }
+
+    /// Builder: give up on the command after `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn shell(&self) -> Command {
+        #[cfg(target_os = "windows")]
+        {
+            let mut cmd = Command::new("cmd");
+            cmd.arg("/C").arg(&self.command);
+            cmd
+        }
+        #[cfg(not(target_os = "windows"))]
+        {
+            let mut cmd = Command::new("sh");
+            cmd.arg("-c").arg(&self.command);
+            cmd
+        }
+    }
+
+    async fn run(&self, text: &str) -> Result<String, PostProcessError> {
+        let mut child = self
+            .shell()
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| PostProcessError::SpawnFailed(e.to_string()))?;
+
+        // Feed stdin while collecting the output, so a command that writes
+        // before it has read everything can't deadlock on a full pipe.
+        let mut stdin = child.stdin.take();
+        let write = async {
+            if let Some(ref mut stdin) = stdin {
+                match stdin.write_all(text.as_bytes()).await {
+                    // A command that ignores its input may exit first.
+                    Err(e) if e.kind() != std::io::ErrorKind::BrokenPipe => return Err(e),
+                    _ => {}
+                }
+            }
+            drop(stdin);
+            Ok(())
+        };
+        let (written, output) = tokio::join!(write, child.wait_with_output());
+        let output = output.map_err(|e| PostProcessError::Failed(e.to_string()))?;
+        written.map_err(|e| PostProcessError::Failed(e.to_string()))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stderr = stderr.trim();
+            return Err(PostProcessError::Failed(if stderr.is_empty() {
+                output.status.to_string()
+            } else {
+                format!("{}: {stderr}", output.status)
+            }));
+        }
+
+        let stdout = String::from_utf8(output.stdout)
+            .map_err(|_| PostProcessError::Failed("output is not valid UTF-8".to_string()))?;
+        let text = stdout.trim_end_matches(['\r', '\n']);
+        if text.trim().is_empty() {
+            return Err(PostProcessError::EmptyOutput);
+        }
+        Ok(text.to_string())
+    }
+}
+
+#[async_trait]
+impl PostProcessor for CommandPostProcessor {
+    async fn process(&self, text: &str) -> Result<String, PostProcessError> {
+        tokio::time::timeout(self.timeout, self.run(text))
+            .await
+            .map_err(|_| PostProcessError::TimedOut(self.timeout.as_secs()))?
+    }
+}
+
+/// Create the post-processor for `command` (`--post-command`), if one is set.
+pub fn create_post_processor(command: Option<&str>) -> Option<Arc<dyn PostProcessor>> {
+    command.map(|cmd| Arc::new(CommandPostProcessor::new(cmd)) as Arc<dyn PostProcessor>)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::application::post_process;
+
+    #[tokio::test]
+    async fn output_replaces_the_text() {
+        let processor = CommandPostProcessor::new("tr a-z A-Z");
+        assert_eq!(
+            processor.process("hello world").await.unwrap(),
+            "HELLO WORLD"
+        );
+    }
+
+    #[tokio::test]
+    async fn non_zero_exit_fails_with_stderr() {
+        let processor = CommandPostProcessor::new("echo broken >&2; exit 3");
+        let err = processor.process("hello").await.unwrap_err();
+        assert!(matches!(err, PostProcessError::Failed(ref m) if m.contains("broken")));
+    }
+
+    #[tokio::test]
+    async fn slow_command_times_out() {
+        let processor =
+            CommandPostProcessor::new("sleep 5").with_timeout(Duration::from_millis(100));
+        let err = processor.process("hello").await.unwrap_err();
+        assert!(matches!(err, PostProcessError::TimedOut(_)));
+    }
+
+    #[tokio::test]
+    async fn failure_falls_back_to_the_transcript() {
+        let upper = create_post_processor(Some("cat | tr a-z A-Z"));
+        assert_eq!(post_process(upper.as_ref(), "hi".into(), None).await, "HI");
+
+        let broken = create_post_processor(Some("exit 1"));
+        assert_eq!(post_process(broken.as_ref(), "hi".into(), None).await, "hi");
+
+        let silent = create_post_processor(Some("cat >/dev/null"));
+        assert_eq!(post_process(silent.as_ref(), "hi".into(), None).await, "hi");
+    }
+}
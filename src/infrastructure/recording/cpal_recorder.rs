@@ -10,7 +10,7 @@
 //! atomics for state plus `tokio::sync::oneshot` for explicit start/stop
 //! handshakes (no `sleep(50ms)` timing hacks).
 
-use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex as StdMutex};
 use std::time::Instant;
 
@@ -22,26 +22,48 @@ use tokio::sync::oneshot;
 use tokio::time::{interval, Duration as TokioDuration};
 
 use super::flac_encoder::{encode_to_flac, TARGET_SAMPLE_RATE};
+use super::mic_lock::{acquire_microphone_lock, MicrophoneLock};
 use crate::application::ports::{
     AudioRecorder, ProgressCallback, RecordingError, UnboundedRecorder,
 };
-use crate::domain::recording::Duration;
+use crate::domain::recording::{clipped_ratio, peak_dbfs, Duration, SampleFormatPreference};
 use crate::domain::transcription::{AudioData, AudioMimeType};
 
 /// Audio recorder using cpal.
 pub struct CpalRecorder {
     /// Recorded samples (mono, i16, at device sample rate).
     audio_buffer: Arc<StdMutex<Vec<i16>>>,
-    /// Device sample rate (may differ from the 16 kHz target).
+    /// Device sample rate (may differ from `target_rate`).
     device_sample_rate: Arc<AtomicU32>,
+    /// Rate (Hz) the capture is resampled to before encoding.
+    target_rate: u32,
+    /// Sample format to open the device in when it offers a choice.
+    sample_format: SampleFormatPreference,
     /// `true` while a recording session is active.
     is_recording: Arc<AtomicBool>,
     /// Session start (ms since epoch), populated by `UnboundedRecorder::start`.
     start_time_ms: Arc<AtomicU64>,
     /// Elapsed time in milliseconds.
     elapsed_ms: Arc<AtomicU64>,
+    /// Level of the latest input callback (`f32` bits, `0.0..=1.0`).
+    level: Arc<AtomicU32>,
+    /// Input device name (`audio_device`); `None` uses the host default.
+    device: Option<String>,
+    /// Most samples an unbounded session may buffer (`usize::MAX` = no cap).
+    sample_cap: Arc<AtomicUsize>,
+    /// Set once the session hit `sample_cap` and started dropping audio.
+    truncated: Arc<AtomicBool>,
+    /// First error the cpal stream reported (device unplugged, driver
+    /// failure); turns the session's result into [`RecordingError::DeviceLost`].
+    stream_error: ErrorSlot,
+    /// Cross-process microphone lock, held while an unbounded session's
+    /// stream is open.
+    mic_lock: Arc<StdMutex<Option<MicrophoneLock>>>,
 }
 
+/// Shared slot the stream's error callback writes into.
+type ErrorSlot = Arc<StdMutex<Option<String>>>;
+
 /// Result of opening the cpal stream: the live stream object plus the
 /// observed device parameters the caller needs to encode the audio later.
 ///
@@ -60,62 +82,104 @@ impl CpalRecorder {
         Self {
             audio_buffer: Arc::new(StdMutex::new(Vec::new())),
             device_sample_rate: Arc::new(AtomicU32::new(0)),
+            target_rate: TARGET_SAMPLE_RATE,
+            sample_format: SampleFormatPreference::default(),
             is_recording: Arc::new(AtomicBool::new(false)),
             start_time_ms: Arc::new(AtomicU64::new(0)),
             elapsed_ms: Arc::new(AtomicU64::new(0)),
+            level: Arc::new(AtomicU32::new(0)),
+            device: None,
+            sample_cap: Arc::new(AtomicUsize::new(usize::MAX)),
+            truncated: Arc::new(AtomicBool::new(false)),
+            stream_error: Arc::new(StdMutex::new(None)),
+            mic_lock: Arc::new(StdMutex::new(None)),
         }
     }
 
-    /// Get the default input device.
-    fn get_input_device() -> Result<cpal::Device, RecordingError> {
-        let host = cpal::default_host();
-        host.default_input_device()
-            .ok_or(RecordingError::NoAudioDevice)
+    /// Fail with the stream error recorded this session, if any, discarding
+    /// the partial buffer.
+    fn take_stream_error(&self) -> Result<(), RecordingError> {
+        match self.stream_error.lock().unwrap().take() {
+            Some(message) => {
+                self.audio_buffer.lock().unwrap().clear();
+                Err(RecordingError::DeviceLost(message))
+            }
+            None => Ok(()),
+        }
     }
 
-    /// Find a suitable input configuration: prefer mono, prefer configs that
-    /// include the 16 kHz target sample rate, only accept I16 or F32.
-    fn get_input_config(
-        device: &cpal::Device,
-    ) -> Result<(StreamConfig, SampleFormat), RecordingError> {
-        let supported_configs = device
-            .supported_input_configs()
-            .map_err(|e| RecordingError::StartFailed(format!("Failed to get configs: {}", e)))?;
+    /// Record from the input device called `device` instead of the default.
+    pub fn with_device(mut self, device: Option<String>) -> Self {
+        self.device = device;
+        self
+    }
 
-        let mut best_config: Option<cpal::SupportedStreamConfigRange> = None;
+    /// Resample recordings to `rate` Hz instead of the 16 kHz default.
+    pub fn with_sample_rate(mut self, rate: u32) -> Self {
+        self.target_rate = rate;
+        self
+    }
 
-        for config in supported_configs {
-            if config.sample_format() != SampleFormat::I16
-                && config.sample_format() != SampleFormat::F32
-            {
-                continue;
-            }
+    /// Capture in `format` when the device supports it.
+    pub fn with_sample_format(mut self, format: SampleFormatPreference) -> Self {
+        self.sample_format = format;
+        self
+    }
 
-            let includes_target = config.min_sample_rate().0 <= TARGET_SAMPLE_RATE
-                && config.max_sample_rate().0 >= TARGET_SAMPLE_RATE;
+    /// Get the input device named `name`, or the default one.
+    ///
+    /// On Linux cpal opens devices through ALSA, so `name` can be a
+    /// hardware device (`hw:0`) or a sound-server plugin (`pulse`,
+    /// `pipewire`, `jack`).
+    fn get_input_device(name: Option<&str>) -> Result<cpal::Device, RecordingError> {
+        let host = cpal::default_host();
+        let Some(name) = name else {
+            return host
+                .default_input_device()
+                .ok_or(RecordingError::NoAudioDevice);
+        };
 
-            let is_better = match &best_config {
-                None => true,
-                Some(current) => {
-                    let fewer_channels = config.channels() < current.channels();
-                    let better_rate =
-                        includes_target && current.min_sample_rate().0 > TARGET_SAMPLE_RATE;
-                    fewer_channels || better_rate
-                }
-            };
-            if is_better {
-                best_config = Some(config);
-            }
+        let devices: Vec<cpal::Device> = host
+            .input_devices()
+            .map_err(|e| RecordingError::StartFailed(format!("Failed to list devices: {}", e)))?
+            .collect();
+        let names: Vec<String> = devices
+            .iter()
+            .map(|d| d.name().unwrap_or_default())
+            .collect();
+        match match_device_name(&names, name) {
+            Some(index) => Ok(devices.into_iter().nth(index).expect("index from names")),
+            None => Err(RecordingError::DeviceNotFound {
+                name: name.to_string(),
+                available: if names.is_empty() {
+                    "none".to_string()
+                } else {
+                    names.join(", ")
+                },
+            }),
         }
+    }
 
-        let config_range = best_config.ok_or(RecordingError::StartFailed(
-            "No suitable config found".into(),
-        ))?;
+    /// Find a suitable input configuration for `device`; see
+    /// [`select_input_config`].
+    fn get_input_config(
+        device: &cpal::Device,
+        target_rate: u32,
+        preference: SampleFormatPreference,
+    ) -> Result<(StreamConfig, SampleFormat), RecordingError> {
+        let supported_configs: Vec<_> = device
+            .supported_input_configs()
+            .map_err(|e| RecordingError::StartFailed(format!("Failed to get configs: {}", e)))?
+            .collect();
 
-        let sample_rate = if config_range.min_sample_rate().0 <= TARGET_SAMPLE_RATE
-            && config_range.max_sample_rate().0 >= TARGET_SAMPLE_RATE
+        let config_range = select_input_config(supported_configs, target_rate, preference).ok_or(
+            RecordingError::StartFailed("No suitable config found".into()),
+        )?;
+
+        let sample_rate = if config_range.min_sample_rate().0 <= target_rate
+            && config_range.max_sample_rate().0 >= target_rate
         {
-            SampleRate(TARGET_SAMPLE_RATE)
+            SampleRate(target_rate)
         } else {
             config_range.min_sample_rate()
         };
@@ -136,12 +200,20 @@ impl CpalRecorder {
     /// Centralises what used to live in two near-identical match blocks in
     /// `record` and `start`. The sink is invoked from the cpal audio
     /// callback thread and must be cheap.
-    fn build_input_stream<F>(samples_sink: F) -> Result<StreamHandle, RecordingError>
+    ///
+    /// Stream errors are logged and the first one is kept in `error_slot`.
+    fn build_input_stream<F>(
+        device_name: Option<&str>,
+        target_rate: u32,
+        sample_format: SampleFormatPreference,
+        error_slot: ErrorSlot,
+        samples_sink: F,
+    ) -> Result<StreamHandle, RecordingError>
     where
         F: Fn(&[i16]) + Send + Sync + 'static,
     {
-        let device = Self::get_input_device()?;
-        let (config, sample_format) = Self::get_input_config(&device)?;
+        let device = Self::get_input_device(device_name)?;
+        let (config, sample_format) = Self::get_input_config(&device, target_rate, sample_format)?;
         let sample_rate = config.sample_rate.0;
         let channels = config.channels;
 
@@ -161,23 +233,24 @@ impl CpalRecorder {
                             let mono = stereo_to_mono(data, channels);
                             sink(&mono);
                         },
-                        |err| eprintln!("Audio stream error: {}", err),
+                        stream_error_callback(Arc::clone(&error_slot)),
                         None,
                     )
                     .map_err(|e| RecordingError::StartFailed(e.to_string()))?
             }
             SampleFormat::F32 => {
                 let sink = Arc::clone(&sink);
+                // Reused across callbacks so the audio thread doesn't
+                // allocate once the buffer has grown to the callback size.
+                let mut mono = Vec::new();
                 device
                     .build_input_stream(
                         &config,
                         move |data: &[f32], _: &cpal::InputCallbackInfo| {
-                            let i16_data: Vec<i16> =
-                                data.iter().map(|&s| (s * 32767.0) as i16).collect();
-                            let mono = stereo_to_mono(&i16_data, channels);
+                            f32_stereo_to_mono_i16(data, channels, &mut mono);
                             sink(&mono);
                         },
-                        |err| eprintln!("Audio stream error: {}", err),
+                        stream_error_callback(Arc::clone(&error_slot)),
                         None,
                     )
                     .map_err(|e| RecordingError::StartFailed(e.to_string()))?
@@ -200,58 +273,194 @@ impl CpalRecorder {
         })
     }
 
-    /// Resample audio from device rate to 16 kHz if needed.
-    fn resample_to_16k(samples: &[i16], source_rate: u32) -> Result<Vec<i16>, RecordingError> {
-        if source_rate == TARGET_SAMPLE_RATE {
+    /// Resample audio from the device rate to `target_rate` if needed.
+    fn resample(
+        samples: &[i16],
+        source_rate: u32,
+        target_rate: u32,
+    ) -> Result<Vec<i16>, RecordingError> {
+        if source_rate == target_rate {
             return Ok(samples.to_vec());
         }
 
         let samples_f32: Vec<f32> = samples.iter().map(|&s| s as f32 / 32768.0).collect();
-        let ratio = TARGET_SAMPLE_RATE as f64 / source_rate as f64;
-        let output_len = (samples_f32.len() as f64 * ratio).ceil() as usize;
-
-        let mut resampler = FftFixedIn::<f32>::new(
-            source_rate as usize,
-            TARGET_SAMPLE_RATE as usize,
-            1024,
-            2,
-            1,
-        )
-        .map_err(|e| RecordingError::RecordingFailed(format!("Resampler init failed: {}", e)))?;
-
-        let mut output = Vec::with_capacity(output_len);
+        let output_len = resampled_len(samples.len(), source_rate, target_rate);
+
+        let mut resampler =
+            FftFixedIn::<f32>::new(source_rate as usize, target_rate as usize, 1024, 2, 1)
+                .map_err(|e| {
+                    RecordingError::RecordingFailed(format!("Resampler init failed: {}", e))
+                })?;
+
+        // The resampler's output lags its input by `delay` frames. Keep
+        // feeding it (zero-padded past the end of the input) until the last
+        // real frame has come out, then drop the lead-in and the padding.
+        let delay = resampler.output_delay();
+        let mut output: Vec<f32> = Vec::with_capacity(delay + output_len);
         let mut input_pos = 0;
-        while input_pos < samples_f32.len() {
-            let frames_needed = resampler.input_frames_next();
-            let end_pos = (input_pos + frames_needed).min(samples_f32.len());
-            let chunk: Vec<Vec<f32>> = vec![samples_f32[input_pos..end_pos].to_vec()];
-            let chunk = if chunk[0].len() < frames_needed {
-                let mut padded = chunk[0].clone();
-                padded.resize(frames_needed, 0.0);
-                vec![padded]
-            } else {
-                chunk
-            };
-            let resampled = resampler.process(&chunk, None).map_err(|e| {
+        while output.len() < delay + output_len {
+            let mut chunk = vec![0.0; resampler.input_frames_next()];
+            let end_pos = (input_pos + chunk.len()).min(samples_f32.len());
+            chunk[..end_pos - input_pos].copy_from_slice(&samples_f32[input_pos..end_pos]);
+            input_pos = end_pos;
+            let resampled = resampler.process(&[chunk], None).map_err(|e| {
                 RecordingError::RecordingFailed(format!("Resampling failed: {}", e))
             })?;
-            output.extend(resampled[0].iter().map(|&s| (s * 32767.0) as i16));
-            input_pos = end_pos;
+            output.extend_from_slice(&resampled[0]);
         }
-        output.truncate(output_len);
-        Ok(output)
+        Ok(output[delay..delay + output_len]
+            .iter()
+            .map(|&s| (s * 32767.0) as i16)
+            .collect())
     }
 
     /// Encode PCM samples to FLAC (lossless).
-    fn encode_audio(samples: &[i16], sample_rate: u32) -> Result<AudioData, RecordingError> {
-        let resampled = Self::resample_to_16k(samples, sample_rate)?;
-        let flac_data = encode_to_flac(&resampled)
+    fn encode_audio(
+        samples: &[i16],
+        sample_rate: u32,
+        target_rate: u32,
+    ) -> Result<AudioData, RecordingError> {
+        let started = Instant::now();
+        let resampled = Self::resample(samples, sample_rate, target_rate)?;
+        let flac_data = encode_to_flac(&resampled, target_rate)
             .map_err(|e| RecordingError::RecordingFailed(format!("FLAC encoding failed: {}", e)))?;
         if flac_data.is_empty() {
             return Err(RecordingError::ReadFailed("Encoded audio is empty".into()));
         }
-        Ok(AudioData::new(flac_data, AudioMimeType::Flac))
+        let peak = peak_dbfs(samples);
+        let clipped = clipped_ratio(samples);
+        tracing::debug!(
+            peak_dbfs = peak,
+            clipped_ratio = clipped,
+            bytes = flac_data.len(),
+            "encoded recording"
+        );
+        Ok(AudioData::new(flac_data, AudioMimeType::Flac)
+            .with_peak_dbfs(peak)
+            .with_clipped_ratio(clipped)
+            .with_duration_ms(captured_ms(samples.len(), sample_rate))
+            .with_encode_ms(started.elapsed().as_millis() as u64))
+    }
+}
+
+/// Length in milliseconds of `sample_count` mono samples at `sample_rate`.
+fn captured_ms(sample_count: usize, sample_rate: u32) -> u64 {
+    if sample_rate == 0 {
+        return 0;
+    }
+    sample_count as u64 * 1000 / sample_rate as u64
+}
+
+/// Number of `target_rate` frames `input_len` frames at `source_rate`
+/// resample to.
+fn resampled_len(input_len: usize, source_rate: u32, target_rate: u32) -> usize {
+    let target = target_rate as u64;
+    let source = source_rate as u64;
+    ((input_len as u64 * target + source / 2) / source) as usize
+}
+
+/// Error callback for an input stream: log every error, keep the first.
+fn stream_error_callback(slot: ErrorSlot) -> impl FnMut(cpal::StreamError) + Send + 'static {
+    move |err| {
+        tracing::error!("Audio stream error: {}", err);
+        if let Ok(mut slot) = slot.lock() {
+            slot.get_or_insert_with(|| err.to_string());
+        }
+    }
+}
+
+/// Headroom past an unbounded session's limit before audio is dropped, so a
+/// caller that stops on time never loses the tail.
+const LIMIT_GRACE_MS: u64 = 1_000;
+
+/// Samples at `sample_rate` that fit in `limit` plus [`LIMIT_GRACE_MS`].
+fn sample_cap(limit: Duration, sample_rate: u32) -> usize {
+    let ms = limit.as_millis().saturating_add(LIMIT_GRACE_MS);
+    (ms.saturating_mul(sample_rate as u64) / 1000) as usize
+}
+
+/// Append as much of `samples` as fits under `cap`; `false` if any were
+/// dropped.
+fn append_capped(buffer: &mut Vec<i16>, samples: &[i16], cap: usize) -> bool {
+    let room = cap.saturating_sub(buffer.len());
+    let take = samples.len().min(room);
+    buffer.extend_from_slice(&samples[..take]);
+    take == samples.len()
+}
+
+/// Signal level below which the meter reads zero.
+const LEVEL_FLOOR_DB: f32 = -60.0;
+
+/// Map the RMS of `samples` onto `0.0..=1.0` on a dB scale, so quiet speech
+/// still moves the meter.
+fn level_from_samples(samples: &[i16]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum_sq: f64 = samples.iter().map(|&s| (s as f64).powi(2)).sum();
+    let rms = (sum_sq / samples.len() as f64).sqrt() / i16::MAX as f64;
+    if rms <= 0.0 {
+        return 0.0;
+    }
+    let db = 20.0 * rms.log10() as f32;
+    ((db - LEVEL_FLOOR_DB) / -LEVEL_FLOOR_DB).clamp(0.0, 1.0)
+}
+
+/// Pick the input configuration to open: only I16 or F32 ranges qualify,
+/// fewer channels win, then ranges that include `target_rate`.
+///
+/// A pinned `preference` narrows the choice to ranges in that format when
+/// the device has any; otherwise (and for `Auto`) every qualifying range
+/// competes.
+fn select_input_config(
+    configs: Vec<cpal::SupportedStreamConfigRange>,
+    target_rate: u32,
+    preference: SampleFormatPreference,
+) -> Option<cpal::SupportedStreamConfigRange> {
+    let mut candidates: Vec<_> = configs
+        .into_iter()
+        .filter(|c| matches!(c.sample_format(), SampleFormat::I16 | SampleFormat::F32))
+        .collect();
+    let preferred = match preference {
+        SampleFormatPreference::Auto => None,
+        SampleFormatPreference::I16 => Some(SampleFormat::I16),
+        SampleFormatPreference::F32 => Some(SampleFormat::F32),
+    };
+    if let Some(format) = preferred {
+        if candidates.iter().any(|c| c.sample_format() == format) {
+            candidates.retain(|c| c.sample_format() == format);
+        }
     }
+
+    let mut best_config: Option<cpal::SupportedStreamConfigRange> = None;
+    for config in candidates {
+        let includes_target =
+            config.min_sample_rate().0 <= target_rate && config.max_sample_rate().0 >= target_rate;
+
+        let is_better = match &best_config {
+            None => true,
+            Some(current) => {
+                let fewer_channels = config.channels() < current.channels();
+                let better_rate = includes_target && current.min_sample_rate().0 > target_rate;
+                fewer_channels || better_rate
+            }
+        };
+        if is_better {
+            best_config = Some(config);
+        }
+    }
+    best_config
+}
+
+/// Index of the device `wanted` in `names`: an exact name wins, otherwise
+/// the first name containing it, ignoring case.
+fn match_device_name(names: &[String], wanted: &str) -> Option<usize> {
+    names.iter().position(|n| n == wanted).or_else(|| {
+        let wanted = wanted.to_lowercase();
+        names
+            .iter()
+            .position(|n| n.to_lowercase().contains(&wanted))
+    })
 }
 
 /// Mix multi-channel samples down to mono. Public to expose for tests.
@@ -259,13 +468,31 @@ fn stereo_to_mono(samples: &[i16], channels: u16) -> Vec<i16> {
     if channels == 1 {
         return samples.to_vec();
     }
-    samples
-        .chunks(channels as usize)
-        .map(|chunk| {
-            let sum: i32 = chunk.iter().map(|&s| s as i32).sum();
-            (sum / channels as i32) as i16
-        })
-        .collect()
+    let mut mono = Vec::with_capacity(samples.len() / channels as usize + 1);
+    downmix_into(samples, channels, |s| s, &mut mono);
+    mono
+}
+
+/// Convert f32 samples to i16 and mix them down to mono in one pass,
+/// replacing the contents of `out`.
+fn f32_stereo_to_mono_i16(samples: &[f32], channels: u16, out: &mut Vec<i16>) {
+    downmix_into(samples, channels, |s| (s * 32767.0) as i16, out);
+}
+
+/// Average each frame of `channels` samples (each converted by `to_i16`)
+/// into `out`, which is cleared first.
+fn downmix_into<T: Copy>(
+    samples: &[T],
+    channels: u16,
+    to_i16: impl Fn(T) -> i16,
+    out: &mut Vec<i16>,
+) {
+    let channels = channels.max(1);
+    out.clear();
+    out.extend(samples.chunks(channels as usize).map(|frame| {
+        let sum: i32 = frame.iter().map(|&s| i32::from(to_i16(s))).sum();
+        (sum / i32::from(channels)) as i16
+    }));
 }
 
 impl Default for CpalRecorder {
@@ -280,35 +507,61 @@ impl AudioRecorder for CpalRecorder {
         &self,
         duration: Duration,
         on_progress: Option<ProgressCallback>,
+        stop: Arc<AtomicBool>,
     ) -> Result<AudioData, RecordingError> {
         let duration_ms = duration.as_millis();
+        // Held only while capturing, not while encoding.
+        let mic_lock = acquire_microphone_lock()?;
 
         // Clear buffer.
         self.audio_buffer.lock().unwrap().clear();
+        self.stream_error.lock().unwrap().take();
         self.is_recording.store(true, Ordering::SeqCst);
 
         let audio_buffer = Arc::clone(&self.audio_buffer);
         let device_sample_rate = Arc::clone(&self.device_sample_rate);
         let is_recording = Arc::clone(&self.is_recording);
+        let device = self.device.clone();
+        let target_rate = self.target_rate;
+        let sample_format = self.sample_format;
+        let stream_error = Arc::clone(&self.stream_error);
 
         // Run cpal on a blocking task because cpal::Stream is not Send.
         let record_handle = tokio::task::spawn_blocking(move || {
             let audio_buffer_for_sink = Arc::clone(&audio_buffer);
             let is_recording_for_sink = Arc::clone(&is_recording);
 
-            let handle = CpalRecorder::build_input_stream(move |samples: &[i16]| {
-                if is_recording_for_sink.load(Ordering::SeqCst) {
-                    if let Ok(mut buffer) = audio_buffer_for_sink.lock() {
-                        buffer.extend_from_slice(samples);
+            let handle = CpalRecorder::build_input_stream(
+                device.as_deref(),
+                target_rate,
+                sample_format,
+                Arc::clone(&stream_error),
+                move |samples: &[i16]| {
+                    if is_recording_for_sink.load(Ordering::SeqCst) {
+                        if let Ok(mut buffer) = audio_buffer_for_sink.lock() {
+                            buffer.extend_from_slice(samples);
+                        }
                     }
-                }
-            })?;
+                },
+            )?;
 
             device_sample_rate.store(handle.sample_rate, Ordering::SeqCst);
 
             // Block this thread for the recording duration. We're already
-            // inside `spawn_blocking`, so the runtime is not blocked.
-            std::thread::sleep(std::time::Duration::from_millis(duration_ms));
+            // inside `spawn_blocking`, so the runtime is not blocked. Sleep
+            // in slices so `cancel` (which clears the flag), the caller's
+            // stop flag, or a stream error ends it early.
+            let end = Instant::now() + std::time::Duration::from_millis(duration_ms);
+            while is_recording.load(Ordering::SeqCst)
+                && !stop.load(Ordering::SeqCst)
+                && stream_error.lock().map_or(true, |e| e.is_none())
+            {
+                let left = end.saturating_duration_since(Instant::now());
+                if left.is_zero() {
+                    break;
+                }
+                std::thread::sleep(left.min(std::time::Duration::from_millis(50)));
+            }
 
             is_recording.store(false, Ordering::SeqCst);
             drop(handle.stream);
@@ -336,7 +589,10 @@ impl AudioRecorder for CpalRecorder {
 
         let sample_rate = record_handle
             .await
-            .map_err(|e| RecordingError::RecordingFailed(format!("Task join error: {}", e)))??;
+            .map_err(|e| RecordingError::RecordingFailed(format!("Task join error: {}", e)))?;
+        drop(mic_lock);
+        let sample_rate = sample_rate?;
+        self.take_stream_error()?;
 
         let samples = std::mem::take(&mut *self.audio_buffer.lock().unwrap());
         if samples.is_empty() {
@@ -345,7 +601,8 @@ impl AudioRecorder for CpalRecorder {
             ));
         }
 
-        tokio::task::spawn_blocking(move || Self::encode_audio(&samples, sample_rate))
+        let target_rate = self.target_rate;
+        tokio::task::spawn_blocking(move || Self::encode_audio(&samples, sample_rate, target_rate))
             .await
             .map_err(|e| RecordingError::RecordingFailed(format!("Encode task error: {}", e)))?
     }
@@ -353,14 +610,18 @@ impl AudioRecorder for CpalRecorder {
 
 #[async_trait]
 impl UnboundedRecorder for CpalRecorder {
-    async fn start(&self) -> Result<(), RecordingError> {
+    async fn start(&self, limit: Option<Duration>) -> Result<(), RecordingError> {
         if self.is_recording.load(Ordering::SeqCst) {
             return Err(RecordingError::StartFailed(
                 "Recording already in progress".to_string(),
             ));
         }
+        *self.mic_lock.lock().unwrap() = acquire_microphone_lock()?;
 
         self.audio_buffer.lock().unwrap().clear();
+        self.sample_cap.store(usize::MAX, Ordering::SeqCst);
+        self.truncated.store(false, Ordering::SeqCst);
+        self.stream_error.lock().unwrap().take();
         self.is_recording.store(true, Ordering::SeqCst);
 
         let now = std::time::SystemTime::now()
@@ -374,6 +635,13 @@ impl UnboundedRecorder for CpalRecorder {
         let is_recording = Arc::clone(&self.is_recording);
         let elapsed_ms = Arc::clone(&self.elapsed_ms);
         let start_time_ms = Arc::clone(&self.start_time_ms);
+        let level = Arc::clone(&self.level);
+        let device = self.device.clone();
+        let target_rate = self.target_rate;
+        let sample_format = self.sample_format;
+        let cap = Arc::clone(&self.sample_cap);
+        let truncated = Arc::clone(&self.truncated);
+        let stream_error = Arc::clone(&self.stream_error);
 
         // Oneshot: the background thread reports whether the stream started.
         // Replaces the previous `tokio::time::sleep(50ms)` race.
@@ -383,13 +651,27 @@ impl UnboundedRecorder for CpalRecorder {
             let audio_buffer_for_sink = Arc::clone(&audio_buffer);
             let is_recording_for_sink = Arc::clone(&is_recording);
 
-            let handle = match CpalRecorder::build_input_stream(move |samples: &[i16]| {
-                if is_recording_for_sink.load(Ordering::SeqCst) {
-                    if let Ok(mut buffer) = audio_buffer_for_sink.lock() {
-                        buffer.extend_from_slice(samples);
+            let level_for_sink = Arc::clone(&level);
+            let cap_for_sink = Arc::clone(&cap);
+
+            let handle = match CpalRecorder::build_input_stream(
+                device.as_deref(),
+                target_rate,
+                sample_format,
+                stream_error,
+                move |samples: &[i16]| {
+                    if is_recording_for_sink.load(Ordering::SeqCst) {
+                        level_for_sink
+                            .store(level_from_samples(samples).to_bits(), Ordering::Relaxed);
+                        if let Ok(mut buffer) = audio_buffer_for_sink.lock() {
+                            let cap = cap_for_sink.load(Ordering::Relaxed);
+                            if !append_capped(&mut buffer, samples, cap) {
+                                truncated.store(true, Ordering::Relaxed);
+                            }
+                        }
                     }
-                }
-            }) {
+                },
+            ) {
                 Ok(h) => h,
                 Err(e) => {
                     is_recording.store(false, Ordering::SeqCst);
@@ -399,6 +681,9 @@ impl UnboundedRecorder for CpalRecorder {
             };
 
             device_sample_rate.store(handle.sample_rate, Ordering::SeqCst);
+            if let Some(limit) = limit {
+                cap.store(sample_cap(limit, handle.sample_rate), Ordering::SeqCst);
+            }
             let _ = ready_tx.send(Ok(handle.sample_rate));
 
             // Spin until stop/cancel flips the atomic; the stream lives in
@@ -415,16 +700,21 @@ impl UnboundedRecorder for CpalRecorder {
             }
 
             drop(handle.stream);
+            level.store(0, Ordering::Relaxed);
         });
 
         // Wait for the worker to either succeed or fail. No timing hack.
-        match ready_rx.await {
+        let started = match ready_rx.await {
             Ok(Ok(_)) => Ok(()),
             Ok(Err(e)) => Err(e),
             Err(_) => Err(RecordingError::StartFailed(
                 "Recording thread terminated before signalling ready".into(),
             )),
+        };
+        if started.is_err() {
+            self.mic_lock.lock().unwrap().take();
         }
+        started
     }
 
     async fn stop(&self) -> Result<AudioData, RecordingError> {
@@ -443,6 +733,8 @@ impl UnboundedRecorder for CpalRecorder {
         // second oneshot here without a redesign of the worker loop; the
         // 100ms ceiling is a worst case, not a correctness requirement.)
         tokio::time::sleep(TokioDuration::from_millis(120)).await;
+        self.mic_lock.lock().unwrap().take();
+        self.take_stream_error()?;
 
         let sample_rate = self.device_sample_rate.load(Ordering::SeqCst);
         if sample_rate == 0 {
@@ -456,15 +748,19 @@ impl UnboundedRecorder for CpalRecorder {
             ));
         }
 
-        tokio::task::spawn_blocking(move || Self::encode_audio(&samples, sample_rate))
+        let truncated = self.truncated.load(Ordering::SeqCst);
+        let target_rate = self.target_rate;
+        tokio::task::spawn_blocking(move || Self::encode_audio(&samples, sample_rate, target_rate))
             .await
             .map_err(|e| RecordingError::RecordingFailed(format!("Encode task error: {}", e)))?
+            .map(|audio| audio.with_truncated(truncated))
     }
 
     async fn cancel(&self) -> Result<(), RecordingError> {
         self.is_recording.store(false, Ordering::SeqCst);
         // Same rationale as `stop`: let the worker thread observe the flag.
         tokio::time::sleep(TokioDuration::from_millis(120)).await;
+        self.mic_lock.lock().unwrap().take();
         self.audio_buffer.lock().unwrap().clear();
         self.elapsed_ms.store(0, Ordering::SeqCst);
         Ok(())
@@ -477,12 +773,76 @@ impl UnboundedRecorder for CpalRecorder {
     fn elapsed_ms(&self) -> u64 {
         self.elapsed_ms.load(Ordering::SeqCst)
     }
+
+    fn has_failed(&self) -> bool {
+        self.stream_error.lock().is_ok_and(|e| e.is_some())
+    }
+
+    fn level(&self) -> Option<f32> {
+        self.is_recording()
+            .then(|| f32::from_bits(self.level.load(Ordering::Relaxed)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn range(
+        channels: u16,
+        min: u32,
+        max: u32,
+        format: SampleFormat,
+    ) -> cpal::SupportedStreamConfigRange {
+        cpal::SupportedStreamConfigRange::new(
+            channels,
+            SampleRate(min),
+            SampleRate(max),
+            cpal::SupportedBufferSize::Unknown,
+            format,
+        )
+    }
+
+    fn device_configs() -> Vec<cpal::SupportedStreamConfigRange> {
+        vec![
+            range(2, 44_100, 48_000, SampleFormat::I16),
+            range(1, 8_000, 48_000, SampleFormat::F32),
+            range(2, 8_000, 48_000, SampleFormat::I16),
+            range(1, 8_000, 48_000, SampleFormat::U8),
+        ]
+    }
+
+    #[test]
+    fn select_input_config_auto_keeps_the_heuristic() {
+        let best =
+            select_input_config(device_configs(), 16_000, SampleFormatPreference::Auto).unwrap();
+        assert_eq!(best.channels(), 1);
+        assert_eq!(best.sample_format(), SampleFormat::F32);
+    }
+
+    #[test]
+    fn select_input_config_prefers_the_pinned_format() {
+        let best =
+            select_input_config(device_configs(), 16_000, SampleFormatPreference::I16).unwrap();
+        assert_eq!(best.sample_format(), SampleFormat::I16);
+        // Among the i16 ranges the one covering 16 kHz still wins.
+        assert_eq!(best.min_sample_rate(), SampleRate(8_000));
+
+        let best =
+            select_input_config(device_configs(), 16_000, SampleFormatPreference::F32).unwrap();
+        assert_eq!(best.sample_format(), SampleFormat::F32);
+    }
+
+    #[test]
+    fn select_input_config_falls_back_when_format_is_missing() {
+        let configs = vec![range(2, 8_000, 48_000, SampleFormat::I16)];
+        let best = select_input_config(configs, 16_000, SampleFormatPreference::F32).unwrap();
+        assert_eq!(best.sample_format(), SampleFormat::I16);
+
+        let unusable = vec![range(1, 8_000, 48_000, SampleFormat::U8)];
+        assert!(select_input_config(unusable, 16_000, SampleFormatPreference::Auto).is_none());
+    }
+
     #[test]
     fn stereo_to_mono_single_channel() {
         let mono = vec![100i16, 200, 300];
@@ -497,10 +857,142 @@ mod tests {
         assert_eq!(result, vec![150, 350]);
     }
 
+    #[test]
+    fn f32_downmix_matches_convert_then_downmix() {
+        let samples: Vec<f32> = (0..960).map(|i| ((i as f32) * 0.37).sin() * 0.9).collect();
+        let mut out = vec![7i16; 3];
+        for channels in [1u16, 2, 6] {
+            let two_step: Vec<i16> = samples.iter().map(|&s| (s * 32767.0) as i16).collect();
+            let expected = stereo_to_mono(&two_step, channels);
+
+            f32_stereo_to_mono_i16(&samples, channels, &mut out);
+            assert_eq!(out, expected, "channels = {channels}");
+        }
+    }
+
+    #[test]
+    fn device_name_prefers_exact_then_substring() {
+        let names: Vec<String> = ["default", "pulse", "pipewire", "hw:CARD=USB,DEV=0"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(match_device_name(&names, "pipewire"), Some(2));
+        assert_eq!(match_device_name(&names, "pulse"), Some(1));
+        assert_eq!(match_device_name(&names, "usb"), Some(3));
+        assert_eq!(match_device_name(&names, "jack"), None);
+    }
+
+    #[test]
+    fn resample_48k_ramp_keeps_length_and_alignment() {
+        let ramp: Vec<i16> = (0..48_000).map(|i| (i / 3 - 8_000) as i16).collect();
+        let out = CpalRecorder::resample(&ramp, 48_000, 16_000).unwrap();
+        assert!(out.len().abs_diff(16_000) <= 1, "{}", out.len());
+        // No latency lead-in: output frame k lines up with input frame 3k.
+        for k in [4_000, 8_000, 12_000] {
+            let diff = (out[k] as i32 - ramp[3 * k] as i32).abs();
+            assert!(diff < 50, "frame {k}: {} vs {}", out[k], ramp[3 * k]);
+        }
+    }
+
+    #[test]
+    fn resample_odd_lengths_round_to_nearest_frame() {
+        assert_eq!(resampled_len(0, 48_000, 16_000), 0);
+        assert_eq!(resampled_len(44_100, 44_100, 16_000), 16_000);
+        assert_eq!(resampled_len(4, 48_000, 16_000), 1);
+        let out = CpalRecorder::resample(&[1_000; 1_000], 44_100, 16_000).unwrap();
+        assert_eq!(out.len(), resampled_len(1_000, 44_100, 16_000));
+    }
+
+    #[test]
+    fn resampled_len_follows_the_target_rate() {
+        assert_eq!(resampled_len(44_100, 44_100, 48_000), 48_000);
+        assert_eq!(resampled_len(48_000, 48_000, 24_000), 24_000);
+        assert_eq!(resampled_len(16_000, 16_000, 8_000), 8_000);
+        let out = CpalRecorder::resample(&[1_000; 1_000], 44_100, 24_000).unwrap();
+        assert_eq!(out.len(), resampled_len(1_000, 44_100, 24_000));
+    }
+
+    #[test]
+    fn sample_cap_covers_limit_plus_grace() {
+        assert_eq!(sample_cap(Duration::from_secs(60), 16_000), 61 * 16_000);
+        assert_eq!(sample_cap(Duration::from_secs(1), 48_000), 96_000);
+    }
+
+    #[test]
+    fn append_capped_stops_at_cap() {
+        let mut buffer = Vec::new();
+        assert!(append_capped(&mut buffer, &[1, 2, 3], 5));
+        assert!(!append_capped(&mut buffer, &[4, 5, 6], 5));
+        assert_eq!(buffer, [1, 2, 3, 4, 5]);
+        assert!(!append_capped(&mut buffer, &[7], 5));
+        assert_eq!(buffer.len(), 5);
+    }
+
+    #[test]
+    fn stream_error_callback_keeps_first_error() {
+        let slot: ErrorSlot = Arc::new(StdMutex::new(None));
+        let mut callback = stream_error_callback(Arc::clone(&slot));
+        callback(cpal::StreamError::DeviceNotAvailable);
+        callback(cpal::StreamError::BackendSpecific {
+            err: cpal::BackendSpecificError {
+                description: "later".into(),
+            },
+        });
+        let first = slot.lock().unwrap().clone().unwrap();
+        assert!(!first.contains("later"), "{first}");
+    }
+
+    #[tokio::test]
+    async fn stop_reports_stream_error_instead_of_partial_audio() {
+        let recorder = CpalRecorder::new();
+        recorder.is_recording.store(true, Ordering::SeqCst);
+        recorder.device_sample_rate.store(16_000, Ordering::SeqCst);
+        recorder.audio_buffer.lock().unwrap().extend([100i16; 160]);
+        *recorder.stream_error.lock().unwrap() = Some("device unplugged".into());
+        assert!(recorder.has_failed());
+
+        let err = recorder.stop().await.unwrap_err();
+        assert!(matches!(err, RecordingError::DeviceLost(ref m) if m == "device unplugged"));
+        assert!(recorder.audio_buffer.lock().unwrap().is_empty());
+        assert!(!recorder.has_failed());
+    }
+
+    #[test]
+    fn encoded_audio_carries_captured_duration() {
+        // 1.5 s at 48 kHz, resampled to 16 kHz before encoding.
+        let samples = vec![1_000i16; 72_000];
+        let audio = CpalRecorder::encode_audio(&samples, 48_000, 16_000).unwrap();
+        assert_eq!(audio.duration_ms(), Some(1_500));
+        assert!(audio.encode_ms().is_some());
+        assert_eq!(captured_ms(16_000, 16_000), 1_000);
+        assert_eq!(captured_ms(100, 0), 0);
+    }
+
+    #[test]
+    fn wideband_target_skips_resampling_a_matching_device() {
+        let samples = vec![1_000i16; 48_000];
+        let audio = CpalRecorder::encode_audio(&samples, 48_000, 48_000).unwrap();
+        let flac = audio.data();
+        let packed = u64::from_be_bytes(flac[18..26].try_into().unwrap());
+        assert_eq!(packed >> 44, 48_000);
+        assert_eq!(packed & ((1 << 36) - 1), 48_000);
+        assert_eq!(audio.duration_ms(), Some(1_000));
+    }
+
     #[test]
     fn recorder_default_state() {
         let recorder = CpalRecorder::new();
         assert!(!recorder.is_recording());
         assert_eq!(recorder.elapsed_ms(), 0);
+        assert_eq!(recorder.level(), None);
+    }
+
+    #[test]
+    fn level_from_samples_spans_floor_to_full_scale() {
+        assert_eq!(level_from_samples(&[]), 0.0);
+        assert_eq!(level_from_samples(&[0; 64]), 0.0);
+        assert_eq!(level_from_samples(&[i16::MAX; 64]), 1.0);
+        // ~-20 dBFS sits two thirds of the way up a 60 dB scale.
+        let level = level_from_samples(&[3277; 64]);
+        assert!((level - 2.0 / 3.0).abs() < 0.01, "{level}");
     }
 }
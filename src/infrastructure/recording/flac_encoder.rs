@@ -4,7 +4,8 @@
 //! highest quality audio input while keeping uploads compact (~40% of WAV size).
 //!
 //! Settings:
-//! - 16kHz sample rate (speech-optimized)
+//! - 16kHz sample rate by default (speech-optimized); `sample_rate` can
+//!   raise it for models that take wideband audio
 //! - Mono channel
 //! - 16-bit samples
 
@@ -14,7 +15,7 @@ use flacenc::config;
 use flacenc::error::Verify;
 use flacenc::source::MemSource;
 
-/// Target sample rate for speech-optimized encoding
+/// Default target sample rate for speech-optimized encoding
 pub const TARGET_SAMPLE_RATE: u32 = 16000;
 
 /// Bits per sample (16-bit audio)
@@ -25,9 +26,12 @@ const CHANNELS: usize = 1;
 
 /// Encode PCM samples to FLAC format
 ///
-/// Input: mono i16 samples at 16kHz
+/// Input: mono i16 samples at `sample_rate` Hz
 /// Output: FLAC bytes
-pub fn encode_to_flac(pcm_samples: &[i16]) -> Result<Vec<u8>, EncodingError> {
+///
+/// The output depends only on the samples: FLAC streams carry no serial
+/// number or timestamp, so equal captures encode to equal bytes.
+pub fn encode_to_flac(pcm_samples: &[i16], sample_rate: u32) -> Result<Vec<u8>, EncodingError> {
     // Convert i16 to i32 (flacenc uses i32 internally)
     let samples_i32: Vec<i32> = pcm_samples.iter().map(|&s| s as i32).collect();
 
@@ -41,7 +45,7 @@ pub fn encode_to_flac(pcm_samples: &[i16]) -> Result<Vec<u8>, EncodingError> {
         &samples_i32,
         CHANNELS,
         BITS_PER_SAMPLE,
-        TARGET_SAMPLE_RATE as usize,
+        sample_rate as usize,
     );
 
     // Encode
@@ -78,7 +82,7 @@ mod tests {
     fn encode_silence() {
         // 1 second of silence at 16kHz
         let silence = vec![0i16; TARGET_SAMPLE_RATE as usize];
-        let result = encode_to_flac(&silence);
+        let result = encode_to_flac(&silence, TARGET_SAMPLE_RATE);
         assert!(result.is_ok());
 
         let flac_data = result.unwrap();
@@ -92,7 +96,7 @@ mod tests {
     fn encode_short_audio() {
         // 100ms of silence (1600 samples at 16kHz)
         let silence = vec![0i16; 1600];
-        let result = encode_to_flac(&silence);
+        let result = encode_to_flac(&silence, TARGET_SAMPLE_RATE);
         assert!(result.is_ok());
     }
 
@@ -106,7 +110,7 @@ mod tests {
             })
             .collect();
 
-        let result = encode_to_flac(&samples);
+        let result = encode_to_flac(&samples, TARGET_SAMPLE_RATE);
         assert!(result.is_ok());
 
         let flac_data = result.unwrap();
@@ -114,6 +118,42 @@ mod tests {
         assert!(flac_data.len() < samples.len() * 2); // Less than raw PCM size
     }
 
+    #[test]
+    fn encoding_is_byte_stable() {
+        let samples: Vec<i16> = (0..4800).map(|i| ((i * 37) % 2000 - 1000) as i16).collect();
+        let first = encode_to_flac(&samples, TARGET_SAMPLE_RATE).unwrap();
+        let second = encode_to_flac(&samples, TARGET_SAMPLE_RATE).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn streaminfo_counts_samples_of_a_partial_last_block() {
+        // Not a multiple of any block size, so the last block is short.
+        let samples = vec![100i16; 4096 * 2 + 123];
+        let flac = encode_to_flac(&samples, TARGET_SAMPLE_RATE).unwrap();
+
+        // "fLaC", a 4-byte block header, then STREAMINFO; bytes 10..18 of
+        // it pack sample rate (20 bits), channels, bit depth and the
+        // 36-bit total sample count.
+        let packed = u64::from_be_bytes(flac[18..26].try_into().unwrap());
+        assert_eq!(packed >> 44, TARGET_SAMPLE_RATE as u64);
+        assert_eq!(packed & ((1 << 36) - 1), samples.len() as u64);
+    }
+
+    #[test]
+    fn streaminfo_carries_a_wideband_sample_rate() {
+        // 1 second at 48 kHz.
+        let samples: Vec<i16> = (0..48_000)
+            .map(|i| ((i * 37) % 2000 - 1000) as i16)
+            .collect();
+        let flac = encode_to_flac(&samples, 48_000).unwrap();
+        assert_eq!(&flac[0..4], b"fLaC");
+
+        let packed = u64::from_be_bytes(flac[18..26].try_into().unwrap());
+        assert_eq!(packed >> 44, 48_000);
+        assert_eq!(packed & ((1 << 36) - 1), samples.len() as u64);
+    }
+
     #[test]
     fn target_sample_rate_is_16khz() {
         assert_eq!(TARGET_SAMPLE_RATE, 16000);
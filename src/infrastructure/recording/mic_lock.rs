@@ -0,0 +1,104 @@
+//! Cross-process lock on the microphone.
+//!
+//! Two SmartScribe processes capturing at once (two quick one-shots, or a
+//! one-shot next to a daemon session) garble both recordings. The recorder
+//! takes this advisory file lock for as long as its input stream is open,
+//! so a second process fails fast instead. The OS drops the lock when the
+//! holder exits, so a crashed recorder never leaves it stuck.
+
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+
+use crate::application::ports::RecordingError;
+
+/// Lock file shared by every SmartScribe process of this user.
+fn default_lock_path() -> PathBuf {
+    std::env::temp_dir().join("smart-scribe-mic.lock")
+}
+
+/// Held while recording; dropping it releases the microphone.
+#[derive(Debug)]
+pub struct MicrophoneLock {
+    _file: File,
+}
+
+/// Take the microphone lock, failing with [`RecordingError::MicrophoneBusy`]
+/// if another process holds it.
+///
+/// Returns `Ok(None)` when the lock file itself can't be used (read-only
+/// temp dir, filesystem without locking); the lock is a safety net, not a
+/// reason to refuse recording.
+pub fn acquire_microphone_lock() -> Result<Option<MicrophoneLock>, RecordingError> {
+    acquire_at(&default_lock_path())
+}
+
+fn acquire_at(path: &Path) -> Result<Option<MicrophoneLock>, RecordingError> {
+    // Not truncated on open: the holder's PID in it is what the busy
+    // message reports.
+    let Ok(mut file) = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+    else {
+        return Ok(None);
+    };
+
+    match file.try_lock() {
+        Ok(()) => {
+            let _ = file.set_len(0);
+            let _ = write!(file, "{}", std::process::id());
+            let _ = file.flush();
+            Ok(Some(MicrophoneLock { _file: file }))
+        }
+        Err(TryLockError::WouldBlock) => {
+            let holder = match holder_pid(&mut file) {
+                Some(pid) => format!("another SmartScribe process (PID: {pid})"),
+                None => "another SmartScribe process".to_string(),
+            };
+            Err(RecordingError::MicrophoneBusy(format!(
+                "{holder} is recording; try again when it finishes"
+            )))
+        }
+        Err(TryLockError::Error(_)) => Ok(None),
+    }
+}
+
+/// PID the current holder wrote into the lock file, if readable.
+fn holder_pid(file: &mut File) -> Option<u32> {
+    let mut contents = String::new();
+    file.rewind().ok()?;
+    file.read_to_string(&mut contents).ok()?;
+    contents.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_acquire_fails_until_the_first_is_released() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mic.lock");
+
+        let first = acquire_at(&path).unwrap().expect("lock file usable");
+        match acquire_at(&path) {
+            Err(RecordingError::MicrophoneBusy(message)) => {
+                assert!(message.contains("is recording"), "{message}");
+            }
+            other => panic!("expected MicrophoneBusy, got {other:?}"),
+        }
+
+        drop(first);
+        assert!(acquire_at(&path).unwrap().is_some());
+    }
+
+    #[test]
+    fn unusable_lock_file_does_not_block_recording() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing-dir").join("mic.lock");
+        assert!(acquire_at(&path).unwrap().is_none());
+    }
+}
@@ -5,11 +5,23 @@
 
 mod cpal_recorder;
 mod flac_encoder;
+mod mic_lock;
 
 pub use cpal_recorder::CpalRecorder;
 pub use flac_encoder::{encode_to_flac, TARGET_SAMPLE_RATE};
 
-/// Create the default recorder for the current platform
-pub fn create_recorder() -> CpalRecorder {
+use crate::domain::recording::SampleFormatPreference;
+
+/// Create the recorder for the current platform, capturing from `device`
+/// (or the default input device when `None`) in `sample_format` where
+/// possible and encoding at `sample_rate`
+pub fn create_recorder(
+    device: Option<String>,
+    sample_rate: u32,
+    sample_format: SampleFormatPreference,
+) -> CpalRecorder {
     CpalRecorder::new()
+        .with_device(device)
+        .with_sample_rate(sample_rate)
+        .with_sample_format(sample_format)
 }
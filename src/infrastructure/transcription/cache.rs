@@ -0,0 +1,274 @@
+//! On-disk transcript cache.
+//!
+//! [`CachingTranscriber`] wraps any [`Transcriber`] and short-circuits
+//! requests whose `(audio bytes, model, prompt, language)` tuple has been
+//! transcribed before. Entries are plain-text files named by the SHA-256 of
+//! that tuple under the user cache dir (`~/.cache/smart-scribe/transcripts`
+//! on Linux), so `smart-scribe cache clear` is just a directory sweep.
+//!
+//! Cache I/O is best-effort: a read or write failure falls through to the
+//! wrapped transcriber rather than failing the transcription.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+use crate::application::ports::{Transcriber, TranscriptDeltaCallback, TranscriptionError};
+use crate::domain::transcription::{AudioData, TimedTranscript};
+
+const ENTRY_EXTENSION: &str = "txt";
+
+/// Directory-backed store of transcripts keyed by content hash.
+#[derive(Debug, Clone)]
+pub struct TranscriptCache {
+    dir: PathBuf,
+}
+
+impl TranscriptCache {
+    /// Cache rooted at the platform cache dir.
+    pub fn new() -> io::Result<Self> {
+        let base = dirs::cache_dir()
+            .or_else(|| dirs::home_dir().map(|h| h.join(".cache")))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no cache directory"))?;
+        Ok(Self::with_dir(
+            base.join("smart-scribe").join("transcripts"),
+        ))
+    }
+
+    /// Cache rooted at an explicit directory (tests, custom layouts).
+    pub fn with_dir(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.{ENTRY_EXTENSION}"))
+    }
+
+    fn get(&self, key: &str) -> Option<String> {
+        fs::read_to_string(self.entry_path(key)).ok()
+    }
+
+    fn put(&self, key: &str, text: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        // Write-then-rename so a crash never leaves a truncated entry behind.
+        let tmp = self.dir.join(format!("{key}.tmp"));
+        fs::write(&tmp, text)?;
+        fs::rename(tmp, self.entry_path(key))
+    }
+
+    /// Delete every cached entry. Returns how many were removed.
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(e) => e,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) == Some(ENTRY_EXTENSION) {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
+/// Transparent caching wrapper around any [`Transcriber`].
+pub struct CachingTranscriber<T> {
+    inner: T,
+    cache: TranscriptCache,
+    /// Request parameters that change the result for the same audio.
+    model: String,
+    prompt: Option<String>,
+    language: Option<String>,
+}
+
+impl<T: Transcriber> CachingTranscriber<T> {
+    pub fn new(inner: T, cache: TranscriptCache, model: impl Into<String>) -> Self {
+        Self {
+            inner,
+            cache,
+            model: model.into(),
+            prompt: None,
+            language: None,
+        }
+    }
+
+    /// Builder: include the effective prompt in the cache key.
+    pub fn with_prompt(mut self, prompt: Option<String>) -> Self {
+        self.prompt = prompt;
+        self
+    }
+
+    /// Builder: include the language hint in the cache key.
+    pub fn with_language(mut self, language: Option<String>) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Unwrap the underlying transcriber, dropping the cache.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    fn key(&self, audio: &AudioData) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(audio.data());
+        // Separator + presence bytes keep ("ab", "c") / ("a", "bc") and
+        // None / Some("") distinct.
+        for part in [
+            Some(self.model.as_str()),
+            self.prompt.as_deref(),
+            self.language.as_deref(),
+        ] {
+            hasher.update([0u8]);
+            if let Some(p) = part {
+                hasher.update([1u8]);
+                hasher.update(p.as_bytes());
+            }
+        }
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    fn store(&self, key: &str, text: &str) {
+        // Best-effort; a full disk must not fail an otherwise good transcription.
+        let _ = self.cache.put(key, text);
+    }
+}
+
+#[async_trait]
+impl<T: Transcriber> Transcriber for CachingTranscriber<T> {
+    async fn transcribe(&self, audio: &AudioData) -> Result<String, TranscriptionError> {
+        let key = self.key(audio);
+        if let Some(text) = self.cache.get(&key) {
+            tracing::debug!(key = %key, "transcript cache hit");
+            return Ok(text);
+        }
+        let text = self.inner.transcribe(audio).await?;
+        self.store(&key, &text);
+        Ok(text)
+    }
+
+    async fn transcribe_stream(
+        &self,
+        audio: &AudioData,
+        on_delta: TranscriptDeltaCallback,
+    ) -> Result<String, TranscriptionError> {
+        let key = self.key(audio);
+        if let Some(text) = self.cache.get(&key) {
+            tracing::debug!(key = %key, "transcript cache hit");
+            on_delta(&text);
+            return Ok(text);
+        }
+        let text = self.inner.transcribe_stream(audio, on_delta).await?;
+        self.store(&key, &text);
+        Ok(text)
+    }
+
+    /// Timed requests bypass the cache, which only stores plain text.
+    async fn transcribe_timed(
+        &self,
+        audio: &AudioData,
+    ) -> Result<TimedTranscript, TranscriptionError> {
+        self.inner.transcribe_timed(audio).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use crate::domain::transcription::AudioMimeType;
+
+    struct CountingTranscriber(Arc<AtomicUsize>);
+
+    #[async_trait]
+    impl Transcriber for CountingTranscriber {
+        async fn transcribe(&self, _audio: &AudioData) -> Result<String, TranscriptionError> {
+            let n = self.0.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(format!("call {n}"))
+        }
+    }
+
+    fn audio() -> AudioData {
+        AudioData::new(vec![1, 2, 3, 4], AudioMimeType::Flac)
+    }
+
+    fn caching(
+        dir: &Path,
+        calls: &Arc<AtomicUsize>,
+        prompt: Option<&str>,
+    ) -> CachingTranscriber<CountingTranscriber> {
+        CachingTranscriber::new(
+            CountingTranscriber(Arc::clone(calls)),
+            TranscriptCache::with_dir(dir),
+            "gpt-4o-transcribe",
+        )
+        .with_prompt(prompt.map(str::to_string))
+    }
+
+    #[tokio::test]
+    async fn second_call_with_same_prompt_hits_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let t = caching(dir.path(), &calls, Some("Rust"));
+
+        assert_eq!(t.transcribe(&audio()).await.unwrap(), "call 1");
+        assert_eq!(t.transcribe(&audio()).await.unwrap(), "call 1");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn changing_prompt_misses_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let first = caching(dir.path(), &calls, Some("Rust"));
+        assert_eq!(first.transcribe(&audio()).await.unwrap(), "call 1");
+
+        let second = caching(dir.path(), &calls, Some("Go"));
+        assert_eq!(second.transcribe(&audio()).await.unwrap(), "call 2");
+
+        let none = caching(dir.path(), &calls, None);
+        assert_eq!(none.transcribe(&audio()).await.unwrap(), "call 3");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn clear_removes_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let t = caching(dir.path(), &calls, None);
+        t.transcribe(&audio()).await.unwrap();
+
+        let cache = TranscriptCache::with_dir(dir.path());
+        assert_eq!(cache.clear().unwrap(), 1);
+        assert_eq!(cache.clear().unwrap(), 0);
+
+        t.transcribe(&audio()).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn clear_on_missing_dir_is_noop() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = TranscriptCache::with_dir(dir.path().join("absent"));
+        assert_eq!(cache.clear().unwrap(), 0);
+    }
+}
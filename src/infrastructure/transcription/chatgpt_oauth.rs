@@ -14,7 +14,7 @@ use crate::application::ports::{Transcriber, TranscriptionError};
 use crate::domain::transcription::AudioData;
 use crate::infrastructure::auth::{refresh, OAuthStore, OAuthToken};
 
-use super::{parse_transcription_response, shared_client};
+use super::{parse_transcription_response, shared_client, RawResponseSink};
 
 const TRANSCRIBE_URL: &str = "https://chatgpt.com/backend-api/transcribe";
 const USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36";
@@ -34,18 +34,30 @@ pub struct ChatGptOAuthTranscriber {
     model: String,
     prompt: Option<String>,
     language: Option<String>,
+    raw_sink: Option<RawResponseSink>,
     cached: Mutex<Option<OAuthToken>>,
 }
 
 impl ChatGptOAuthTranscriber {
     pub fn new(store: OAuthStore, model: impl Into<String>) -> Self {
+        Self::with_client(store, model, shared_client())
+    }
+
+    /// Construct with an explicit HTTP client (e.g. one routed through a
+    /// proxy via [`build_proxied_client`](super::build_proxied_client)).
+    pub fn with_client(
+        store: OAuthStore,
+        model: impl Into<String>,
+        client: reqwest::Client,
+    ) -> Self {
         Self {
             store,
-            client: shared_client(),
+            client,
             device_id: Uuid::new_v4().to_string(),
             model: model.into(),
             prompt: None,
             language: None,
+            raw_sink: None,
             cached: Mutex::new(None),
         }
     }
@@ -65,6 +77,13 @@ impl ChatGptOAuthTranscriber {
         self
     }
 
+    /// Builder: pass each raw response body (access token redacted) to
+    /// `sink`.
+    pub fn with_raw_sink(mut self, sink: RawResponseSink) -> Self {
+        self.raw_sink = Some(sink);
+        self
+    }
+
     pub fn model(&self) -> &str {
         &self.model
     }
@@ -100,7 +119,8 @@ impl ChatGptOAuthTranscriber {
                 .ok_or(TranscriptionError::NotAuthenticated)?;
             // The From<OAuthError> impl in `auth/error.rs` collapses
             // InvalidGrant -> NotAuthenticated for us.
-            let fresh = refresh(&refresh_token).await?;
+            // Over `self.client`, so the refresh honours `proxy` too.
+            let fresh = refresh(&self.client, &refresh_token).await?;
             self.store
                 .save(&fresh)
                 .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
@@ -125,6 +145,7 @@ impl ChatGptOAuthTranscriber {
             self.prompt.as_deref(),
             self.language.as_deref(),
             audio,
+            self.raw_sink.as_ref(),
         )
         .await
     }
@@ -139,6 +160,7 @@ async fn send_transcribe(
     prompt: Option<&str>,
     language: Option<&str>,
     audio: &AudioData,
+    raw: Option<&RawResponseSink>,
 ) -> Result<String, TranscriptionError> {
     let mime_str = audio.mime_type().as_str();
     let extension = audio.mime_type().extension();
@@ -191,7 +213,7 @@ async fn send_transcribe(
         .await
         .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
 
-    parse_transcription_response(response).await
+    parse_transcription_response(response, raw, &token.access_token).await
 }
 
 #[async_trait]
@@ -204,9 +226,11 @@ impl Transcriber for ChatGptOAuthTranscriber {
                 self.invalidate_cache().await;
                 match self.do_transcribe(audio).await {
                     Ok(text) => Ok(text),
-                    Err(TranscriptionError::InvalidApiKey) => Err(TranscriptionError::ApiError(
-                        "OAuth token rejected. Run `smart-scribe login` again.".to_string(),
-                    )),
+                    Err(TranscriptionError::InvalidApiKey) => Err(TranscriptionError::ApiError {
+                        status: Some(401),
+                        message: "OAuth token rejected. Run `smart-scribe login` again."
+                            .to_string(),
+                    }),
                     Err(other) => Err(other),
                 }
             }
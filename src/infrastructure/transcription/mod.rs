@@ -5,19 +5,24 @@
 //! [`create_transcriber`] factory the CLI uses as its only entry point, and
 //! a shared response parser so both adapters speak the same error language.
 
+mod cache;
 mod chatgpt_oauth;
 mod openai_api;
+mod stream;
 
+pub use cache::{CachingTranscriber, TranscriptCache};
 pub use chatgpt_oauth::ChatGptOAuthTranscriber;
-pub use openai_api::OpenAiApiTranscriber;
+pub use openai_api::{ModelInfo, OpenAiApiTranscriber};
 
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 
 use async_trait::async_trait;
 
-use crate::application::ports::{Transcriber as TranscriberPort, TranscriptionError};
+use crate::application::ports::{
+    Transcriber as TranscriberPort, TranscriptDeltaCallback, TranscriptionError,
+};
 use crate::domain::config::{AppConfig, AuthMode};
-use crate::domain::transcription::AudioData;
+use crate::domain::transcription::{AudioData, TimedTranscript};
 use crate::infrastructure::auth::OAuthStore;
 
 /// Process-wide shared `reqwest::Client`.
@@ -37,16 +42,63 @@ pub(crate) fn shared_client() -> reqwest::Client {
     shared_client_cell().clone()
 }
 
-/// Parse a transcription HTTP response into either the trimmed transcript
-/// text or a typed [`TranscriptionError`].
+/// Build a dedicated client that routes every request through `proxy`.
 ///
-/// Both the OpenAI API and the ChatGPT OAuth endpoint return the same
-/// `{"text": "..."}` shape and map auth/quota the same way. Keeping the
-/// parsing logic here keeps the adapters short and means a status-code
-/// fix lands in one place.
-pub(crate) async fn parse_transcription_response(
+/// Proxy precedence, highest first:
+/// 1. The explicit `proxy` config key (this function).
+/// 2. `HTTPS_PROXY` / `HTTP_PROXY` for the matching scheme.
+/// 3. `ALL_PROXY` for everything else.
+///
+/// Levels 2 and 3 are reqwest's built-in env handling on the
+/// [`shared_client`]. `NO_PROXY` is honoured in all cases, so hosts listed
+/// there bypass even an explicit proxy.
+pub fn build_proxied_client(proxy: &str) -> Result<reqwest::Client, String> {
+    let proxy = reqwest::Proxy::all(proxy)
+        .map_err(|e| format!("Invalid proxy '{proxy}': {e}"))?
+        .no_proxy(reqwest::NoProxy::from_env());
+    reqwest::Client::builder()
+        .proxy(proxy)
+        .build()
+        .map_err(|e| format!("Could not build proxied HTTP client: {e}"))
+}
+
+/// HTTP client for OpenAI requests: one through `proxy` when it is set,
+/// otherwise the [`shared_client`].
+pub fn create_http_client(proxy: Option<&str>) -> Result<reqwest::Client, String> {
+    match proxy {
+        Some(proxy) => build_proxied_client(proxy),
+        None => Ok(shared_client()),
+    }
+}
+
+/// Error codes OpenAI uses when it refuses a request on content grounds.
+const SAFETY_ERROR_CODES: &[&str] = &["content_policy_violation", "content_filter"];
+
+/// If an error body (`{"error": {"code": ..., "message": ...}}`) reports a
+/// content-policy refusal, return the reason to show the user.
+fn safety_block_reason(body: &str) -> Option<String> {
+    let body: serde_json::Value = serde_json::from_str(body).ok()?;
+    let error = body.get("error")?;
+    let code = error.get("code")?.as_str()?;
+    if !SAFETY_ERROR_CODES.contains(&code) {
+        return None;
+    }
+    let reason = error
+        .get("message")
+        .and_then(|m| m.as_str())
+        .filter(|m| !m.trim().is_empty())
+        .unwrap_or(code);
+    Some(reason.to_string())
+}
+
+/// Map a non-success transcription response onto a typed
+/// [`TranscriptionError`], passing successful responses through untouched.
+///
+/// Split out of [`parse_transcription_response`] so the streaming path can
+/// reuse the status mapping before consuming the body as events.
+pub(crate) async fn check_transcription_status(
     response: reqwest::Response,
-) -> Result<String, TranscriptionError> {
+) -> Result<reqwest::Response, TranscriptionError> {
     let status = response.status();
     if status == reqwest::StatusCode::UNAUTHORIZED {
         return Err(TranscriptionError::InvalidApiKey);
@@ -59,15 +111,65 @@ pub(crate) async fn parse_transcription_response(
             .text()
             .await
             .unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(TranscriptionError::ApiError(format!(
-            "HTTP {status}: {error_text}"
-        )));
+        if let Some(reason) = safety_block_reason(&error_text) {
+            return Err(TranscriptionError::SafetyBlocked { reason });
+        }
+        return Err(TranscriptionError::ApiError {
+            status: Some(status.as_u16()),
+            message: format!("HTTP {status}: {error_text}"),
+        });
     }
 
-    let body: serde_json::Value = response
-        .json()
+    Ok(response)
+}
+
+/// Receives the body of every successful transcription response, already
+/// redacted, before it is parsed (`--raw`).
+pub type RawResponseSink = Arc<dyn Fn(&str) + Send + Sync>;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Replace every occurrence of `secret` in `body`. Endpoints don't echo
+/// credentials today, but a raw dump ends up in terminals and bug reports.
+fn redact(body: &str, secret: &str) -> String {
+    if secret.is_empty() {
+        return body.to_string();
+    }
+    body.replace(secret, REDACTED)
+}
+
+/// Read a successful response as JSON, handing the redacted body text to
+/// `raw` first so the dump shows exactly what the server sent.
+pub(crate) async fn read_json_body(
+    response: reqwest::Response,
+    raw: Option<&RawResponseSink>,
+    secret: &str,
+) -> Result<serde_json::Value, TranscriptionError> {
+    let text = response
+        .text()
         .await
         .map_err(|e| TranscriptionError::ParseError(e.to_string()))?;
+    if let Some(sink) = raw {
+        sink(&redact(&text, secret));
+    }
+    serde_json::from_str(&text).map_err(|e| TranscriptionError::ParseError(e.to_string()))
+}
+
+/// Parse a transcription HTTP response into either the trimmed transcript
+/// text or a typed [`TranscriptionError`].
+///
+/// Both the OpenAI API and the ChatGPT OAuth endpoint return the same
+/// `{"text": "..."}` shape and map auth/quota the same way. Keeping the
+/// parsing logic here keeps the adapters short and means a status-code
+/// fix lands in one place. `raw` and `secret` are forwarded to
+/// [`read_json_body`].
+pub(crate) async fn parse_transcription_response(
+    response: reqwest::Response,
+    raw: Option<&RawResponseSink>,
+    secret: &str,
+) -> Result<String, TranscriptionError> {
+    let response = check_transcription_status(response).await?;
+    let body = read_json_body(response, raw, secret).await?;
 
     let text = body
         .get("text")
@@ -88,6 +190,21 @@ pub(crate) async fn parse_transcription_response(
 pub enum Transcriber {
     Oauth(ChatGptOAuthTranscriber),
     ApiKey(OpenAiApiTranscriber),
+    /// Either of the above behind the on-disk transcript cache.
+    Cached(Box<CachingTranscriber<Transcriber>>),
+}
+
+impl Transcriber {
+    /// Dump every raw response body to `sink` (`--raw`).
+    ///
+    /// Drops the transcript cache: a cache hit has no response to show.
+    pub fn with_raw_sink(self, sink: RawResponseSink) -> Self {
+        match self {
+            Self::Oauth(t) => Self::Oauth(t.with_raw_sink(sink)),
+            Self::ApiKey(t) => Self::ApiKey(t.with_raw_sink(sink)),
+            Self::Cached(t) => t.into_inner().with_raw_sink(sink),
+        }
+    }
 }
 
 #[async_trait]
@@ -96,41 +213,162 @@ impl TranscriberPort for Transcriber {
         match self {
             Self::Oauth(t) => t.transcribe(audio).await,
             Self::ApiKey(t) => t.transcribe(audio).await,
+            Self::Cached(t) => t.transcribe(audio).await,
+        }
+    }
+
+    async fn transcribe_stream(
+        &self,
+        audio: &AudioData,
+        on_delta: TranscriptDeltaCallback,
+    ) -> Result<String, TranscriptionError> {
+        match self {
+            Self::Oauth(t) => t.transcribe_stream(audio, on_delta).await,
+            Self::ApiKey(t) => t.transcribe_stream(audio, on_delta).await,
+            Self::Cached(t) => t.transcribe_stream(audio, on_delta).await,
+        }
+    }
+
+    async fn transcribe_timed(
+        &self,
+        audio: &AudioData,
+    ) -> Result<TimedTranscript, TranscriptionError> {
+        match self {
+            Self::Oauth(t) => t.transcribe_timed(audio).await,
+            Self::ApiKey(t) => t.transcribe_timed(audio).await,
+            Self::Cached(t) => t.transcribe_timed(audio).await,
         }
     }
 }
 
+/// Why [`create_transcriber`] could not build a transcriber.
+#[derive(Debug, thiserror::Error)]
+pub enum TranscriberSetupError {
+    /// `auth = "api_key"` without a key in the config or `OPENAI_API_KEY`.
+    #[error(
+        "Missing OpenAI API key. Set OPENAI_API_KEY or run \
+         'smart-scribe config set openai_api_key <key>'."
+    )]
+    MissingApiKey,
+
+    /// Anything else: proxy URL, token store, cache directory.
+    #[error("{0}")]
+    Other(String),
+}
+
+impl From<String> for TranscriberSetupError {
+    fn from(message: String) -> Self {
+        Self::Other(message)
+    }
+}
+
 /// Build the right transcriber for a validated [`AppConfig`].
 ///
 /// For OAuth we construct the transcriber even if no token is yet on disk —
 /// the missing-token error is surfaced at the first transcribe call so that
 /// `smart-scribe login` can still be used to populate it.
-pub fn create_transcriber(config: &AppConfig) -> Result<Transcriber, String> {
+pub fn create_transcriber(config: &AppConfig) -> Result<Transcriber, TranscriberSetupError> {
+    let transcriber = create_uncached_transcriber(config)?;
+    if !config.cache {
+        return Ok(transcriber);
+    }
+    let cache =
+        TranscriptCache::new().map_err(|e| format!("Could not locate transcript cache: {e}"))?;
+    Ok(Transcriber::Cached(Box::new(
+        CachingTranscriber::new(transcriber, cache, config.openai_transcribe_model.clone())
+            .with_prompt(config.transcribe_prompt_some().map(str::to_string))
+            .with_language(config.transcribe_language_some().map(str::to_string)),
+    )))
+}
+
+/// Build the API-key adapter for `models list`, honouring `openai_base_url`
+/// and `proxy`. The ChatGPT OAuth backend has no model listing.
+pub fn create_model_lister(
+    config: &AppConfig,
+) -> Result<OpenAiApiTranscriber, TranscriberSetupError> {
+    if config.auth != AuthMode::ApiKey {
+        return Err(TranscriberSetupError::Other(
+            "Listing models needs `auth = api_key`; the ChatGPT OAuth backend has no model listing"
+                .to_string(),
+        ));
+    }
+    let api_key = config
+        .openai_api_key
+        .as_ref()
+        .ok_or(TranscriberSetupError::MissingApiKey)?;
+    let client = create_http_client(config.proxy.as_deref())?;
+    Ok(
+        OpenAiApiTranscriber::with_client(api_key, config.openai_transcribe_model.clone(), client)
+            .with_base_url(config.openai_base_url.clone()),
+    )
+}
+
+fn create_uncached_transcriber(config: &AppConfig) -> Result<Transcriber, TranscriberSetupError> {
     let model = config.openai_transcribe_model.clone();
     let prompt = config.transcribe_prompt_some().map(str::to_string);
     let language = config.transcribe_language_some().map(str::to_string);
+    let client = create_http_client(config.proxy.as_deref())?;
 
     match config.auth {
         AuthMode::Oauth => {
             let store = OAuthStore::new()
                 .map_err(|e| format!("Could not initialize OAuth token store: {e}"))?;
             Ok(Transcriber::Oauth(
-                ChatGptOAuthTranscriber::new(store, model)
+                ChatGptOAuthTranscriber::with_client(store, model, client)
                     .with_prompt(prompt)
                     .with_language(language),
             ))
         }
         AuthMode::ApiKey => {
-            let api_key = config.openai_api_key.as_ref().ok_or_else(|| {
-                "Missing OpenAI API key. Set OPENAI_API_KEY or run \
-                 'smart-scribe config set openai_api_key <key>'."
-                    .to_string()
-            })?;
+            let api_key = config
+                .openai_api_key
+                .as_ref()
+                .ok_or(TranscriberSetupError::MissingApiKey)?;
             Ok(Transcriber::ApiKey(
-                OpenAiApiTranscriber::new(api_key, model)
+                OpenAiApiTranscriber::with_client(api_key, model, client)
+                    .with_base_url(config.openai_base_url.clone())
                     .with_prompt(prompt)
                     .with_language(language),
             ))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_proxied_client_accepts_http_and_socks() {
+        assert!(build_proxied_client("http://127.0.0.1:3128").is_ok());
+        assert!(build_proxied_client("socks5h://127.0.0.1:1080").is_ok());
+    }
+
+    #[test]
+    fn build_proxied_client_rejects_garbage() {
+        assert!(build_proxied_client("not a url").is_err());
+    }
+
+    #[test]
+    fn safety_block_reason_reads_policy_errors_only() {
+        let blocked = r#"{"error":{"code":"content_policy_violation","message":"Flagged"}}"#;
+        assert_eq!(safety_block_reason(blocked).as_deref(), Some("Flagged"));
+        let no_message = r#"{"error":{"code":"content_filter"}}"#;
+        assert_eq!(
+            safety_block_reason(no_message).as_deref(),
+            Some("content_filter")
+        );
+        let other = r#"{"error":{"code":"invalid_value","message":"Bad model"}}"#;
+        assert_eq!(safety_block_reason(other), None);
+        assert_eq!(safety_block_reason("Bad Gateway"), None);
+    }
+
+    #[test]
+    fn redact_masks_every_occurrence() {
+        assert_eq!(
+            redact(r#"{"key":"sk-1","echo":"sk-1"}"#, "sk-1"),
+            r#"{"key":"[REDACTED]","echo":"[REDACTED]"}"#
+        );
+        assert_eq!(redact("sk-1", ""), "sk-1");
+    }
+}
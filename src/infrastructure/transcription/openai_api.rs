@@ -4,30 +4,81 @@
 //! and the chosen Whisper model; expects `{"text": "..."}` back.
 
 use async_trait::async_trait;
+use serde::Serialize;
 
-use crate::application::ports::{Transcriber, TranscriptionError};
-use crate::domain::transcription::AudioData;
+use crate::application::ports::{Transcriber, TranscriptDeltaCallback, TranscriptionError};
+use crate::domain::config::DEFAULT_OPENAI_BASE_URL;
+use crate::domain::transcription::{AudioData, TimedTranscript, TranscriptSegment};
 
-use super::{parse_transcription_response, shared_client};
+use super::stream::TranscriptStreamParser;
+use super::{
+    check_transcription_status, parse_transcription_response, read_json_body, shared_client,
+    RawResponseSink,
+};
 
-const TRANSCRIBE_URL: &str = "https://api.openai.com/v1/audio/transcriptions";
+const TRANSCRIBE_PATH: &str = "/audio/transcriptions";
+const MODELS_PATH: &str = "/models";
+
+/// Models that reject `stream=true`. Streaming is a `gpt-4o-*-transcribe`
+/// feature; `whisper-1` answers with a 400.
+const NON_STREAMING_MODELS: &[&str] = &["whisper-1"];
+
+/// Models that return segment timing with `response_format=verbose_json`.
+/// The `gpt-4o-*-transcribe` models only support `json`/`text`.
+const SEGMENT_MODELS: &[&str] = &["whisper-1"];
+
+/// One entry from the `GET /models` listing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModelInfo {
+    pub id: String,
+    pub owned_by: Option<String>,
+    /// Whether the model is a speech-to-text model (`*transcribe*`,
+    /// `whisper-*`), i.e. a valid `openai_transcribe_model`.
+    pub transcription: bool,
+}
+
+impl ModelInfo {
+    fn new(id: impl Into<String>, owned_by: Option<String>) -> Self {
+        let id = id.into();
+        let transcription = id.contains("transcribe") || id.starts_with("whisper");
+        Self {
+            id,
+            owned_by,
+            transcription,
+        }
+    }
+}
 
 pub struct OpenAiApiTranscriber {
     api_key: String,
     model: String,
+    base_url: String,
     prompt: Option<String>,
     language: Option<String>,
     client: reqwest::Client,
+    raw_sink: Option<RawResponseSink>,
 }
 
 impl OpenAiApiTranscriber {
     pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        Self::with_client(api_key, model, shared_client())
+    }
+
+    /// Construct with an explicit HTTP client (e.g. one routed through a
+    /// proxy via [`build_proxied_client`](super::build_proxied_client)).
+    pub fn with_client(
+        api_key: impl Into<String>,
+        model: impl Into<String>,
+        client: reqwest::Client,
+    ) -> Self {
         Self {
             api_key: api_key.into(),
             model: model.into(),
+            base_url: DEFAULT_OPENAI_BASE_URL.to_string(),
             prompt: None,
             language: None,
-            client: shared_client(),
+            client,
+            raw_sink: None,
         }
     }
 
@@ -42,13 +93,100 @@ impl OpenAiApiTranscriber {
         self.language = language.filter(|s| !s.trim().is_empty());
         self
     }
-}
 
-#[async_trait]
-impl Transcriber for OpenAiApiTranscriber {
-    async fn transcribe(&self, audio: &AudioData) -> Result<String, TranscriptionError> {
+    /// Builder: override the API base URL (e.g. a proxy or LLM gateway).
+    ///
+    /// Expects an already-validated URL (see
+    /// [`normalize_base_url`](crate::domain::config::normalize_base_url));
+    /// a trailing slash is tolerated.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Builder: pass each raw response body (API key redacted) to `sink`.
+    /// Streaming requests fall back to a single response while set.
+    pub fn with_raw_sink(mut self, sink: RawResponseSink) -> Self {
+        self.raw_sink = Some(sink);
+        self
+    }
+
+    /// Full endpoint URL for the transcription request.
+    fn transcribe_url(&self) -> String {
+        format!("{}{TRANSCRIBE_PATH}", self.base_url)
+    }
+
+    /// Full endpoint URL for the model listing.
+    fn models_url(&self) -> String {
+        format!("{}{MODELS_PATH}", self.base_url)
+    }
+
+    /// List the models the key can use, sorted by id.
+    ///
+    /// Errors map like a transcription request: 401 is
+    /// [`TranscriptionError::InvalidApiKey`], transport failures are
+    /// [`TranscriptionError::RequestFailed`].
+    pub async fn list_models(&self) -> Result<Vec<ModelInfo>, TranscriptionError> {
+        let response = self
+            .client
+            .get(self.models_url())
+            .bearer_auth(&self.api_key)
+            .send()
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+        let response = check_transcription_status(response).await?;
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| TranscriptionError::ParseError(e.to_string()))?;
+        let data = body
+            .get("data")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| TranscriptionError::ParseError("missing `data` list".to_string()))?;
+
+        let mut models: Vec<ModelInfo> = data
+            .iter()
+            .filter_map(|m| {
+                let id = m.get("id")?.as_str()?;
+                let owned_by = m.get("owned_by").and_then(|v| v.as_str());
+                Some(ModelInfo::new(id, owned_by.map(str::to_string)))
+            })
+            .collect();
+        models.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(models)
+    }
+
+    /// Whether the configured model accepts `stream=true`.
+    fn supports_streaming(&self) -> bool {
+        self.raw_sink.is_none() && !NON_STREAMING_MODELS.contains(&self.model.as_str())
+    }
+
+    /// Whether the configured model can return segment timing.
+    fn supports_segments(&self) -> bool {
+        SEGMENT_MODELS.contains(&self.model.as_str())
+    }
+
+    /// Build the multipart body shared by the plain, streaming and timed
+    /// requests.
+    ///
+    /// The body is sent as-is, not gzipped: the audio travels as raw bytes
+    /// (no base64) in an already-compressed format, so `Content-Encoding:
+    /// gzip` would save next to nothing, and the endpoint doesn't document
+    /// compressed uploads.
+    fn build_form(
+        &self,
+        audio: &AudioData,
+        response_format: &str,
+    ) -> Result<reqwest::multipart::Form, TranscriptionError> {
         let mime_str = audio.mime_type().as_str();
         let extension = audio.mime_type().extension();
+        tracing::debug!(
+            url = %self.transcribe_url(),
+            model = %self.model,
+            bytes = audio.size_bytes(),
+            "building transcription request"
+        );
         let filename = format!("audio.{extension}");
 
         let file_part = reqwest::multipart::Part::bytes(audio.data().to_vec())
@@ -59,25 +197,123 @@ impl Transcriber for OpenAiApiTranscriber {
         let mut form = reqwest::multipart::Form::new()
             .part("file", file_part)
             .text("model", self.model.clone())
-            .text("response_format", "json");
+            .text("response_format", response_format.to_string());
         if let Some(prompt) = &self.prompt {
             form = form.text("prompt", prompt.clone());
         }
         if let Some(language) = &self.language {
             form = form.text("language", language.clone());
         }
+        Ok(form)
+    }
+
+    /// Attach the endpoint and credentials to a request.
+    ///
+    /// The key travels only in the `Authorization` header, never in the URL,
+    /// so it stays out of proxy access logs and error messages that echo the
+    /// request URL.
+    fn authorized_post(&self) -> reqwest::RequestBuilder {
+        self.client
+            .post(self.transcribe_url())
+            .bearer_auth(&self.api_key)
+    }
+}
+
+#[async_trait]
+impl Transcriber for OpenAiApiTranscriber {
+    async fn transcribe(&self, audio: &AudioData) -> Result<String, TranscriptionError> {
+        let form = self.build_form(audio, "json")?;
 
         let response = self
-            .client
-            .post(TRANSCRIBE_URL)
-            .header("Authorization", format!("Bearer {}", self.api_key))
+            .authorized_post()
             .multipart(form)
             .send()
             .await
             .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
 
-        parse_transcription_response(response).await
+        parse_transcription_response(response, self.raw_sink.as_ref(), &self.api_key).await
     }
+
+    async fn transcribe_stream(
+        &self,
+        audio: &AudioData,
+        on_delta: TranscriptDeltaCallback,
+    ) -> Result<String, TranscriptionError> {
+        if !self.supports_streaming() {
+            let text = self.transcribe(audio).await?;
+            on_delta(&text);
+            return Ok(text);
+        }
+
+        let form = self.build_form(audio, "json")?.text("stream", "true");
+
+        let response = self
+            .authorized_post()
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+        let mut response = check_transcription_status(response).await?;
+
+        let mut parser = TranscriptStreamParser::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?
+        {
+            parser.push(&chunk, on_delta.as_ref())?;
+        }
+        parser.finish(on_delta.as_ref())
+    }
+
+    async fn transcribe_timed(
+        &self,
+        audio: &AudioData,
+    ) -> Result<TimedTranscript, TranscriptionError> {
+        if !self.supports_segments() {
+            return Ok(TimedTranscript::untimed(self.transcribe(audio).await?));
+        }
+
+        let form = self
+            .build_form(audio, "verbose_json")?
+            .text("timestamp_granularities[]", "segment");
+        let response = self
+            .authorized_post()
+            .multipart(form)
+            .send()
+            .await
+            .map_err(|e| TranscriptionError::RequestFailed(e.to_string()))?;
+        let response = check_transcription_status(response).await?;
+        let body = read_json_body(response, self.raw_sink.as_ref(), &self.api_key).await?;
+        parse_verbose_json(&body)
+    }
+}
+
+/// Read `{"text": ..., "segments": [{"start": 1.5, "text": ...}]}` from a
+/// `verbose_json` response. A body without segments yields `segments: None`.
+fn parse_verbose_json(body: &serde_json::Value) -> Result<TimedTranscript, TranscriptionError> {
+    let text = body
+        .get("text")
+        .and_then(|v| v.as_str())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .ok_or(TranscriptionError::EmptyResponse)?;
+    let segments = body.get("segments").and_then(|v| v.as_array()).map(|list| {
+        list.iter()
+            .filter_map(|s| {
+                let start = s.get("start")?.as_f64()?;
+                let text = s.get("text")?.as_str()?;
+                Some(TranscriptSegment {
+                    start_ms: (start.max(0.0) * 1000.0).round() as u64,
+                    text: text.to_string(),
+                })
+            })
+            .collect::<Vec<_>>()
+    });
+    Ok(TimedTranscript {
+        text: text.to_string(),
+        segments: segments.filter(|s| !s.is_empty()),
+    })
 }
 
 #[cfg(test)]
@@ -91,6 +327,82 @@ mod tests {
         assert_eq!(t.model, "gpt-4o-transcribe");
         assert!(t.prompt.is_none());
         assert!(t.language.is_none());
+        assert_eq!(
+            t.transcribe_url(),
+            "https://api.openai.com/v1/audio/transcriptions"
+        );
+    }
+
+    #[test]
+    fn with_base_url_overrides_endpoint() {
+        let t =
+            OpenAiApiTranscriber::new("sk-test", "m").with_base_url("http://localhost:8080/v1/");
+        assert_eq!(
+            t.transcribe_url(),
+            "http://localhost:8080/v1/audio/transcriptions"
+        );
+        assert!(!t.transcribe_url().contains("sk-test"));
+    }
+
+    #[test]
+    fn api_key_sent_as_header_not_in_url() {
+        let t =
+            OpenAiApiTranscriber::new("sk-secret", "m").with_base_url("https://gw.example.com/v1");
+        let request = t.authorized_post().build().unwrap();
+        assert!(!request.url().as_str().contains("sk-secret"));
+        assert!(request.url().query().is_none());
+        assert_eq!(
+            request
+                .headers()
+                .get(reqwest::header::AUTHORIZATION)
+                .unwrap(),
+            "Bearer sk-secret"
+        );
+    }
+
+    #[test]
+    fn model_info_flags_transcription_models() {
+        assert!(ModelInfo::new("gpt-4o-transcribe", None).transcription);
+        assert!(ModelInfo::new("whisper-1", None).transcription);
+        assert!(!ModelInfo::new("gpt-4o", None).transcription);
+        assert_eq!(
+            OpenAiApiTranscriber::new("k", "m")
+                .with_base_url("http://localhost:8080/v1")
+                .models_url(),
+            "http://localhost:8080/v1/models"
+        );
+    }
+
+    #[test]
+    fn segments_only_for_whisper() {
+        assert!(OpenAiApiTranscriber::new("k", "whisper-1").supports_segments());
+        assert!(!OpenAiApiTranscriber::new("k", "gpt-4o-transcribe").supports_segments());
+    }
+
+    #[test]
+    fn parse_verbose_json_reads_segment_starts() {
+        let body = serde_json::json!({
+            "text": "Hello there. Next item.",
+            "segments": [
+                {"id": 0, "start": 0.0, "end": 2.1, "text": " Hello there."},
+                {"id": 1, "start": 65.04, "end": 67.0, "text": " Next item."},
+            ],
+        });
+        let timed = parse_verbose_json(&body).unwrap();
+        assert_eq!(timed.text, "Hello there. Next item.");
+        let segments = timed.segments.unwrap();
+        assert_eq!(segments[1].start_ms, 65_040);
+        assert_eq!(segments[1].text, " Next item.");
+
+        let plain = parse_verbose_json(&serde_json::json!({"text": "hi"})).unwrap();
+        assert!(plain.segments.is_none());
+        assert!(parse_verbose_json(&serde_json::json!({"text": " "})).is_err());
+    }
+
+    #[test]
+    fn streaming_disabled_for_whisper() {
+        assert!(OpenAiApiTranscriber::new("k", "gpt-4o-transcribe").supports_streaming());
+        assert!(!OpenAiApiTranscriber::new("k", "whisper-1").supports_streaming());
     }
 
     #[test]
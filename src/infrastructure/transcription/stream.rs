@@ -0,0 +1,161 @@
+//! Server-sent-events parser for streamed transcriptions.
+//!
+//! With `stream=true`, `/v1/audio/transcriptions` answers with an SSE body of
+//! `transcript.text.delta` events followed by a single
+//! `transcript.text.done` carrying the full text. The parser is fed raw body
+//! chunks (which may split lines or even UTF-8 sequences) and reports each
+//! delta as soon as its line is complete.
+
+use crate::application::ports::TranscriptionError;
+
+/// Incremental parser for the transcription event stream.
+#[derive(Debug, Default)]
+pub(crate) struct TranscriptStreamParser {
+    pending: Vec<u8>,
+    accumulated: String,
+    done: Option<String>,
+}
+
+impl TranscriptStreamParser {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed a body chunk, invoking `on_delta` for every complete delta event.
+    pub(crate) fn push(
+        &mut self,
+        chunk: &[u8],
+        on_delta: &dyn Fn(&str),
+    ) -> Result<(), TranscriptionError> {
+        self.pending.extend_from_slice(chunk);
+        while let Some(pos) = self.pending.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            self.handle_line(line.trim_end_matches(['\r', '\n']), on_delta)?;
+        }
+        Ok(())
+    }
+
+    /// Finish the stream and return the full transcript.
+    ///
+    /// Prefers the `done` event's text; falls back to the concatenated deltas
+    /// if the server closed the stream without one.
+    pub(crate) fn finish(mut self, on_delta: &dyn Fn(&str)) -> Result<String, TranscriptionError> {
+        if !self.pending.is_empty() {
+            let rest = std::mem::take(&mut self.pending);
+            let line = String::from_utf8_lossy(&rest);
+            self.handle_line(line.trim_end_matches('\r'), on_delta)?;
+        }
+        let text = self.done.unwrap_or(self.accumulated);
+        let trimmed = text.trim();
+        if trimmed.is_empty() {
+            return Err(TranscriptionError::EmptyResponse);
+        }
+        Ok(trimmed.to_string())
+    }
+
+    fn handle_line(
+        &mut self,
+        line: &str,
+        on_delta: &dyn Fn(&str),
+    ) -> Result<(), TranscriptionError> {
+        let Some(data) = line.strip_prefix("data:") else {
+            // Blank separators, `event:` and comment lines carry nothing we need.
+            return Ok(());
+        };
+        let data = data.trim_start();
+        if data.is_empty() || data == "[DONE]" {
+            return Ok(());
+        }
+
+        let event: serde_json::Value = serde_json::from_str(data)
+            .map_err(|e| TranscriptionError::ParseError(e.to_string()))?;
+        match event.get("type").and_then(|v| v.as_str()) {
+            Some("transcript.text.delta") => {
+                if let Some(delta) = event.get("delta").and_then(|v| v.as_str()) {
+                    if !delta.is_empty() {
+                        self.accumulated.push_str(delta);
+                        on_delta(delta);
+                    }
+                }
+            }
+            Some("transcript.text.done") => {
+                self.done = event
+                    .get("text")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string);
+            }
+            Some("error") => {
+                let message = event
+                    .pointer("/error/message")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("stream error");
+                return Err(TranscriptionError::ApiError {
+                    status: None,
+                    message: message.to_string(),
+                });
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::RefCell;
+
+    const BODY: &str = concat!(
+        "data: {\"type\":\"transcript.text.delta\",\"delta\":\"Hello\"}\n\n",
+        "data: {\"type\":\"transcript.text.delta\",\"delta\":\" wörld\"}\n\n",
+        "data: {\"type\":\"transcript.text.done\",\"text\":\"Hello wörld.\"}\n\n",
+    );
+
+    #[test]
+    fn reports_deltas_and_prefers_done_text() {
+        let seen = RefCell::new(Vec::new());
+        let on_delta = |d: &str| seen.borrow_mut().push(d.to_string());
+        let mut parser = TranscriptStreamParser::new();
+        // Feed byte-by-byte to exercise split lines and split UTF-8.
+        for b in BODY.as_bytes() {
+            parser.push(std::slice::from_ref(b), &on_delta).unwrap();
+        }
+        assert_eq!(parser.finish(&on_delta).unwrap(), "Hello wörld.");
+        assert_eq!(*seen.borrow(), vec!["Hello", " wörld"]);
+    }
+
+    #[test]
+    fn falls_back_to_accumulated_deltas() {
+        let mut parser = TranscriptStreamParser::new();
+        parser
+            .push(
+                b"data: {\"type\":\"transcript.text.delta\",\"delta\":\"partial\"}",
+                &|_| {},
+            )
+            .unwrap();
+        assert_eq!(parser.finish(&|_| {}).unwrap(), "partial");
+    }
+
+    #[test]
+    fn empty_stream_is_empty_response() {
+        let parser = TranscriptStreamParser::new();
+        assert!(matches!(
+            parser.finish(&|_| {}),
+            Err(TranscriptionError::EmptyResponse)
+        ));
+    }
+
+    #[test]
+    fn error_event_surfaces_message() {
+        let mut parser = TranscriptStreamParser::new();
+        let err = parser
+            .push(
+                b"data: {\"type\":\"error\",\"error\":{\"message\":\"boom\"}}\n",
+                &|_| {},
+            )
+            .unwrap_err();
+        assert!(matches!(err, TranscriptionError::ApiError { message, .. } if message == "boom"));
+    }
+}
@@ -8,69 +8,227 @@ use std::process::ExitCode;
 
 use clap::Parser;
 
-#[cfg(target_os = "linux")]
+use smart_scribe::application::DEFAULT_CLIPBOARD_SEPARATOR;
+#[cfg(any(target_os = "linux", target_os = "windows"))]
 use smart_scribe::cli::IndicatorPosition;
 use smart_scribe::cli::{
     app::{load_merged_config, run_oneshot},
-    args::{AuthAction, Cli, Commands},
+    args::{AuthAction, Cli, Commands, DaemonAction},
     auth_cmd::{run_auth_status, run_login, run_logout},
+    batch_cmd::run_batch,
+    cache_cmd::handle_cache_command,
+    completions::write_completions,
     config_cmd::handle_config_command,
     daemon_app::run_daemon,
-    daemon_cmd::handle_daemon_command,
+    daemon_cmd::{daemon_log_path, handle_daemon_command, show_daemon_logs},
+    devices_cmd::run_devices_command,
     exit_codes,
-    presenter::Presenter,
-    DaemonOptions, TranscribeOptions,
+    history_cmd::handle_history_command,
+    ipc::IpcEndpoint,
+    logging::{default_daemon_log_path, init_logging, DAEMON_LOG_LEVEL, DEFAULT_LOG_LEVEL},
+    models_cmd::run_models_command,
+    output::DaemonOutputStyle,
+    presenter::{init_color, Presenter},
+    replay_cmd::run_replay,
+    BatchOptions, DaemonOptions, TranscribeOptions,
 };
 use smart_scribe::domain::config::{RawAppConfig, RawLinuxConfig, RawWindowsConfig};
 use smart_scribe::infrastructure::XdgConfigStore;
 
+/// Delimiter between `--repeat` outputs when none is given.
+const DEFAULT_REPEAT_SEPARATOR: &str = "---";
+
 #[tokio::main(flavor = "multi_thread", worker_threads = 2)]
 async fn main() -> ExitCode {
     let cli = Cli::parse();
-    let presenter = Presenter::new(cli.output);
+    init_color(cli.no_color);
+    let presenter = Presenter::new(cli.format)
+        .with_quiet(cli.quiet)
+        .with_json_errors(cli.json_errors);
+    let code = run(cli, &presenter).await;
+    // The one-shot and daemon runners keep their own presenter and report
+    // their own failures; this one only saw errors from the steps above.
+    presenter.report_failure(code);
+    code
+}
+
+/// Dispatch the parsed CLI to a subcommand or runner.
+async fn run(cli: Cli, presenter: &Presenter) -> ExitCode {
+    // The daemon usually runs detached from any terminal, so it logs to a
+    // file unless told otherwise.
+    let log_file = cli
+        .log_file
+        .clone()
+        .or_else(|| cli.daemon.then(default_daemon_log_path));
+    let default_log_level = if cli.daemon {
+        DAEMON_LOG_LEVEL
+    } else {
+        DEFAULT_LOG_LEVEL
+    };
+    if let Err(e) = init_logging(
+        cli.log_level.as_deref(),
+        log_file.as_deref(),
+        default_log_level,
+    ) {
+        presenter.error(&e);
+        return ExitCode::from(exit_codes::USAGE_ERROR);
+    }
 
     // Handle subcommands that don't need the merged AppConfig.
     match cli.command {
-        Some(Commands::Config { action }) => {
-            let store = XdgConfigStore::new();
-            if let Err(e) = handle_config_command(action, &store, &presenter).await {
+        Some(Commands::Config { ref action }) => {
+            let store = XdgConfigStore::at(cli.config_path.as_deref());
+            if let Err(e) = handle_config_command(
+                action.clone(),
+                cli.profile.as_deref(),
+                cli_to_raw(&cli),
+                &store,
+                presenter,
+            )
+            .await
+            {
                 presenter.error(&e.to_string());
                 return ExitCode::from(exit_codes::ERROR);
             }
             return ExitCode::SUCCESS;
         }
+        Some(Commands::Daemon {
+            action: DaemonAction::Logs { lines, follow },
+        }) => {
+            let path = match cli.log_file.clone() {
+                Some(path) => path,
+                None => {
+                    // The token is only needed to ask a running daemon, so
+                    // an unreadable config just means the default path.
+                    let token = load_merged_config(
+                        RawAppConfig::empty(),
+                        cli.profile.as_deref(),
+                        cli.config_path.as_deref(),
+                    )
+                    .await
+                    .ok()
+                    .and_then(|c| c.ipc_token);
+                    daemon_log_path(&cli.ipc.clone().unwrap_or_default(), token).await
+                }
+            };
+            if let Err(e) = show_daemon_logs(&path, lines, follow, presenter).await {
+                presenter.error(&e);
+                return ExitCode::from(exit_codes::ERROR);
+            }
+            return ExitCode::SUCCESS;
+        }
+        Some(Commands::Daemon {
+            action: DaemonAction::Restart,
+        }) if matches!(cli.ipc, Some(IpcEndpoint::Tcp(_))) => {
+            // Restarting spawns the new daemon on this machine, which is not
+            // where a TCP endpoint's daemon runs.
+            presenter.error(
+                "daemon restart only works for a local daemon; restart the remote \
+                 daemon on its own host",
+            );
+            return ExitCode::from(exit_codes::USAGE_ERROR);
+        }
         Some(Commands::Daemon { action }) => {
-            if let Err(e) = handle_daemon_command(action, &presenter).await {
+            // Only the IPC token is needed, but it comes from the same
+            // file/env layers the daemon reads.
+            let config = match load_merged_config(
+                RawAppConfig::empty(),
+                cli.profile.as_deref(),
+                cli.config_path.as_deref(),
+            )
+            .await
+            {
+                Ok(c) => c,
+                Err(e) => {
+                    presenter.error(&format!("Invalid configuration: {}", e));
+                    return ExitCode::from(exit_codes::USAGE_ERROR);
+                }
+            };
+            let endpoint = cli.ipc.clone().unwrap_or_default();
+            if let Err(e) =
+                handle_daemon_command(action, &endpoint, config.ipc_token, presenter).await
+            {
                 presenter.error(&e);
                 return ExitCode::from(exit_codes::ERROR);
             }
             return ExitCode::SUCCESS;
         }
         Some(Commands::Login { from_codex }) => {
-            return run_login(from_codex, cli.output).await;
+            // Only `proxy` is needed, but from the usual layers.
+            let config = match load_merged_config(
+                cli_to_raw(&cli),
+                cli.profile.as_deref(),
+                cli.config_path.as_deref(),
+            )
+            .await
+            {
+                Ok(c) => c,
+                Err(e) => {
+                    presenter.error(&format!("Invalid configuration: {}", e));
+                    return ExitCode::from(exit_codes::USAGE_ERROR);
+                }
+            };
+            return run_login(from_codex, config.proxy.as_deref(), presenter).await;
         }
         Some(Commands::Logout) => {
-            return run_logout(cli.output).await;
+            return run_logout(presenter).await;
         }
         Some(Commands::Auth {
             action: AuthAction::Status,
         }) => {
-            let config = match load_merged_config(RawAppConfig::empty()).await {
+            let config = match load_merged_config(
+                RawAppConfig::empty(),
+                cli.profile.as_deref(),
+                cli.config_path.as_deref(),
+            )
+            .await
+            {
                 Ok(c) => c,
                 Err(e) => {
                     presenter.error(&format!("Invalid configuration: {}", e));
                     return ExitCode::from(exit_codes::USAGE_ERROR);
                 }
             };
-            return run_auth_status(&config, cli.output).await;
+            return run_auth_status(&config, presenter).await;
+        }
+        Some(Commands::Cache { action }) => {
+            if let Err(e) = handle_cache_command(action, presenter) {
+                presenter.error(&e);
+                return ExitCode::from(exit_codes::ERROR);
+            }
+            return ExitCode::SUCCESS;
         }
-        None => {}
+        Some(Commands::History { action }) => {
+            if let Err(e) = handle_history_command(action, presenter).await {
+                presenter.error(&e);
+                return ExitCode::from(exit_codes::ERROR);
+            }
+            return ExitCode::SUCCESS;
+        }
+        Some(Commands::Completions { shell }) => {
+            write_completions(shell, &mut std::io::stdout());
+            return ExitCode::SUCCESS;
+        }
+        // Needs the merged config (model, prompt, auth); handled below.
+        Some(
+            Commands::Batch { .. }
+            | Commands::Models { .. }
+            | Commands::Devices { .. }
+            | Commands::Replay { .. },
+        )
+        | None => {}
     }
 
     // Build the CLI overlay as a RawAppConfig (one place, no cfg blocks).
     let cli_config = cli_to_raw(&cli);
 
-    let config = match load_merged_config(cli_config).await {
+    let config = match load_merged_config(
+        cli_config.clone(),
+        cli.profile.as_deref(),
+        cli.config_path.as_deref(),
+    )
+    .await
+    {
         Ok(c) => c,
         Err(e) => {
             presenter.error(&format!("Invalid configuration: {}", e));
@@ -78,6 +236,38 @@ async fn main() -> ExitCode {
         }
     };
 
+    if let Some(Commands::Models { action }) = cli.command {
+        return run_models_command(action, &config, presenter).await;
+    }
+    if let Some(Commands::Devices { action }) = cli.command {
+        return run_devices_command(action, &config, presenter).await;
+    }
+    if let Some(Commands::Replay { .. }) = cli.command {
+        return run_replay(&config, presenter).await;
+    }
+    if let Some(Commands::Batch {
+        paths,
+        concurrency,
+        output,
+        timeout,
+    }) = cli.command
+    {
+        let options = BatchOptions {
+            paths,
+            concurrency: concurrency as usize,
+            output,
+            timeout,
+        };
+        return run_batch(options, &config, presenter).await;
+    }
+
+    // `--clipboard-append` carries its separator; `None` keeps plain copies.
+    let clipboard_append = cli.clipboard_append.then(|| {
+        cli.clipboard_separator
+            .clone()
+            .unwrap_or_else(|| DEFAULT_CLIPBOARD_SEPARATOR.to_string())
+    });
+
     if cli.daemon {
         // Daemon mode always needs a concrete max duration; fall back to the
         // domain default if neither config nor CLI supplied one.
@@ -91,34 +281,86 @@ async fn main() -> ExitCode {
             .indicator_position
             .parse()
             .unwrap_or_default();
+        #[cfg(target_os = "windows")]
+        let indicator_position: IndicatorPosition = config
+            .platform
+            .windows_indicator_position
+            .parse()
+            .unwrap_or_default();
 
         let options = DaemonOptions {
-            output: cli.output,
+            format: cli.format,
+            quiet: cli.quiet,
+            json_errors: cli.json_errors,
+            ipc: cli.ipc.clone().unwrap_or_default(),
+            cli_overlay: cli_config,
+            profile: cli.profile.clone(),
+            config_path: cli.config_path.clone(),
+            log_file: log_file.clone(),
             max_duration,
+            min_duration: config.min_duration,
+            silence_threshold: config.silence_threshold,
             clipboard: config.clipboard,
+            clipboard_append: clipboard_append.clone(),
             keystroke: config.keystroke,
             keystroke_tool: Some(config.platform.keystroke_tool.clone()),
             paste: config.platform.linux_paste,
             notify: config.notify,
             audio_cue: config.audio_cue,
+            template: config.text_template.clone(),
+            vad_silence: config.daemon_vad.then_some(config.daemon_vad_silence),
+            timing: cli.timing,
             #[cfg(any(target_os = "linux", target_os = "windows"))]
             indicator: config.platform.indicator,
-            #[cfg(target_os = "linux")]
+            #[cfg(any(target_os = "linux", target_os = "windows"))]
             indicator_position,
+            #[cfg(target_os = "linux")]
+            indicator_clickable: config.platform.indicator_clickable,
+            output_style: DaemonOutputStyle {
+                timestamp: config.daemon_output_timestamp,
+                separator: config.daemon_output_separator.clone(),
+                profile: config
+                    .daemon_output_profile
+                    .then(|| cli.profile.clone())
+                    .flatten(),
+            },
         };
 
         run_daemon(options, &config).await
     } else {
         let options = TranscribeOptions {
-            output: cli.output,
+            format: cli.format,
+            quiet: cli.quiet,
+            output_file: cli.output.clone(),
+            append: cli.append,
+            repeat: cli.repeat,
+            repeat_separator: cli
+                .repeat_separator
+                .clone()
+                .unwrap_or_else(|| DEFAULT_REPEAT_SEPARATOR.to_string()),
             duration: config.duration,
             max_duration: config.max_duration,
+            min_duration: config.min_duration,
+            silence_threshold: config.silence_threshold,
+            timeout: cli.timeout,
+            dry_run: cli.dry_run,
+            timestamps: cli.timestamps,
+            raw: cli.raw,
+            timing: cli.timing,
+            force: cli.force,
+            stdin: cli.stdin,
+            stdin_format: cli.stdin_format,
+            countdown: cli.countdown,
             clipboard: config.clipboard,
+            clipboard_append: clipboard_append.clone(),
             keystroke: config.keystroke,
             keystroke_tool: Some(config.platform.keystroke_tool.clone()),
             paste: config.platform.linux_paste,
             notify: config.notify,
+            notify_on_error: config.notify_on_error,
+            json_errors: cli.json_errors,
             audio_cue: config.audio_cue,
+            template: config.text_template.clone(),
         };
 
         run_oneshot(options, &config).await
@@ -131,7 +373,7 @@ async fn main() -> ExitCode {
 /// merge step keeps file/env values intact). Platform-gated CLI flags are
 /// the only `#[cfg]` blocks here; the rest is portable.
 fn cli_to_raw(cli: &Cli) -> RawAppConfig {
-    #[cfg(target_os = "linux")]
+    #[cfg(any(target_os = "linux", target_os = "windows"))]
     let indicator_position = cli.indicator_position.map(|p| {
         match p {
             IndicatorPosition::TopRight => "top-right",
@@ -143,7 +385,7 @@ fn cli_to_raw(cli: &Cli) -> RawAppConfig {
         }
         .to_string()
     });
-    #[cfg(not(target_os = "linux"))]
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
     let indicator_position: Option<String> = None;
 
     #[cfg(target_os = "linux")]
@@ -151,6 +393,11 @@ fn cli_to_raw(cli: &Cli) -> RawAppConfig {
     #[cfg(not(target_os = "linux"))]
     let cli_paste = false;
 
+    #[cfg(target_os = "linux")]
+    let cli_indicator_clickable = cli.indicator_clickable;
+    #[cfg(not(target_os = "linux"))]
+    let cli_indicator_clickable = false;
+
     #[cfg(any(target_os = "linux", target_os = "windows"))]
     let cli_indicator = cli.indicator;
     #[cfg(not(any(target_os = "linux", target_os = "windows")))]
@@ -159,28 +406,84 @@ fn cli_to_raw(cli: &Cli) -> RawAppConfig {
     let linux = Some(RawLinuxConfig {
         keystroke_tool: cli.keystroke_tool.clone(),
         indicator: if cli_indicator { Some(true) } else { None },
-        indicator_position,
+        indicator_position: indicator_position.clone(),
+        indicator_clickable: if cli_indicator_clickable {
+            Some(true)
+        } else {
+            None
+        },
         paste: if cli_paste { Some(true) } else { None },
     });
 
     let windows = Some(RawWindowsConfig {
         indicator: if cli_indicator { Some(true) } else { None },
+        indicator_position,
         show_balloon: None,
     });
 
+    // `replay` overrides the request settings for that one run.
+    let (model, prompt, language) = match &cli.command {
+        Some(Commands::Replay {
+            model,
+            prompt,
+            language,
+        }) => (model.clone(), prompt.clone(), language.clone()),
+        _ => (None, None, None),
+    };
+
     RawAppConfig {
+        version: None,
         auth: None,
         openai_api_key: None,
-        openai_transcribe_model: None,
-        transcribe_prompt: None,
-        transcribe_language: None,
+        openai_transcribe_model: model,
+        openai_base_url: None,
+        proxy: None,
+        transcribe_prompt: prompt,
+        transcribe_language: language,
+        text_prepend: cli.prepend.clone(),
+        text_append: cli.append_text.clone(),
+        post_command: cli.post_command.clone(),
         duration: cli.duration.clone(),
         max_duration: cli.max_duration.clone(),
-        clipboard: if cli.clipboard { Some(true) } else { None },
+        min_duration: cli.min_duration.clone(),
+        silence_check: if cli.no_silence_check {
+            Some(false)
+        } else {
+            None
+        },
+        silence_threshold: None,
+        audio_device: cli.device.clone(),
+        sample_rate: None,
+        sample_format: cli.sample_format.clone(),
+        // Appending is a kind of copying; keeping it in the overlay also
+        // keeps it on across `daemon reload`.
+        clipboard: if cli.clipboard || cli.clipboard_append {
+            Some(true)
+        } else {
+            None
+        },
         keystroke: if cli.keystroke { Some(true) } else { None },
         notify: if cli.notify { Some(true) } else { None },
+        notify_on_error: if cli.notify_on_error {
+            Some(true)
+        } else {
+            None
+        },
         audio_cue: if cli.audio_cue { Some(true) } else { None },
+        cue_volume: None,
+        cache: if cli.cache { Some(true) } else { None },
+        history: if cli.no_history { Some(false) } else { None },
+        history_max_entries: None,
+        daemon_output_timestamp: None,
+        daemon_output_separator: None,
+        daemon_output_profile: None,
+        daemon_vad: if cli.vad { Some(true) } else { None },
+        daemon_vad_silence: None,
+        ipc_token: None,
         linux,
         windows,
+        indicator: None,
+        cues: None,
+        profiles: None,
     }
 }
@@ -16,6 +16,7 @@ fn help_output() {
     assert!(output.status.success());
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(stdout.contains("transcription"));
+    assert!(stdout.contains("--format"));
     assert!(stdout.contains("--output"));
     assert!(stdout.contains("--duration"));
     assert!(stdout.contains("--daemon"));
@@ -134,6 +135,18 @@ fn daemon_duration_conflict() {
     );
 }
 
+#[test]
+fn daemon_restart_rejects_tcp_endpoint() {
+    let output = smart_scribe_bin()
+        .args(["--ipc", "tcp:127.0.0.1:9", "daemon", "restart"])
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("local daemon"), "got: {stderr}");
+}
+
 #[test]
 fn rejects_legacy_domain_flag() {
     let output = smart_scribe_bin()
@@ -159,3 +172,190 @@ fn rejects_legacy_backend_flag() {
 
     assert!(!output.status.success());
 }
+
+// Skipped on Windows for the same reason as the XDG-redirecting tests in
+// error_tests.rs: `dirs::config_dir()` ignores XDG_CONFIG_HOME there.
+#[cfg(not(windows))]
+#[test]
+fn quiet_suppresses_status_output() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let run = |args: &[&str]| {
+        smart_scribe_bin()
+            .env("HOME", dir.path())
+            .env("XDG_CONFIG_HOME", dir.path())
+            .args(args)
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    let loud = run(&["config", "set", "notify", "true"]);
+    assert!(loud.status.success());
+    assert!(!loud.stderr.is_empty(), "expected a success line on stderr");
+
+    let quiet = run(&["--quiet", "config", "set", "clipboard", "true"]);
+    assert!(quiet.status.success());
+    assert!(quiet.stderr.is_empty());
+
+    let list = run(&["-q", "config", "list"]);
+    assert!(list.status.success());
+    assert!(
+        list.stderr.is_empty(),
+        "unexpected stderr: {}",
+        String::from_utf8_lossy(&list.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&list.stdout);
+    assert!(stdout.contains("notify"));
+    assert!(stdout.contains("clipboard"));
+}
+
+/// Poll `cond` every 50ms for up to 10s.
+#[cfg(unix)]
+fn wait_for(mut cond: impl FnMut() -> bool) -> bool {
+    for _ in 0..200 {
+        if cond() {
+            return true;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+    false
+}
+
+#[cfg(unix)]
+#[test]
+fn daemon_sigterm_removes_socket_and_pid_file() {
+    use nix::sys::signal::{kill, Signal};
+    use nix::unistd::Pid;
+    use std::process::Stdio;
+
+    // The socket lives in XDG_RUNTIME_DIR and the PID file in TMPDIR;
+    // pointing both at a tempdir keeps this away from a real daemon.
+    let dir = tempfile::tempdir().expect("tempdir");
+    let mut child = smart_scribe_bin()
+        .env("HOME", dir.path())
+        .env("XDG_CONFIG_HOME", dir.path())
+        .env("XDG_RUNTIME_DIR", dir.path())
+        .env("TMPDIR", dir.path())
+        .arg("--daemon")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("Failed to spawn daemon");
+
+    let socket = dir.path().join("smart-scribe.sock");
+    let pid_file = dir.path().join("smart-scribe.pid");
+    if !wait_for(|| socket.exists()) {
+        let _ = child.kill();
+        panic!("daemon never bound its socket");
+    }
+    assert!(pid_file.exists());
+
+    kill(Pid::from_raw(child.id() as i32), Signal::SIGTERM).expect("send SIGTERM");
+    let mut status = None;
+    wait_for(|| {
+        status = child.try_wait().expect("try_wait");
+        status.is_some()
+    });
+    let Some(status) = status else {
+        let _ = child.kill();
+        panic!("daemon did not exit after SIGTERM");
+    };
+
+    assert!(status.success(), "{status}");
+    assert!(!socket.exists(), "socket left behind");
+    assert!(!pid_file.exists(), "PID file left behind");
+}
+
+#[test]
+fn config_flag_round_trips_set_and_get() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("alt.toml");
+    let run = |args: &[&str]| {
+        smart_scribe_bin()
+            .arg("--config")
+            .arg(&path)
+            .args(args)
+            .env_remove("SMART_SCRIBE_CONFIG")
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    let set = run(&["config", "set", "duration", "45s"]);
+    assert!(
+        set.status.success(),
+        "{}",
+        String::from_utf8_lossy(&set.stderr)
+    );
+    assert!(path.exists());
+
+    let get = run(&["config", "get", "duration"]);
+    assert!(get.status.success());
+    assert_eq!(String::from_utf8_lossy(&get.stdout).trim(), "45s");
+
+    let shown = run(&["config", "path"]);
+    assert!(String::from_utf8_lossy(&shown.stdout).contains("alt.toml"));
+}
+
+#[test]
+fn config_env_var_selects_the_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("env.toml");
+    std::fs::write(&path, "duration = \"12s\"\n").unwrap();
+
+    let output = smart_scribe_bin()
+        .args(["config", "get", "duration"])
+        .env("SMART_SCRIBE_CONFIG", &path)
+        .output()
+        .expect("Failed to execute command");
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "12s");
+}
+
+#[cfg(not(windows))]
+#[test]
+fn replay_without_saved_audio_fails_clearly() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = smart_scribe_bin()
+        .args(["replay", "--model", "whisper-1"])
+        .env("HOME", dir.path())
+        .env("XDG_CONFIG_HOME", dir.path())
+        .env("XDG_DATA_HOME", dir.path())
+        .env("OPENAI_API_KEY", "sk-test-key")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No saved audio to replay"), "{stderr}");
+}
+
+#[cfg(not(windows))]
+#[test]
+fn json_errors_reports_missing_api_key() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    std::fs::write(&config, "auth = \"api_key\"\n").unwrap();
+    let output = smart_scribe_bin()
+        .args(["--json-errors", "--duration", "1s"])
+        .env("SMART_SCRIBE_CONFIG", &config)
+        .env("HOME", dir.path())
+        .env("XDG_DATA_HOME", dir.path())
+        .env("XDG_RUNTIME_DIR", dir.path())
+        .env_remove("OPENAI_API_KEY")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let line = stderr.lines().last().unwrap_or_default();
+    let error: serde_json::Value = serde_json::from_str(line).expect(&stderr);
+    assert_eq!(error["error_kind"], "missing_api_key");
+    assert_eq!(error["exit_code"], 3);
+    assert!(
+        error["message"]
+            .as_str()
+            .unwrap()
+            .contains("Missing OpenAI API key"),
+        "{stderr}"
+    );
+    assert!(!stderr.contains('✗'), "human error line printed: {stderr}");
+}
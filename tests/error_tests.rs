@@ -12,8 +12,7 @@ fn smart_scribe_bin() -> Command {
 // real config instead of the tmp `auth = api_key` one. Linux + macOS coverage
 // exercises the same code path.
 #[cfg(not(windows))]
-#[test]
-fn missing_api_key_in_api_mode_errors_quickly() {
+fn run_in_api_mode_without_key() -> std::process::Output {
     // Force `auth = api_key` via a tmp config so the missing-key check fires
     // before any recording starts.
     let dir = tempfile::tempdir().expect("tempdir");
@@ -25,13 +24,19 @@ fn missing_api_key_in_api_mode_errors_quickly() {
     )
     .unwrap();
 
-    let output = smart_scribe_bin()
+    smart_scribe_bin()
         .env_remove("OPENAI_API_KEY")
         .env("HOME", dir.path())
         .env("XDG_CONFIG_HOME", dir.path())
         .args(["-d", "1s"])
         .output()
-        .expect("Failed to execute command");
+        .expect("Failed to execute command")
+}
+
+#[cfg(not(windows))]
+#[test]
+fn missing_api_key_in_api_mode_errors_quickly() {
+    let output = run_in_api_mode_without_key();
 
     assert!(!output.status.success());
     let stderr = String::from_utf8_lossy(&output.stderr);
@@ -42,6 +47,15 @@ fn missing_api_key_in_api_mode_errors_quickly() {
     );
 }
 
+#[cfg(not(windows))]
+#[test]
+fn missing_api_key_exits_with_auth_code() {
+    let output = run_in_api_mode_without_key();
+
+    // exit_codes::AUTH
+    assert_eq!(output.status.code(), Some(3));
+}
+
 #[test]
 fn config_get_unknown_key() {
     let output = smart_scribe_bin()
@@ -9,7 +9,7 @@ fn smart_scribe_bin() -> Command {
 #[test]
 fn config_path_supports_json_output() {
     let output = smart_scribe_bin()
-        .args(["--output", "json", "config", "path"])
+        .args(["--format", "json", "config", "path"])
         .output()
         .expect("Failed to execute command");
 
@@ -25,7 +25,7 @@ fn config_path_supports_json_output() {
 #[test]
 fn config_list_supports_json_output() {
     let output = smart_scribe_bin()
-        .args(["--output", "json", "config", "list"])
+        .args(["--format", "json", "config", "list"])
         .env("HOME", "/nonexistent")
         .env("XDG_CONFIG_HOME", "/nonexistent")
         .output()
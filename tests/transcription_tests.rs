@@ -80,3 +80,119 @@ fn audio_data_formats() {
     let webm = AudioData::new(vec![1, 2, 3], AudioMimeType::Webm);
     assert_eq!(webm.mime_type().to_string(), "audio/webm");
 }
+
+#[tokio::test]
+async fn transcribe_stream_reports_deltas_from_sse_body() {
+    use std::sync::{Arc, Mutex};
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    let body = concat!(
+        "data: {\"type\":\"transcript.text.delta\",\"delta\":\"Hello\"}\n\n",
+        "data: {\"type\":\"transcript.text.delta\",\"delta\":\" there\"}\n\n",
+        "data: {\"type\":\"transcript.text.done\",\"text\":\"Hello there.\"}\n\n",
+    );
+    Mock::given(method("POST"))
+        .and(path("/v1/audio/transcriptions"))
+        .and(header("authorization", "Bearer sk-test"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let transcriber = OpenAiApiTranscriber::new("sk-test", "gpt-4o-transcribe")
+        .with_base_url(format!("{}/v1", server.uri()));
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&seen);
+    let text = transcriber
+        .transcribe_stream(
+            &create_test_audio(),
+            Arc::new(move |d: &str| sink.lock().unwrap().push(d.to_string())),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(text, "Hello there.");
+    assert_eq!(*seen.lock().unwrap(), vec!["Hello", " there"]);
+}
+
+#[tokio::test]
+async fn list_models_parses_listing_and_maps_auth_errors() {
+    use smart_scribe::application::ports::TranscriptionError;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/models"))
+        .and(header("authorization", "Bearer sk-test"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "object": "list",
+            "data": [
+                {"id": "whisper-1", "owned_by": "openai-internal"},
+                {"id": "gpt-4o", "owned_by": "system"},
+                {"id": "gpt-4o-transcribe", "owned_by": "system"},
+            ],
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/models"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    let base_url = format!("{}/v1", server.uri());
+    let models = OpenAiApiTranscriber::new("sk-test", "gpt-4o-transcribe")
+        .with_base_url(&base_url)
+        .list_models()
+        .await
+        .unwrap();
+    let ids: Vec<_> = models.iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(ids, ["gpt-4o", "gpt-4o-transcribe", "whisper-1"]);
+    assert!(!models[0].transcription);
+    assert!(models[1].transcription);
+
+    let err = OpenAiApiTranscriber::new("sk-wrong", "m")
+        .with_base_url(&base_url)
+        .list_models()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, TranscriptionError::InvalidApiKey), "{err}");
+}
+
+#[tokio::test]
+async fn raw_sink_receives_redacted_body_and_disables_streaming() {
+    use std::sync::{Arc, Mutex};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/audio/transcriptions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "text": " Hello. ",
+            "debug": "key=sk-secret",
+        })))
+        .mount(&server)
+        .await;
+
+    let dumps = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&dumps);
+    let transcriber = OpenAiApiTranscriber::new("sk-secret", "gpt-4o-transcribe")
+        .with_base_url(format!("{}/v1", server.uri()))
+        .with_raw_sink(Arc::new(move |body: &str| {
+            sink.lock().unwrap().push(body.to_string())
+        }));
+    let text = transcriber
+        .transcribe_stream(&create_test_audio(), Arc::new(|_: &str| {}))
+        .await
+        .unwrap();
+
+    assert_eq!(text, "Hello.");
+    let dumps = dumps.lock().unwrap();
+    assert_eq!(dumps.len(), 1);
+    assert!(dumps[0].contains("\" Hello. \""), "{}", dumps[0]);
+    assert!(dumps[0].contains("key=[REDACTED]"), "{}", dumps[0]);
+    assert!(!dumps[0].contains("sk-secret"));
+}